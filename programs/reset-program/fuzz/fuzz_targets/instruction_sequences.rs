@@ -0,0 +1,322 @@
+//! Random instruction sequences against an in-memory model of an auction
+//!
+//! Run with `cargo fuzz run instruction_sequences` from `programs/reset-program`.
+//!
+//! The model mirrors the state transitions of `commit`, `decrease_commit`, `set_price`,
+//! `finalize_auction`, `claim`, `withdraw_funds` and `withdraw_fees` with the
+//! program's own allocation math, tracking the vault balances the token transfers
+//! would leave. After every step the accounting must match the vaults, and no vault
+//! may pay out more than it holds. Each sequence ends by settling everything, after
+//! which the vaults may only hold rounding dust.
+#![no_main]
+
+use anchor_lang::prelude::Pubkey;
+use arbitrary::Arbitrary;
+use launchpad_program::{
+    calculate_bin_allocation_ratio, calculate_bin_entitlement, calculate_entitled_withdraw_amounts,
+    calculate_payment_vault_liabilities, calculate_sale_vault_liabilities,
+    calculate_total_entitlements, calculate_withdrawable_fees, AuctionBin, AuctionBinParams,
+    AuctionExtensions, BinKind,
+};
+use libfuzzer_sys::fuzz_target;
+
+const USER_COUNT: usize = 4;
+const BIN_COUNT: usize = 2;
+
+#[derive(Arbitrary, Debug)]
+enum Op {
+    Commit { user: u8, bin: u8, amount: u32 },
+    Decrease { user: u8, bin: u8, amount: u32 },
+    SetPrice { bin: u8, price: u16 },
+    EndCommitPeriod,
+    Finalize,
+    Claim { user: u8, bin: u8, sale_bps: u16, refund_bps: u16 },
+    WithdrawFunds,
+    WithdrawFees,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    prices: [u16; BIN_COUNT],
+    caps: [u32; BIN_COUNT],
+    claim_fee_bps: u8,
+    ops: Vec<Op>,
+}
+
+/// A user's commitment to a bin
+#[derive(Clone, Copy, Default)]
+struct UserBin {
+    committed: u64,
+    sale_claimed: u64,
+    refunded: u64,
+}
+
+struct Model {
+    bins: Vec<AuctionBin>,
+    extensions: AuctionExtensions,
+    users: [[UserBin; BIN_COUNT]; USER_COUNT],
+    user_keys: [Pubkey; USER_COUNT],
+    commit_open: bool,
+    finalized: bool,
+    funds_withdrawn: bool,
+    total_sale_entitled: u64,
+    total_refund_entitled: u64,
+    total_payment_refunded: u64,
+    total_payment_withdrawn: u64,
+    total_fees_collected: u64,
+    total_fees_withdrawn: u64,
+    sale_vault: u64,
+    payment_vault: u64,
+}
+
+/// Take `amount` out of a vault, failing the run if it doesn't hold that much
+fn pay_out(vault: &mut u64, amount: u64, name: &str) {
+    *vault = vault
+        .checked_sub(amount)
+        .unwrap_or_else(|| panic!("{name} vault overdrawn: {} < {amount}", *vault));
+}
+
+impl Model {
+    /// Auction with the sale tokens of every bin deposited, in its commit period
+    fn new(input: &Input) -> Self {
+        let bins: Vec<AuctionBin> = (0..BIN_COUNT)
+            .map(|index| {
+                let params = AuctionBinParams {
+                    sale_token_price: input.prices[index].max(1) as u64,
+                    sale_token_cap: input.caps[index].max(1) as u64,
+                    free_claim_amount: 0,
+                    kind: BinKind::Sale,
+                    hard_cap: false,
+                    round: 0,
+                    claim_fee_rate: None,
+                };
+                AuctionBin::from_params(params, 0, 0)
+            })
+            .collect();
+        let extensions = AuctionExtensions {
+            claim_fee_rate: Some(input.claim_fee_bps as u64),
+            ..Default::default()
+        };
+        let sale_vault = bins.iter().map(|bin| bin.sale_token_cap).sum();
+
+        Self {
+            bins,
+            extensions,
+            users: [[UserBin::default(); BIN_COUNT]; USER_COUNT],
+            user_keys: std::array::from_fn(|index| Pubkey::new_from_array([index as u8 + 1; 32])),
+            commit_open: true,
+            finalized: false,
+            funds_withdrawn: false,
+            total_sale_entitled: 0,
+            total_refund_entitled: 0,
+            total_payment_refunded: 0,
+            total_payment_withdrawn: 0,
+            total_fees_collected: 0,
+            total_fees_withdrawn: 0,
+            sale_vault,
+            payment_vault: 0,
+        }
+    }
+
+    /// Apply an operation, ignoring the ones the program would reject
+    fn apply(&mut self, op: &Op) {
+        match *op {
+            Op::Commit { user, bin, amount } => self.commit(user, bin, amount as u64),
+            Op::Decrease { user, bin, amount } => self.decrease(user, bin, amount as u64),
+            Op::SetPrice { bin, price } => self.set_price(bin, price as u64),
+            Op::EndCommitPeriod => self.commit_open = false,
+            Op::Finalize => self.finalize(),
+            Op::Claim {
+                user,
+                bin,
+                sale_bps,
+                refund_bps,
+            } => self.claim(user, bin, sale_bps.min(10000), refund_bps.min(10000)),
+            Op::WithdrawFunds => self.withdraw_funds(),
+            Op::WithdrawFees => self.withdraw_fees(),
+        }
+    }
+
+    fn commit(&mut self, user: u8, bin: u8, amount: u64) {
+        if !self.commit_open || amount == 0 {
+            return;
+        }
+        let (user, bin) = (user as usize % USER_COUNT, bin as usize % BIN_COUNT);
+        self.users[user][bin].committed += amount;
+        self.bins[bin].payment_token_raised += amount;
+        self.payment_vault += amount;
+    }
+
+    fn decrease(&mut self, user: u8, bin: u8, amount: u64) {
+        let (user, bin) = (user as usize % USER_COUNT, bin as usize % BIN_COUNT);
+        if !self.commit_open || amount == 0 || amount > self.users[user][bin].committed {
+            return;
+        }
+        self.users[user][bin].committed -= amount;
+        self.bins[bin].payment_token_raised -= amount;
+        pay_out(&mut self.payment_vault, amount, "payment");
+    }
+
+    fn set_price(&mut self, bin: u8, price: u64) {
+        if self.finalized || price == 0 {
+            return;
+        }
+        self.bins[bin as usize % BIN_COUNT].sale_token_price = price;
+    }
+
+    fn finalize(&mut self) {
+        if self.commit_open || self.finalized {
+            return;
+        }
+        let entitlements = calculate_total_entitlements(&self.bins, false).unwrap();
+        self.total_sale_entitled = entitlements.total_sale_entitled;
+        self.total_refund_entitled = entitlements.total_refund_entitled;
+        for bin in self.bins.iter_mut() {
+            bin.allocation_ratio = calculate_bin_allocation_ratio(bin).unwrap().raw_ratio();
+        }
+        self.finalized = true;
+    }
+
+    fn claim(&mut self, user: u8, bin: u8, sale_bps: u16, refund_bps: u16) {
+        if self.commit_open {
+            return;
+        }
+        let (user, bin) = (user as usize % USER_COUNT, bin as usize % BIN_COUNT);
+        let commitment = self.users[user][bin];
+        if commitment.committed == 0 {
+            return;
+        }
+        let auction_bin = &self.bins[bin];
+        let amounts =
+            calculate_bin_entitlement(commitment.committed, 0, 0, auction_bin, false).unwrap();
+        amounts.validate(commitment.committed).unwrap();
+
+        let remaining_sale_tokens = amounts.sale_tokens.saturating_sub(commitment.sale_claimed);
+        let remaining_refund = amounts
+            .refund_payment_tokens
+            .saturating_sub(commitment.refunded);
+        let sale_tokens = (remaining_sale_tokens as u128 * sale_bps as u128 / 10000) as u64;
+        let refund = (remaining_refund as u128 * refund_bps as u128 / 10000) as u64;
+        if sale_tokens == 0 && refund == 0 {
+            return;
+        }
+
+        let fee = self.extensions.calculate_claim_fee(
+            &self.user_keys[user],
+            sale_tokens,
+            self.extensions.bin_claim_fee_rate(auction_bin),
+            0,
+            0,
+        );
+        pay_out(&mut self.sale_vault, sale_tokens - fee, "sale");
+        pay_out(&mut self.payment_vault, refund, "payment");
+
+        self.users[user][bin].sale_claimed += sale_tokens;
+        self.users[user][bin].refunded += refund;
+        self.bins[bin].record_claim(sale_tokens, fee).unwrap();
+        self.total_fees_collected += fee;
+        self.total_payment_refunded += refund;
+    }
+
+    fn withdraw_funds(&mut self) {
+        if !self.finalized || self.funds_withdrawn {
+            return;
+        }
+        let amounts = calculate_entitled_withdraw_amounts(
+            &self.bins,
+            self.total_sale_entitled,
+            self.total_refund_entitled,
+        )
+        .unwrap();
+        pay_out(
+            &mut self.payment_vault,
+            amounts.total_payment_tokens,
+            "payment",
+        );
+        pay_out(
+            &mut self.sale_vault,
+            amounts.total_unsold_sale_tokens,
+            "sale",
+        );
+        self.total_payment_withdrawn += amounts.total_payment_tokens;
+        self.funds_withdrawn = true;
+    }
+
+    fn withdraw_fees(&mut self) {
+        let fees =
+            calculate_withdrawable_fees(self.total_fees_collected, self.total_fees_withdrawn)
+                .unwrap();
+        pay_out(&mut self.sale_vault, fees, "sale");
+        self.total_fees_withdrawn += fees;
+    }
+
+    /// Settle everything still outstanding
+    fn settle(&mut self) {
+        self.commit_open = false;
+        self.finalize();
+        for user in 0..USER_COUNT {
+            for bin in 0..BIN_COUNT {
+                self.claim(user as u8, bin as u8, 10000, 10000);
+            }
+        }
+        self.withdraw_funds();
+        self.withdraw_fees();
+    }
+
+    /// Sale tokens and payment tokens the vaults still owe
+    fn liabilities(&self) -> (u64, u64) {
+        let sale_liabilities = calculate_sale_vault_liabilities(
+            &self.bins,
+            self.total_sale_entitled,
+            self.total_fees_withdrawn,
+            self.funds_withdrawn,
+        )
+        .unwrap();
+        let total_payment_raised = self.bins.iter().map(|bin| bin.payment_token_raised).sum();
+        let payment_liabilities = calculate_payment_vault_liabilities(
+            total_payment_raised,
+            self.total_payment_refunded,
+            self.total_payment_withdrawn,
+        );
+        (sale_liabilities, payment_liabilities)
+    }
+
+    fn check_invariants(&self) {
+        for (index, bin) in self.bins.iter().enumerate() {
+            // Bin totals match the users' commitments
+            let committed: u64 = self.users.iter().map(|user| user[index].committed).sum();
+            assert_eq!(bin.payment_token_raised, committed, "bin {index} raise");
+            let claimed: u64 = self.users.iter().map(|user| user[index].sale_claimed).sum();
+            assert_eq!(bin.sale_token_claimed, claimed, "bin {index} claims");
+
+            // Claims never sell more than the cap
+            assert!(bin.sale_token_claimed <= bin.sale_token_cap, "bin {index} oversold");
+        }
+
+        // Vaults hold exactly what they owe
+        let (sale_liabilities, payment_liabilities) = self.liabilities();
+        assert_eq!(self.sale_vault, sale_liabilities, "sale vault");
+        assert_eq!(self.payment_vault, payment_liabilities, "payment vault");
+    }
+}
+
+fuzz_target!(|input: Input| {
+    let mut model = Model::new(&input);
+    for op in input.ops.iter() {
+        model.apply(op);
+        model.check_invariants();
+    }
+
+    model.settle();
+    model.check_invariants();
+
+    // Nothing is owed anymore, the vaults only keep rounding dust: a sale token per
+    // commitment, and the payment of a partial sale token per bin and commitment
+    let commitment_count = (USER_COUNT * BIN_COUNT) as u64;
+    let price_sum: u64 = model.bins.iter().map(|bin| bin.sale_token_price).sum();
+    assert!(model.sale_vault <= commitment_count, "sale vault leftover");
+    assert!(
+        model.payment_vault <= price_sum * (USER_COUNT as u64 + 1),
+        "payment vault leftover"
+    );
+});
@@ -42,6 +42,99 @@ impl AllocationRatio {
         Ok(AllocationRatio { ratio })
     }
 
+    /// Calculate the allocation ratio of one tier of a bin with boosted commitments
+    ///
+    /// In an oversubscribed bin, boosted commitments weigh `boost_weight_bps` basis
+    /// points against the others: the weighted raise is
+    /// `raised + boosted_committed * (weight - 1)` and each tier gets its weighted share
+    /// of the target. Once boosted commitments would be allocated in full, they are,
+    /// and the rest of the target is split pro-rata among the other commitments. Both
+    /// tiers together always allocate the whole target.
+    ///
+    /// # Arguments
+    /// * `target_amount` - Target payment tokens to raise for this bin
+    /// * `raised_amount` - Actual payment tokens raised for this bin
+    /// * `boosted_committed` - Payment tokens of boosted commitments, part of `raised_amount`
+    /// * `boost_weight_bps` - Allocation weight of boosted commitments in basis points
+    /// * `boosted` - Whether to calculate the ratio of the boosted tier
+    ///
+    /// # Returns
+    /// * `Ok(AllocationRatio)` - The calculated allocation ratio
+    /// * `Err(Error)` - If calculation fails (overflow, division by zero)
+    pub fn calculate_boosted(
+        target_amount: u64,
+        raised_amount: u64,
+        boosted_committed: u64,
+        boost_weight_bps: u16,
+        boosted: bool,
+    ) -> Result<Self> {
+        if boosted_committed == 0 || boost_weight_bps <= 10000 || raised_amount <= target_amount {
+            return Self::calculate(target_amount, raised_amount);
+        }
+
+        let target = target_amount as u128;
+        let weight = boost_weight_bps as u128;
+        let weighted_raised = (raised_amount as u128)
+            .checked_mul(10000)
+            .and_then(|raised| raised.checked_add(boosted_committed as u128 * (weight - 10000)))
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+        let weighted_target = target * weight;
+
+        let ratio = if weighted_target >= weighted_raised {
+            // Boosted commitments are allocated in full
+            if boosted {
+                PRECISION_FACTOR as u128
+            } else {
+                (target - boosted_committed as u128) * PRECISION_FACTOR as u128
+                    / (raised_amount - boosted_committed) as u128
+            }
+        } else if boosted {
+            weighted_target * PRECISION_FACTOR as u128 / weighted_raised
+        } else {
+            target * 10000 * PRECISION_FACTOR as u128 / weighted_raised
+        };
+
+        Ok(AllocationRatio {
+            ratio: ratio as u64,
+        })
+    }
+
+    /// Calculate the allocation ratio of batch window commitments of a hard-cap bin
+    ///
+    /// Commitments before the batch window fit in the cap and are allocated in full,
+    /// the window's commitments share the rest of the target pro-rata, whatever order
+    /// they landed in.
+    ///
+    /// # Arguments
+    /// * `target_amount` - Target payment tokens to raise for this bin
+    /// * `raised_amount` - Actual payment tokens raised for this bin
+    /// * `window_committed` - Payment tokens committed in the batch window, part of `raised_amount`
+    ///
+    /// # Returns
+    /// * `Ok(AllocationRatio)` - The calculated allocation ratio
+    /// * `Err(Error)` - If calculation fails (underflow, division by zero)
+    pub fn calculate_batch_window(
+        target_amount: u64,
+        raised_amount: u64,
+        window_committed: u64,
+    ) -> Result<Self> {
+        let pre_window_raised = raised_amount
+            .checked_sub(window_committed)
+            .ok_or(crate::errors::LauchpadError::MathUnderflow)?;
+        let window_target = target_amount
+            .checked_sub(pre_window_raised)
+            .ok_or(crate::errors::LauchpadError::MathUnderflow)?;
+
+        Self::calculate(window_target, window_committed)
+    }
+
+    /// The allocation ratio `finalize_auction` cached on the bin
+    pub fn cached(bin: &crate::state::AuctionBin) -> Self {
+        AllocationRatio {
+            ratio: bin.allocation_ratio,
+        }
+    }
+
     /// Apply allocation ratio to a user's commitment
     ///
     /// # Arguments
@@ -80,6 +173,48 @@ impl AllocationRatio {
     }
 }
 
+/// Calculate the allocation ratio of a bin at finalization
+///
+/// A bin without any commitment has nothing to allocate and is treated as fully allocated.
+/// With boosted commitments, this is the ratio of the commitments that aren't boosted,
+/// with batch window commitments the ratio of the window.
+///
+/// # Arguments
+/// * `bin` - The auction bin
+///
+/// # Returns
+/// * `Ok(AllocationRatio)` - The calculated allocation ratio
+/// * `Err(Error)` - If calculation fails
+pub fn calculate_bin_allocation_ratio(bin: &crate::state::AuctionBin) -> Result<AllocationRatio> {
+    // Donation bins keep every committed payment token, free-claim bins take none
+    if bin.payment_token_raised == 0 || bin.kind != crate::state::BinKind::Sale {
+        return Ok(AllocationRatio {
+            ratio: PRECISION_FACTOR,
+        });
+    }
+
+    let bin_target = bin
+        .sale_token_cap
+        .checked_mul(bin.sale_token_price)
+        .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+
+    if bin.window_committed > 0 {
+        return AllocationRatio::calculate_batch_window(
+            bin_target,
+            bin.payment_token_raised,
+            bin.window_committed,
+        );
+    }
+
+    AllocationRatio::calculate_boosted(
+        bin_target,
+        bin.payment_token_raised,
+        bin.boosted_committed,
+        bin.boost_weight_bps,
+        false,
+    )
+}
+
 /// Calculate claimable amounts for a user in a specific bin
 ///
 /// This is the main function that implements the allocation logic from the spec:
@@ -106,6 +241,15 @@ pub fn calculate_claimable_amounts(
     // Calculate allocation ratio for this bin
     let ratio = AllocationRatio::calculate(bin_target, bin_raised)?;
 
+    calculate_claimable_amounts_at_ratio(user_committed, ratio, sale_token_price)
+}
+
+/// Calculate claimable amounts for a user in a sale bin allocated at `ratio`
+pub fn calculate_claimable_amounts_at_ratio(
+    user_committed: u64,
+    ratio: AllocationRatio,
+    sale_token_price: u64,
+) -> Result<ClaimableAmounts> {
     // Apply ratio to user's commitment to get effective payment amount
     let (effective_payment, refund_payment) = ratio.apply_to_commitment(user_committed)?;
 
@@ -119,9 +263,240 @@ pub fn calculate_claimable_amounts(
         refund_payment_tokens: refund_payment,
         effective_payment_tokens: effective_payment,
         allocation_ratio: ratio,
+        boosted_sale_tokens: 0,
+    })
+}
+
+/// Calculate claimable amounts for a user in a sale bin with boosted commitments
+///
+/// The boosted part of the user's commitment is allocated at the boosted tier's ratio
+/// and the rest at `regular_ratio`, see `AllocationRatio::calculate_boosted`. Sale
+/// tokens of each part are rounded down separately, as they unlock at different times.
+pub fn calculate_boosted_claimable_amounts(
+    user_committed: u64,
+    user_boosted: u64,
+    bin: &crate::state::AuctionBin,
+    regular_ratio: AllocationRatio,
+) -> Result<ClaimableAmounts> {
+    let bin_target = bin.payment_token_target()?;
+    let boosted_ratio = AllocationRatio::calculate_boosted(
+        bin_target,
+        bin.payment_token_raised,
+        bin.boosted_committed,
+        bin.boost_weight_bps,
+        true,
+    )?;
+
+    let regular_committed = user_committed
+        .checked_sub(user_boosted)
+        .ok_or(crate::errors::LauchpadError::MathUnderflow)?;
+    let (regular_effective, regular_refund) =
+        regular_ratio.apply_to_commitment(regular_committed)?;
+    let (boosted_effective, boosted_refund) = boosted_ratio.apply_to_commitment(user_boosted)?;
+
+    let regular_sale_tokens = regular_effective
+        .checked_div(bin.sale_token_price)
+        .ok_or(crate::errors::LauchpadError::DivisionByZero)?;
+    let boosted_sale_tokens = boosted_effective
+        .checked_div(bin.sale_token_price)
+        .ok_or(crate::errors::LauchpadError::DivisionByZero)?;
+
+    Ok(ClaimableAmounts {
+        sale_tokens: regular_sale_tokens + boosted_sale_tokens,
+        refund_payment_tokens: regular_refund + boosted_refund,
+        effective_payment_tokens: regular_effective + boosted_effective,
+        allocation_ratio: regular_ratio,
+        boosted_sale_tokens,
+    })
+}
+
+/// Calculate claimable amounts for a user in a hard-cap bin with batch window commitments
+///
+/// The user's commitments before the window are allocated in full and the ones in
+/// the window at `window_ratio`, see `AllocationRatio::calculate_batch_window`.
+pub fn calculate_batch_window_claimable_amounts(
+    user_committed: u64,
+    user_window: u64,
+    sale_token_price: u64,
+    window_ratio: AllocationRatio,
+) -> Result<ClaimableAmounts> {
+    let pre_window_committed = user_committed
+        .checked_sub(user_window)
+        .ok_or(crate::errors::LauchpadError::MathUnderflow)?;
+    let (window_effective, refund_payment) = window_ratio.apply_to_commitment(user_window)?;
+    let effective_payment = pre_window_committed
+        .checked_add(window_effective)
+        .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+
+    let sale_tokens = effective_payment
+        .checked_div(sale_token_price)
+        .ok_or(crate::errors::LauchpadError::DivisionByZero)?;
+
+    Ok(ClaimableAmounts {
+        sale_tokens,
+        refund_payment_tokens: refund_payment,
+        effective_payment_tokens: effective_payment,
+        allocation_ratio: window_ratio,
+        boosted_sale_tokens: 0,
+    })
+}
+
+/// Calculate claimable amounts for a user in a donation bin
+///
+/// Donations are neither refunded nor rewarded with sale tokens.
+pub fn calculate_donation_amounts(user_committed: u64) -> ClaimableAmounts {
+    ClaimableAmounts {
+        sale_tokens: 0,
+        refund_payment_tokens: 0,
+        effective_payment_tokens: user_committed,
+        allocation_ratio: AllocationRatio {
+            ratio: PRECISION_FACTOR,
+        },
+        boosted_sale_tokens: 0,
+    }
+}
+
+/// Calculate a user's total entitlement in a bin, dispatching on the bin kind
+///
+/// # Arguments
+/// * `user_committed` - Payment tokens the user committed to this bin
+/// * `user_boosted` - Part of `user_committed` that is boosted
+/// * `user_window` - Part of `user_committed` committed in the batch window
+/// * `bin` - The auction bin
+/// * `refund_only` - Whether the auction only refunds commitments
+/// * `finalized` - Whether `finalize_auction` cached the bin's allocation ratio
+///
+/// # Returns
+/// * `Ok(ClaimableAmounts)` - The user's total entitlement, ignoring prior claims
+/// * `Err(Error)` - If calculation fails
+pub fn calculate_bin_entitlement(
+    user_committed: u64,
+    user_boosted: u64,
+    user_window: u64,
+    bin: &crate::state::AuctionBin,
+    refund_only: bool,
+    finalized: bool,
+) -> Result<ClaimableAmounts> {
+    if refund_only {
+        return Ok(calculate_refund_only_amounts(user_committed));
+    }
+
+    // Once finalized, sale bins allocate at the cached ratio rather than one
+    // recalculated from the bin state
+    let ratio = if finalized {
+        AllocationRatio::cached(bin)
+    } else {
+        calculate_bin_allocation_ratio(bin)?
+    };
+    match bin.kind {
+        crate::state::BinKind::Sale if bin.window_committed > 0 => {
+            calculate_batch_window_claimable_amounts(
+                user_committed,
+                user_window,
+                bin.sale_token_price,
+                ratio,
+            )
+        }
+        crate::state::BinKind::Sale if bin.boosted_committed > 0 => {
+            calculate_boosted_claimable_amounts(user_committed, user_boosted, bin, ratio)
+        }
+        crate::state::BinKind::Sale => {
+            calculate_claimable_amounts_at_ratio(user_committed, ratio, bin.sale_token_price)
+        }
+        crate::state::BinKind::Donation => Ok(calculate_donation_amounts(user_committed)),
+        crate::state::BinKind::FreeClaim => Ok(calculate_free_claim_amounts(bin)),
+    }
+}
+
+/// Calculate a user's entitlement in a bin once `force_refund_mode` froze claims
+///
+/// Sale tokens stay at what the user already claimed, and the effective payment
+/// of the unclaimed remainder is refunded on top of the oversubscription refund.
+/// Donations remain non-refundable.
+///
+/// # Arguments
+/// * `user_committed` - Payment tokens the user committed to this bin
+/// * `user_boosted` - Part of `user_committed` that is boosted
+/// * `user_window` - Part of `user_committed` committed in the batch window
+/// * `bin` - The auction bin
+/// * `sale_token_claimed` - Sale tokens the user claimed from this bin before the freeze
+/// * `finalized` - Whether `finalize_auction` cached the bin's allocation ratio
+///
+/// # Returns
+/// * `Ok(ClaimableAmounts)` - The user's total entitlement, ignoring prior refunds
+/// * `Err(Error)` - If calculation fails
+pub fn calculate_force_refund_amounts(
+    user_committed: u64,
+    user_boosted: u64,
+    user_window: u64,
+    bin: &crate::state::AuctionBin,
+    sale_token_claimed: u64,
+    finalized: bool,
+) -> Result<ClaimableAmounts> {
+    let amounts = calculate_bin_entitlement(
+        user_committed,
+        user_boosted,
+        user_window,
+        bin,
+        false,
+        finalized,
+    )?;
+    let sale_tokens = sale_token_claimed.min(amounts.sale_tokens);
+
+    // Refund the effective payment pro-rata to the unclaimed sale tokens, rounded down
+    let unclaimed_effective_payment = match amounts.sale_tokens {
+        0 => 0,
+        entitled_sale_tokens => {
+            (amounts.effective_payment_tokens as u128
+                * (entitled_sale_tokens - sale_tokens) as u128
+                / entitled_sale_tokens as u128) as u64
+        }
+    };
+    let refund_payment_tokens = amounts
+        .refund_payment_tokens
+        .checked_add(unclaimed_effective_payment)
+        .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+
+    Ok(ClaimableAmounts {
+        sale_tokens,
+        refund_payment_tokens,
+        effective_payment_tokens: user_committed
+            .checked_sub(refund_payment_tokens)
+            .ok_or(crate::errors::LauchpadError::MathUnderflow)?,
+        allocation_ratio: amounts.allocation_ratio,
+        boosted_sale_tokens: 0,
     })
 }
 
+/// Calculate claimable amounts for a registered user in a free-claim bin
+///
+/// Every registered user claims the bin's fixed amount without paying, so no
+/// division by price is involved.
+pub fn calculate_free_claim_amounts(bin: &crate::state::AuctionBin) -> ClaimableAmounts {
+    ClaimableAmounts {
+        sale_tokens: bin.free_claim_amount,
+        refund_payment_tokens: 0,
+        effective_payment_tokens: 0,
+        allocation_ratio: AllocationRatio {
+            ratio: PRECISION_FACTOR,
+        },
+        boosted_sale_tokens: 0,
+    }
+}
+
+/// Calculate claimable amounts for a user in a refund-only auction
+///
+/// No sale tokens are distributed, the whole commitment is refunded.
+pub fn calculate_refund_only_amounts(user_committed: u64) -> ClaimableAmounts {
+    ClaimableAmounts {
+        sale_tokens: 0,
+        refund_payment_tokens: user_committed,
+        effective_payment_tokens: 0,
+        allocation_ratio: AllocationRatio { ratio: 0 },
+        boosted_sale_tokens: 0,
+    }
+}
+
 /// Result of claimable amount calculation
 #[derive(Debug, Clone)]
 pub struct ClaimableAmounts {
@@ -133,6 +508,9 @@ pub struct ClaimableAmounts {
     pub effective_payment_tokens: u64,
     /// The allocation ratio used for this calculation
     pub allocation_ratio: AllocationRatio,
+    /// Part of `sale_tokens` allocated to boosted commitments, unlocking after the
+    /// boost lock period
+    pub boosted_sale_tokens: u64,
 }
 
 impl ClaimableAmounts {
@@ -191,6 +569,63 @@ pub fn calculate_bin_withdraw_amounts(
     })
 }
 
+/// Calculate withdrawal amounts for a free-claim bin for admin withdraw_funds
+///
+/// No payment tokens are raised, the fixed amounts of registered users count as sold.
+///
+/// # Arguments
+/// * `bin` - The free-claim auction bin
+///
+/// # Returns
+/// * `Ok(WithdrawAmounts)` - Calculated amounts to withdraw
+/// * `Err(Error)` - If calculation fails
+pub fn calculate_free_claim_withdraw_amounts(
+    bin: &crate::state::AuctionBin,
+) -> Result<WithdrawAmounts> {
+    let sale_tokens_sold = bin
+        .free_claim_amount
+        .checked_mul(bin.free_claim_registered)
+        .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+
+    let unsold_sale_tokens = bin
+        .sale_token_cap
+        .checked_sub(sale_tokens_sold)
+        .ok_or(crate::errors::LauchpadError::MathUnderflow)?;
+
+    Ok(WithdrawAmounts {
+        payment_tokens_to_withdraw: 0,
+        unsold_sale_tokens,
+        sale_tokens_sold,
+    })
+}
+
+/// Calculate withdrawal amounts of a bin of any kind for admin withdraw_funds
+///
+/// # Arguments
+/// * `bin` - The auction bin
+///
+/// # Returns
+/// * `Ok(WithdrawAmounts)` - Calculated amounts to withdraw
+/// * `Err(Error)` - If calculation fails
+pub fn calculate_bin_kind_withdraw_amounts(
+    bin: &crate::state::AuctionBin,
+) -> Result<WithdrawAmounts> {
+    match bin.kind {
+        crate::state::BinKind::Sale => calculate_bin_withdraw_amounts(
+            bin.payment_token_raised,
+            bin.sale_token_cap,
+            bin.sale_token_price,
+        ),
+        // Donations are withdrawn in full and sell nothing
+        crate::state::BinKind::Donation => Ok(WithdrawAmounts {
+            payment_tokens_to_withdraw: bin.payment_token_raised,
+            unsold_sale_tokens: 0,
+            sale_tokens_sold: 0,
+        }),
+        crate::state::BinKind::FreeClaim => calculate_free_claim_withdraw_amounts(bin),
+    }
+}
+
 /// Calculate all bins withdraw amounts for admin withdraw_funds
 ///
 /// # Arguments
@@ -204,13 +639,10 @@ pub fn calculate_total_withdraw_amounts(
 ) -> Result<TotalWithdrawAmounts> {
     let mut total_payment_to_withdraw = 0u64;
     let mut total_unsold_sale_tokens = 0u64;
+    let mut total_sale_tokens_sold = 0u64;
 
     for bin in bins.iter() {
-        let bin_amounts = calculate_bin_withdraw_amounts(
-            bin.payment_token_raised,
-            bin.sale_token_cap,
-            bin.sale_token_price,
-        )?;
+        let bin_amounts = calculate_bin_kind_withdraw_amounts(bin)?;
 
         total_payment_to_withdraw = total_payment_to_withdraw
             .checked_add(bin_amounts.payment_tokens_to_withdraw)
@@ -219,19 +651,131 @@ pub fn calculate_total_withdraw_amounts(
         total_unsold_sale_tokens = total_unsold_sale_tokens
             .checked_add(bin_amounts.unsold_sale_tokens)
             .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+
+        total_sale_tokens_sold = total_sale_tokens_sold
+            .checked_add(bin_amounts.sale_tokens_sold)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
     }
 
     Ok(TotalWithdrawAmounts {
         total_payment_tokens: total_payment_to_withdraw,
         total_unsold_sale_tokens,
+        total_sale_tokens_sold,
+    })
+}
+
+/// Calculate all bins withdraw amounts for a refund-only auction
+///
+/// Nothing was sold, so no payment tokens are withdrawn and every bin's sale
+/// token cap is returned as unsold.
+///
+/// # Arguments
+/// * `bins` - All auction bins
+///
+/// # Returns
+/// * `Ok(TotalWithdrawAmounts)` - Total amounts across all bins
+/// * `Err(Error)` - If calculation fails
+pub fn calculate_refund_only_withdraw_amounts(
+    bins: &[crate::state::AuctionBin],
+) -> Result<TotalWithdrawAmounts> {
+    let mut total_unsold_sale_tokens = 0u64;
+
+    for bin in bins.iter() {
+        total_unsold_sale_tokens = total_unsold_sale_tokens
+            .checked_add(bin.sale_token_cap)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+    }
+
+    Ok(TotalWithdrawAmounts {
+        total_payment_tokens: 0,
+        total_unsold_sale_tokens,
+        total_sale_tokens_sold: 0,
+    })
+}
+
+/// Calculate the total entitlements of all users across all bins, recorded at finalize time
+///
+/// # Arguments
+/// * `bins` - All auction bins
+/// * `refund_only` - Whether the auction only refunds commitments
+///
+/// # Returns
+/// * `Ok(TotalEntitlements)` - Sale tokens and payment refunds owed to all users
+/// * `Err(Error)` - If calculation fails
+pub fn calculate_total_entitlements(
+    bins: &[crate::state::AuctionBin],
+    refund_only: bool,
+) -> Result<TotalEntitlements> {
+    let total_amounts = if refund_only {
+        calculate_refund_only_withdraw_amounts(bins)?
+    } else {
+        calculate_total_withdraw_amounts(bins)?
+    };
+
+    let mut total_payment_raised = 0u64;
+    for bin in bins.iter() {
+        total_payment_raised = total_payment_raised
+            .checked_add(bin.payment_token_raised)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+    }
+
+    // Everything raised and not withdrawn by the authority is refunded to users
+    let total_refund_entitled = total_payment_raised
+        .checked_sub(total_amounts.total_payment_tokens)
+        .ok_or(crate::errors::LauchpadError::MathUnderflow)?;
+
+    Ok(TotalEntitlements {
+        total_sale_entitled: total_amounts.total_sale_tokens_sold,
+        total_refund_entitled,
+    })
+}
+
+/// Calculate all bins withdraw amounts for admin withdraw_funds from the recorded
+/// total entitlements, so the authority never takes what is owed to users
+///
+/// # Arguments
+/// * `bins` - All auction bins
+/// * `total_sale_entitled` - Sale tokens owed to all users
+/// * `total_refund_entitled` - Payment tokens owed to all users
+///
+/// # Returns
+/// * `Ok(TotalWithdrawAmounts)` - Total amounts across all bins
+/// * `Err(Error)` - If the entitlements exceed the bins' supply or raised amounts
+pub fn calculate_entitled_withdraw_amounts(
+    bins: &[crate::state::AuctionBin],
+    total_sale_entitled: u64,
+    total_refund_entitled: u64,
+) -> Result<TotalWithdrawAmounts> {
+    let mut total_payment_raised = 0u64;
+    let mut total_sale_token_cap = 0u64;
+    for bin in bins.iter() {
+        total_payment_raised = total_payment_raised
+            .checked_add(bin.payment_token_raised)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+        total_sale_token_cap = total_sale_token_cap
+            .checked_add(bin.sale_token_cap)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+    }
+
+    Ok(TotalWithdrawAmounts {
+        total_payment_tokens: total_payment_raised
+            .checked_sub(total_refund_entitled)
+            .ok_or(crate::errors::LauchpadError::MathUnderflow)?,
+        total_unsold_sale_tokens: total_sale_token_cap
+            .checked_sub(total_sale_entitled)
+            .ok_or(crate::errors::LauchpadError::MathUnderflow)?,
+        total_sale_tokens_sold: total_sale_entitled,
     })
 }
 
 /// Check if all bins are fully claimed by a user
 ///
 /// # Arguments
-/// * `committed_bins` - User's committed bins
+/// * `committed_bins` - Bin slots of the user's `Committed` account, unused ones are skipped
 /// * `auction_bins` - Auction bins for reference
+/// * `refund_only` - Whether the auction only refunds commitments
+/// * `force_refund` - Whether `force_refund_mode` froze sale token claims
+/// * `finalized` - Whether `finalize_auction` cached the bins' allocation ratios
 ///
 /// # Returns
 /// * `Ok(bool)` - True if all bins are fully claimed
@@ -239,26 +783,36 @@ pub fn calculate_total_withdraw_amounts(
 pub fn check_all_bins_fully_claimed(
     committed_bins: &[crate::state::CommittedBin],
     auction_bins: &[crate::state::AuctionBin],
+    refund_only: bool,
+    force_refund: bool,
+    finalized: bool,
 ) -> Result<bool> {
-    for committed_bin in committed_bins.iter() {
+    for committed_bin in committed_bins.iter().filter(|bin| bin.is_active()) {
         // Find the corresponding auction bin
         let auction_bin = auction_bins
             .get(committed_bin.bin_id as usize)
             .ok_or(crate::errors::LauchpadError::InvalidBinId)?;
 
-        // Calculate bin target (sale tokens * price)
-        let bin_target = auction_bin
-            .sale_token_cap
-            .checked_mul(auction_bin.sale_token_price)
-            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
-
         // Calculate user's entitlements for this bin
-        let claimable_amounts = calculate_claimable_amounts(
-            committed_bin.payment_token_committed,
-            bin_target,
-            auction_bin.payment_token_raised,
-            auction_bin.sale_token_price,
-        )?;
+        let claimable_amounts = if force_refund {
+            calculate_force_refund_amounts(
+                committed_bin.payment_token_committed,
+                committed_bin.boosted_committed,
+                committed_bin.window_committed,
+                auction_bin,
+                committed_bin.sale_token_claimed,
+                finalized,
+            )?
+        } else {
+            calculate_bin_entitlement(
+                committed_bin.payment_token_committed,
+                committed_bin.boosted_committed,
+                committed_bin.window_committed,
+                auction_bin,
+                refund_only,
+                finalized,
+            )?
+        };
 
         // Check if this bin is fully claimed
         let bin_fully_claimed = committed_bin.sale_token_claimed >= claimable_amounts.sale_tokens
@@ -272,6 +826,129 @@ pub fn check_all_bins_fully_claimed(
     Ok(true)
 }
 
+/// Calculate the payment tokens needed to sell every bin's whole cap
+///
+/// Claims compute each bin target (cap * price) and withdrawals sum them, so a
+/// configuration whose targets don't fit in u64 would brick claims.
+///
+/// # Arguments
+/// * `bins` - Sale token cap and price of every bin
+///
+/// # Returns
+/// * `Ok(u64)` - Total payment target across all bins
+/// * `Err(Error)` - If a bin target or the total overflows u64
+pub fn calculate_total_bin_target(bins: impl IntoIterator<Item = (u64, u64)>) -> Result<u64> {
+    let mut total_target = 0u64;
+
+    for (sale_token_cap, sale_token_price) in bins {
+        let bin_target = sale_token_cap
+            .checked_mul(sale_token_price)
+            .ok_or(crate::errors::LauchpadError::BinTargetOverflow)?;
+        total_target = total_target
+            .checked_add(bin_target)
+            .ok_or(crate::errors::LauchpadError::BinTargetOverflow)?;
+    }
+
+    Ok(total_target)
+}
+
+/// Calculate the sale tokens the sale vault still owes to users and the authority
+///
+/// Everything deposited (the bin caps) and not transferred out yet by claims,
+/// `withdraw_fees` or `withdraw_funds` is owed, a larger vault balance is surplus.
+/// Claims are counted net of their fees, which stay in the vault until withdrawn,
+/// so fee repairs by `reconcile_fees` don't move the liabilities off the vault.
+///
+/// # Arguments
+/// * `bins` - All auction bins
+/// * `total_sale_entitled` - Sale tokens owed to all users at finalization
+/// * `total_fees_withdrawn` - Claim fees withdrawn by `withdraw_fees`
+/// * `unsold_withdrawn` - Whether `withdraw_funds` withdrew the unsold sale tokens
+///
+/// # Returns
+/// * `Ok(u64)` - Sale tokens owed from the vault
+/// * `Err(Error)` - If calculation fails
+pub fn calculate_sale_vault_liabilities(
+    bins: &[crate::state::AuctionBin],
+    total_sale_entitled: u64,
+    total_fees_withdrawn: u64,
+    unsold_withdrawn: bool,
+) -> Result<u64> {
+    let mut total_sale_token_cap = 0u64;
+    let mut claimed_out = 0u64;
+    for bin in bins.iter() {
+        total_sale_token_cap = total_sale_token_cap
+            .checked_add(bin.sale_token_cap)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+        claimed_out = claimed_out
+            .checked_add(bin.sale_token_distributed)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+    }
+
+    let unsold_out = if unsold_withdrawn {
+        total_sale_token_cap.saturating_sub(total_sale_entitled)
+    } else {
+        0
+    };
+
+    Ok(total_sale_token_cap
+        .saturating_sub(claimed_out)
+        .saturating_sub(total_fees_withdrawn)
+        .saturating_sub(unsold_out))
+}
+
+/// Calculate the payment tokens the payment vault still owes to users and the authority
+///
+/// # Arguments
+/// * `total_payment_raised` - Payment tokens committed across all bins
+/// * `total_payment_refunded` - Payment tokens refunded to users
+/// * `total_payment_withdrawn` - Payment tokens withdrawn by `withdraw_funds`
+///
+/// # Returns
+/// * `u64` - Payment tokens owed from the vault
+pub fn calculate_payment_vault_liabilities(
+    total_payment_raised: u64,
+    total_payment_refunded: u64,
+    total_payment_withdrawn: u64,
+) -> u64 {
+    total_payment_raised
+        .saturating_sub(total_payment_refunded)
+        .saturating_sub(total_payment_withdrawn)
+}
+
+/// Calculate the real sale tokens the redemption vault of a placeholder auction still owes
+///
+/// The vault is funded for every sold sale token, but claim fees and swept sale tokens
+/// never reach a user's swap ledger. Fees collected so far only grow, so this bounds what
+/// users can still swap at any time.
+///
+/// # Arguments
+/// * `bins` - All auction bins
+/// * `total_sale_swept` - Sale tokens swept by `sweep_unclaimed`
+/// * `placeholder_swapped` - Placeholder sale tokens swapped already
+///
+/// # Returns
+/// * `Ok(u64)` - Real sale tokens owed from the redemption vault
+/// * `Err(Error)` - If calculation fails
+pub fn calculate_redemption_vault_liabilities(
+    bins: &[crate::state::AuctionBin],
+    total_sale_swept: u64,
+    placeholder_swapped: u64,
+) -> Result<u64> {
+    let total_amounts = calculate_total_withdraw_amounts(bins)?;
+    let mut fees_collected = 0u64;
+    for bin in bins.iter() {
+        fees_collected = fees_collected
+            .checked_add(bin.fees_collected)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+    }
+    Ok(total_amounts
+        .total_sale_tokens_sold
+        .saturating_sub(fees_collected)
+        .saturating_sub(total_sale_swept)
+        .saturating_sub(placeholder_swapped))
+}
+
 /// Calculate available fees to withdraw
 ///
 /// # Arguments
@@ -290,6 +967,40 @@ pub fn calculate_withdrawable_fees(
         .ok_or(crate::errors::LauchpadError::MathUnderflow.into())
 }
 
+/// Split a withdrawal between a revenue share and the main recipient, e.g. fees between
+/// the staking rewards vault and the fee recipient, or proceeds between the platform
+/// treasury and the project
+///
+/// # Arguments
+/// * `fees` - Amount being withdrawn
+/// * `share_bps` - Revenue share in basis points, rounded down
+///
+/// # Returns
+/// * `Ok((u64, u64))` - Amounts to the revenue share and to the main recipient
+/// * `Err(Error)` - If calculation fails
+pub fn calculate_revenue_share(fees: u64, share_bps: u16) -> Result<(u64, u64)> {
+    let staking_fees = (fees as u128 * share_bps.min(10000) as u128 / 10000) as u64;
+    let recipient_fees = fees
+        .checked_sub(staking_fees)
+        .ok_or(crate::errors::LauchpadError::MathUnderflow)?;
+    Ok((staking_fees, recipient_fees))
+}
+
+/// Calculate the most claim fees a bin can have collected from its claims
+///
+/// Every claim pays at most the bin rate (early commitments pay less, exempt users
+/// nothing) and rounds its fee down, so the fee of all claims at once bounds their sum.
+///
+/// # Arguments
+/// * `sale_token_claimed` - Sale tokens claimed from the bin
+/// * `fee_rate` - Claim fee rate of the bin in basis points
+///
+/// # Returns
+/// * `u64` - Upper bound of the bin's collected fees
+pub fn calculate_max_bin_fees(sale_token_claimed: u64, fee_rate: u64) -> u64 {
+    (sale_token_claimed as u128 * fee_rate as u128 / 10000).min(u64::MAX as u128) as u64
+}
+
 /// Result of bin withdraw amount calculation
 #[derive(Debug, Clone)]
 pub struct WithdrawAmounts {
@@ -308,12 +1019,40 @@ pub struct TotalWithdrawAmounts {
     pub total_payment_tokens: u64,
     /// Total unsold sale tokens across all bins
     pub total_unsold_sale_tokens: u64,
+    /// Total sale tokens sold across all bins
+    pub total_sale_tokens_sold: u64,
+}
+
+/// Result of total entitlement calculation across all bins
+#[derive(Debug, Clone)]
+pub struct TotalEntitlements {
+    /// Total sale tokens owed to users across all bins
+    pub total_sale_entitled: u64,
+    /// Total payment tokens refunded to users across all bins
+    pub total_refund_entitled: u64,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Sale bin without commitments, tests override the fields they exercise
+    fn sale_bin(sale_token_price: u64, sale_token_cap: u64) -> crate::state::AuctionBin {
+        crate::state::AuctionBin::from_params(
+            crate::state::AuctionBinParams {
+                sale_token_price,
+                sale_token_cap,
+                free_claim_amount: 0,
+                kind: crate::state::BinKind::Sale,
+                hard_cap: false,
+                round: 0,
+                claim_fee_rate: None,
+            },
+            0,
+            0,
+        )
+    }
+
     #[test]
     fn test_undersubscribed_allocation() {
         let target = 1000;
@@ -424,16 +1163,12 @@ mod tests {
         use crate::state::AuctionBin;
         let bins = vec![
             AuctionBin {
-                sale_token_price: 1000,
-                sale_token_cap: 10000,
                 payment_token_raised: 8000000, // 8000 tokens at price 1000
-                sale_token_claimed: 0,
+                ..sale_bin(1000, 10000)
             },
             AuctionBin {
-                sale_token_price: 2000,
-                sale_token_cap: 5000,
                 payment_token_raised: 15000000, // 7500 tokens at price 2000 (oversubscribed)
-                sale_token_claimed: 0,
+                ..sale_bin(2000, 5000)
             },
         ];
 
@@ -443,6 +1178,26 @@ mod tests {
         // Bin 2: 5000 tokens sold (capped), 10000000 payment, 0 unsold
         assert_eq!(result.total_payment_tokens, 18000000); // 8000000 + 10000000
         assert_eq!(result.total_unsold_sale_tokens, 2000); // 2000 + 0
+        assert_eq!(result.total_sale_tokens_sold, 13000); // 8000 + 5000
+
+        // Users are owed the sold tokens and the oversubscribed payment of bin 2
+        let entitlements = calculate_total_entitlements(&bins, false).unwrap();
+        assert_eq!(entitlements.total_sale_entitled, 13000);
+        assert_eq!(entitlements.total_refund_entitled, 5000000); // 15000000 - 10000000
+
+        let result = calculate_entitled_withdraw_amounts(
+            &bins,
+            entitlements.total_sale_entitled,
+            entitlements.total_refund_entitled,
+        )
+        .unwrap();
+        assert_eq!(result.total_payment_tokens, 18000000);
+        assert_eq!(result.total_unsold_sale_tokens, 2000);
+
+        // Refund-only auctions owe every payment token and no sale tokens
+        let entitlements = calculate_total_entitlements(&bins, true).unwrap();
+        assert_eq!(entitlements.total_sale_entitled, 0);
+        assert_eq!(entitlements.total_refund_entitled, 23000000);
     }
 
     #[test]
@@ -451,10 +1206,8 @@ mod tests {
 
         // Create mock data
         let auction_bins = vec![AuctionBin {
-            sale_token_price: 1000,
-            sale_token_cap: 10000,
             payment_token_raised: 15000000, // Oversubscribed: 15000 tokens demanded, 10000 cap
-            sale_token_claimed: 0,
+            ..sale_bin(1000, 10000)
         }];
 
         // Calculate actual entitlements using our allocation algorithm
@@ -473,10 +1226,14 @@ mod tests {
             payment_token_committed: user_committed,
             sale_token_claimed: claimable.sale_tokens, // Use actual calculated value
             payment_token_refunded: claimable.refund_payment_tokens, // Use actual calculated value
+            active: 1,
+            ..bytemuck::Zeroable::zeroed()
         }];
 
         // Test fully claimed
-        let result = check_all_bins_fully_claimed(&committed_bins, &auction_bins).unwrap();
+        let result =
+            check_all_bins_fully_claimed(&committed_bins, &auction_bins, false, false, false)
+                .unwrap();
         assert!(result);
 
         // Test not fully claimed (less sale tokens claimed)
@@ -485,9 +1242,18 @@ mod tests {
             payment_token_committed: user_committed,
             sale_token_claimed: claimable.sale_tokens - 1, // Less than entitled
             payment_token_refunded: claimable.refund_payment_tokens,
+            active: 1,
+            ..bytemuck::Zeroable::zeroed()
         }];
 
-        let result = check_all_bins_fully_claimed(&committed_bins_partial, &auction_bins).unwrap();
+        let result = check_all_bins_fully_claimed(
+            &committed_bins_partial,
+            &auction_bins,
+            false,
+            false,
+            false,
+        )
+        .unwrap();
         assert!(!result);
 
         // Test not fully claimed (less refund claimed)
@@ -496,12 +1262,359 @@ mod tests {
             payment_token_committed: user_committed,
             sale_token_claimed: claimable.sale_tokens,
             payment_token_refunded: claimable.refund_payment_tokens - 1, // Less than entitled
+            active: 1,
+            ..bytemuck::Zeroable::zeroed()
         }];
 
-        let result = check_all_bins_fully_claimed(&committed_bins_partial2, &auction_bins).unwrap();
+        let result = check_all_bins_fully_claimed(
+            &committed_bins_partial2,
+            &auction_bins,
+            false,
+            false,
+            false,
+        )
+        .unwrap();
+        assert!(!result);
+
+        // Test refund-only: the sale token claims above no longer count, only the full refund does
+        let result =
+            check_all_bins_fully_claimed(&committed_bins, &auction_bins, true, false, false)
+                .unwrap();
+        assert!(!result);
+
+        let committed_bins_refunded = vec![CommittedBin {
+            bin_id: 0,
+            payment_token_committed: user_committed,
+            sale_token_claimed: 0,
+            payment_token_refunded: user_committed,
+            active: 1,
+            ..bytemuck::Zeroable::zeroed()
+        }];
+        let result = check_all_bins_fully_claimed(
+            &committed_bins_refunded,
+            &auction_bins,
+            true,
+            false,
+            false,
+        )
+        .unwrap();
+        assert!(result);
+
+        // Force refund: the claimed sale tokens count, the regular refund alone doesn't
+        let result = check_all_bins_fully_claimed(
+            &committed_bins_partial,
+            &auction_bins,
+            false,
+            true,
+            false,
+        )
+        .unwrap();
         assert!(!result);
     }
 
+    #[test]
+    fn test_calculate_force_refund_amounts() {
+        use crate::state::{AuctionBin, BinKind};
+
+        // Exactly subscribed: 3000000 committed buys 3000 sale tokens
+        let bin = AuctionBin {
+            payment_token_raised: 10000000,
+            ..sale_bin(1000, 10000)
+        };
+
+        // Nothing claimed: the whole commitment is refunded
+        let amounts = calculate_force_refund_amounts(3000000, 0, 0, &bin, 0, false).unwrap();
+        assert_eq!(amounts.sale_tokens, 0);
+        assert_eq!(amounts.refund_payment_tokens, 3000000);
+        amounts.validate(3000000).unwrap();
+
+        // A quarter claimed: three quarters of the effective payment are refunded
+        let amounts = calculate_force_refund_amounts(3000000, 0, 0, &bin, 750, false).unwrap();
+        assert_eq!(amounts.sale_tokens, 750);
+        assert_eq!(amounts.refund_payment_tokens, 2250000);
+        amounts.validate(3000000).unwrap();
+
+        // Everything claimed: nothing is refunded
+        let amounts = calculate_force_refund_amounts(3000000, 0, 0, &bin, 3000, false).unwrap();
+        assert_eq!(amounts.sale_tokens, 3000);
+        assert_eq!(amounts.refund_payment_tokens, 0);
+
+        // Oversubscribed: the oversubscription refund is kept on top
+        let oversubscribed_bin = AuctionBin {
+            payment_token_raised: 15000000,
+            ..bin.clone()
+        };
+        let regular =
+            calculate_bin_entitlement(3000000, 0, 0, &oversubscribed_bin, false, false).unwrap();
+        let amounts = calculate_force_refund_amounts(
+            3000000,
+            0,
+            0,
+            &oversubscribed_bin,
+            regular.sale_tokens,
+            false,
+        )
+        .unwrap();
+        assert_eq!(amounts.refund_payment_tokens, regular.refund_payment_tokens);
+
+        // Donations stay non-refundable
+        let donation_bin = AuctionBin {
+            kind: BinKind::Donation,
+            sale_token_price: 0,
+            sale_token_cap: 0,
+            ..bin
+        };
+        let amounts =
+            calculate_force_refund_amounts(3000000, 0, 0, &donation_bin, 0, false).unwrap();
+        assert_eq!(amounts.refund_payment_tokens, 0);
+    }
+
+    #[test]
+    fn test_calculate_boosted_bin_entitlement() {
+        use crate::state::AuctionBin;
+
+        // Target 10000000, 2x oversubscribed with 5000000 boosted at double weight:
+        // boosted commitments are allocated 80%, the others 40%
+        let bin = AuctionBin {
+            payment_token_raised: 20000000,
+            boosted_committed: 5000000,
+            boost_weight_bps: 20000,
+            ..sale_bin(1000, 10000)
+        };
+
+        let amounts = calculate_bin_entitlement(5000000, 2000000, 0, &bin, false, false).unwrap();
+        amounts.validate(5000000).unwrap();
+        assert_eq!(amounts.sale_tokens, 2800);
+        assert_eq!(amounts.boosted_sale_tokens, 1600);
+        assert_eq!(amounts.refund_payment_tokens, 2200000);
+
+        // Both tiers together sell the whole cap
+        let regular = calculate_bin_entitlement(15000000, 0, 0, &bin, false, false).unwrap();
+        let boosted = calculate_bin_entitlement(5000000, 5000000, 0, &bin, false, false).unwrap();
+        assert_eq!(
+            regular.sale_tokens + boosted.sale_tokens,
+            bin.sale_token_cap
+        );
+        assert_eq!(
+            calculate_bin_allocation_ratio(&bin).unwrap().raw_ratio(),
+            regular.allocation_ratio.raw_ratio()
+        );
+
+        // Boosted commitments would get more than committed: they are allocated in
+        // full and the rest of the target is split among the others
+        let clamped_bin = AuctionBin {
+            payment_token_raised: 12000000,
+            boosted_committed: 4000000,
+            boost_weight_bps: 30000,
+            price_updated_at: 0,
+            ..bin.clone()
+        };
+        let boosted =
+            calculate_bin_entitlement(4000000, 4000000, 0, &clamped_bin, false, false).unwrap();
+        assert_eq!(boosted.sale_tokens, 4000);
+        assert_eq!(boosted.refund_payment_tokens, 0);
+        let regular = calculate_bin_entitlement(8000000, 0, 0, &clamped_bin, false, false).unwrap();
+        assert_eq!(regular.sale_tokens, 6000);
+        assert_eq!(regular.refund_payment_tokens, 2000000);
+
+        // Undersubscribed bins allocate everything regardless of boosts
+        let undersubscribed_bin = AuctionBin {
+            payment_token_raised: 8000000,
+            ..bin
+        };
+        let amounts =
+            calculate_bin_entitlement(5000000, 2000000, 0, &undersubscribed_bin, false, false)
+                .unwrap();
+        assert_eq!(amounts.sale_tokens, 5000);
+        assert_eq!(amounts.refund_payment_tokens, 0);
+    }
+
+    #[test]
+    fn test_calculate_batch_window_bin_entitlement() {
+        use crate::state::AuctionBin;
+
+        // Hard-cap target 10000000, 6000000 committed before the batch window and
+        // 8000000 in it: the window shares the remaining 4000000, allocated 50%
+        let bin = AuctionBin {
+            payment_token_raised: 14000000,
+            hard_cap: true,
+            window_committed: 8000000,
+            ..sale_bin(1000, 10000)
+        };
+
+        let amounts = calculate_bin_entitlement(5000000, 0, 2000000, &bin, false, false).unwrap();
+        amounts.validate(5000000).unwrap();
+        assert_eq!(amounts.sale_tokens, 4000);
+        assert_eq!(amounts.refund_payment_tokens, 1000000);
+        assert_eq!(
+            calculate_bin_allocation_ratio(&bin).unwrap().raw_ratio(),
+            PRECISION_FACTOR / 2
+        );
+
+        // Commitments before the window and the window together sell the whole cap
+        let pre_window = calculate_bin_entitlement(6000000, 0, 0, &bin, false, false).unwrap();
+        let window = calculate_bin_entitlement(8000000, 0, 8000000, &bin, false, false).unwrap();
+        assert_eq!(pre_window.refund_payment_tokens, 0);
+        assert_eq!(
+            pre_window.sale_tokens + window.sale_tokens,
+            bin.sale_token_cap
+        );
+
+        // An undersubscribed window is allocated in full
+        let undersubscribed_bin = AuctionBin {
+            payment_token_raised: 9000000,
+            window_committed: 3000000,
+            ..bin
+        };
+        let amounts =
+            calculate_bin_entitlement(3000000, 0, 3000000, &undersubscribed_bin, false, false)
+                .unwrap();
+        assert_eq!(amounts.sale_tokens, 3000);
+        assert_eq!(amounts.refund_payment_tokens, 0);
+    }
+
+    #[test]
+    fn test_calculate_bin_allocation_ratio() {
+        use crate::state::AuctionBin;
+        let mut bin = AuctionBin {
+            payment_token_raised: 15000000, // 15000 tokens demanded, 10000 cap
+            ..sale_bin(1000, 10000)
+        };
+
+        let ratio = calculate_bin_allocation_ratio(&bin).unwrap();
+        assert_eq!(ratio.raw_ratio(), 666666666);
+
+        // Undersubscribed
+        bin.payment_token_raised = 5000000;
+        let ratio = calculate_bin_allocation_ratio(&bin).unwrap();
+        assert!(ratio.is_full_allocation());
+
+        // No commitments at all
+        bin.payment_token_raised = 0;
+        let ratio = calculate_bin_allocation_ratio(&bin).unwrap();
+        assert!(ratio.is_full_allocation());
+    }
+
+    #[test]
+    fn test_finalized_entitlement_uses_cached_ratio() {
+        use crate::state::AuctionBin;
+        let mut bin = AuctionBin {
+            payment_token_raised: 20000000, // 2x oversubscribed
+            ..sale_bin(1000, 10000)
+        };
+        let live = calculate_bin_entitlement(4000000, 0, 0, &bin, false, false).unwrap();
+        assert_eq!(live.sale_tokens, 2000);
+        assert_eq!(live.refund_payment_tokens, 2000000);
+
+        // Finalization caches the ratio, the same entitlement follows from it
+        bin.allocation_ratio = calculate_bin_allocation_ratio(&bin).unwrap().raw_ratio();
+        let settled = calculate_bin_entitlement(4000000, 0, 0, &bin, false, true).unwrap();
+        assert_eq!(settled.sale_tokens, live.sale_tokens);
+        assert_eq!(settled.refund_payment_tokens, live.refund_payment_tokens);
+
+        // Bin state drifting afterwards doesn't change what finalized claims pay
+        bin.payment_token_raised = 10000000;
+        let settled = calculate_bin_entitlement(4000000, 0, 0, &bin, false, true).unwrap();
+        assert_eq!(settled.sale_tokens, 2000);
+        let forced = calculate_force_refund_amounts(4000000, 0, 0, &bin, 0, true).unwrap();
+        assert_eq!(forced.refund_payment_tokens, 4000000);
+        assert_eq!(
+            calculate_bin_entitlement(4000000, 0, 0, &bin, false, false)
+                .unwrap()
+                .sale_tokens,
+            4000
+        );
+    }
+
+    #[test]
+    fn test_donation_bin() {
+        use crate::state::{AuctionBin, BinKind};
+        let donation_bin = AuctionBin {
+            payment_token_raised: 5000,
+            kind: BinKind::Donation,
+            ..sale_bin(0, 0)
+        };
+
+        // Donations are neither refunded nor rewarded
+        let amounts = calculate_bin_entitlement(1000, 0, 0, &donation_bin, false, false).unwrap();
+        amounts.validate(1000).unwrap();
+        assert_eq!(amounts.sale_tokens, 0);
+        assert_eq!(amounts.refund_payment_tokens, 0);
+        assert_eq!(amounts.effective_payment_tokens, 1000);
+
+        // ... unless the whole auction is refunded
+        let amounts = calculate_bin_entitlement(1000, 0, 0, &donation_bin, true, false).unwrap();
+        assert_eq!(amounts.refund_payment_tokens, 1000);
+
+        let ratio = calculate_bin_allocation_ratio(&donation_bin).unwrap();
+        assert!(ratio.is_full_allocation());
+
+        // Donations are withdrawn in full and excluded from sold / unsold sale tokens
+        let sale_bin = AuctionBin {
+            payment_token_raised: 8000000,
+            ..sale_bin(1000, 10000)
+        };
+        let result = calculate_total_withdraw_amounts(&[sale_bin, donation_bin]).unwrap();
+        assert_eq!(result.total_payment_tokens, 8005000);
+        assert_eq!(result.total_unsold_sale_tokens, 2000);
+        assert_eq!(result.total_sale_tokens_sold, 8000);
+    }
+
+    #[test]
+    fn test_free_claim_bin() {
+        use crate::state::{AuctionBin, BinKind};
+        let free_claim_bin = AuctionBin {
+            free_claim_amount: 30,
+            free_claim_registered: 10,
+            kind: BinKind::FreeClaim,
+            ..sale_bin(0, 1000)
+        };
+
+        // Registered users claim the fixed amount without paying
+        let amounts = calculate_bin_entitlement(0, 0, 0, &free_claim_bin, false, false).unwrap();
+        amounts.validate(0).unwrap();
+        assert_eq!(amounts.sale_tokens, 30);
+        assert_eq!(amounts.refund_payment_tokens, 0);
+
+        // Nothing is distributed if the auction is refunded
+        let amounts = calculate_bin_entitlement(0, 0, 0, &free_claim_bin, true, false).unwrap();
+        assert_eq!(amounts.sale_tokens, 0);
+
+        let ratio = calculate_bin_allocation_ratio(&free_claim_bin).unwrap();
+        assert!(ratio.is_full_allocation());
+
+        let result = calculate_total_withdraw_amounts(&[free_claim_bin]).unwrap();
+        assert_eq!(result.total_payment_tokens, 0);
+        assert_eq!(result.total_sale_tokens_sold, 300); // 30 * 10
+        assert_eq!(result.total_unsold_sale_tokens, 700); // 1000 - 300
+    }
+
+    #[test]
+    fn test_refund_only_amounts() {
+        let amounts = calculate_refund_only_amounts(1500);
+        amounts.validate(1500).unwrap();
+        assert_eq!(amounts.sale_tokens, 0);
+        assert_eq!(amounts.refund_payment_tokens, 1500);
+        assert_eq!(amounts.effective_payment_tokens, 0);
+
+        use crate::state::AuctionBin;
+        let bins = vec![
+            AuctionBin {
+                payment_token_raised: 8000000,
+                ..sale_bin(1000, 10000)
+            },
+            AuctionBin {
+                payment_token_raised: 15000000,
+                ..sale_bin(2000, 5000)
+            },
+        ];
+
+        let result = calculate_refund_only_withdraw_amounts(&bins).unwrap();
+        assert_eq!(result.total_payment_tokens, 0);
+        assert_eq!(result.total_unsold_sale_tokens, 15000);
+        assert_eq!(result.total_sale_tokens_sold, 0);
+    }
+
     #[test]
     fn test_calculate_withdrawable_fees() {
         // Test normal case
@@ -517,6 +1630,39 @@ mod tests {
         assert_eq!(result, 500);
     }
 
+    #[test]
+    fn test_calculate_revenue_share() {
+        // 25% to stakers
+        assert_eq!(calculate_revenue_share(1000, 2500).unwrap(), (250, 750));
+
+        // Stakers' share rounds down
+        assert_eq!(calculate_revenue_share(3, 5000).unwrap(), (1, 2));
+
+        // Everything to stakers
+        assert_eq!(calculate_revenue_share(1000, 10000).unwrap(), (1000, 0));
+
+        // No fees
+        assert_eq!(calculate_revenue_share(0, 2500).unwrap(), (0, 0));
+    }
+
+    #[test]
+    fn test_calculate_max_bin_fees() {
+        // 1% of the claimed sale tokens
+        assert_eq!(calculate_max_bin_fees(1_000_000, 100), 10_000);
+
+        // Rounded down like each claim's fee
+        assert_eq!(calculate_max_bin_fees(199, 50), 0);
+
+        // Separate claims never collect more than the bound
+        let separate_claims = (0..10)
+            .map(|_| calculate_max_bin_fees(12_345, 250))
+            .sum::<u64>();
+        assert!(separate_claims <= calculate_max_bin_fees(123_450, 250));
+
+        // No fees without a rate
+        assert_eq!(calculate_max_bin_fees(1_000_000, 0), 0);
+    }
+
     #[test]
     fn test_edge_cases_withdraw_amounts() {
         // Test zero payment raised
@@ -531,4 +1677,197 @@ mod tests {
         assert_eq!(result.payment_tokens_to_withdraw, 0);
         assert_eq!(result.unsold_sale_tokens, 0);
     }
+
+    #[test]
+    fn test_calculate_vault_liabilities() {
+        use crate::state::AuctionBin;
+        let bins = vec![AuctionBin {
+            payment_token_raised: 15000000,
+            sale_token_claimed: 4000,
+            fees_collected: 40,
+            sale_token_distributed: 3960,
+            ..sale_bin(1000, 10000)
+        }];
+
+        // 3960 claimed out, fees still held, nothing unsold
+        assert_eq!(
+            calculate_sale_vault_liabilities(&bins, 10000, 0, false).unwrap(),
+            6040
+        );
+        // Fees withdrawn
+        assert_eq!(
+            calculate_sale_vault_liabilities(&bins, 10000, 40, false).unwrap(),
+            6000
+        );
+        // Unsold sale tokens withdrawn
+        assert_eq!(
+            calculate_sale_vault_liabilities(&bins, 8000, 40, true).unwrap(),
+            4000
+        );
+
+        // Fees repaired down by `reconcile_fees` stay in the vault and stay owed
+        let repaired = vec![AuctionBin {
+            fees_collected: 10,
+            ..bins[0].clone()
+        }];
+        assert_eq!(
+            calculate_sale_vault_liabilities(&repaired, 10000, 0, false).unwrap(),
+            6040
+        );
+
+        // Fully settled vaults owe nothing
+        assert_eq!(
+            calculate_sale_vault_liabilities(&bins[..0], 0, 0, true).unwrap(),
+            0
+        );
+        assert_eq!(
+            calculate_payment_vault_liabilities(15000000, 5000000, 10000000),
+            0
+        );
+        assert_eq!(
+            calculate_payment_vault_liabilities(15000000, 2000000, 10000000),
+            3000000
+        );
+    }
+
+    #[test]
+    fn test_calculate_redemption_vault_liabilities() {
+        use crate::state::AuctionBin;
+        // 8000 sold, 4000 claimed by users paying 40 in fees
+        let bins = vec![AuctionBin {
+            payment_token_raised: 8000000,
+            sale_token_claimed: 4000,
+            fees_collected: 40,
+            sale_token_distributed: 3960,
+            ..sale_bin(1000, 10000)
+        }];
+
+        // The 40 fee placeholders are never swapped, their real tokens are surplus
+        assert_eq!(
+            calculate_redemption_vault_liabilities(&bins, 0, 0).unwrap(),
+            7960
+        );
+        assert_eq!(
+            calculate_redemption_vault_liabilities(&bins, 0, 3960).unwrap(),
+            4000
+        );
+
+        // Later claims pay more fees, and swept sale tokens are never swapped either
+        let claimed = vec![AuctionBin {
+            sale_token_claimed: 6000,
+            fees_collected: 60,
+            sale_token_distributed: 5940,
+            ..bins[0].clone()
+        }];
+        assert_eq!(
+            calculate_redemption_vault_liabilities(&claimed, 2000, 3960).unwrap(),
+            1980
+        );
+    }
+
+    #[test]
+    fn test_vault_drains_to_zero_with_claim_fees() {
+        // Bin 0 oversubscribed 2x by two users, bin 1 half sold to one user
+        let mut bins = vec![sale_bin(1000, 10000), sale_bin(500, 4000)];
+        let commitments = [(0usize, 12000000u64), (0, 8000000), (1, 1000000)];
+        for &(bin_id, committed) in commitments.iter() {
+            bins[bin_id].payment_token_raised += committed;
+        }
+        let fee_rate = 100;
+        let mut sale_vault = 14000u64;
+        let mut payment_vault = 21000000u64;
+
+        let entitlements = calculate_total_entitlements(&bins, false).unwrap();
+        for bin in bins.iter_mut() {
+            bin.allocation_ratio = calculate_bin_allocation_ratio(bin).unwrap().raw_ratio();
+        }
+        let (mut fees_collected, mut fees_withdrawn, mut payment_refunded) = (0u64, 0u64, 0u64);
+
+        for &(bin_id, committed) in commitments.iter() {
+            let amounts =
+                calculate_bin_entitlement(committed, 0, 0, &bins[bin_id], false, false).unwrap();
+            let claim_fee = amounts.sale_tokens * fee_rate / 10000;
+            bins[bin_id]
+                .record_claim(amounts.sale_tokens, claim_fee)
+                .unwrap();
+            fees_collected += claim_fee;
+            sale_vault -= amounts.sale_tokens - claim_fee;
+            payment_vault -= amounts.refund_payment_tokens;
+            payment_refunded += amounts.refund_payment_tokens;
+
+            // The vault holds exactly what it owes after every claim
+            assert_eq!(
+                calculate_sale_vault_liabilities(&bins, entitlements.total_sale_entitled, 0, false)
+                    .unwrap(),
+                sale_vault
+            );
+        }
+        assert_eq!(bins[0].sale_token_claimed, 10000);
+        assert_eq!(bins[0].sale_token_distributed, 9900);
+        assert_eq!(bins[0].fees_collected, 100);
+
+        let withdraw_amounts = calculate_entitled_withdraw_amounts(
+            &bins,
+            entitlements.total_sale_entitled,
+            entitlements.total_refund_entitled,
+        )
+        .unwrap();
+        sale_vault -= withdraw_amounts.total_unsold_sale_tokens;
+        payment_vault -= withdraw_amounts.total_payment_tokens;
+        let fees = calculate_withdrawable_fees(fees_collected, fees_withdrawn).unwrap();
+        sale_vault -= fees;
+        fees_withdrawn += fees;
+
+        // Both vaults are drained to zero and owe nothing anymore
+        assert_eq!(sale_vault, 0);
+        assert_eq!(payment_vault, 0);
+        assert_eq!(
+            calculate_sale_vault_liabilities(
+                &bins,
+                entitlements.total_sale_entitled,
+                fees_withdrawn,
+                true
+            )
+            .unwrap(),
+            0
+        );
+        assert_eq!(
+            calculate_payment_vault_liabilities(
+                21000000,
+                payment_refunded,
+                withdraw_amounts.total_payment_tokens
+            ),
+            0
+        );
+    }
+
+    #[test]
+    fn test_calculate_total_bin_target() {
+        // Targets exactly at the u64 boundary fit
+        assert_eq!(
+            calculate_total_bin_target([(u64::MAX, 1)]).unwrap(),
+            u64::MAX
+        );
+        assert_eq!(
+            calculate_total_bin_target([(u64::MAX / 2, 2)]).unwrap(),
+            u64::MAX - 1
+        );
+        assert_eq!(
+            calculate_total_bin_target([(u64::MAX - 1, 1), (1, 1)]).unwrap(),
+            u64::MAX
+        );
+
+        // Donation bins have no target
+        assert_eq!(
+            calculate_total_bin_target([(0, 0), (1000, 5)]).unwrap(),
+            5000
+        );
+
+        // A single bin target overflowing
+        assert!(calculate_total_bin_target([(u64::MAX / 2 + 1, 2)]).is_err());
+        assert!(calculate_total_bin_target([(1 << 32, 1 << 32)]).is_err());
+
+        // Bin targets fitting individually but not in total
+        assert!(calculate_total_bin_target([(u64::MAX, 1), (1, 1)]).is_err());
+    }
 }
@@ -7,6 +7,30 @@ pub enum LauchpadError {
     OperationPaused = 6000,
     #[msg("Only LaunchpadAdmin can access this function")]
     OnlyLaunchpadAdmin = 6001,
+    #[msg("Pause message is too long")]
+    PauseMessageTooLong = 6002,
+    #[msg("Sale token mint is blocked by the launchpad config")]
+    SaleMintBlocked = 6003,
+    #[msg("Program data account doesn't belong to the program")]
+    InvalidProgramData = 6004,
+    #[msg("Timelocked auctions require a queued admin action")]
+    TimelockRequired = 6005,
+    #[msg("Invalid admin action")]
+    InvalidAdminAction = 6006,
+    #[msg("Admin action timelock has not passed")]
+    AdminActionNotReady = 6007,
+    #[msg("Auction is cancelled")]
+    AuctionCancelled = 6008,
+    #[msg("Auctions can only be cancelled before the commit period starts")]
+    CancelAfterCommitStart = 6009,
+    #[msg("Auctions can only be aborted from commit start until claims or withdrawals")]
+    AbortWindowClosed = 6010,
+    #[msg("Auction is aborted")]
+    AuctionAborted = 6011,
+    #[msg("Operation is paused program-wide by the launchpad config")]
+    GloballyPaused = 6012,
+    #[msg("Aborting a finalized auction requires its result and the launchpad stats")]
+    MissingAuctionResult = 6013,
 
     // Common Errors (6100-6199)
     #[msg("Math overflow")]
@@ -19,6 +43,8 @@ pub enum LauchpadError {
     InvalidCalculation = 6103,
     #[msg("Unauthorized")]
     Unauthorized = 6104,
+    #[msg("Invalid address lookup table")]
+    InvalidLookupTable = 6105,
 
     // Init Auction Errors (6200-6299)
     #[msg("Invalid auction time range")]
@@ -27,6 +53,86 @@ pub enum LauchpadError {
     InvalidAuctionBinsLength = 6201,
     #[msg("Auction bin price and cap must be greater than zero")]
     InvalidAuctionBinsPriceOrCap = 6202,
+    #[msg("Claim fees are not supported in IOU mode")]
+    ClaimFeeNotSupportedInIouMode = 6203,
+    #[msg("Donation bin price and cap must be zero")]
+    InvalidDonationBin = 6204,
+    #[msg("Free-claim bin must have zero price and a per-user amount within its cap")]
+    InvalidFreeClaimBin = 6205,
+    #[msg("Free-claim bins require the whitelist to be enabled")]
+    FreeClaimRequiresWhitelist = 6206,
+    #[msg("NFT payment value must be greater than zero")]
+    InvalidNftPaymentConfig = 6207,
+    #[msg("Commit cap scaling requires a commit cap and whitelist authority, and a share of at most 100%")]
+    InvalidCapScalingConfig = 6208,
+    #[msg("Only sale bins can have a hard cap")]
+    InvalidHardCapBin = 6209,
+    #[msg("Bins must be ordered by round starting from round 0")]
+    InvalidBinRounds = 6210,
+    #[msg("Loyalty cap multipliers require a commit cap and must not decrease by tier")]
+    InvalidLoyaltyConfig = 6211,
+    #[msg("Claim fee schedule requires claim fees, a positive window and a discounted early rate")]
+    InvalidClaimFeeSchedule = 6212,
+    #[msg("Keeper bounty must be greater than zero")]
+    InvalidKeeperBounty = 6213,
+    #[msg("Payment token mint is not approved by the launchpad config")]
+    PaymentMintNotApproved = 6214,
+    #[msg("Too many approved payment mints")]
+    TooManyApprovedPaymentMints = 6215,
+    #[msg("Bin cap times price overflows")]
+    BinTargetOverflow = 6216,
+    #[msg("Too many users exempt from claim fees")]
+    TooManyClaimFeeExemptUsers = 6217,
+    #[msg("Reserved refund window must be greater than zero")]
+    InvalidReservedRefundWindow = 6218,
+    #[msg("Per-transaction commit limit must be greater than zero")]
+    InvalidMaxCommitPerTx = 6219,
+    #[msg("Claim grace period must not be negative")]
+    InvalidClaimGracePeriod = 6220,
+    #[msg("Claim start is too close to commit end")]
+    ClaimGracePeriodTooShort = 6221,
+    #[msg("Security deposit must be greater than zero")]
+    InvalidSecurityDeposit = 6222,
+    #[msg("Custody allocation limit must be between 1 and 10000 basis points")]
+    InvalidCustodyAllocationLimit = 6223,
+    #[msg("Too many blocked sale mints")]
+    TooManyBlockedSaleMints = 6224,
+    #[msg("Burned proceeds can't have a proceeds recipient or NFT payments")]
+    InvalidBurnProceeds = 6225,
+    #[msg("Commit boost requires a positive lock period, a weight above 10000 basis points and no NFT payments")]
+    InvalidCommitBoost = 6226,
+    #[msg("Too many whitelist authorities")]
+    TooManyWhitelistAuthorities = 6227,
+    #[msg("Claim timing fee requires a non-negative free period, a positive step and claim fees")]
+    InvalidClaimTimingFee = 6228,
+    #[msg("Late refund pool requires a refund reservation, a positive ramp period and no NFT payments")]
+    InvalidLateRefundPool = 6229,
+    #[msg("Maximum price age must be positive")]
+    InvalidMaxPriceAge = 6230,
+    #[msg("Admin timelock must be positive")]
+    InvalidAdminTimelock = 6231,
+    #[msg("Revenue share must be between 1 and 10000 basis points")]
+    InvalidRevenueShare = 6232,
+    #[msg("Missing or mismatched staking rewards vault")]
+    InvalidStakingRewardsVault = 6233,
+    #[msg("Auction times can only be pushed later, before their phase has ended")]
+    InvalidTimeUpdate = 6234,
+    #[msg("Bins can only be changed before the commit period starts")]
+    BinLayoutLocked = 6235,
+    #[msg("Top-ups must add sale tokens to a sale bin")]
+    InvalidTopUp = 6236,
+    #[msg("Invalid proceeds split")]
+    InvalidProceedsSplit = 6237,
+    #[msg("Invalid batch window")]
+    InvalidBatchWindow = 6238,
+    #[msg("Invalid protocol fee")]
+    InvalidProtocolFee = 6239,
+    #[msg("Invalid commit-time reservation configuration")]
+    InvalidReservationMode = 6240,
+    #[msg("Bin price is locked by sale tokens reserved at it")]
+    ReservedBinPriceLocked = 6241,
+    #[msg("Invalid claim deadline")]
+    InvalidClaimDeadline = 6242,
 
     // Commit / Claim Errors (6300-6399)
     #[msg("Out of commitment period")]
@@ -43,6 +149,52 @@ pub enum LauchpadError {
     InvalidClaimAmount = 6305,
     #[msg("Commit cap exceeded")]
     CommitCapExceeded = 6306,
+    #[msg("Operation not supported for this bin kind")]
+    UnsupportedBinKind = 6307,
+    #[msg("Already registered for this free-claim bin")]
+    FreeClaimAlreadyRegistered = 6308,
+    #[msg("Refund deadline has passed")]
+    RefundDeadlinePassed = 6309,
+    #[msg("Commitment exceeds the per-transaction limit")]
+    CommitPerTxExceeded = 6310,
+    #[msg("Claim order policy requires settling the other leg first")]
+    ClaimOrderViolated = 6311,
+    #[msg("Invalid custody commit batch accounts")]
+    InvalidCustodyCommitBatch = 6312,
+    #[msg("Identity group commit cap exceeded")]
+    IdentityGroupCapExceeded = 6313,
+    #[msg("Custody allocation limit of the bin exceeded")]
+    CustodyAllocationLimitExceeded = 6314,
+    #[msg("Invalid custody claim batch")]
+    InvalidCustodyClaimBatch = 6315,
+    #[msg("Commitment boosting is not enabled")]
+    CommitBoostDisabled = 6316,
+    #[msg("Boosted commitments can't be decreased")]
+    BoostedCommitmentLocked = 6317,
+    #[msg("Sale tokens of boosted commitments are still locked")]
+    SaleTokensLocked = 6318,
+    #[msg("Terms hash doesn't match the auction's sale terms")]
+    TermsHashMismatch = 6319,
+    #[msg("Sale terms can no longer be updated")]
+    TermsHashLocked = 6320,
+    #[msg("Updated sale terms must be accepted before claiming sale tokens")]
+    TermsNotAccepted = 6321,
+    #[msg("Bin price is stale")]
+    PriceStale = 6322,
+    #[msg("Auction only accepts commitments signed at the top level of the transaction")]
+    PdaParticipantForbidden = 6323,
+    #[msg("Auction only accepts commitments from wallets")]
+    ContractParticipantForbidden = 6324,
+    #[msg("No voucher partner configured for this auction")]
+    VouchersDisabled = 6325,
+    #[msg("Commitment must pay for whole sale tokens in reservation mode")]
+    PartialSaleTokenReservation = 6326,
+    #[msg("Claim period has ended")]
+    ClaimPeriodEnded = 6327,
+    #[msg("Claim period has not ended yet")]
+    ClaimPeriodNotEnded = 6328,
+    #[msg("Invalid cross-auction claim accounts")]
+    InvalidCrossAuctionClaim = 6329,
 
     // Withdraw Errors (6400-6499)
     #[msg("In commitment period")]
@@ -51,6 +203,18 @@ pub enum LauchpadError {
     DoubleFundsWithdrawal = 6401,
     #[msg("No claim fees configured for this auction")]
     NoClaimFeesConfigured = 6402,
+    #[msg("Withdraw recipient does not match the configured recipient")]
+    InvalidWithdrawRecipient = 6403,
+    #[msg("No late refund pool configured for this auction")]
+    LateRefundPoolDisabled = 6404,
+    #[msg("Auction is not settled: not finalized, funds not withdrawn or vaults not empty")]
+    AuctionNotSettled = 6405,
+    #[msg("Withdrawal above the co-approval threshold requires the co-signer")]
+    CoSignerRequired = 6406,
+    #[msg("Fee withdrawal amount exceeds the withdrawable fees")]
+    InvalidFeeWithdrawAmount = 6407,
+    #[msg("Vault dust requires a recipient account")]
+    DustRecipientRequired = 6408,
 
     // Signature Verification Errors (6500-6599)
     #[msg("Missing sysvar instructions account")]
@@ -73,4 +237,96 @@ pub enum LauchpadError {
     MissingExpiry = 6509,
     #[msg("Invalid custody authority")]
     InvalidCustodyAuthority = 6510,
+    #[msg("Cap attestation share must be at most 100%")]
+    InvalidCapAttestation = 6511,
+    #[msg("Commit cap scaling is not enabled")]
+    CapScalingDisabled = 6512,
+    #[msg("Identity groups require the whitelist")]
+    IdentityGroupRequiresWhitelist = 6513,
+    #[msg("Nonce floor must be above the user's current nonce")]
+    InvalidNonceFloor = 6514,
+
+    // Redemption Errors (6600-6699)
+    #[msg("Auction is not in IOU mode")]
+    IouModeDisabled = 6600,
+    #[msg("Redemption already enabled")]
+    RedemptionAlreadyEnabled = 6601,
+    #[msg("Redemption not enabled")]
+    RedemptionNotEnabled = 6602,
+    #[msg("Missing redemption account")]
+    MissingRedemptionAccount = 6603,
+    #[msg("Nothing to redeem")]
+    NothingToRedeem = 6604,
+    #[msg("Auction is in IOU mode")]
+    IouModeEnabled = 6605,
+    #[msg("Swap exceeds the placeholder sale tokens claimed by the user")]
+    PlaceholderSwapExceeded = 6606,
+
+    // Refund and Crank Errors (6700-6799)
+    #[msg("Auction is not in refund-only state")]
+    AuctionNotRefundOnly = 6700,
+    #[msg("Invalid refund batch accounts")]
+    InvalidRefundBatch = 6701,
+    #[msg("Auction already refunds all commitments")]
+    RefundModeAlreadyEnabled = 6703,
+    #[msg("Sale token claims are frozen by forced refund mode")]
+    SaleClaimsFrozen = 6704,
+    #[msg("Missing or mismatched security deposit treasury")]
+    InvalidSecurityDepositTreasury = 6705,
+    #[msg("Refunds can't be forced after the effective payment was withdrawn")]
+    FundsAlreadyWithdrawn = 6706,
+
+    // Finalization Errors (6800-6899)
+    #[msg("Auction not finalized")]
+    AuctionNotFinalized = 6800,
+    #[msg("Auction already finalized")]
+    AuctionAlreadyFinalized = 6801,
+
+    // NFT Payment Errors (6900-6999)
+    #[msg("Auction is not in NFT payment mode")]
+    NftPaymentDisabled = 6900,
+    #[msg("Payment tokens are not accepted in NFT payment mode")]
+    NftPaymentEnabled = 6901,
+    #[msg("Invalid NFT metadata account")]
+    InvalidNftMetadata = 6902,
+    #[msg("NFT is not a verified member of the auction collection")]
+    InvalidNftCollection = 6903,
+    #[msg("Mint is not an NFT")]
+    InvalidNftMint = 6904,
+    #[msg("NFT commitments are refunded by returning the escrowed NFT")]
+    NftRefundRequiresReturn = 6905,
+    #[msg("NFT cannot be returned or collected in the current auction state")]
+    InvalidNftEscrowState = 6906,
+
+    // Waitlist Errors (7000-7099)
+    #[msg("Bin has no hard cap")]
+    BinNotHardCapped = 7000,
+    #[msg("Bin has room for the commitment")]
+    BinNotFull = 7001,
+    #[msg("Bin has users waiting, join the waitlist")]
+    WaitlistNotEmpty = 7002,
+    #[msg("Invalid waitlist sequence")]
+    InvalidWaitlistSequence = 7003,
+    #[msg("Invalid waitlist batch accounts")]
+    InvalidWaitlistBatch = 7004,
+
+    // Round Errors (7100-7199)
+    #[msg("Invalid round configuration")]
+    InvalidRoundConfig = 7100,
+    #[msg("Missing or mismatched round account")]
+    InvalidRoundAccount = 7101,
+    #[msg("Round of the bin is not open")]
+    RoundNotOpen = 7102,
+    #[msg("Round has not ended")]
+    RoundNotEnded = 7103,
+
+    // Front-end Errors (7200-7299)
+    #[msg("Front-end is not registered")]
+    FrontendNotRegistered = 7200,
+    #[msg("Too many registered front-ends")]
+    TooManyRegisteredFrontends = 7201,
+    #[msg("Front-end fee share must be at most 10000 bps")]
+    InvalidFrontendFeeShare = 7202,
+    #[msg("Missing or mismatched front-end fees account")]
+    InvalidFrontendFeesAccount = 7203,
 }
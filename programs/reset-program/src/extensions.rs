@@ -1,20 +1,250 @@
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{
-    ed25519_program, sysvar::instructions::load_instruction_at_checked,
+    ed25519_program,
+    instruction::TRANSACTION_LEVEL_STACK_HEIGHT,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
 };
 
 /// Extension configuration data (embedded in Auction)
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
 pub struct AuctionExtensions {
-    /// Whitelist authority for access control
-    pub whitelist_authority: Option<Pubkey>,
+    /// Whitelist authorities for access control (disabled if empty), any of them may
+    /// sign, e.g. blue/green backend keys during rotation, at most `MAX_WHITELIST_AUTHORITIES`
+    pub whitelist_authorities: Vec<Pubkey>,
     /// Per-user commitment cap (if enabled)
     pub commit_cap_per_user: Option<u64>,
-    /// Claim fee rate (if enabled)
+    /// Claim fee rate (if enabled), bins may override it with their own rate
     pub claim_fee_rate: Option<u64>,
+    /// Pre-TGE IOU mode: claims are recorded in a `Redemption` PDA instead of
+    /// transferring sale tokens, which are delivered by `redeem` once minted
+    pub iou_mode: bool,
+    /// Minimum payment tokens raised across all bins (if enabled), below which
+    /// the auction fails and every commitment is refunded
+    pub soft_cap: Option<u64>,
+    /// NFT payment mode (if enabled): users commit by escrowing NFTs from a
+    /// collection instead of payment tokens
+    pub nft_payment: Option<NftPaymentConfig>,
+    /// Commit cap scaling (if enabled): share of `commit_cap_per_user` in basis
+    /// points granted to wallets without a `CommitCapAttestation`
+    pub unattested_cap_bps: Option<u16>,
+    /// Loyalty cap multipliers (if enabled): `commit_cap_per_user` multiplier in
+    /// basis points for `UserProfile` tiers 1 to `LOYALTY_TIER_COUNT`
+    pub loyalty_cap_bps: Option<[u16; LOYALTY_TIER_COUNT]>,
+    /// Early-committer claim fee discount (if enabled), replacing `claim_fee_rate`
+    /// for commitments made early in the commit period
+    pub claim_fee_schedule: Option<ClaimFeeSchedule>,
+    /// Lamports paid to callers of permissionless cranks (if enabled), out of the
+    /// auction account's balance above rent exemption, topped up by the authority
+    pub keeper_bounty: Option<u64>,
+    /// Users exempt from claim fees, e.g. the project treasury or market makers,
+    /// at most `MAX_CLAIM_FEE_EXEMPT_USERS`
+    pub claim_fee_exempt_users: Vec<Pubkey>,
+    /// Owner of the account receiving unsold sale tokens in `withdraw_funds`,
+    /// e.g. a burn or community wallet (the authority if not set)
+    pub unsold_sale_token_recipient: Option<Pubkey>,
+    /// Owner of the account receiving payment token proceeds in `withdraw_funds`,
+    /// e.g. the project treasury (the authority if not set)
+    pub proceeds_recipient: Option<Pubkey>,
+    /// Refund reservation (if enabled): seconds after `claim_start_time` during which
+    /// `withdraw_funds` only releases the payment of settled commitments, keeping
+    /// outstanding refunds in the payment vault
+    pub reserved_refund_window: Option<i64>,
+    /// Maximum payment tokens committed by a single `commit` or `join_waitlist`
+    /// (if enabled), forcing large commitments to be split
+    pub max_commit_per_tx: Option<u64>,
+    /// Project security deposit (if enabled), escrowed in the auction account at
+    /// creation, slashed by `force_refund_mode` and returned by `withdraw_funds` otherwise
+    pub security_deposit: Option<SecurityDeposit>,
+    /// Which leg of `claim` must be settled first while both are outstanding
+    pub claim_order: ClaimOrder,
+    /// Share of each bin's payment target in basis points that custody-routed
+    /// commitments may take (if enabled), preserving room for direct participants
+    pub custody_allocation_limit: Option<u16>,
+    /// Buy-and-burn launch: `withdraw_funds` burns the effective payment tokens
+    /// instead of transferring them to the proceeds recipient
+    pub burn_proceeds: bool,
+    /// Commitment boosting (if enabled): users may lock their sale tokens for longer
+    /// in exchange for a larger share of oversubscribed bins
+    pub commit_boost: Option<CommitBoost>,
+    /// Claim timing fee (if enabled): claims are fee-free for a while after
+    /// `claim_start_time`, then pay a growing share of their committed claim fee
+    pub claim_timing_fee: Option<ClaimTimingFee>,
+    /// Late refund pool (if enabled): refunds stay claimable after the refund deadline,
+    /// with a growing share going to a community pool instead of the proceeds
+    pub late_refund_pool: Option<LateRefundPool>,
+    /// Maximum age in seconds of sale bin prices (if enabled): commitments are refused
+    /// until the price setter refreshes a stale price
+    pub max_price_age: Option<i64>,
+    /// Delay in seconds of `set_price` and `withdraw_funds` (if enabled), queued by
+    /// `queue_admin_action` so committed users can react before they take effect
+    pub admin_timelock: Option<i64>,
+    /// Refuse program-controlled participants: commitments must be top-level instructions,
+    /// since PDAs can only sign through CPIs (wallets calling through other programs too)
+    pub forbid_pda_participants: bool,
+    /// EOA-only participation, e.g. when legal terms require direct human participants:
+    /// commitments are refused from program-owned accounts and PDAs
+    pub eoa_only: bool,
+    /// Owner of the account receiving claim fees in `withdraw_fees`, e.g. the
+    /// platform treasury (the authority if not set), also allowed to withdraw them
+    pub fee_recipient: Option<Pubkey>,
+    /// Four-eyes rule on `withdraw_funds` (if enabled): withdrawals above a threshold
+    /// must also be signed by a co-signer, e.g. the custody or a guardian
+    pub withdraw_co_approval: Option<WithdrawCoApproval>,
+    /// Redemption partner (if enabled), e.g. an exchange distributing entitlements
+    /// off-chain: owner of the account receiving the sale tokens of claim vouchers
+    pub voucher_partner: Option<Pubkey>,
+    /// Platform revenue split (if enabled): share of the payment tokens withdrawn by
+    /// `withdraw_funds` sent to the platform treasury, the rest to the proceeds recipient
+    pub proceeds_split: Option<ProceedsSplit>,
+    /// Batch window in seconds before `commit_end_time` (if enabled): commitments to
+    /// hard-cap bins in the window may exceed the cap and are settled pro-rata among
+    /// themselves, so landing first in the final seconds doesn't pay off
+    pub batch_window: Option<i64>,
+    /// Commitments reserve whole sale tokens at the bin price when made, so bins can't
+    /// be oversubscribed and settle without a pro-rata step
+    pub reserve_at_commit: bool,
+    /// Claim deadline (if enabled): claims close `claim_window` seconds after
+    /// `claim_start_time`, and `sweep_unclaimed` moves what was left to the recovery
+    pub claim_deadline: Option<ClaimDeadline>,
 }
 
+/// Order in which `claim` settles the sale token and refund legs of a commitment
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ClaimOrder {
+    /// Either leg first
+    #[default]
+    Any,
+    /// Refunds are settled before sale tokens leave the vault
+    RefundFirst,
+    /// Sale tokens are claimed before refunds leave the vault
+    SaleFirst,
+}
+
+impl ClaimOrder {
+    /// Whether a claim of `sale_token_to_claim` and `payment_token_to_refund` respects
+    /// the order, given the amounts outstanding before it
+    pub fn allows(
+        &self,
+        sale_token_to_claim: u64,
+        remaining_sale_tokens: u64,
+        payment_token_to_refund: u64,
+        remaining_payment_refund: u64,
+    ) -> bool {
+        match self {
+            Self::Any => true,
+            Self::RefundFirst => {
+                sale_token_to_claim == 0 || payment_token_to_refund >= remaining_payment_refund
+            }
+            Self::SaleFirst => {
+                payment_token_to_refund == 0 || sale_token_to_claim >= remaining_sale_tokens
+            }
+        }
+    }
+}
+
+/// Project security deposit deterring cancelled launches
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SecurityDeposit {
+    /// Lamports escrowed from the authority
+    pub lamports: u64,
+    /// Account receiving the slashed deposit (if set), otherwise it is split among
+    /// participants pro-rata to their commitments as they settle
+    pub treasury: Option<Pubkey>,
+}
+
+/// Deadline of claims, after which unclaimed tokens are swept to a recovery account
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ClaimDeadline {
+    /// Seconds after `claim_start_time` during which sale tokens and refunds can be claimed
+    pub claim_window: i64,
+    /// Owner of the token accounts receiving the swept sale and payment tokens
+    pub recovery: Pubkey,
+}
+
+/// Platform treasury share of the proceeds
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ProceedsSplit {
+    /// Owner of the payment token account receiving the platform share
+    pub treasury: Pubkey,
+    /// Platform share of the withdrawn payment tokens in basis points
+    pub treasury_bps: u16,
+}
+
+/// Co-signer required by large `withdraw_funds`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct WithdrawCoApproval {
+    /// Key co-signing withdrawals above the threshold
+    pub co_signer: Pubkey,
+    /// Payment tokens a withdrawal may take without the co-signer
+    pub threshold: u64,
+}
+
+/// Commitment boost trading a longer lock of sale tokens for allocation weight
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CommitBoost {
+    /// Seconds after `claim_start_time` during which sale tokens of boosted
+    /// commitments stay locked
+    pub lock_period: i64,
+    /// Allocation weight of boosted commitments in basis points, above 10000
+    pub weight_bps: u16,
+}
+
+/// Claim fee schedule rewarding early commitments
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ClaimFeeSchedule {
+    /// Seconds from the commit start time during which commitments get the early rate
+    pub early_window: i64,
+    /// Claim fee rate for early commitments, capped at each bin's regular rate
+    pub early_fee_rate: u64,
+}
+
+/// Claim fee schedule rewarding prompt claims
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ClaimTimingFee {
+    /// Seconds from the claim start time during which claims are fee-free
+    pub free_period: i64,
+    /// Seconds after which the fee rate grows by another `step_fee_rate`
+    pub step_period: i64,
+    /// Fee rate added every `step_period`, capped at the committed claim fee rate
+    pub step_fee_rate: u64,
+}
+
+/// Community pool receiving late refunds
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LateRefundPool {
+    /// Owner of the payment token account receiving the pool
+    pub recipient: Pubkey,
+    /// Seconds after the refund deadline over which the diverted share of late
+    /// refunds grows from nothing to all of it
+    pub ramp_period: i64,
+}
+
+/// NFT payment mode configuration
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct NftPaymentConfig {
+    /// Verified Metaplex collection the escrowed NFTs must belong to
+    pub collection: Pubkey,
+    /// Payment value each escrowed NFT counts as
+    pub payment_value: u64,
+}
+
+/// Wallet-quality attestation payload signed by the whitelist authority
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CapAttestationPayload {
+    /// User public key
+    pub user: Pubkey,
+    /// Auction address
+    pub auction: Pubkey,
+    /// Share of the per-user commit cap granted, in basis points
+    pub cap_bps: u16,
+    /// Signature expiration timestamp
+    pub expiry: u64,
+}
+
+// TODO: whitelist signatures carry no tier yet. Once tiered whitelisting lands (e.g. a
+// tier wrapper like `IdentityGroupWhitelistPayload`), count each tier's participants on
+// their first commit and cap them per tier, so signed tiers can't oversell seats.
 /// Whitelist payload for off-chain signature verification
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct WhitelistPayload {
@@ -32,12 +262,97 @@ pub struct WhitelistPayload {
     pub expiry: u64,
 }
 
+/// Whitelist payload linking the user's wallet to an identity group
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct IdentityGroupWhitelistPayload {
+    /// Commit authorization, as signed without a group
+    pub whitelist: WhitelistPayload,
+    /// Identity group id the commitment counts against
+    pub identity_group: [u8; 32],
+}
+
 impl AuctionExtensions {
+    pub const SPACE: usize = 4
+        + 32 * MAX_WHITELIST_AUTHORITIES
+        + 9
+        + 9
+        + 1
+        + 9
+        + 41
+        + 3
+        + 1
+        + 2 * LOYALTY_TIER_COUNT
+        + 17
+        + 9
+        + 4
+        + 32 * MAX_CLAIM_FEE_EXEMPT_USERS
+        + 33
+        + 33
+        + 9
+        + 9
+        + 42
+        + 1
+        + 3
+        + 1
+        + 11
+        + 25
+        + 41
+        + 9
+        + 9
+        + 1
+        + 1
+        + 33
+        + 41
+        + 33
+        + 35
+        + 9
+        + 1
+        + 41;
+
+    /// Time at which sale tokens of boosted commitments unlock (`claim_start_time` if
+    /// boosting is disabled)
+    pub fn boost_unlock_time(&self, claim_start_time: i64) -> i64 {
+        match &self.commit_boost {
+            Some(boost) => claim_start_time.saturating_add(boost.lock_period),
+            None => claim_start_time,
+        }
+    }
+
+    /// Whether a `withdraw_funds` of `payment_tokens` is approved, signed by `co_signer`
+    pub fn is_withdraw_approved(&self, payment_tokens: u64, co_signer: Option<&Pubkey>) -> bool {
+        match &self.withdraw_co_approval {
+            Some(approval) if payment_tokens > approval.threshold => {
+                co_signer == Some(&approval.co_signer)
+            }
+            _ => true,
+        }
+    }
+
+    /// Whether `amount` fits in a single commit transaction
+    pub fn is_within_commit_per_tx(&self, amount: u64) -> bool {
+        match self.max_commit_per_tx {
+            Some(max_commit_per_tx) => amount <= max_commit_per_tx,
+            None => true,
+        }
+    }
+
     pub fn is_whitelist_enabled(&self) -> bool {
-        self.whitelist_authority.is_some()
+        !self.whitelist_authorities.is_empty()
     }
 
-    /// Verify whitelist signature for commit operation
+    /// Whitelist authorities of a commitment, a round's own authority (if any)
+    /// overriding the auction's
+    pub fn commit_whitelist_authorities<'a>(
+        &'a self,
+        round_whitelist_authority: &'a Option<Pubkey>,
+    ) -> &'a [Pubkey] {
+        match round_whitelist_authority {
+            Some(whitelist_authority) => std::slice::from_ref(whitelist_authority),
+            None => &self.whitelist_authorities,
+        }
+    }
+
+    /// Verify whitelist signature for commit operation, returning the authority that signed
     pub fn verify_whitelist_signature(
         &self,
         sysvar_instructions: &AccountInfo,
@@ -47,8 +362,11 @@ impl AuctionExtensions {
         payment_token_committed: u64,
         current_nonce: u64,
         expiry: u64,
-    ) -> Result<()> {
-        let whitelist_authority = self.whitelist_authority.expect("Whitelist enabled checked");
+    ) -> Result<Pubkey> {
+        require!(
+            self.is_whitelist_enabled(),
+            crate::errors::LauchpadError::WrongWhitelistAuthority
+        );
         self.verify_signature_authorization(
             sysvar_instructions,
             user,
@@ -57,11 +375,12 @@ impl AuctionExtensions {
             payment_token_committed,
             current_nonce,
             expiry,
-            &whitelist_authority,
+            &self.whitelist_authorities,
         )
     }
 
-    /// Generic signature verification for both whitelist and custody authorization
+    /// Generic signature verification for both whitelist and custody authorization,
+    /// returning the authority that signed
     pub fn verify_signature_authorization(
         &self,
         sysvar_instructions: &AccountInfo,
@@ -71,61 +390,77 @@ impl AuctionExtensions {
         payment_token_committed: u64,
         current_nonce: u64,
         expiry: u64,
-        expected_authority: &Pubkey,
-    ) -> Result<()> {
-        // 1. Read the previous instruction (Ed25519 verification instruction)
-        let ix = load_instruction_at_checked(0, sysvar_instructions)
-            .map_err(|_| crate::errors::LauchpadError::MissingSysvarInstructions)?;
+        expected_authorities: &[Pubkey],
+    ) -> Result<Pubkey> {
+        // Construct expected payload using Anchor serialization
+        let expected_payload = WhitelistPayload {
+            user: *user,
+            auction: *auction,
+            bin_id,
+            payment_token_committed,
+            nonce: current_nonce,
+            expiry,
+        };
 
-        // 2. Verify it's an Ed25519 verification instruction
-        require_eq!(
-            ix.program_id,
-            ed25519_program::ID,
-            crate::errors::LauchpadError::WrongProgram
-        );
+        let mut expected_message = Vec::new();
+        expected_payload
+            .serialize(&mut expected_message)
+            .map_err(|_| crate::errors::LauchpadError::SerializationError)?;
 
-        // 3. Parse Ed25519 instruction data manually
-        // Ed25519 instruction format: [num_signatures: u8][signature: 64 bytes][public_key: 32 bytes][message_data_offset: u16][message_instruction_offset: u16][message_data...]
-        let data = &ix.data;
-        require!(
-            data.len() >= 1 + 64 + 32 + 2 + 2,
-            crate::errors::LauchpadError::MalformedEd25519Ix
-        );
+        verify_signed_message(
+            sysvar_instructions,
+            &expected_message,
+            expiry,
+            expected_authorities,
+        )
+    }
 
-        let num_signatures = data[0];
-        require_eq!(
-            num_signatures,
-            1,
-            crate::errors::LauchpadError::MalformedEd25519Ix
-        );
+    /// Verify a whitelist signature linking the commitment to an identity group,
+    /// returning the authority that signed
+    pub fn verify_identity_group_signature(
+        &self,
+        sysvar_instructions: &AccountInfo,
+        whitelist: WhitelistPayload,
+        identity_group: [u8; 32],
+        whitelist_authorities: &[Pubkey],
+    ) -> Result<Pubkey> {
+        let expected_payload = IdentityGroupWhitelistPayload {
+            whitelist,
+            identity_group,
+        };
 
-        // Extract public key (skip num_signatures + signature)
-        let public_key_start = 1 + 64;
-        let public_key = &data[public_key_start..public_key_start + 32];
+        let mut expected_message = Vec::new();
+        expected_payload
+            .serialize(&mut expected_message)
+            .map_err(|_| crate::errors::LauchpadError::SerializationError)?;
+
+        verify_signed_message(
+            sysvar_instructions,
+            &expected_message,
+            expected_payload.whitelist.expiry,
+            whitelist_authorities,
+        )
+    }
 
-        // 4. Verify public key matches expected authority
+    /// Verify a wallet-quality attestation signed by a whitelist authority, returning
+    /// the authority that signed
+    pub fn verify_cap_attestation(
+        &self,
+        sysvar_instructions: &AccountInfo,
+        user: &Pubkey,
+        auction: &Pubkey,
+        cap_bps: u16,
+        expiry: u64,
+    ) -> Result<Pubkey> {
         require!(
-            public_key == expected_authority.to_bytes(),
-            crate::errors::LauchpadError::Unauthorized
+            self.is_whitelist_enabled(),
+            crate::errors::LauchpadError::WrongWhitelistAuthority
         );
 
-        // 5. Extract and verify message
-        let message_data_offset_start = public_key_start + 32;
-        // let _message_data_offset = u16::from_le_bytes([
-        //     data[message_data_offset_start],
-        //     data[message_data_offset_start + 1],
-        // ]) as usize;
-
-        let message_start = message_data_offset_start + 4; // skip message_data_offset and message_instruction_offset
-        let message = &data[message_start..];
-
-        // 6. Construct expected payload using Anchor serialization
-        let expected_payload = WhitelistPayload {
+        let expected_payload = CapAttestationPayload {
             user: *user,
             auction: *auction,
-            bin_id,
-            payment_token_committed,
-            nonce: current_nonce,
+            cap_bps,
             expiry,
         };
 
@@ -134,29 +469,45 @@ impl AuctionExtensions {
             .serialize(&mut expected_message)
             .map_err(|_| crate::errors::LauchpadError::SerializationError)?;
 
-        // 7. Verify message matches signed content
-        require!(
-            message == expected_message.as_slice(),
-            crate::errors::LauchpadError::PayloadMismatch
-        );
-
-        // 8. Check signature hasn't expired
-        let current_time = Clock::get()?.unix_timestamp as u64;
-        require!(
-            current_time <= expiry,
-            crate::errors::LauchpadError::SignatureExpired
-        );
+        verify_signed_message(
+            sysvar_instructions,
+            &expected_message,
+            expiry,
+            &self.whitelist_authorities,
+        )
+    }
 
-        Ok(())
+    /// Per-user commit cap scaled by the user's attested share (if cap scaling enabled)
+    /// and loyalty tier multiplier (if loyalty enabled)
+    pub fn effective_commit_cap(
+        &self,
+        attested_cap_bps: Option<u16>,
+        loyalty_tier: u8,
+    ) -> Option<u64> {
+        let mut commit_cap = self.commit_cap_per_user? as u128;
+        if let Some(unattested_cap_bps) = self.unattested_cap_bps {
+            // Attestations only ever raise the cap above the unattested share
+            let cap_bps =
+                attested_cap_bps.map_or(unattested_cap_bps, |bps| bps.max(unattested_cap_bps));
+            commit_cap = commit_cap * cap_bps as u128 / 10000;
+        }
+        if let (Some(loyalty_cap_bps), 1..) = (self.loyalty_cap_bps, loyalty_tier) {
+            commit_cap = commit_cap * loyalty_cap_bps[loyalty_tier as usize - 1] as u128 / 10000;
+        }
+        Some(commit_cap.min(u64::MAX as u128) as u64)
     }
 
     pub fn check_commit_cap_exceeded(
         &self,
         committed: &Committed,
         additional_payment: u64,
+        attested_cap_bps: Option<u16>,
+        loyalty_tier: u8,
     ) -> Result<()> {
-        if let Some(commit_cap) = self.commit_cap_per_user {
-            let total_payment_committed = committed.total_payment_committed();
+        if let Some(commit_cap) = self.effective_commit_cap(attested_cap_bps, loyalty_tier) {
+            // Pending waitlist escrows count as commitments, promotions aren't re-checked
+            let total_payment_committed =
+                committed.total_payment_committed() + committed.waitlist_pending;
             require!(
                 total_payment_committed + additional_payment <= commit_cap,
                 crate::errors::LauchpadError::CommitCapExceeded
@@ -165,11 +516,778 @@ impl AuctionExtensions {
         Ok(())
     }
 
-    pub fn calculate_claim_fee(&self, sale_token_claimed: u64) -> u64 {
-        if let Some(fee_rate) = self.claim_fee_rate {
-            (sale_token_claimed as u128 * fee_rate as u128 / 10000) as u64
-        } else {
-            0
+    pub fn is_nft_payment_enabled(&self) -> bool {
+        self.nft_payment.is_some()
+    }
+
+    /// Verify the NFT's Metaplex metadata places it in the configured collection
+    pub fn verify_nft_collection(&self, metadata: &AccountInfo, nft_mint: &Pubkey) -> Result<()> {
+        let nft_payment = self
+            .nft_payment
+            .as_ref()
+            .ok_or(crate::errors::LauchpadError::NftPaymentDisabled)?;
+
+        // 1. Verify the metadata account is the mint's Metaplex metadata PDA
+        let (expected_metadata, _) = Pubkey::find_program_address(
+            &[
+                b"metadata",
+                TOKEN_METADATA_PROGRAM_ID.as_ref(),
+                nft_mint.as_ref(),
+            ],
+            &TOKEN_METADATA_PROGRAM_ID,
+        );
+        require_keys_eq!(
+            metadata.key(),
+            expected_metadata,
+            crate::errors::LauchpadError::InvalidNftMetadata
+        );
+        require_keys_eq!(
+            *metadata.owner,
+            TOKEN_METADATA_PROGRAM_ID,
+            crate::errors::LauchpadError::InvalidNftMetadata
+        );
+
+        // 2. Parse the metadata data manually
+        // Metadata format: [key: u8][update_authority: 32][mint: 32][name: string][symbol: string][uri: string]
+        // [seller_fee_basis_points: u16][creators: option<vec<creator>>][primary_sale_happened: bool]
+        // [is_mutable: bool][edition_nonce: option<u8>][token_standard: option<u8>][collection: option<collection>]
+        let data = metadata.try_borrow_data()?;
+        let mut reader = MetadataReader {
+            data: &data,
+            offset: 0,
+        };
+        reader.skip(1 + 32)?;
+        require!(
+            reader.read(32)? == nft_mint.as_ref(),
+            crate::errors::LauchpadError::InvalidNftMetadata
+        );
+        for _ in 0..3 {
+            let len = reader.read_u32()?;
+            reader.skip(len as usize)?;
         }
+        reader.skip(2)?;
+        if reader.read_bool()? {
+            let creators = reader.read_u32()?;
+            reader.skip(creators as usize * (32 + 1 + 1))?;
+        }
+        reader.skip(1 + 1)?;
+        for _ in 0..2 {
+            if reader.read_bool()? {
+                reader.skip(1)?;
+            }
+        }
+
+        // 3. Verify the collection is set, verified by its authority, and matches
+        require!(
+            reader.read_bool()?,
+            crate::errors::LauchpadError::InvalidNftCollection
+        );
+        let verified = reader.read_bool()?;
+        let collection = reader.read(32)?;
+        require!(
+            verified && collection == nft_payment.collection.as_ref(),
+            crate::errors::LauchpadError::InvalidNftCollection
+        );
+
+        Ok(())
+    }
+
+    /// Whether the price of `bin` is too old to commit at `current_time`
+    pub fn is_price_stale(&self, bin: &AuctionBin, current_time: i64) -> bool {
+        bin.kind == crate::state::BinKind::Sale
+            && self.max_price_age.is_some_and(|max_price_age| {
+                current_time.saturating_sub(bin.price_updated_at) > max_price_age
+            })
+    }
+
+    /// Batch window open at `current_time` (if any), identified by its start time
+    pub fn batch_window_id(&self, commit_end_time: i64, current_time: i64) -> Option<i64> {
+        let window_start = commit_end_time.saturating_sub(self.batch_window?);
+        (current_time >= window_start).then_some(window_start)
+    }
+
+    /// Whether a commitment invoked at `stack_height` is accepted, PDA signers are only
+    /// possible below the top level
+    pub fn is_participant_allowed(&self, stack_height: usize) -> bool {
+        !self.forbid_pda_participants || stack_height <= TRANSACTION_LEVEL_STACK_HEIGHT
+    }
+
+    /// Whether a commitment signed by a user account owned by `owner`, invoked at
+    /// `stack_height`, is accepted in EOA-only mode: wallets are system accounts and
+    /// sign at the top level, where PDAs can't
+    pub fn is_eoa_participant(&self, owner: &Pubkey, stack_height: usize) -> bool {
+        !self.eoa_only
+            || (*owner == anchor_lang::system_program::ID
+                && stack_height <= TRANSACTION_LEVEL_STACK_HEIGHT)
+    }
+
+    /// Claim fee rate of a bin, its own override or the auction rate (0 without fees)
+    pub fn bin_claim_fee_rate(&self, bin: &AuctionBin) -> u64 {
+        bin.claim_fee_rate.or(self.claim_fee_rate).unwrap_or(0)
+    }
+
+    /// Claim fee rate for a commitment to `bin` made at `commit_time`, recorded in its
+    /// `CommittedBin`
+    pub fn claim_fee_rate_at(
+        &self,
+        bin: &AuctionBin,
+        commit_start_time: i64,
+        commit_time: i64,
+    ) -> u64 {
+        let fee_rate = self.bin_claim_fee_rate(bin);
+        match &self.claim_fee_schedule {
+            Some(schedule)
+                if commit_time < commit_start_time.saturating_add(schedule.early_window) =>
+            {
+                schedule.early_fee_rate.min(fee_rate)
+            }
+            _ => fee_rate,
+        }
+    }
+
+    /// Claim fee for sale tokens claimed by `user` at `claim_time` from a bin committed
+    /// at `committed_fee_rate`, nothing for exempt users or prompt claims
+    pub fn calculate_claim_fee(
+        &self,
+        user: &Pubkey,
+        sale_token_claimed: u64,
+        committed_fee_rate: u64,
+        claim_start_time: i64,
+        claim_time: i64,
+    ) -> u64 {
+        if self.claim_fee_exempt_users.contains(user) {
+            return 0;
+        }
+        let fee_rate = match &self.claim_timing_fee {
+            Some(timing_fee) => {
+                let elapsed = claim_time
+                    .saturating_sub(claim_start_time)
+                    .saturating_sub(timing_fee.free_period);
+                if elapsed < 0 {
+                    0
+                } else {
+                    let steps = (elapsed / timing_fee.step_period) as u64 + 1;
+                    timing_fee
+                        .step_fee_rate
+                        .saturating_mul(steps)
+                        .min(committed_fee_rate)
+                }
+            }
+            None => committed_fee_rate,
+        };
+        (sale_token_claimed as u128 * fee_rate as u128 / 10000) as u64
+    }
+}
+
+/// Offset of the first `Ed25519SignatureOffsets` in Ed25519 instruction data, after
+/// `[num_signatures: u8][padding: u8]`
+pub const ED25519_SIGNATURE_OFFSETS_START: usize = 2;
+/// Serialized size of `Ed25519SignatureOffsets`
+pub const ED25519_SIGNATURE_OFFSETS_SIZE: usize = 14;
+
+/// Location of one signature, its public key and message in an Ed25519 instruction,
+/// as parsed by the Ed25519 program
+///
+/// Each part lives at an offset of the instruction at its instruction index, where
+/// `u16::MAX` refers to the Ed25519 instruction itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ed25519SignatureOffsets {
+    pub signature_offset: u16,
+    pub signature_instruction_index: u16,
+    pub public_key_offset: u16,
+    pub public_key_instruction_index: u16,
+    pub message_data_offset: u16,
+    pub message_data_size: u16,
+    pub message_instruction_index: u16,
+}
+
+impl Ed25519SignatureOffsets {
+    /// Parse the offsets of every signature of Ed25519 instruction data
+    pub fn parse_all(data: &[u8]) -> Result<Vec<Self>> {
+        let num_signatures = *data
+            .first()
+            .ok_or(crate::errors::LauchpadError::MalformedEd25519Ix)?
+            as usize;
+        let offsets_end =
+            ED25519_SIGNATURE_OFFSETS_START + num_signatures * ED25519_SIGNATURE_OFFSETS_SIZE;
+        require!(
+            num_signatures > 0 && data.len() >= offsets_end,
+            crate::errors::LauchpadError::MalformedEd25519Ix
+        );
+
+        Ok(data[ED25519_SIGNATURE_OFFSETS_START..offsets_end]
+            .chunks_exact(ED25519_SIGNATURE_OFFSETS_SIZE)
+            .map(|chunk| {
+                let field =
+                    |index: usize| u16::from_le_bytes([chunk[2 * index], chunk[2 * index + 1]]);
+                Self {
+                    signature_offset: field(0),
+                    signature_instruction_index: field(1),
+                    public_key_offset: field(2),
+                    public_key_instruction_index: field(3),
+                    message_data_offset: field(4),
+                    message_data_size: field(5),
+                    message_instruction_index: field(6),
+                }
+            })
+            .collect())
+    }
+
+    /// Serialize the offsets as laid out in Ed25519 instruction data
+    pub fn to_le_bytes(&self) -> [u8; ED25519_SIGNATURE_OFFSETS_SIZE] {
+        let mut bytes = [0; ED25519_SIGNATURE_OFFSETS_SIZE];
+        let fields = [
+            self.signature_offset,
+            self.signature_instruction_index,
+            self.public_key_offset,
+            self.public_key_instruction_index,
+            self.message_data_offset,
+            self.message_data_size,
+            self.message_instruction_index,
+        ];
+        for (chunk, field) in bytes.chunks_exact_mut(2).zip(fields) {
+            chunk.copy_from_slice(&field.to_le_bytes());
+        }
+        bytes
+    }
+}
+
+/// Verify an Ed25519 instruction of the transaction carries a signature of
+/// `expected_message` by one of `expected_authorities`, and that it hasn't expired,
+/// returning the authority that signed
+fn verify_signed_message(
+    sysvar_instructions: &AccountInfo,
+    expected_message: &[u8],
+    expiry: u64,
+    expected_authorities: &[Pubkey],
+) -> Result<Pubkey> {
+    let signer = find_message_signer(sysvar_instructions, expected_message, expected_authorities)?;
+
+    // 6. Check signature hasn't expired
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    require!(
+        current_time <= expiry,
+        crate::errors::LauchpadError::SignatureExpired
+    );
+
+    Ok(signer)
+}
+
+/// Find the authority among `expected_authorities` whose signature of
+/// `expected_message` an Ed25519 instruction of the transaction verifies
+///
+/// The runtime verifies Ed25519 instructions before executing any instruction, so
+/// their position doesn't matter: every other instruction is scanned, whatever order
+/// wallets put ATA creation, compute budget or guard instructions in. An Ed25519
+/// instruction may verify several signatures, whose public keys and messages may
+/// live in other instructions of the transaction.
+fn find_message_signer(
+    sysvar_instructions: &AccountInfo,
+    expected_message: &[u8],
+    expected_authorities: &[Pubkey],
+) -> Result<Pubkey> {
+    let current_index = load_current_index_checked(sysvar_instructions)
+        .map_err(|_| crate::errors::LauchpadError::MissingSysvarInstructions)?;
+    // The sysvar data starts with the number of instructions
+    let ix_count = sysvar_instructions
+        .try_borrow_data()?
+        .get(..2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        .ok_or(crate::errors::LauchpadError::MissingSysvarInstructions)?;
+
+    let mut has_ed25519_ix = false;
+    let mut signed_by_authority = false;
+    let mut signer = None;
+    for ix_index in (0..ix_count).filter(|ix_index| *ix_index != current_index) {
+        // 1. Read the other instruction
+        let ix = load_instruction_at_checked(ix_index as usize, sysvar_instructions)
+            .map_err(|_| crate::errors::LauchpadError::MissingSysvarInstructions)?;
+
+        // 2. Skip anything but Ed25519 verification instructions
+        if ix.program_id != ed25519_program::ID {
+            continue;
+        }
+        has_ed25519_ix = true;
+
+        // 3. Find a signature by an expected authority over the expected message
+        for offsets in Ed25519SignatureOffsets::parse_all(&ix.data)? {
+            let public_key = read_ed25519_ix_bytes(
+                sysvar_instructions,
+                &ix.data,
+                offsets.public_key_instruction_index,
+                offsets.public_key_offset,
+                32,
+            )?;
+            let Some(authority) = expected_authorities
+                .iter()
+                .find(|authority| authority.as_ref() == public_key.as_slice())
+            else {
+                continue;
+            };
+            signed_by_authority = true;
+
+            let message = read_ed25519_ix_bytes(
+                sysvar_instructions,
+                &ix.data,
+                offsets.message_instruction_index,
+                offsets.message_data_offset,
+                offsets.message_data_size as usize,
+            )?;
+            if message == expected_message {
+                signer = Some(*authority);
+                break;
+            }
+        }
+        if signer.is_some() {
+            break;
+        }
+    }
+
+    require!(has_ed25519_ix, crate::errors::LauchpadError::WrongProgram);
+
+    // 4. Verify public key matches an expected authority
+    require!(
+        signed_by_authority,
+        crate::errors::LauchpadError::Unauthorized
+    );
+
+    // 5. Verify message matches signed content
+    signer.ok_or(crate::errors::LauchpadError::PayloadMismatch.into())
+}
+
+/// Read `size` bytes at `offset` of the instruction an Ed25519 signature part points
+/// to, `u16::MAX` referring to the Ed25519 instruction itself
+fn read_ed25519_ix_bytes(
+    sysvar_instructions: &AccountInfo,
+    ed25519_data: &[u8],
+    instruction_index: u16,
+    offset: u16,
+    size: usize,
+) -> Result<Vec<u8>> {
+    let other_data;
+    let data = if instruction_index == u16::MAX {
+        ed25519_data
+    } else {
+        other_data = load_instruction_at_checked(instruction_index as usize, sysvar_instructions)
+            .map_err(|_| crate::errors::LauchpadError::MalformedEd25519Ix)?
+            .data;
+        &other_data
+    };
+
+    let start = offset as usize;
+    data.get(start..start + size)
+        .map(<[u8]>::to_vec)
+        .ok_or(crate::errors::LauchpadError::MalformedEd25519Ix.into())
+}
+
+/// Bounds-checked cursor over Borsh-encoded Metaplex metadata
+struct MetadataReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> MetadataReader<'a> {
+    fn read(&mut self, len: usize) -> Result<&'a [u8]> {
+        let end = self
+            .offset
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or(crate::errors::LauchpadError::InvalidNftMetadata)?;
+        let bytes = &self.data[self.offset..end];
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    fn skip(&mut self, len: usize) -> Result<()> {
+        self.read(len).map(|_| ())
+    }
+
+    fn read_bool(&mut self) -> Result<bool> {
+        Ok(self.read(1)?[0] != 0)
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        let bytes = self.read(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::instruction::Instruction;
+
+    #[test]
+    fn test_parse_ed25519_signature_offsets() {
+        let first = Ed25519SignatureOffsets {
+            signature_offset: 48,
+            signature_instruction_index: u16::MAX,
+            public_key_offset: 16,
+            public_key_instruction_index: u16::MAX,
+            message_data_offset: 112,
+            message_data_size: 90,
+            message_instruction_index: u16::MAX,
+        };
+        let second = Ed25519SignatureOffsets {
+            message_instruction_index: 2,
+            ..first
+        };
+
+        // Two signatures, padding and trailing data
+        let mut data = vec![2, 0];
+        data.extend_from_slice(&first.to_le_bytes());
+        data.extend_from_slice(&second.to_le_bytes());
+        data.extend_from_slice(&[0; 96]);
+        assert_eq!(
+            Ed25519SignatureOffsets::parse_all(&data).unwrap(),
+            vec![first, second]
+        );
+
+        // Truncated offsets and empty instructions are malformed
+        assert!(Ed25519SignatureOffsets::parse_all(&data[..20]).is_err());
+        assert!(Ed25519SignatureOffsets::parse_all(&[0, 0]).is_err());
+        assert!(Ed25519SignatureOffsets::parse_all(&[]).is_err());
+    }
+
+    #[test]
+    fn test_calculate_claim_timing_fee() {
+        let user = Pubkey::new_unique();
+        let extensions = AuctionExtensions {
+            claim_timing_fee: Some(ClaimTimingFee {
+                free_period: 7 * 86_400,
+                step_period: 86_400,
+                step_fee_rate: 50,
+            }),
+            ..Default::default()
+        };
+        let fee_at =
+            |claim_time| extensions.calculate_claim_fee(&user, 1_000_000, 200, 0, claim_time);
+
+        // Fee-free during the free period
+        assert_eq!(fee_at(0), 0);
+        assert_eq!(fee_at(7 * 86_400 - 1), 0);
+
+        // Then one step per day, capped at the committed rate
+        assert_eq!(fee_at(7 * 86_400), 5_000);
+        assert_eq!(fee_at(8 * 86_400), 10_000);
+        assert_eq!(fee_at(30 * 86_400), 20_000);
+
+        // Without timing fees the committed rate applies at any time
+        let extensions = AuctionExtensions::default();
+        assert_eq!(
+            extensions.calculate_claim_fee(&user, 1_000_000, 200, 0, 0),
+            20_000
+        );
+    }
+
+    #[test]
+    fn test_pda_participants() {
+        // PDA participants are accepted by default, through CPIs at any depth
+        let mut extensions = AuctionExtensions::default();
+        assert!(extensions.is_participant_allowed(TRANSACTION_LEVEL_STACK_HEIGHT));
+        assert!(extensions.is_participant_allowed(TRANSACTION_LEVEL_STACK_HEIGHT + 1));
+
+        // Forbidding them limits commitments to top-level instructions
+        extensions.forbid_pda_participants = true;
+        assert!(extensions.is_participant_allowed(TRANSACTION_LEVEL_STACK_HEIGHT));
+        assert!(!extensions.is_participant_allowed(TRANSACTION_LEVEL_STACK_HEIGHT + 1));
+    }
+
+    #[test]
+    fn test_withdraw_co_approval() {
+        let co_signer = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+
+        // Any withdrawal without co-approval
+        let mut extensions = AuctionExtensions::default();
+        assert!(extensions.is_withdraw_approved(u64::MAX, None));
+
+        // Up to the threshold alone, above it only with the co-signer
+        extensions.withdraw_co_approval = Some(WithdrawCoApproval {
+            co_signer,
+            threshold: 1_000,
+        });
+        assert!(extensions.is_withdraw_approved(1_000, None));
+        assert!(!extensions.is_withdraw_approved(1_001, None));
+        assert!(!extensions.is_withdraw_approved(1_001, Some(&other)));
+        assert!(extensions.is_withdraw_approved(1_001, Some(&co_signer)));
+    }
+
+    /// Find the signer of `message` in a transaction of `instructions` executing
+    /// the one at `current_index`
+    fn find_transaction_signer(
+        instructions: &[Instruction],
+        current_index: u16,
+        message: &[u8],
+        authorities: &[Pubkey],
+    ) -> Result<Pubkey> {
+        use anchor_lang::solana_program::sysvar::{
+            self,
+            instructions::{construct_instructions_data, store_current_index, BorrowedInstruction},
+        };
+
+        let borrowed = instructions
+            .iter()
+            .map(|ix| BorrowedInstruction {
+                program_id: &ix.program_id,
+                accounts: vec![],
+                data: &ix.data,
+            })
+            .collect::<Vec<_>>();
+        let mut data = construct_instructions_data(&borrowed);
+        store_current_index(&mut data, current_index);
+
+        let key = sysvar::instructions::ID;
+        let owner = sysvar::ID;
+        let mut lamports = 0;
+        let sysvar_instructions = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        find_message_signer(&sysvar_instructions, message, authorities)
+    }
+
+    /// Ed25519 instruction verifying a single signature of `message` by
+    /// `authority`, with its public key and message inline
+    fn ed25519_verification(authority: &Pubkey, message: &[u8]) -> Instruction {
+        let offsets = Ed25519SignatureOffsets {
+            signature_offset: 48,
+            signature_instruction_index: u16::MAX,
+            public_key_offset: 16,
+            public_key_instruction_index: u16::MAX,
+            message_data_offset: 112,
+            message_data_size: message.len() as u16,
+            message_instruction_index: u16::MAX,
+        };
+        let mut data = vec![1, 0];
+        data.extend_from_slice(&offsets.to_le_bytes());
+        data.extend_from_slice(authority.as_ref());
+        data.extend_from_slice(&[0; 64]);
+        data.extend_from_slice(message);
+        Instruction {
+            program_id: ed25519_program::ID,
+            accounts: vec![],
+            data,
+        }
+    }
+
+    #[test]
+    fn test_find_message_signer_in_wallet_orderings() {
+        let authority = Pubkey::new_unique();
+        let message = b"whitelist payload".to_vec();
+
+        let ix = |program_id: Pubkey, data: Vec<u8>| Instruction {
+            program_id,
+            accounts: vec![],
+            data,
+        };
+        let ed25519 = ed25519_verification(&authority, &message);
+        let compute_limit = ix(
+            anchor_lang::solana_program::pubkey!("ComputeBudget111111111111111111111111111111"),
+            vec![2, 0, 0x0d, 0x03, 0],
+        );
+        let compute_price = ix(compute_limit.program_id, vec![3, 1, 0, 0, 0, 0, 0, 0, 0]);
+        let create_ata = ix(anchor_spl::associated_token::ID, vec![1]);
+        let guard = ix(Pubkey::new_unique(), vec![]);
+        let commit = ix(crate::ID, vec![0; 8]);
+
+        // Every ordering wallets produce, as (instructions, index of commit)
+        let orderings = [
+            (vec![ed25519.clone(), commit.clone()], 1),
+            (
+                vec![
+                    compute_limit.clone(),
+                    compute_price.clone(),
+                    ed25519.clone(),
+                    commit.clone(),
+                ],
+                3,
+            ),
+            (
+                vec![
+                    compute_limit.clone(),
+                    compute_price.clone(),
+                    create_ata.clone(),
+                    ed25519.clone(),
+                    commit.clone(),
+                ],
+                4,
+            ),
+            (
+                vec![
+                    ed25519.clone(),
+                    create_ata.clone(),
+                    compute_price.clone(),
+                    compute_limit.clone(),
+                    commit.clone(),
+                ],
+                4,
+            ),
+            (
+                vec![
+                    compute_limit.clone(),
+                    create_ata.clone(),
+                    commit.clone(),
+                    ed25519.clone(),
+                ],
+                2,
+            ),
+            (
+                vec![
+                    compute_price.clone(),
+                    compute_limit.clone(),
+                    ed25519.clone(),
+                    create_ata.clone(),
+                    commit.clone(),
+                    guard.clone(),
+                ],
+                4,
+            ),
+        ];
+        for (instructions, commit_index) in orderings {
+            assert_eq!(
+                find_transaction_signer(&instructions, commit_index, &message, &[authority])
+                    .unwrap(),
+                authority
+            );
+        }
+
+        // Without a verification the commitment isn't authorized
+        let unsigned = [compute_limit, create_ata, commit.clone()];
+        assert_eq!(
+            find_transaction_signer(&unsigned, 2, &message, &[authority]).unwrap_err(),
+            crate::errors::LauchpadError::WrongProgram.into()
+        );
+
+        // Nor by another signer or over another message
+        let signed = [ed25519, commit];
+        assert_eq!(
+            find_transaction_signer(&signed, 1, &message, &[Pubkey::new_unique()]).unwrap_err(),
+            crate::errors::LauchpadError::Unauthorized.into()
+        );
+        assert_eq!(
+            find_transaction_signer(&signed, 1, b"other payload", &[authority]).unwrap_err(),
+            crate::errors::LauchpadError::PayloadMismatch.into()
+        );
+    }
+
+    #[test]
+    fn test_commit_whitelist_signature() {
+        let auction_authority = Pubkey::new_unique();
+        let round_authority = Pubkey::new_unique();
+        let extensions = AuctionExtensions {
+            whitelist_authorities: vec![auction_authority],
+            ..Default::default()
+        };
+
+        // The round whitelist overrides the auction whitelist
+        assert_eq!(
+            extensions.commit_whitelist_authorities(&None),
+            [auction_authority]
+        );
+        let round_whitelist_authority = Some(round_authority);
+        let round_authorities = extensions.commit_whitelist_authorities(&round_whitelist_authority);
+        assert_eq!(round_authorities, [round_authority]);
+
+        let (user, auction) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let payload = |nonce| {
+            let mut message = Vec::new();
+            WhitelistPayload {
+                user,
+                auction,
+                bin_id: 0,
+                payment_token_committed: 1_000,
+                nonce,
+                expiry: 0,
+            }
+            .serialize(&mut message)
+            .unwrap();
+            message
+        };
+        let message = payload(0);
+        let commit = Instruction {
+            program_id: crate::ID,
+            accounts: vec![],
+            data: vec![0; 8],
+        };
+        let signed = [ed25519_verification(&round_authority, &message), commit];
+
+        // Only the round authority authorizes commitments to the round's bins
+        assert_eq!(
+            find_transaction_signer(&signed, 1, &message, round_authorities).unwrap(),
+            round_authority
+        );
+        assert_eq!(
+            find_transaction_signer(&signed, 1, &message, &[auction_authority]).unwrap_err(),
+            crate::errors::LauchpadError::Unauthorized.into()
+        );
+
+        // Once the commitment bumped the nonce, the signature can't be replayed
+        assert_eq!(
+            find_transaction_signer(&signed, 1, &payload(1), round_authorities).unwrap_err(),
+            crate::errors::LauchpadError::PayloadMismatch.into()
+        );
+    }
+
+    #[test]
+    fn test_commit_cap_counts_waitlist_escrows() {
+        let extensions = AuctionExtensions {
+            commit_cap_per_user: Some(5_000),
+            ..Default::default()
+        };
+        let mut committed: Committed = bytemuck::Zeroable::zeroed();
+        committed.add_commitment(0, 3_000, 0, 2).unwrap();
+        committed.waitlist_pending = 1_500;
+
+        assert!(extensions
+            .check_commit_cap_exceeded(&committed, 500, None, 0)
+            .is_ok());
+        assert_eq!(
+            extensions
+                .check_commit_cap_exceeded(&committed, 501, None, 0)
+                .unwrap_err(),
+            crate::errors::LauchpadError::CommitCapExceeded.into()
+        );
+
+        // Promotion keeps the total within the cap it was checked against
+        committed.promote_waitlist_escrow(1, 1_500, 0, 2).unwrap();
+        assert!(extensions
+            .check_commit_cap_exceeded(&committed, 500, None, 0)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_eoa_only_participants() {
+        let system = anchor_lang::system_program::ID;
+        let program = Pubkey::new_unique();
+
+        // Any signer commits by default
+        let mut extensions = AuctionExtensions::default();
+        assert!(extensions.is_eoa_participant(&program, TRANSACTION_LEVEL_STACK_HEIGHT + 1));
+
+        // EOA-only: system accounts signing at the top level
+        extensions.eoa_only = true;
+        assert!(extensions.is_eoa_participant(&system, TRANSACTION_LEVEL_STACK_HEIGHT));
+        assert!(!extensions.is_eoa_participant(&program, TRANSACTION_LEVEL_STACK_HEIGHT));
+        assert!(!extensions.is_eoa_participant(&system, TRANSACTION_LEVEL_STACK_HEIGHT + 1));
+    }
+
+    #[test]
+    fn test_batch_window_id() {
+        // No batch window by default
+        let mut extensions = AuctionExtensions::default();
+        assert_eq!(extensions.batch_window_id(1000, 999), None);
+
+        // The last 60 seconds of the commit period form the window
+        extensions.batch_window = Some(60);
+        assert_eq!(extensions.batch_window_id(1000, 939), None);
+        assert_eq!(extensions.batch_window_id(1000, 940), Some(940));
+        assert_eq!(extensions.batch_window_id(1000, 1000), Some(940));
     }
 }
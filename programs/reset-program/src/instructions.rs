@@ -1,16 +1,35 @@
 use crate::allocation::{
-    calculate_claimable_amounts, calculate_total_withdraw_amounts, calculate_withdrawable_fees,
-    check_all_bins_fully_claimed,
+    calculate_bin_allocation_ratio, calculate_bin_entitlement, calculate_bin_kind_withdraw_amounts,
+    calculate_bin_withdraw_amounts, calculate_entitled_withdraw_amounts,
+    calculate_force_refund_amounts, calculate_free_claim_withdraw_amounts, calculate_max_bin_fees,
+    calculate_payment_vault_liabilities, calculate_redemption_vault_liabilities,
+    calculate_revenue_share, calculate_sale_vault_liabilities, calculate_total_bin_target,
+    calculate_total_entitlements, calculate_total_withdraw_amounts, calculate_withdrawable_fees,
+    check_all_bins_fully_claimed, ClaimableAmounts, TotalEntitlements, TotalWithdrawAmounts,
 };
 use crate::consts::LAUNCHPAD_ADMIN;
 use crate::errors::LauchpadError;
-use crate::extensions::AuctionExtensions;
+use crate::extensions::{AuctionExtensions, WhitelistPayload};
 use crate::state::*;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    hash::hash,
+    instruction::{get_stack_height, Instruction},
+    program::invoke_signed,
+};
+use anchor_lang::system_program;
 use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{self, Mint, Token, TokenAccount, Transfer},
+    associated_token::{self, get_associated_token_address, AssociatedToken},
+    token::{
+        spl_token::native_mint, Burn, CloseAccount, Mint, SyncNative, Token, TokenAccount, Transfer,
+    },
 };
+use std::cell::RefMut;
+// Simulation builds skip token CPIs
+#[cfg(feature = "simulation")]
+use crate::simulation::token;
+#[cfg(not(feature = "simulation"))]
+use anchor_spl::token;
 
 /// Create a new auction
 pub fn init_auction(
@@ -24,710 +43,995 @@ pub fn init_auction(
 ) -> Result<()> {
     // CHECK: authority validation, verify signer is LaunchpadAdmin
     require_keys_eq!(
-        LAUNCHPAD_ADMIN,
+        ctx.accounts.global_config.admin,
         ctx.accounts.authority.key(),
         LauchpadError::OnlyLaunchpadAdmin
     );
 
-    // CHECK: timing validation, require current_time <= commit_start_time <= commit_end_time <= claim_start_time
-    let current_time = Clock::get()?.unix_timestamp;
+    // CHECK: payment mint validation, prevent raising in an unapproved mint
     require!(
-        current_time <= commit_start_time
-            && commit_start_time <= commit_end_time
-            && commit_end_time <= claim_start_time,
-        LauchpadError::InvalidAuctionTimeRange
+        ctx.accounts
+            .config
+            .is_payment_mint_allowed(&ctx.accounts.payment_token_mint.key()),
+        LauchpadError::PaymentMintNotApproved
     );
 
-    // CHECK: bins length validation, require 1-10 bins
-    require!(
-        bins.len() >= 1 && bins.len() <= 10,
-        LauchpadError::InvalidAuctionBinsLength
-    );
+    // Initialize auction
+    *ctx.accounts.auction = build_auction(
+        NewAuction {
+            authority: ctx.accounts.authority.key(),
+            sale_token_mint: ctx.accounts.sale_token_mint.key(),
+            payment_token_mint: ctx.accounts.payment_token_mint.key(),
+            commit_start_time,
+            commit_end_time,
+            claim_start_time,
+            bins,
+            custody,
+            extensions,
+        },
+        ctx.accounts.config.min_claim_grace_period,
+        ctx.bumps.vault_sale_token,
+        ctx.bumps.vault_payment_token,
+        ctx.bumps.auction,
+    )?;
+    escrow_security_deposit(
+        &mut ctx.accounts.auction,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+    )?;
 
-    // CHECK: bins price and cap validation, require price and cap to be greater than zero
-    require!(
-        bins.iter()
-            .all(|bin| bin.sale_token_price > 0 && bin.sale_token_cap > 0),
-        LauchpadError::InvalidAuctionBinsPriceOrCap
+    deposit_sale_tokens(
+        &ctx.accounts.auction,
+        &ctx.accounts.sale_token_seller,
+        &ctx.accounts.sale_token_seller_authority,
+        &ctx.accounts.vault_sale_token,
+        &ctx.accounts.token_program,
+    )
+}
+
+/// Create a new auction from a template, only the sale token and times differ
+pub fn clone_auction(
+    ctx: Context<CloneAuction>,
+    commit_start_time: i64,
+    commit_end_time: i64,
+    claim_start_time: i64,
+) -> Result<()> {
+    // CHECK: authority validation, verify signer is LaunchpadAdmin
+    require_keys_eq!(
+        ctx.accounts.global_config.admin,
+        ctx.accounts.authority.key(),
+        LauchpadError::OnlyLaunchpadAdmin
     );
 
-    // TODO: fee rate format?
-    // CHECK: extensions configuration validation
+    // CHECK: payment mint validation, prevent raising in an unapproved mint
     require!(
-        extensions.claim_fee_rate.map_or(true, |rate| rate > 0),
-        LauchpadError::NoClaimFeesConfigured
+        ctx.accounts
+            .config
+            .is_payment_mint_allowed(&ctx.accounts.payment_token_mint.key()),
+        LauchpadError::PaymentMintNotApproved
     );
 
     // Initialize auction
-    *ctx.accounts.auction = Auction {
-        authority: LAUNCHPAD_ADMIN,
-        custody,
-        sale_token_mint: ctx.accounts.sale_token_mint.key(),
-        payment_token_mint: ctx.accounts.payment_token_mint.key(),
-        commit_start_time,
-        commit_end_time,
-        claim_start_time,
-        bins: bins
-            .into_iter()
-            .map(|params| AuctionBin {
-                sale_token_price: params.sale_token_price,
-                sale_token_cap: params.sale_token_cap,
-                payment_token_raised: 0,
-                sale_token_claimed: 0,
-            })
-            .collect(),
-        extensions,
-        total_participants: 0,
-        unsold_sale_tokens_and_effective_payment_tokens_withdrawn: false,
-        total_fees_collected: 0,
-        total_fees_withdrawn: 0,
-        emergency_state: EmergencyState::default(),
-        vault_sale_bump: ctx.bumps.vault_sale_token,
-        vault_payment_bump: ctx.bumps.vault_payment_token,
-        bump: ctx.bumps.auction,
+    let template = &ctx.accounts.template;
+    *ctx.accounts.auction = build_auction(
+        NewAuction {
+            authority: ctx.accounts.authority.key(),
+            sale_token_mint: ctx.accounts.sale_token_mint.key(),
+            payment_token_mint: ctx.accounts.payment_token_mint.key(),
+            commit_start_time,
+            commit_end_time,
+            claim_start_time,
+            bins: template.bins.clone(),
+            custody: template.custody,
+            extensions: template.extensions.clone(),
+        },
+        ctx.accounts.config.min_claim_grace_period,
+        ctx.bumps.vault_sale_token,
+        ctx.bumps.vault_payment_token,
+        ctx.bumps.auction,
+    )?;
+    escrow_security_deposit(
+        &mut ctx.accounts.auction,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+    )?;
+
+    msg!("Auction cloned from template {}", template.template_id);
+    deposit_sale_tokens(
+        &ctx.accounts.auction,
+        &ctx.accounts.sale_token_seller,
+        &ctx.accounts.sale_token_seller_authority,
+        &ctx.accounts.vault_sale_token,
+        &ctx.accounts.token_program,
+    )
+}
+
+/// Create the global config, handing the LaunchpadAdmin role from the key compiled
+/// into the program to `admin`
+pub fn init_config(ctx: Context<InitConfig>, admin: Pubkey) -> Result<()> {
+    // CHECK: authority validation, only the deploy-time admin may bootstrap the config
+    require_keys_eq!(
+        LAUNCHPAD_ADMIN,
+        ctx.accounts.authority.key(),
+        LauchpadError::OnlyLaunchpadAdmin
+    );
+
+    *ctx.accounts.global_config = GlobalConfig {
+        admin,
+        protocol_fee: None,
+        bump: ctx.bumps.global_config,
     };
 
-    // Transfer required sale tokens from sale_token_seller to vault
-    let total_sale_tokens_needed: u64 = ctx
-        .accounts
-        .auction
-        .bins
-        .iter()
-        .map(|bin| bin.sale_token_cap)
-        .sum();
-    token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.sale_token_seller.to_account_info(),
-                to: ctx.accounts.vault_sale_token.to_account_info(),
-                authority: ctx.accounts.sale_token_seller_authority.to_account_info(),
-            },
-        ),
-        total_sale_tokens_needed,
-    )?;
+    msg!("Global config initialized, admin = {}", admin);
+    Ok(())
+}
 
-    msg!("Auction initialized");
+/// Admin rotates the LaunchpadAdmin role to `admin`
+///
+/// Existing auctions keep their authority, the new admin moves them over with
+/// `transfer_auction_authority`.
+pub fn update_config(ctx: Context<UpdateConfig>, admin: Pubkey) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+    let previous_admin = global_config.admin;
+    global_config.admin = admin;
+
+    msg!(
+        "LaunchpadAdmin rotated from {} to {}",
+        previous_admin,
+        admin
+    );
     Ok(())
 }
 
-/// Emergency control for pausing/resuming auction operations
-pub fn emergency_control(
-    ctx: Context<EmergencyControl>,
-    params: EmergencyControlParams,
+/// Admin hands an auction's authority over to `new_authority`, e.g. to itself after
+/// `update_config` rotated the LaunchpadAdmin away from the auction's authority
+pub fn transfer_auction_authority(
+    ctx: Context<TransferAuctionAuthority>,
+    new_authority: Pubkey,
 ) -> Result<()> {
-    // Construct new paused operations bitmask
-    let mut new_paused_operations = 0u64;
-    if params.pause_auction_commit {
-        new_paused_operations |= EmergencyState::PAUSE_AUCTION_COMMIT;
-    }
-    if params.pause_auction_claim {
-        new_paused_operations |= EmergencyState::PAUSE_AUCTION_CLAIM;
-    }
-    if params.pause_auction_withdraw_fees {
-        new_paused_operations |= EmergencyState::PAUSE_AUCTION_WITHDRAW_FEES;
-    }
-    if params.pause_auction_withdraw_funds {
-        new_paused_operations |= EmergencyState::PAUSE_AUCTION_WITHDRAW_FUNDS;
-    }
-    if params.pause_auction_updation {
-        new_paused_operations |= EmergencyState::PAUSE_AUCTION_UPDATION;
-    }
-
-    // Update emergency state
     let auction = &mut ctx.accounts.auction;
-    auction.emergency_state.paused_operations = new_paused_operations;
-
-    // Emit event
-    emit!(EmergencyControlEvent {
-        auction: auction.key(),
-        authority: ctx.accounts.authority.key(),
-        paused_operations: new_paused_operations,
-    });
+    let previous_authority = auction.authority;
+    auction.authority = new_authority;
 
     msg!(
-        "Emergency control updated for auction {}: paused_operations = {}",
+        "Auction {} authority transferred from {} to {}",
         auction.key(),
-        new_paused_operations
+        previous_authority,
+        new_authority
     );
-
     Ok(())
 }
 
-/// User commits to an auction bin
-pub fn commit(
-    ctx: Context<Commit>,
-    bin_id: u8,
-    payment_token_committed: u64,
-    expiry: u64,
+/// Admin sets the platform fee `withdraw_funds` routes to the platform treasury
+/// on every auction, or removes it with `None`
+pub fn set_protocol_fee(
+    ctx: Context<UpdateConfig>,
+    protocol_fee: Option<ProtocolFee>,
 ) -> Result<()> {
-    // CHECK: emergency state validation
-    check_emergency_state(&ctx.accounts.auction, EmergencyState::PAUSE_AUCTION_COMMIT)?;
+    if let Some(protocol_fee) = &protocol_fee {
+        // CHECK: fee validation
+        require!(
+            protocol_fee.fee_bps > 0 && protocol_fee.fee_bps <= 10000,
+            LauchpadError::InvalidProtocolFee
+        );
+    }
 
-    let user_key = ctx.accounts.user.key();
+    ctx.accounts.global_config.protocol_fee = protocol_fee;
 
-    // Store keys before mutably borrowing auction
-    let auction_key = ctx.accounts.auction.key();
+    msg!("Protocol fee updated: {:?}", protocol_fee);
+    Ok(())
+}
 
-    // CHECK: Timing validation
-    let current_time = Clock::get()?.unix_timestamp;
+/// Admin sets the global launchpad config, i.e. the payment mints new auctions may
+/// raise in, or `allow_unlisted_payment_mints` to lift that restriction
+pub fn set_launchpad_config(
+    ctx: Context<SetLaunchpadConfig>,
+    approved_payment_mints: Vec<Pubkey>,
+    allow_unlisted_payment_mints: bool,
+    min_claim_grace_period: i64,
+    governance_authority: Option<Pubkey>,
+) -> Result<()> {
+    // CHECK: authority validation, verify signer is LaunchpadAdmin
+    require_keys_eq!(
+        ctx.accounts.global_config.admin,
+        ctx.accounts.authority.key(),
+        LauchpadError::OnlyLaunchpadAdmin
+    );
+
+    // CHECK: approved mints fit in the config account
     require!(
-        ctx.accounts.auction.commit_start_time <= current_time
-            && current_time <= ctx.accounts.auction.commit_end_time,
-        LauchpadError::OutOfCommitmentPeriod
+        approved_payment_mints.len() <= MAX_APPROVED_PAYMENT_MINTS,
+        LauchpadError::TooManyApprovedPaymentMints
     );
 
-    // CHECK: commitment amount validation
-    require_neq!(
-        payment_token_committed,
-        0,
-        LauchpadError::InvalidCommitmentAmount
+    // CHECK: grace period validation
+    require!(
+        min_claim_grace_period >= 0,
+        LauchpadError::InvalidClaimGracePeriod
     );
 
-    // CHECK: commitment bin validation
-    let _ = ctx.accounts.auction.get_bin(bin_id)?;
+    let config = &mut ctx.accounts.config;
+    config.approved_payment_mints = approved_payment_mints;
+    config.allow_unlisted_payment_mints = allow_unlisted_payment_mints;
+    config.min_claim_grace_period = min_claim_grace_period;
+    config.governance_authority = governance_authority;
+    config.bump = ctx.bumps.config;
 
-    // CHECK: Custody authorization - skip restrictions if authorized by custody
-    let custody = ctx.accounts.auction.custody;
-    let is_custody_authorized = check_custody_authorization(
-        &ctx,
-        &user_key,
-        &auction_key,
-        bin_id,
-        payment_token_committed,
-        expiry,
-        custody,
-    )?;
+    msg!(
+        "Launchpad config updated: {} approved payment mints, unlisted allowed = {}, claim grace period = {}s",
+        config.approved_payment_mints.len(),
+        allow_unlisted_payment_mints,
+        min_claim_grace_period
+    );
+    Ok(())
+}
 
-    // Now get mutable reference to auction
-    let auction = &mut ctx.accounts.auction;
+/// Admin blocks or unblocks a sale mint program-wide, e.g. an exploited token,
+/// stopping `claim` and `withdraw_funds` on every auction selling it
+pub fn set_sale_mint_blocked(
+    ctx: Context<SetSaleMintBlocked>,
+    sale_token_mint: Pubkey,
+    blocked: bool,
+) -> Result<()> {
+    // CHECK: authority validation, verify signer is LaunchpadAdmin
+    require_keys_eq!(
+        ctx.accounts.global_config.admin,
+        ctx.accounts.authority.key(),
+        LauchpadError::OnlyLaunchpadAdmin
+    );
 
-    // CHECK: Extension validations (skip if custody authorized)
-    if !is_custody_authorized {
-        auction
-            .extensions
-            .check_commit_cap_exceeded(&ctx.accounts.committed, payment_token_committed)?;
-        if auction.extensions.is_whitelist_enabled() {
-            let sysvar_instructions = ctx
-                .accounts
-                .sysvar_instructions
-                .as_ref()
-                .ok_or(LauchpadError::MissingSysvarInstructions)?;
-            auction.extensions.verify_whitelist_signature(
-                sysvar_instructions,
-                &user_key,
-                &auction_key,
-                bin_id,
-                payment_token_committed,
-                ctx.accounts.committed.nonce,
-                expiry,
-            )?;
-        }
+    let config = &mut ctx.accounts.config;
+    config
+        .blocked_sale_mints
+        .retain(|mint| *mint != sale_token_mint);
+    if blocked {
+        // CHECK: blocked mints fit in the config account
+        require!(
+            config.blocked_sale_mints.len() < MAX_BLOCKED_SALE_MINTS,
+            LauchpadError::TooManyBlockedSaleMints
+        );
+        config.blocked_sale_mints.push(sale_token_mint);
     }
 
-    // Initialize committed account if it's newly created
-    let is_new_participant = ctx.accounts.committed.bins.is_empty();
-    if is_new_participant {
-        ctx.accounts.committed.auction = auction_key;
-        ctx.accounts.committed.user = user_key;
-        ctx.accounts.committed.nonce = 0;
-        ctx.accounts.committed.bump = ctx.bumps.committed;
-    }
+    msg!(
+        "Sale mint {} blocked = {}, {} mints blocked",
+        sale_token_mint,
+        blocked,
+        config.blocked_sale_mints.len()
+    );
+    Ok(())
+}
 
-    // Update committed account
-    let committed_bin = ctx.accounts.committed.find_bin_mut(bin_id);
-    match committed_bin {
-        Some(committed_bin) => {
-            committed_bin.payment_token_committed = committed_bin
-                .payment_token_committed
-                .checked_add(payment_token_committed)
-                .ok_or(LauchpadError::MathOverflow)?;
-        }
-        None => {
-            ctx.accounts.committed.bins.push(CommittedBin {
-                bin_id,
-                payment_token_committed,
-                sale_token_claimed: 0,
-                payment_token_refunded: 0,
-            });
-        }
-    }
+/// Admin routes a share of withdrawn fees to the staking rewards vaults of the
+/// launchpad token, or stops routing with `None`
+pub fn set_revenue_share(
+    ctx: Context<SetRevenueShare>,
+    revenue_share: Option<RevenueShare>,
+) -> Result<()> {
+    // CHECK: authority validation, verify signer is LaunchpadAdmin
+    require_keys_eq!(
+        ctx.accounts.global_config.admin,
+        ctx.accounts.authority.key(),
+        LauchpadError::OnlyLaunchpadAdmin
+    );
 
-    // Update Auction state
-    if is_new_participant {
-        auction.total_participants = auction
-            .total_participants
-            .checked_add(1)
-            .ok_or(LauchpadError::MathOverflow)?;
+    if let Some(revenue_share) = &revenue_share {
+        // CHECK: share validation
+        require!(
+            revenue_share.share_bps > 0 && revenue_share.share_bps <= 10000,
+            LauchpadError::InvalidRevenueShare
+        );
     }
-    let bin = auction.get_bin_mut(bin_id)?;
-    bin.payment_token_raised += payment_token_committed;
 
-    // Transfer payment tokens to vault
-    token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.user_payment_token.to_account_info(),
-                to: ctx.accounts.vault_payment_token.to_account_info(),
-                authority: ctx.accounts.user.to_account_info(),
-            },
-        ),
-        payment_token_committed,
-    )?;
+    ctx.accounts.config.revenue_share = revenue_share;
 
-    // Increment nonce to prevent replay attacks (only after successful commit)
-    ctx.accounts.committed.nonce = ctx
-        .accounts
-        .committed
-        .nonce
-        .checked_add(1)
-        .ok_or(LauchpadError::NonceOverflow)?;
+    msg!("Revenue share updated: {:?}", revenue_share);
+    Ok(())
+}
+
+/// Admin registers or unregisters an integrating front-end users may `commit` through
+///
+/// Unregistering stops new commitments through the front-end, fees keep accruing on
+/// the commitments already made through it.
+pub fn set_frontend_registered(
+    ctx: Context<UpdateFrontends>,
+    frontend_id: Pubkey,
+    registered: bool,
+) -> Result<()> {
+    // CHECK: authority validation, verify signer is LaunchpadAdmin
+    require_keys_eq!(
+        ctx.accounts.global_config.admin,
+        ctx.accounts.authority.key(),
+        LauchpadError::OnlyLaunchpadAdmin
+    );
+
+    // CHECK: the all-zeros key marks direct commitments
+    require!(
+        frontend_id != Pubkey::default(),
+        LauchpadError::FrontendNotRegistered
+    );
+
+    let config = &mut ctx.accounts.config;
+    config
+        .registered_frontends
+        .retain(|frontend| *frontend != frontend_id);
+    if registered {
+        // CHECK: registered front-ends fit in the config account
+        require!(
+            config.registered_frontends.len() < MAX_REGISTERED_FRONTENDS,
+            LauchpadError::TooManyRegisteredFrontends
+        );
+        config.registered_frontends.push(frontend_id);
+    }
 
     msg!(
-        "User {} committed {} tokens to bin {}, nonce incremented to {} (custody_authorized: {})",
-        user_key,
-        payment_token_committed,
-        bin_id,
-        ctx.accounts.committed.nonce,
-        is_custody_authorized
+        "Front-end {} registered = {}, {} front-ends registered",
+        frontend_id,
+        registered,
+        config.registered_frontends.len()
     );
     Ok(())
 }
 
-/// Check if the current transaction is authorized by custody account
-/// Returns true if user is custody or has valid custody signature authorization
-fn check_custody_authorization(
-    ctx: &Context<Commit>,
-    user: &Pubkey,
-    auction: &Pubkey,
-    bin_id: u8,
-    payment_token_committed: u64,
-    expiry: u64,
-    custody: Pubkey,
-) -> Result<bool> {
-    // Case 1: User is directly the custody account
-    if *user == custody {
-        return Ok(true);
-    }
+/// Admin sets the share of the claim fees accrued to the front-end a commitment was
+/// made through, zero to stop accruing
+pub fn set_frontend_fee_share(ctx: Context<UpdateFrontends>, fee_share_bps: u16) -> Result<()> {
+    // CHECK: authority validation, verify signer is LaunchpadAdmin
+    require_keys_eq!(
+        ctx.accounts.global_config.admin,
+        ctx.accounts.authority.key(),
+        LauchpadError::OnlyLaunchpadAdmin
+    );
 
-    // Case 2: Check for custody signature authorization (if custody_authority provided)
-    if let Some(custody_authority) = &ctx.accounts.custody_authority {
-        // Verify the custody_authority matches the stored custody account
-        require_keys_eq!(
-            custody_authority.key(),
-            custody,
-            LauchpadError::InvalidCustodyAuthority
-        );
+    // CHECK: share validation
+    require!(
+        fee_share_bps <= 10000,
+        LauchpadError::InvalidFrontendFeeShare
+    );
 
-        // Verify custody signature using the same mechanism as whitelist
-        if let Some(sysvar_instructions) = &ctx.accounts.sysvar_instructions {
-            ctx.accounts
-                .auction
-                .extensions
-                .verify_signature_authorization(
-                    sysvar_instructions,
-                    user,
-                    auction,
-                    bin_id,
-                    payment_token_committed,
-                    ctx.accounts.committed.nonce,
-                    expiry,
-                    &custody_authority.key(),
-                )?;
-            return Ok(true);
-        }
-    }
+    ctx.accounts.config.frontend_fee_share_bps = fee_share_bps;
 
-    Ok(false)
+    msg!("Front-end fee share updated: {} bps", fee_share_bps);
+    Ok(())
 }
 
-/// User decreases a commitment (renamed from revert_commit)
-pub fn decrease_commit(
-    ctx: Context<DecreaseCommit>,
-    bin_id: u8,
-    payment_token_reverted: u64,
+/// Admin pauses operations on every auction at once, e.g. during a protocol-level
+/// incident, or resumes them with an empty mask
+pub fn set_global_pause(ctx: Context<SetGlobalPause>, paused_operations: u64) -> Result<()> {
+    // CHECK: authority validation, verify signer is LaunchpadAdmin
+    require_keys_eq!(
+        ctx.accounts.global_config.admin,
+        ctx.accounts.authority.key(),
+        LauchpadError::OnlyLaunchpadAdmin
+    );
+
+    ctx.accounts.config.paused_operations = paused_operations;
+
+    msg!("Global pause updated: {:#x}", paused_operations);
+    Ok(())
+}
+
+/// Admin saves a reusable auction configuration for `clone_auction`
+pub fn create_template(
+    ctx: Context<CreateTemplate>,
+    template_id: u64,
+    bins: Vec<AuctionBinParams>,
+    custody: Pubkey,
+    extensions: AuctionExtensions,
 ) -> Result<()> {
-    // CHECK: emergency state validation
-    check_emergency_state(&ctx.accounts.auction, EmergencyState::PAUSE_AUCTION_COMMIT)?;
+    // CHECK: authority validation, verify signer is LaunchpadAdmin
+    require_keys_eq!(
+        ctx.accounts.global_config.admin,
+        ctx.accounts.authority.key(),
+        LauchpadError::OnlyLaunchpadAdmin
+    );
 
-    let auction = &mut ctx.accounts.auction;
+    // CHECK: configuration validation, so clones can't fail on a bad template
+    validate_auction_config(&bins, &extensions)?;
 
-    // CHECK: Timing validation
+    *ctx.accounts.template = Template {
+        template_id,
+        bins,
+        custody,
+        extensions,
+        bump: ctx.bumps.template,
+    };
+
+    msg!("Template {} created", template_id);
+    Ok(())
+}
+
+/// Parameters of a new auction
+struct NewAuction {
+    authority: Pubkey,
+    sale_token_mint: Pubkey,
+    payment_token_mint: Pubkey,
+    commit_start_time: i64,
+    commit_end_time: i64,
+    claim_start_time: i64,
+    bins: Vec<AuctionBinParams>,
+    custody: Pubkey,
+    extensions: AuctionExtensions,
+}
+
+/// Validate the parameters of a new auction and build its initial state
+fn build_auction(
+    params: NewAuction,
+    min_claim_grace_period: i64,
+    vault_sale_bump: u8,
+    vault_payment_bump: u8,
+    bump: u8,
+) -> Result<Auction> {
+    // CHECK: timing validation, require current_time <= commit_start_time <= commit_end_time <= claim_start_time
     let current_time = Clock::get()?.unix_timestamp;
     require!(
-        auction.commit_start_time <= current_time && current_time <= auction.commit_end_time,
-        LauchpadError::OutOfCommitmentPeriod
+        current_time <= params.commit_start_time
+            && params.commit_start_time <= params.commit_end_time
+            && params.commit_end_time <= params.claim_start_time,
+        LauchpadError::InvalidAuctionTimeRange
     );
 
-    // CHECK: commitment amount validation
-    require_neq!(
-        payment_token_reverted,
-        0,
-        LauchpadError::InvalidCommitmentAmount
+    // CHECK: claim grace period validation, leave time to finalize before claims open
+    require!(
+        params
+            .claim_start_time
+            .checked_sub(params.commit_end_time)
+            .ok_or(LauchpadError::MathUnderflow)?
+            >= min_claim_grace_period,
+        LauchpadError::ClaimGracePeriodTooShort
     );
 
-    let committed = &mut ctx.accounts.committed;
+    let round_count = validate_auction_config(&params.bins, &params.extensions)?;
+    let boost_weight_bps = params
+        .extensions
+        .commit_boost
+        .as_ref()
+        .map_or(0, |boost| boost.weight_bps);
+
+    Ok(Auction {
+        authority: params.authority,
+        custody: params.custody,
+        sale_token_mint: params.sale_token_mint,
+        payment_token_mint: params.payment_token_mint,
+        commit_start_time: params.commit_start_time,
+        commit_end_time: params.commit_end_time,
+        claim_start_time: params.claim_start_time,
+        bins: params
+            .bins
+            .into_iter()
+            .map(|params| AuctionBin::from_params(params, boost_weight_bps, current_time))
+            .collect(),
+        extensions: params.extensions,
+        total_participants: 0,
+        unsold_sale_tokens_and_effective_payment_tokens_withdrawn: false,
+        finalized: false,
+        force_refund: false,
+        cancelled: false,
+        aborted: false,
+        total_sale_entitled: 0,
+        total_refund_entitled: 0,
+        total_payment_refunded: 0,
+        total_payment_released: 0,
+        total_payment_withdrawn: 0,
+        total_refund_diverted: 0,
+        total_pool_withdrawn: 0,
+        total_sale_swept: 0,
+        total_payment_swept: 0,
+        waitlist_escrowed: 0,
+        total_rent_funded: 0,
+        round_count,
+        rounds_closed: 0,
+        total_fees_collected: 0,
+        total_fees_withdrawn: 0,
+        total_frontend_fees_accrued: 0,
+        total_frontend_fees_withdrawn: 0,
+        security_deposit_held: 0,
+        security_deposit_slashed: 0,
+        redemption_mint: None,
+        placeholder_swapped: 0,
+        terms_hash: [0; 32],
+        roles: AuctionRoles::default(),
+        emergency_state: EmergencyState::default(),
+        vault_sale_bump,
+        vault_payment_bump,
+        vault_redemption_bump: 0,
+        bump,
+    })
+}
 
-    // CHECK: Validate sufficient committed amount
-    let committed_bin = committed
-        .find_bin_mut(bin_id)
-        .ok_or(LauchpadError::InvalidBinId)?;
+/// Validate the bins and extensions of an auction configuration, returning the
+/// number of rounds the bins are split into
+fn validate_auction_config(
+    bins: &[AuctionBinParams],
+    extensions: &AuctionExtensions,
+) -> Result<u8> {
+    // CHECK: bins length validation, require 1-10 bins
     require!(
-        committed_bin.payment_token_committed >= payment_token_reverted,
-        LauchpadError::InvalidCommitmentAmount
+        bins.len() >= 1 && bins.len() <= MAX_AUCTION_BINS,
+        LauchpadError::InvalidAuctionBinsLength
     );
 
-    // Update committed account
-    committed_bin.payment_token_committed -= payment_token_reverted;
+    // CHECK: bins rounds validation, require bins ordered by consecutive rounds from 0
+    require!(
+        bins[0].round == 0
+            && bins
+                .windows(2)
+                .all(|pair| pair[1].round == pair[0].round || pair[1].round == pair[0].round + 1),
+        LauchpadError::InvalidBinRounds
+    );
 
-    // Update Auction state
-    let bin = auction.get_bin_mut(bin_id)?;
-    bin.payment_token_raised -= payment_token_reverted;
+    // CHECK: bins price and cap validation per bin kind
+    for bin in bins.iter() {
+        // Only sale bins allocate pro-rata, so only they can hard cap instead
+        require!(
+            !bin.hard_cap || bin.kind == BinKind::Sale,
+            LauchpadError::InvalidHardCapBin
+        );
 
-    // Transfer payment tokens back to user
-    let auction_key = auction.key();
-    let vault_seeds = &[
-        VAULT_PAYMENT_SEED,
-        auction_key.as_ref(),
-        &[auction.vault_payment_bump],
-    ];
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.vault_payment_token.to_account_info(),
-                to: ctx.accounts.user_payment_token.to_account_info(),
-                authority: ctx.accounts.vault_payment_token.to_account_info(),
-            },
-            &[vault_seeds],
-        ),
-        payment_token_reverted,
+        match bin.kind {
+            // Require price and cap to be greater than zero
+            BinKind::Sale => require!(
+                bin.sale_token_price > 0 && bin.sale_token_cap > 0 && bin.free_claim_amount == 0,
+                LauchpadError::InvalidAuctionBinsPriceOrCap
+            ),
+            // Donation bins sell nothing, require price and cap to be zero
+            BinKind::Donation => require!(
+                bin.sale_token_price == 0 && bin.sale_token_cap == 0 && bin.free_claim_amount == 0,
+                LauchpadError::InvalidDonationBin
+            ),
+            // Free-claim bins are free, require a per-user amount within the cap
+            // and a whitelist to keep sybils from draining them
+            BinKind::FreeClaim => {
+                require!(
+                    bin.sale_token_price == 0
+                        && bin.free_claim_amount > 0
+                        && bin.free_claim_amount <= bin.sale_token_cap,
+                    LauchpadError::InvalidFreeClaimBin
+                );
+                require!(
+                    extensions.is_whitelist_enabled(),
+                    LauchpadError::FreeClaimRequiresWhitelist
+                );
+            }
+        }
+    }
+
+    // TODO: fee rate format?
+    // CHECK: bin targets fit in u64, so claims can compute them
+    calculate_total_bin_target(
+        bins.iter()
+            .map(|bin| (bin.sale_token_cap, bin.sale_token_price)),
     )?;
 
-    msg!(
-        "User {} decreased commitment by {} tokens from bin {}",
-        ctx.accounts.user.key(),
-        payment_token_reverted,
-        bin_id
+    // CHECK: extensions configuration validation
+    require!(
+        extensions.claim_fee_rate.map_or(true, |rate| rate > 0)
+            && bins
+                .iter()
+                .all(|bin| bin.claim_fee_rate.map_or(true, |rate| rate > 0)),
+        LauchpadError::NoClaimFeesConfigured
     );
-    Ok(())
-}
-
-/// claims tokens with flexible amounts
-pub fn claim(
-    ctx: Context<Claim>,
-    bin_id: u8,
-    sale_token_to_claim: u64,
-    payment_token_to_refund: u64,
-) -> Result<()> {
-    // CHECK: emergency state validation
-    check_emergency_state(&ctx.accounts.auction, EmergencyState::PAUSE_AUCTION_CLAIM)?;
+    let has_claim_fees =
+        extensions.claim_fee_rate.is_some() || bins.iter().any(|bin| bin.claim_fee_rate.is_some());
 
-    // CHECK: Timing validation
-    let current_time = Clock::get()?.unix_timestamp;
+    // CHECK: NFT payment mode requires each NFT to count for a payment value
     require!(
-        ctx.accounts.auction.claim_start_time <= current_time,
-        LauchpadError::OutOfClaimPeriod
+        extensions
+            .nft_payment
+            .as_ref()
+            .map_or(true, |config| config.payment_value > 0),
+        LauchpadError::InvalidNftPaymentConfig
     );
 
-    // CHECK: Claim amount validation
+    // CHECK: commit cap scaling needs a cap to scale and an authority to attest
     require!(
-        sale_token_to_claim != 0 || payment_token_to_refund != 0,
-        LauchpadError::InvalidClaimAmount
+        extensions.unattested_cap_bps.map_or(true, |bps| {
+            bps <= 10000
+                && extensions.commit_cap_per_user.is_some()
+                && extensions.is_whitelist_enabled()
+        }),
+        LauchpadError::InvalidCapScalingConfig
     );
 
-    // CHECK: Validate authority
-    require_keys_eq!(
-        ctx.accounts.committed.user,
-        ctx.accounts.user.key(),
-        LauchpadError::Unauthorized
+    // CHECK: loyalty multipliers need a cap to scale, and higher tiers never get less
+    require!(
+        extensions.loyalty_cap_bps.map_or(true, |cap_bps| {
+            extensions.commit_cap_per_user.is_some()
+                && cap_bps.windows(2).all(|pair| pair[0] <= pair[1])
+        }),
+        LauchpadError::InvalidLoyaltyConfig
     );
 
-    // Store keys and values before borrowing mutably
-    let auction_key = ctx.accounts.auction.key();
-    let vault_sale_bump = ctx.accounts.auction.vault_sale_bump;
-    let vault_payment_bump = ctx.accounts.auction.vault_payment_bump;
-    let user_key = ctx.accounts.user.key();
+    // CHECK: early-committer fee schedule discounts the configured claim fee
+    require!(
+        extensions
+            .claim_fee_schedule
+            .as_ref()
+            .map_or(true, |schedule| {
+                schedule.early_window > 0 && has_claim_fees
+            }),
+        LauchpadError::InvalidClaimFeeSchedule
+    );
 
-    // Calculate claim fee before entering mutable borrow scope
-    let claim_fee = ctx
-        .accounts
-        .auction
-        .extensions
-        .calculate_claim_fee(sale_token_to_claim);
+    // CHECK: IOU mode has no sale tokens in the vault to collect claim fees from
+    require!(
+        !extensions.iou_mode || !has_claim_fees,
+        LauchpadError::ClaimFeeNotSupportedInIouMode
+    );
 
-    // Perform all mutations and calculations in a scoped block
-    let all_bins_fully_claimed = {
-        let auction = &mut ctx.accounts.auction;
-        let committed = &mut ctx.accounts.committed;
+    // CHECK: keeper bounty validation
+    require!(
+        extensions.keeper_bounty.map_or(true, |bounty| bounty > 0),
+        LauchpadError::InvalidKeeperBounty
+    );
 
-        // Find the specific bin commitment
-        let committed_bin = committed
-            .find_bin_mut(bin_id)
-            .ok_or(LauchpadError::InvalidBinId)?;
+    // CHECK: whitelist authorities fit in the auction account
+    require!(
+        extensions.whitelist_authorities.len() <= MAX_WHITELIST_AUTHORITIES,
+        LauchpadError::TooManyWhitelistAuthorities
+    );
 
-        // Get the auction bin for calculations
-        let bin = auction.get_bin_mut(bin_id)?;
+    // CHECK: claim fee exemptions fit in the auction account
+    require!(
+        extensions.claim_fee_exempt_users.len() <= MAX_CLAIM_FEE_EXEMPT_USERS,
+        LauchpadError::TooManyClaimFeeExemptUsers
+    );
 
-        // Calculate what user is entitled to based on allocation algorithm using allocation.rs
-        let bin_target = bin
-            .sale_token_cap
-            .checked_mul(bin.sale_token_price)
-            .ok_or(LauchpadError::MathOverflow)?;
+    // CHECK: reserved refund window validation
+    require!(
+        extensions
+            .reserved_refund_window
+            .map_or(true, |window| window > 0),
+        LauchpadError::InvalidReservedRefundWindow
+    );
 
-        let claimable_amounts = calculate_claimable_amounts(
-            committed_bin.payment_token_committed,
-            bin_target,
-            bin.payment_token_raised,
-            bin.sale_token_price,
-        )?;
+    // CHECK: per-transaction commit limit validation
+    require!(
+        extensions.max_commit_per_tx.map_or(true, |max| max > 0),
+        LauchpadError::InvalidMaxCommitPerTx
+    );
 
-        // Validate the calculation consistency
-        claimable_amounts.validate(committed_bin.payment_token_committed)?;
+    // CHECK: security deposit validation
+    require!(
+        extensions
+            .security_deposit
+            .as_ref()
+            .map_or(true, |deposit| deposit.lamports > 0),
+        LauchpadError::InvalidSecurityDeposit
+    );
 
-        let total_sale_tokens_entitled = claimable_amounts.sale_tokens;
-        let total_payment_refund_entitled = claimable_amounts.refund_payment_tokens;
+    // CHECK: custody allocation limit validation
+    require!(
+        extensions
+            .custody_allocation_limit
+            .map_or(true, |limit_bps| limit_bps > 0 && limit_bps <= 10000),
+        LauchpadError::InvalidCustodyAllocationLimit
+    );
 
-        // CHECK: Validate requested amounts don't exceed entitlements
-        let remaining_sale_tokens =
-            total_sale_tokens_entitled.saturating_sub(committed_bin.sale_token_claimed);
-        let remaining_payment_refund =
-            total_payment_refund_entitled.saturating_sub(committed_bin.payment_token_refunded);
-        require!(
-            sale_token_to_claim <= remaining_sale_tokens
-                && payment_token_to_refund <= remaining_payment_refund,
-            LauchpadError::InvalidClaimAmount
-        );
+    // CHECK: burned proceeds go nowhere and must be payment tokens
+    require!(
+        !extensions.burn_proceeds
+            || (extensions.proceeds_recipient.is_none() && extensions.nft_payment.is_none()),
+        LauchpadError::InvalidBurnProceeds
+    );
 
-        // Transfer sale tokens if requested
-        if sale_token_to_claim > 0 {
-            // Actual tokens to transfer to user (after deducting fee)
-            let actual_tokens_to_user = sale_token_to_claim.saturating_sub(claim_fee);
+    // CHECK: the platform takes a real share of transferred payment tokens
+    require!(
+        extensions.proceeds_split.as_ref().map_or(true, |split| {
+            split.treasury_bps > 0
+                && split.treasury_bps <= 10000
+                && !extensions.burn_proceeds
+                && extensions.nft_payment.is_none()
+        }),
+        LauchpadError::InvalidProceedsSplit
+    );
 
-            let vault_sale_seeds = &[VAULT_SALE_SEED, auction_key.as_ref(), &[vault_sale_bump]];
-            token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.vault_sale_token.to_account_info(),
-                        to: ctx.accounts.user_sale_token.to_account_info(),
-                        authority: ctx.accounts.vault_sale_token.to_account_info(),
-                    },
-                    &[vault_sale_seeds],
-                ),
-                actual_tokens_to_user,
-            )?;
+    // CHECK: reservations keep raised payments at the bin price, which batch windows
+    // and NFT valuations don't
+    require!(
+        !extensions.reserve_at_commit
+            || (extensions.batch_window.is_none() && extensions.nft_payment.is_none()),
+        LauchpadError::InvalidReservationMode
+    );
 
-            // Update state
-            committed_bin.sale_token_claimed += sale_token_to_claim;
-            bin.sale_token_claimed += sale_token_to_claim;
+    // CHECK: the claim deadline is the only disposal of unclaimed refunds, and only
+    // covers tokens held in the vaults
+    require!(
+        extensions.claim_deadline.as_ref().map_or(true, |deadline| {
+            deadline.claim_window > 0
+                && extensions.reserved_refund_window.is_none()
+                && extensions.nft_payment.is_none()
+                && !extensions.iou_mode
+        }),
+        LauchpadError::InvalidClaimDeadline
+    );
 
-            // Update fee collection state
-            if claim_fee > 0 {
-                auction.total_fees_collected += claim_fee;
-            }
-        }
-
-        // Transfer payment token refund if requested
-        if payment_token_to_refund > 0 {
-            let vault_payment_seeds = &[
-                VAULT_PAYMENT_SEED,
-                auction_key.as_ref(),
-                &[vault_payment_bump],
-            ];
+    // CHECK: batch windows are a real period, kept apart from boosted allocations
+    require!(
+        extensions.batch_window.map_or(true, |window| window > 0
+            && extensions.commit_boost.is_none()),
+        LauchpadError::InvalidBatchWindow
+    );
 
-            token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.vault_payment_token.to_account_info(),
-                        to: ctx.accounts.user_payment_token.to_account_info(),
-                        authority: ctx.accounts.vault_payment_token.to_account_info(),
-                    },
-                    &[vault_payment_seeds],
-                ),
-                payment_token_to_refund,
-            )?;
+    // CHECK: commit boost trades a lock for more weight, NFT bins never oversubscribe
+    require!(
+        extensions.commit_boost.as_ref().map_or(true, |boost| {
+            boost.lock_period > 0 && boost.weight_bps > 10000 && extensions.nft_payment.is_none()
+        }),
+        LauchpadError::InvalidCommitBoost
+    );
 
-            // Update state
-            committed_bin.payment_token_refunded += payment_token_to_refund;
-        }
+    // CHECK: claim timing fee grows the configured claim fee after the free period
+    require!(
+        extensions
+            .claim_timing_fee
+            .as_ref()
+            .map_or(true, |timing_fee| {
+                timing_fee.free_period >= 0
+                    && timing_fee.step_period > 0
+                    && timing_fee.step_fee_rate > 0
+                    && has_claim_fees
+            }),
+        LauchpadError::InvalidClaimTimingFee
+    );
 
-        // Check if this bin is fully claimed
-        let current_bin_fully_claimed = committed_bin.sale_token_claimed
-            >= total_sale_tokens_entitled
-            && payment_token_to_refund >= remaining_payment_refund;
+    // CHECK: late refunds are pooled after the refund deadline, NFTs are returned instead
+    require!(
+        extensions.late_refund_pool.as_ref().map_or(true, |pool| {
+            pool.ramp_period > 0
+                && extensions.reserved_refund_window.is_some()
+                && extensions.nft_payment.is_none()
+        }),
+        LauchpadError::InvalidLateRefundPool
+    );
 
-        if current_bin_fully_claimed {
-            // Check if all bins are fully claimed using allocation.rs function
-            check_all_bins_fully_claimed(&committed.bins, &auction.bins)?
-        } else {
-            false
-        }
-    };
+    // CHECK: admin timelock validation
+    require!(
+        extensions.admin_timelock.map_or(true, |delay| delay > 0),
+        LauchpadError::InvalidAdminTimelock
+    );
 
-    // Handle account closure if all bins are fully claimed
-    if all_bins_fully_claimed {
-        // Create a snapshot of the committed account data before closing it
-        let committed_account_info = ctx.accounts.committed.to_account_info();
-        let committed_account_key = committed_account_info.key();
-        let rent_lamports = committed_account_info.lamports();
-
-        // Create snapshot of the committed data
-        let committed_data_snapshot =
-            CommittedAccountSnapshot::from_committed(&ctx.accounts.committed);
-
-        // Emit the CommittedAccountClosedEvent before closing the account
-        emit!(CommittedAccountClosedEvent {
-            user_key,
-            auction_key,
-            committed_account_key,
-            rent_returned: rent_lamports,
-            committed_data: committed_data_snapshot,
-        });
+    // CHECK: maximum price age validation
+    require!(
+        extensions.max_price_age.map_or(true, |age| age > 0),
+        LauchpadError::InvalidMaxPriceAge
+    );
 
-        // Close the committed account and return the rent to the user
-        let dest_account_info = ctx.accounts.user.to_account_info();
+    Ok(bins[bins.len() - 1].round + 1)
+}
 
-        **committed_account_info.try_borrow_mut_lamports()? = 0;
-        **dest_account_info.try_borrow_mut_lamports()? = dest_account_info
-            .lamports()
-            .checked_add(rent_lamports)
-            .expect("Math overflow");
-        let mut committed_data = committed_account_info.try_borrow_mut_data()?;
-        for byte in committed_data.iter_mut() {
-            *byte = 0;
-        }
-    }
+/// Transfer the security deposit of a new auction (if enabled) from the authority
+/// into the auction account
+fn escrow_security_deposit<'info>(
+    auction: &mut Account<'info, Auction>,
+    authority: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let Some(lamports) = auction
+        .extensions
+        .security_deposit
+        .as_ref()
+        .map(|deposit| deposit.lamports)
+    else {
+        return Ok(());
+    };
 
-    msg!(
-        "User {} claimed {} sale tokens and {} payment refund from bin {}",
-        ctx.accounts.user.key(),
-        sale_token_to_claim,
-        payment_token_to_refund,
-        bin_id
-    );
+    system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: authority.to_account_info(),
+                to: auction.to_account_info(),
+            },
+        ),
+        lamports,
+    )?;
+    auction.security_deposit_held = lamports;
     Ok(())
 }
 
-/// Admin withdraws funds from all auction bins
-pub fn withdraw_funds(ctx: Context<WithdrawFunds>) -> Result<()> {
-    // Check emergency state - withdraw funds operations
-    check_emergency_state(
-        &ctx.accounts.auction,
-        EmergencyState::PAUSE_AUCTION_WITHDRAW_FUNDS,
-    )?;
-
-    let auction = &mut ctx.accounts.auction;
-
-    // CHECK: Prevent double withdrawal
-    require!(
-        !auction.unsold_sale_tokens_and_effective_payment_tokens_withdrawn,
-        LauchpadError::DoubleFundsWithdrawal
-    );
-
-    // CHECK: Timing validation - can withdraw after commit period ends
-    let current_time = Clock::get()?.unix_timestamp;
-    require!(
-        current_time > auction.commit_end_time,
-        LauchpadError::InCommitmentPeriod
-    );
+/// Accrue the front-end share of a claim fee to the fee balance of `frontend_id`, the
+/// front-end the commitment was made through (if any)
+fn accrue_frontend_fee(
+    auction: &mut Auction,
+    config: &LaunchpadConfig,
+    frontend_id: Pubkey,
+    frontend_fees: Option<&mut FrontendFees>,
+    claim_fee: u64,
+) -> Result<()> {
+    if frontend_id == Pubkey::default() || claim_fee == 0 {
+        return Ok(());
+    }
 
-    // CHECK: Validate authority
+    // CHECK: the fee balance is the front-end's
+    let frontend_fees = frontend_fees.ok_or(LauchpadError::InvalidFrontendFeesAccount)?;
     require_keys_eq!(
-        auction.authority,
-        ctx.accounts.authority.key(),
-        LauchpadError::Unauthorized
+        frontend_fees.frontend,
+        frontend_id,
+        LauchpadError::InvalidFrontendFeesAccount
     );
 
-    // Calculate withdrawal amounts using allocation.rs functions
-    let total_amounts = calculate_total_withdraw_amounts(&auction.bins)?;
+    let (frontend_share, _) = calculate_revenue_share(claim_fee, config.frontend_fee_share_bps)?;
+    frontend_fees.fees_accrued = frontend_fees
+        .fees_accrued
+        .checked_add(frontend_share)
+        .ok_or(LauchpadError::MathOverflow)?;
+    auction.total_frontend_fees_accrued = auction
+        .total_frontend_fees_accrued
+        .checked_add(frontend_share)
+        .ok_or(LauchpadError::MathOverflow)?;
+    Ok(())
+}
 
-    // Transfer payment tokens if any
-    if total_amounts.total_payment_tokens > 0 {
-        let auction_key = auction.key();
-        let vault_payment_seeds = &[
-            VAULT_PAYMENT_SEED,
-            auction_key.as_ref(),
-            &[auction.vault_payment_bump],
-        ];
+/// Pay escrowed security deposit lamports out of the auction account
+fn release_security_deposit(
+    auction: &mut Account<Auction>,
+    destination: &AccountInfo,
+    lamports: u64,
+) -> Result<()> {
+    auction.security_deposit_held = auction
+        .security_deposit_held
+        .checked_sub(lamports)
+        .ok_or(LauchpadError::MathUnderflow)?;
+    **auction.to_account_info().try_borrow_mut_lamports()? -= lamports;
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(LauchpadError::MathOverflow)?;
+    Ok(())
+}
 
-        token::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.vault_payment_token.to_account_info(),
-                    to: ctx.accounts.payment_token_recipient.to_account_info(),
-                    authority: ctx.accounts.vault_payment_token.to_account_info(),
+/// Make a wSOL account hold at least `amount`: sync lamports already sent to it, then
+/// wrap the remaining shortfall from the owner's lamports, returning the lamports wrapped
+fn wrap_native_shortfall<'info>(
+    owner: &Signer<'info>,
+    native_token: &mut Account<'info, TokenAccount>,
+    amount: u64,
+    token_program: &Program<'info, Token>,
+    system_program: &Program<'info, System>,
+) -> Result<u64> {
+    let sync_native = |native_token: &Account<'info, TokenAccount>| {
+        token::sync_native(CpiContext::new(
+            token_program.to_account_info(),
+            SyncNative {
+                account: native_token.to_account_info(),
+            },
+        ))
+    };
+
+    sync_native(native_token)?;
+    native_token.reload()?;
+    let shortfall = amount.saturating_sub(native_token.amount);
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: owner.to_account_info(),
+                    to: native_token.to_account_info(),
                 },
-                &[vault_payment_seeds],
             ),
-            total_amounts.total_payment_tokens,
+            shortfall,
         )?;
+        sync_native(native_token)?;
+        native_token.reload()?;
     }
 
-    // Transfer unsold sale tokens if any
-    if total_amounts.total_unsold_sale_tokens > 0 {
-        let auction_key = auction.key();
-        let vault_sale_seeds = &[
-            VAULT_SALE_SEED,
-            auction_key.as_ref(),
-            &[auction.vault_sale_bump],
-        ];
+    Ok(shortfall)
+}
 
-        token::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.vault_sale_token.to_account_info(),
-                    to: ctx.accounts.sale_token_recipient.to_account_info(),
-                    authority: ctx.accounts.vault_sale_token.to_account_info(),
-                },
-                &[vault_sale_seeds],
-            ),
-            total_amounts.total_unsold_sale_tokens,
-        )?;
+/// Transfer the sale tokens of every bin from the seller to the vault of a new auction
+fn deposit_sale_tokens<'info>(
+    auction: &Account<'info, Auction>,
+    sale_token_seller: &Account<'info, TokenAccount>,
+    sale_token_seller_authority: &Signer<'info>,
+    vault_sale_token: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+) -> Result<()> {
+    // Pre-TGE auctions have no sale tokens yet, they are deposited by `enable_redemption`
+    if auction.extensions.iou_mode {
+        msg!("Auction initialized in IOU mode");
+        return Ok(());
     }
 
-    // Set the flag to true to prevent double withdrawal
-    auction.unsold_sale_tokens_and_effective_payment_tokens_withdrawn = true;
+    // Transfer required sale tokens from sale_token_seller to vault
+    let total_sale_tokens_needed: u64 = auction.bins.iter().map(|bin| bin.sale_token_cap).sum();
+    token::transfer(
+        CpiContext::new(
+            token_program.to_account_info(),
+            Transfer {
+                from: sale_token_seller.to_account_info(),
+                to: vault_sale_token.to_account_info(),
+                authority: sale_token_seller_authority.to_account_info(),
+            },
+        ),
+        total_sale_tokens_needed,
+    )?;
+
+    msg!("Auction initialized");
+    Ok(())
+}
+
+/// Emergency control for pausing/resuming auction operations
+pub fn emergency_control(
+    ctx: Context<EmergencyControl>,
+    params: EmergencyControlParams,
+) -> Result<()> {
+    // CHECK: the message fits in the space reserved for it
+    require!(
+        params.message.len() <= MAX_PAUSE_MESSAGE_LEN,
+        LauchpadError::PauseMessageTooLong
+    );
+
+    // Construct new paused operations bitmask
+    let mut new_paused_operations = 0u64;
+    if params.pause_auction_commit {
+        new_paused_operations |= EmergencyState::PAUSE_AUCTION_COMMIT;
+    }
+    if params.pause_auction_claim {
+        new_paused_operations |= EmergencyState::PAUSE_AUCTION_CLAIM;
+    }
+    if params.pause_auction_withdraw_fees {
+        new_paused_operations |= EmergencyState::PAUSE_AUCTION_WITHDRAW_FEES;
+    }
+    if params.pause_auction_withdraw_funds {
+        new_paused_operations |= EmergencyState::PAUSE_AUCTION_WITHDRAW_FUNDS;
+    }
+    if params.pause_auction_updation {
+        new_paused_operations |= EmergencyState::PAUSE_AUCTION_UPDATION;
+    }
+
+    // Update emergency state
+    let auction = &mut ctx.accounts.auction;
+    auction.emergency_state.paused_operations = new_paused_operations;
+    auction.emergency_state.reason_code = params.reason_code;
+    auction.emergency_state.message = params.message.clone();
+
+    // Emit event
+    emit!(EmergencyControlEvent {
+        auction: auction.key(),
+        authority: ctx.accounts.authority.key(),
+        paused_operations: new_paused_operations,
+        reason_code: params.reason_code,
+        message: params.message,
+    });
 
     msg!(
-        "Authority withdrew {} payment tokens and {} unsold sale tokens from all bins",
-        total_amounts.total_payment_tokens,
-        total_amounts.total_unsold_sale_tokens
+        "Emergency control updated for auction {}: paused_operations = {}, reason_code = {}",
+        auction.key(),
+        new_paused_operations,
+        params.reason_code
     );
+
     Ok(())
 }
 
-/// Admin withdraws collected fees from all bins
-pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
-    // Check emergency state - withdraw fees operations
-    check_emergency_state(
-        &ctx.accounts.auction,
-        EmergencyState::PAUSE_AUCTION_WITHDRAW_FEES,
-    )?;
-
+/// Authority pulls the launch before its commit period starts, e.g. for legal or
+/// market reasons, returning every sale token (and the security deposit) and
+/// blocking all further commits and claims. Irreversible.
+pub fn cancel_auction(ctx: Context<CancelAuction>) -> Result<()> {
     let current_time = Clock::get()?.unix_timestamp;
+
+    // CHECK: no commitments may exist yet
     require!(
-        current_time > ctx.accounts.auction.commit_end_time,
-        LauchpadError::InCommitmentPeriod
+        current_time < ctx.accounts.auction.commit_start_time,
+        LauchpadError::CancelAfterCommitStart
     );
 
-    let auction = &mut ctx.accounts.auction;
-
-    // Calculate fees to withdraw using allocation.rs function
-    let fees_to_withdraw =
-        calculate_withdrawable_fees(auction.total_fees_collected, auction.total_fees_withdrawn)?;
+    // CHECK: auctions are cancelled once
+    require!(
+        !ctx.accounts.auction.cancelled,
+        LauchpadError::AuctionCancelled
+    );
 
-    // Transfer fees if any
-    if fees_to_withdraw > 0 {
-        let auction_key = auction.key();
+    // Return all sale tokens to the seller-designated account
+    let sale_tokens_returned = ctx.accounts.vault_sale_token.amount;
+    if sale_tokens_returned > 0 {
+        let auction_key = ctx.accounts.auction.key();
         let vault_sale_seeds = &[
             VAULT_SALE_SEED,
             auction_key.as_ref(),
-            &[auction.vault_sale_bump],
+            &[ctx.accounts.auction.vault_sale_bump],
         ];
 
         token::transfer(
@@ -735,233 +1039,8088 @@ pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
                 ctx.accounts.token_program.to_account_info(),
                 Transfer {
                     from: ctx.accounts.vault_sale_token.to_account_info(),
-                    to: ctx.accounts.fee_recipient_account.to_account_info(),
+                    to: ctx.accounts.sale_token_recipient.to_account_info(),
                     authority: ctx.accounts.vault_sale_token.to_account_info(),
                 },
                 &[vault_sale_seeds],
             ),
-            fees_to_withdraw,
+            sale_tokens_returned,
         )?;
+    }
 
-        // Update state
-        auction.total_fees_withdrawn += fees_to_withdraw;
+    let auction = &mut ctx.accounts.auction;
 
-        msg!(
-            "Authority withdrew {} fee tokens to recipient {}",
-            fees_to_withdraw,
-            ctx.accounts.fee_recipient_account.key()
-        );
+    // Nothing was committed, the security deposit goes back to the authority
+    let security_deposit = auction.security_deposit_held;
+    if security_deposit > 0 {
+        release_security_deposit(
+            auction,
+            &ctx.accounts.authority.to_account_info(),
+            security_deposit,
+        )?;
     }
 
+    auction.cancelled = true;
+
+    emit!(AuctionCancelledEvent {
+        auction: auction.key(),
+        authority: ctx.accounts.authority.key(),
+        timestamp: current_time,
+        sale_token_recipient: ctx.accounts.sale_token_recipient.key(),
+        sale_tokens_returned,
+    });
+
+    msg!(
+        "Auction cancelled, {} sale tokens returned to {}",
+        sale_tokens_returned,
+        ctx.accounts.sale_token_recipient.key()
+    );
     Ok(())
 }
 
-/// Admin sets new price for a bin
-pub fn set_price(ctx: Context<SetPrice>, bin_id: u8, new_price: u64) -> Result<()> {
-    // CHECK: emergency control
-    check_emergency_state(
-        &ctx.accounts.auction,
-        EmergencyState::PAUSE_AUCTION_UPDATION,
-    )?;
-
-    // CHECK: Validate new price
-    require!(new_price > 0, LauchpadError::InvalidAuctionBinsPriceOrCap);
+/// Admin aborts an auction during or after its commit period in response to an
+/// incident, turning it refund-only: `claim` and `refund_batch` return every
+/// commitment in full right away and no sale tokens are distributed. Irreversible,
+/// and only usable before claims start and funds are withdrawn, when nothing has been
+/// paid out. A finalized auction has its entitlements, `AuctionResult` and stats reset.
+pub fn abort_auction(ctx: Context<AbortAuction>) -> Result<()> {
+    // CHECK: authority validation, verify signer is LaunchpadAdmin
+    require_keys_eq!(
+        ctx.accounts.global_config.admin,
+        ctx.accounts.admin.key(),
+        LauchpadError::OnlyLaunchpadAdmin
+    );
 
+    let current_time = Clock::get()?.unix_timestamp;
     let auction = &mut ctx.accounts.auction;
-    let bin = auction.get_bin_mut(bin_id)?;
-    bin.sale_token_price = new_price;
-    msg!("Price for bin {} updated to {}", bin_id, new_price);
-    Ok(())
-}
 
-/// Get the hardcoded LaunchpadAdmin public key
-pub fn get_launchpad_admin() -> Result<Pubkey> {
-    Ok(LAUNCHPAD_ADMIN)
-}
+    // CHECK: commitments exist and nothing was paid out yet
+    require!(
+        auction.commit_start_time <= current_time
+            && current_time < auction.claim_start_time
+            && !auction.unsold_sale_tokens_and_effective_payment_tokens_withdrawn,
+        LauchpadError::AbortWindowClosed
+    );
 
-/// Emergency control event
-#[event]
-pub struct EmergencyControlEvent {
+    // CHECK: refunds aren't already enabled
+    require!(
+        !auction.aborted && !auction.force_refund && !auction.is_refund_only(current_time),
+        LauchpadError::RefundModeAlreadyEnabled
+    );
+
+    // CHECK: NFT payment mode holds no payment tokens, NFTs are refunded by `return_nft`
+    require!(
+        !auction.extensions.is_nft_payment_enabled(),
+        LauchpadError::NftRefundRequiresReturn
+    );
+
+    auction.aborted = true;
+    let total_payment_raised = auction.total_payment_raised()?;
+
+    // Every commitment is now refunded in full, settle the finalized auction again
+    if auction.finalized {
+        // CHECK: the published settlement and stats are passed to be reset
+        let (Some(result), Some(stats)) = (
+            ctx.accounts.auction_result.as_mut(),
+            ctx.accounts.stats.as_mut(),
+        ) else {
+            return err!(LauchpadError::MissingAuctionResult);
+        };
+
+        let kept = total_payment_raised.saturating_sub(auction.total_refund_entitled);
+        let entitlements = calculate_total_entitlements(&auction.bins, true)?;
+        auction.total_sale_entitled = entitlements.total_sale_entitled;
+        auction.total_refund_entitled = entitlements.total_refund_entitled;
+
+        result.refund_only = true;
+        result.total_sale_entitled = entitlements.total_sale_entitled;
+        result.total_refund_entitled = entitlements.total_refund_entitled;
+        stats.record_abort(&auction.payment_token_mint, kept)?;
+    }
+
+    emit!(AuctionAbortedEvent {
+        auction: auction.key(),
+        admin: ctx.accounts.admin.key(),
+        timestamp: current_time,
+        total_payment_raised,
+    });
+
+    msg!(
+        "Auction {} aborted, {} payment tokens to refund",
+        auction.key(),
+        total_payment_raised
+    );
+    Ok(())
+}
+
+/// Authority freezes sale token claims after a critical sale token issue (e.g. a
+/// compromised mint), refunding each user the effective payment of the sale tokens
+/// they haven't claimed yet. Irreversible, and usable after claims started until
+/// `withdraw_funds` took the effective payment.
+pub fn force_refund_mode(ctx: Context<ForceRefundMode>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let auction = &mut ctx.accounts.auction;
+
+    // CHECK: commitments must have settled
+    require!(
+        current_time > auction.commit_end_time,
+        LauchpadError::InCommitmentPeriod
+    );
+
+    // CHECK: failed auctions already refund everything
+    require!(
+        !auction.force_refund && !auction.is_refund_only(current_time),
+        LauchpadError::RefundModeAlreadyEnabled
+    );
+
+    // CHECK: NFT payment mode holds no payment tokens, NFTs are refunded by `return_nft`
+    require!(
+        !auction.extensions.is_nft_payment_enabled(),
+        LauchpadError::NftRefundRequiresReturn
+    );
+
+    // CHECK: forced refunds are paid from the effective payment, which must still be
+    // in the vault
+    require!(
+        !auction.unsold_sale_tokens_and_effective_payment_tokens_withdrawn,
+        LauchpadError::FundsAlreadyWithdrawn
+    );
+
+    auction.force_refund = true;
+
+    // Slash the security deposit to the treasury, or to participants as they settle
+    let security_deposit = auction.security_deposit_held;
+    if security_deposit > 0 {
+        match auction
+            .extensions
+            .security_deposit
+            .as_ref()
+            .and_then(|deposit| deposit.treasury)
+        {
+            Some(treasury) => {
+                let treasury_info = ctx
+                    .accounts
+                    .security_deposit_treasury
+                    .as_ref()
+                    .filter(|account| account.key() == treasury)
+                    .ok_or(LauchpadError::InvalidSecurityDepositTreasury)?;
+                release_security_deposit(auction, treasury_info, security_deposit)?;
+            }
+            None => auction.security_deposit_slashed = security_deposit,
+        }
+    }
+
+    emit!(ForceRefundModeEvent {
+        auction: auction.key(),
+        authority: ctx.accounts.authority.key(),
+        timestamp: current_time,
+        security_deposit_slashed: security_deposit,
+    });
+
+    msg!("Forced refund mode enabled for auction {}", auction.key());
+    Ok(())
+}
+
+/// User commits to an auction bin, returning the receipt of the accepted commitment
+///
+/// wSOL auctions sync lamports sent to the user's wSOL account earlier in the
+/// transaction and wrap any remaining shortfall from the user's lamports, so the
+/// exact amount is committed without a separate wrap transaction.
+pub fn commit(
+    ctx: Context<Commit>,
+    bin_id: u8,
+    payment_token_committed: u64,
+    expiry: u64,
+    terms_hash: [u8; 32],
+) -> Result<CommitReceipt> {
+    // CHECK: emergency state validation
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_COMMIT,
+    )?;
+
+    // CHECK: the user acknowledges the current sale terms
+    require!(
+        terms_hash == ctx.accounts.auction.terms_hash,
+        LauchpadError::TermsHashMismatch
+    );
+
+    let user_key = ctx.accounts.user.key();
+
+    // Store keys before mutably borrowing auction
+    let auction_key = ctx.accounts.auction.key();
+
+    // CHECK: Timing validation
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.auction.commit_start_time <= current_time
+            && current_time <= ctx.accounts.auction.commit_end_time,
+        LauchpadError::OutOfCommitmentPeriod
+    );
+
+    // CHECK: commitment bin validation
+    let bin_kind = ctx.accounts.auction.get_bin(bin_id)?.kind;
+
+    // CHECK: round validation, the bin's round must be open
+    let round_whitelist_authority = check_round_open(
+        &ctx.accounts.auction,
+        bin_id,
+        ctx.accounts.round.as_ref(),
+        current_time,
+    )?;
+
+    // CHECK: the bin's price is fresh
+    check_price_fresh(&ctx.accounts.auction, bin_id, current_time)?;
+
+    // CHECK: PDAs only sign through CPIs, top-level signers are wallets
+    require!(
+        ctx.accounts
+            .auction
+            .extensions
+            .is_participant_allowed(get_stack_height()),
+        LauchpadError::PdaParticipantForbidden
+    );
+
+    // CHECK: commitment amount validation, free-claim registrations commit nothing
+    require!(
+        (payment_token_committed == 0) == (bin_kind == BinKind::FreeClaim),
+        LauchpadError::InvalidCommitmentAmount
+    );
+
+    // CHECK: large commitments must be split across transactions
+    require!(
+        ctx.accounts
+            .auction
+            .extensions
+            .is_within_commit_per_tx(payment_token_committed),
+        LauchpadError::CommitPerTxExceeded
+    );
+
+    // CHECK: NFT payment mode only takes commitments through `commit_nft`
+    require!(
+        payment_token_committed == 0 || !ctx.accounts.auction.extensions.is_nft_payment_enabled(),
+        LauchpadError::NftPaymentEnabled
+    );
+
+    let mut committed = load_committed_init_if_needed(&ctx.accounts.committed)?;
+
+    // CHECK: free-claim registration happens once per user
+    require!(
+        bin_kind != BinKind::FreeClaim || committed.find_bin(bin_id).is_none(),
+        LauchpadError::FreeClaimAlreadyRegistered
+    );
+
+    // CHECK: room freed in a hard-cap bin goes to waitlisted users first
+    require!(
+        !ctx.accounts.auction.get_bin(bin_id)?.has_waitlist(),
+        LauchpadError::WaitlistNotEmpty
+    );
+
+    // CHECK: Custody authorization - skip restrictions if authorized by custody
+    let is_custody_authorized = check_custody_authorization(
+        &ctx,
+        &user_key,
+        &auction_key,
+        bin_id,
+        payment_token_committed,
+        committed.nonce,
+        expiry,
+    )?;
+
+    // Now get mutable reference to auction
+    let auction = &mut ctx.accounts.auction;
+
+    // CHECK: Extension validations (skip if custody authorized)
+    let mut whitelist_signer = None;
+    if !is_custody_authorized {
+        let attested_cap_bps = ctx.accounts.cap_attestation.as_ref().map(|a| a.cap_bps);
+        let loyalty_tier = ctx.accounts.user_profile.as_ref().map_or(0, |p| p.tier());
+        auction.extensions.check_commit_cap_exceeded(
+            &committed,
+            payment_token_committed,
+            attested_cap_bps,
+            loyalty_tier,
+        )?;
+        // The round whitelist overrides the auction whitelist
+        let whitelist_authorities = auction
+            .extensions
+            .commit_whitelist_authorities(&round_whitelist_authority);
+        // CHECK: identity groups are only linked by whitelist signatures
+        let identity_group = ctx.accounts.identity_group.as_ref().map(|g| g.group_id);
+        require!(
+            identity_group.is_none() || !whitelist_authorities.is_empty(),
+            LauchpadError::IdentityGroupRequiresWhitelist
+        );
+        if !whitelist_authorities.is_empty() {
+            let sysvar_instructions = ctx
+                .accounts
+                .sysvar_instructions
+                .as_ref()
+                .ok_or(LauchpadError::MissingSysvarInstructions)?;
+            let signer = match identity_group {
+                Some(identity_group) => auction.extensions.verify_identity_group_signature(
+                    sysvar_instructions,
+                    WhitelistPayload {
+                        user: user_key,
+                        auction: auction_key,
+                        bin_id,
+                        payment_token_committed,
+                        nonce: committed.nonce,
+                        expiry,
+                    },
+                    identity_group,
+                    whitelist_authorities,
+                )?,
+                None => auction.extensions.verify_signature_authorization(
+                    sysvar_instructions,
+                    &user_key,
+                    &auction_key,
+                    bin_id,
+                    payment_token_committed,
+                    committed.nonce,
+                    expiry,
+                    whitelist_authorities,
+                )?,
+            };
+            whitelist_signer = Some(signer);
+        }
+
+        // CHECK: the per-user commit cap is shared by all wallets of the identity group
+        if let Some(group) = ctx.accounts.identity_group.as_mut() {
+            group.payment_token_committed = group
+                .payment_token_committed
+                .checked_add(payment_token_committed)
+                .ok_or(LauchpadError::MathOverflow)?;
+            require!(
+                auction
+                    .extensions
+                    .commit_cap_per_user
+                    .map_or(true, |cap| group.payment_token_committed <= cap),
+                LauchpadError::IdentityGroupCapExceeded
+            );
+        }
+    }
+
+    // Initialize committed account if it's newly created, keeping a nonce raised
+    // by `revoke_whitelist_signatures`
+    let is_new_participant = !committed.has_commitments();
+    if is_new_participant {
+        committed.auction = auction_key;
+        committed.user = user_key;
+        committed.bump = ctx.bumps.committed;
+    }
+
+    // Record the front-end of the user's first commitment through one, accruing it a
+    // share of the claim fees
+    if let Some(frontend_fees) = ctx.accounts.frontend_fees.as_ref() {
+        // CHECK: commitments through a front-end name a registered one
+        require!(
+            ctx.accounts
+                .config
+                .is_frontend_registered(&frontend_fees.frontend),
+            LauchpadError::FrontendNotRegistered
+        );
+        if committed.frontend_id == Pubkey::default() {
+            committed.frontend_id = frontend_fees.frontend;
+        }
+    }
+
+    // Update committed account
+    let is_new_bin_participant = committed.find_bin(bin_id).is_none();
+    let claim_fee_rate = auction.extensions.claim_fee_rate_at(
+        auction.get_bin(bin_id)?,
+        auction.commit_start_time,
+        current_time,
+    );
+    committed.add_commitment(
+        bin_id,
+        payment_token_committed,
+        claim_fee_rate,
+        auction.bins.len(),
+    )?;
+
+    // Commitments to hard-cap bins in the batch window are settled pro-rata
+    let batch_window_id = auction
+        .extensions
+        .batch_window_id(auction.commit_end_time, current_time)
+        .filter(|_| auction.bins[bin_id as usize].hard_cap);
+    if batch_window_id.is_some() {
+        let committed_bin = committed
+            .find_bin_mut(bin_id)
+            .ok_or(LauchpadError::InvalidBinId)?;
+        committed_bin.window_committed += payment_token_committed;
+    }
+
+    // Update Auction state
+    if is_new_participant {
+        auction.total_participants = auction
+            .total_participants
+            .checked_add(1)
+            .ok_or(LauchpadError::MathOverflow)?;
+    }
+    let custody_allocation_limit = auction.extensions.custody_allocation_limit;
+    let reserve_at_commit = auction.extensions.reserve_at_commit;
+    let bin = auction.get_bin_mut(bin_id)?;
+    bin.payment_token_raised += payment_token_committed;
+    if batch_window_id.is_some() {
+        bin.window_committed += payment_token_committed;
+    }
+
+    // CHECK: reservations fit in the cap, sale bins are never oversubscribed
+    if reserve_at_commit && bin.kind == BinKind::Sale {
+        bin.reserve(payment_token_committed)?;
+    }
+    if is_new_bin_participant {
+        bin.add_participant()?;
+    }
+
+    // CHECK: custody-routed commitments stay within the custody allocation limit
+    if is_custody_authorized {
+        bin.add_custody_commitment(payment_token_committed, custody_allocation_limit)?;
+    }
+
+    // CHECK: hard-cap bins can't be oversubscribed, except by the batch window
+    require!(
+        !bin.hard_cap
+            || batch_window_id.is_some()
+            || bin.payment_token_raised <= bin.payment_token_target()?,
+        LauchpadError::CommitmentBinCapExceeded
+    );
+
+    if bin.kind == BinKind::FreeClaim {
+        // CHECK: registered users' fixed amounts must fit in the bin cap
+        bin.free_claim_registered = bin
+            .free_claim_registered
+            .checked_add(1)
+            .ok_or(LauchpadError::MathOverflow)?;
+        let sale_tokens_reserved = bin
+            .free_claim_registered
+            .checked_mul(bin.free_claim_amount)
+            .ok_or(LauchpadError::MathOverflow)?;
+        require!(
+            sale_tokens_reserved <= bin.sale_token_cap,
+            LauchpadError::CommitmentBinCapExceeded
+        );
+    } else {
+        // wSOL payments are topped up from the user's lamports, no separate wrap needed
+        if ctx.accounts.auction.payment_token_mint == native_mint::ID {
+            wrap_native_shortfall(
+                &ctx.accounts.user,
+                &mut ctx.accounts.user_payment_token,
+                payment_token_committed,
+                &ctx.accounts.token_program,
+                &ctx.accounts.system_program,
+            )?;
+        }
+
+        // Transfer payment tokens to vault
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_payment_token.to_account_info(),
+                    to: ctx.accounts.vault_payment_token.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            payment_token_committed,
+        )?;
+    }
+
+    let receipt = CommitReceipt {
+        auction: auction_key,
+        user: user_key,
+        bin_id,
+        payment_token_committed,
+        user_bin_payment_token_committed: committed
+            .find_bin(bin_id)
+            .map_or(0, |bin| bin.payment_token_committed),
+        bin_payment_token_raised: ctx.accounts.auction.get_bin(bin_id)?.payment_token_raised,
+        sequence: committed.nonce,
+        attribution: None,
+        terms_hash,
+        whitelist_signer,
+        batch_window_id,
+    };
+    committed.terms_hash = terms_hash;
+
+    // Increment nonce to prevent replay attacks (only after successful commit)
+    committed.nonce = committed
+        .nonce
+        .checked_add(1)
+        .ok_or(LauchpadError::NonceOverflow)?;
+
+    emit!(CommitEvent {
+        receipt: receipt.clone(),
+    });
+
+    msg!(
+        "User {} committed {} tokens to bin {}, nonce incremented to {} (custody_authorized: {})",
+        user_key,
+        payment_token_committed,
+        bin_id,
+        committed.nonce,
+        is_custody_authorized
+    );
+    Ok(receipt)
+}
+
+/// Custody commits to a bin on behalf of many users at once, e.g. for exchange-aggregated
+/// order flow, paying the total from its own payment token account
+///
+/// `remaining_accounts` holds one `[committed, user]` pair per entry, at most
+/// `MAX_CUSTODY_COMMIT_BATCH_SIZE`. Missing `Committed` accounts are created with
+/// rent paid by the custody. Like custody-authorized commits, per-user caps and the
+/// whitelist don't apply. Each entry's attribution tag is recorded in the user's
+/// `CommittedBin` and commit receipt.
+pub fn custody_commit_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CustodyCommitBatch<'info>>,
+    bin_id: u8,
+    entries: Vec<CustodyCommitEntry>,
+) -> Result<()> {
+    // CHECK: emergency state validation
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_COMMIT,
+    )?;
+
+    // CHECK: Timing validation
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.auction.commit_start_time <= current_time
+            && current_time <= ctx.accounts.auction.commit_end_time,
+        LauchpadError::OutOfCommitmentPeriod
+    );
+
+    // CHECK: round validation, the bin's round must be open
+    check_round_open(
+        &ctx.accounts.auction,
+        bin_id,
+        ctx.accounts.round.as_ref(),
+        current_time,
+    )?;
+
+    // CHECK: the bin's price is fresh
+    check_price_fresh(&ctx.accounts.auction, bin_id, current_time)?;
+
+    // CHECK: remaining accounts come in one pair per entry, bounded
+    let batch = ctx.remaining_accounts.chunks_exact(2);
+    require!(
+        !entries.is_empty()
+            && entries.len() <= MAX_CUSTODY_COMMIT_BATCH_SIZE
+            && batch.len() == entries.len()
+            && batch.remainder().is_empty(),
+        LauchpadError::InvalidCustodyCommitBatch
+    );
+
+    let auction_key = ctx.accounts.auction.key();
+    let custody_key = ctx.accounts.custody.key();
+    let auction = &mut ctx.accounts.auction;
+
+    // CHECK: only sale and donation bins take payment, NFT mode only takes NFTs
+    require!(
+        auction.get_bin(bin_id)?.kind != BinKind::FreeClaim,
+        LauchpadError::UnsupportedBinKind
+    );
+    require!(
+        !auction.extensions.is_nft_payment_enabled(),
+        LauchpadError::NftPaymentEnabled
+    );
+
+    // CHECK: room freed in a hard-cap bin goes to waitlisted users first
+    require!(
+        !auction.get_bin(bin_id)?.has_waitlist(),
+        LauchpadError::WaitlistNotEmpty
+    );
+
+    let claim_fee_rate = auction.extensions.claim_fee_rate_at(
+        auction.get_bin(bin_id)?,
+        auction.commit_start_time,
+        current_time,
+    );
+    let terms_hash = auction.terms_hash;
+    let mut total_payment_committed = 0u64;
+    for (accounts, entry) in batch.zip(entries.iter()) {
+        let (committed_info, user_info) = (&accounts[0], &accounts[1]);
+        let user_key = user_info.key();
+        let payment_token_committed = entry.payment_token_committed;
+
+        // CHECK: commitment amount validation
+        require!(
+            payment_token_committed > 0
+                && auction
+                    .extensions
+                    .is_within_commit_per_tx(payment_token_committed),
+            LauchpadError::InvalidCommitmentAmount
+        );
+
+        // CHECK: committed account belongs to this auction and user
+        let (expected_committed, committed_bump) =
+            Committed::find_program_address(&auction_key, &user_key);
+        require_keys_eq!(
+            committed_info.key(),
+            expected_committed,
+            LauchpadError::InvalidCustodyCommitBatch
+        );
+        if committed_info.data_is_empty() {
+            create_committed_account(
+                committed_info,
+                &ctx.accounts.custody,
+                &ctx.accounts.system_program,
+                &auction_key,
+                &user_key,
+                committed_bump,
+            )?;
+        }
+
+        let committed_loader = AccountLoader::<Committed>::try_from(committed_info)?;
+        let mut committed = committed_loader.load_mut()?;
+        let is_new_participant = !committed.has_commitments();
+        let is_new_bin_participant = committed.find_bin(bin_id).is_none();
+        committed.add_commitment(
+            bin_id,
+            payment_token_committed,
+            claim_fee_rate,
+            auction.bins.len(),
+        )?;
+        let committed_bin = committed
+            .find_bin_mut(bin_id)
+            .ok_or(LauchpadError::InvalidBinId)?;
+        committed_bin.set_attribution(entry.attribution);
+        let user_bin_payment_token_committed = committed_bin.payment_token_committed;
+
+        // Update Auction state
+        if is_new_participant {
+            auction.total_participants = auction
+                .total_participants
+                .checked_add(1)
+                .ok_or(LauchpadError::MathOverflow)?;
+        }
+        let reserve_at_commit = auction.extensions.reserve_at_commit;
+        let bin = auction.get_bin_mut(bin_id)?;
+        bin.payment_token_raised = bin
+            .payment_token_raised
+            .checked_add(payment_token_committed)
+            .ok_or(LauchpadError::MathOverflow)?;
+        if is_new_bin_participant {
+            bin.add_participant()?;
+        }
+        // CHECK: reservations fit in the cap, sale bins are never oversubscribed
+        if reserve_at_commit && bin.kind == BinKind::Sale {
+            bin.reserve(payment_token_committed)?;
+        }
+        total_payment_committed = total_payment_committed
+            .checked_add(payment_token_committed)
+            .ok_or(LauchpadError::MathOverflow)?;
+
+        let receipt = CommitReceipt {
+            auction: auction_key,
+            user: user_key,
+            bin_id,
+            payment_token_committed,
+            user_bin_payment_token_committed,
+            bin_payment_token_raised: bin.payment_token_raised,
+            sequence: committed.nonce,
+            attribution: Some(entry.attribution),
+            terms_hash,
+            whitelist_signer: None,
+            batch_window_id: None,
+        };
+        committed.nonce = committed
+            .nonce
+            .checked_add(1)
+            .ok_or(LauchpadError::NonceOverflow)?;
+        emit!(CommitEvent { receipt });
+    }
+
+    // CHECK: the batch stays within the custody allocation limit
+    let custody_allocation_limit = auction.extensions.custody_allocation_limit;
+    let bin = auction.get_bin_mut(bin_id)?;
+    bin.add_custody_commitment(total_payment_committed, custody_allocation_limit)?;
+
+    // CHECK: hard-cap bins can't be oversubscribed
+    require!(
+        !bin.hard_cap || bin.payment_token_raised <= bin.payment_token_target()?,
+        LauchpadError::CommitmentBinCapExceeded
+    );
+
+    // Transfer the batch total to the vault
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.custody_payment_token.to_account_info(),
+                to: ctx.accounts.vault_payment_token.to_account_info(),
+                authority: ctx.accounts.custody.to_account_info(),
+            },
+        ),
+        total_payment_committed,
+    )?;
+
+    msg!(
+        "Custody {} committed {} tokens to bin {} for {} users",
+        custody_key,
+        total_payment_committed,
+        bin_id,
+        entries.len()
+    );
+    Ok(())
+}
+
+/// Custody claims everything still owed from a bin for many users it committed for,
+/// e.g. to settle an exchange's users internally, into its own token accounts
+///
+/// `remaining_accounts` holds the users' `Committed` accounts, at most
+/// `MAX_CUSTODY_COMMIT_BATCH_SIZE`. Only commitments carrying a custody attribution tag
+/// (see `custody_commit_batch`) are claimable, each user's claim is emitted with its tag
+/// for reconciliation. Refunds are skipped once the refund deadline has passed.
+pub fn claim_to_custody<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimToCustody<'info>>,
+    bin_id: u8,
+) -> Result<()> {
+    // CHECK: emergency state validation
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_CLAIM,
+    )?;
+
+    // CHECK: the sale mint isn't blocked program-wide
+    require!(
+        !ctx.accounts
+            .config
+            .is_sale_mint_blocked(&ctx.accounts.auction.sale_token_mint),
+        LauchpadError::SaleMintBlocked
+    );
+
+    // CHECK: Timing validation, forced refunds and aborted auctions open immediately
+    let current_time = Clock::get()?.unix_timestamp;
+    let force_refund = ctx.accounts.auction.force_refund;
+    require!(
+        ctx.accounts.auction.claim_start_time <= current_time
+            || force_refund
+            || ctx.accounts.auction.aborted,
+        LauchpadError::OutOfClaimPeriod
+    );
+    require!(
+        ctx.accounts.auction.claims_open(current_time),
+        LauchpadError::ClaimPeriodEnded
+    );
+
+    // CHECK: allocations are settled by `finalize_auction`, refund-only auctions
+    // refund every commitment in full without it
+    require!(
+        ctx.accounts.auction.finalized || ctx.accounts.auction.is_refund_only(current_time),
+        LauchpadError::AuctionNotFinalized
+    );
+
+    // CHECK: IOU and NFT payment modes settle through their own instructions
+    require!(
+        !ctx.accounts.auction.extensions.iou_mode,
+        LauchpadError::IouModeEnabled
+    );
+    require!(
+        !ctx.accounts.auction.extensions.is_nft_payment_enabled(),
+        LauchpadError::NftPaymentEnabled
+    );
+
+    // CHECK: remaining accounts are a bounded batch of `Committed` accounts
+    require!(
+        !ctx.remaining_accounts.is_empty()
+            && ctx.remaining_accounts.len() <= MAX_CUSTODY_COMMIT_BATCH_SIZE,
+        LauchpadError::InvalidCustodyClaimBatch
+    );
+
+    let auction_key = ctx.accounts.auction.key();
+    let custody_key = ctx.accounts.custody.key();
+    let refund_only = ctx.accounts.auction.is_refund_only(current_time);
+    let finalized = ctx.accounts.auction.finalized;
+    let refunds_open = ctx.accounts.auction.refunds_open(current_time);
+    let refund_diverted_bps = ctx.accounts.auction.late_refund_diverted_bps(current_time);
+    let auction = &mut ctx.accounts.auction;
+    let claim_order = auction.extensions.claim_order;
+    let boost_locked = current_time
+        < auction
+            .extensions
+            .boost_unlock_time(auction.claim_start_time);
+
+    let mut total_sale_tokens = 0u64;
+    let mut total_refund_payment_tokens = 0u64;
+    let mut total_compensation = 0u64;
+    for committed_info in ctx.remaining_accounts.iter() {
+        let committed_loader = AccountLoader::<Committed>::try_from(committed_info)?;
+        let mut committed = committed_loader.load_mut()?;
+        let user_key = committed.user;
+
+        // CHECK: committed account belongs to this auction
+        let (expected_committed, _) = Committed::find_program_address(&auction_key, &user_key);
+        require_keys_eq!(
+            committed_info.key(),
+            expected_committed,
+            LauchpadError::InvalidCustodyClaimBatch
+        );
+
+        let committed_bin = committed
+            .find_bin_mut(bin_id)
+            .ok_or(LauchpadError::InvalidBinId)?;
+
+        // CHECK: only commitments made by the custody are claimed by it
+        let attribution = committed_bin
+            .attribution()
+            .ok_or(LauchpadError::InvalidCustodyClaimBatch)?;
+
+        let bin = auction.get_bin_mut(bin_id)?;
+        let claimable_amounts = if force_refund {
+            calculate_force_refund_amounts(
+                committed_bin.payment_token_committed,
+                committed_bin.boosted_committed,
+                committed_bin.window_committed,
+                bin,
+                committed_bin.sale_token_claimed,
+                finalized,
+            )?
+        } else {
+            calculate_bin_entitlement(
+                committed_bin.payment_token_committed,
+                committed_bin.boosted_committed,
+                committed_bin.window_committed,
+                bin,
+                refund_only,
+                finalized,
+            )?
+        };
+        claimable_amounts.validate(committed_bin.payment_token_committed)?;
+
+        let remaining_sale_tokens = claimable_amounts
+            .sale_tokens
+            .saturating_sub(committed_bin.sale_token_claimed);
+        // Sale tokens of boosted commitments are left for after the boost lock period
+        let unlocked_sale_tokens = if boost_locked {
+            claimable_amounts
+                .sale_tokens
+                .saturating_sub(claimable_amounts.boosted_sale_tokens)
+                .saturating_sub(committed_bin.sale_token_claimed)
+        } else {
+            remaining_sale_tokens
+        };
+        let remaining_payment_refund = claimable_amounts
+            .refund_payment_tokens
+            .saturating_sub(committed_bin.payment_token_refunded);
+        let sale_token_to_claim = if force_refund {
+            0
+        } else {
+            unlocked_sale_tokens
+        };
+        let payment_token_to_refund = if refunds_open {
+            remaining_payment_refund
+        } else {
+            0
+        };
+        if sale_token_to_claim == 0 && payment_token_to_refund == 0 {
+            continue;
+        }
+
+        // CHECK: claim order policy, the first leg must settle before the other starts
+        require!(
+            claim_order.allows(
+                sale_token_to_claim,
+                remaining_sale_tokens,
+                payment_token_to_refund,
+                remaining_payment_refund,
+            ),
+            LauchpadError::ClaimOrderViolated
+        );
+
+        let claim_fee = auction.extensions.calculate_claim_fee(
+            &user_key,
+            sale_token_to_claim,
+            committed_bin.claim_fee_rate,
+            auction.claim_start_time,
+            current_time,
+        );
+
+        // Late refunds are partly diverted to the late refund pool
+        let refund_diverted =
+            (payment_token_to_refund as u128 * refund_diverted_bps as u128 / 10000) as u64;
+        let refund_payment_tokens = payment_token_to_refund - refund_diverted;
+
+        // Update state
+        committed_bin.sale_token_claimed += sale_token_to_claim;
+        committed_bin.payment_token_refunded += payment_token_to_refund;
+        committed.refund_diverted += refund_diverted;
+        auction
+            .get_bin_mut(bin_id)?
+            .record_claim(sale_token_to_claim, claim_fee)?;
+        auction.total_fees_collected += claim_fee;
+        auction.total_payment_refunded += refund_payment_tokens;
+        auction.total_refund_diverted += refund_diverted;
+        let sale_tokens = sale_token_to_claim.saturating_sub(claim_fee);
+        total_sale_tokens = total_sale_tokens
+            .checked_add(sale_tokens)
+            .ok_or(LauchpadError::MathOverflow)?;
+        total_refund_payment_tokens = total_refund_payment_tokens
+            .checked_add(refund_payment_tokens)
+            .ok_or(LauchpadError::MathOverflow)?;
+
+        // Release the settled payment of a fully claimed commitment to `withdraw_funds`,
+        // with its share of the slashed security deposit going to the custody
+        if check_all_bins_fully_claimed(
+            &committed.bins,
+            &auction.bins,
+            refund_only,
+            force_refund,
+            finalized,
+        )? {
+            let settled_payment = committed
+                .active_bins()
+                .map(|bin| {
+                    bin.payment_token_committed
+                        .saturating_sub(bin.payment_token_refunded)
+                })
+                .sum::<u64>();
+            auction.total_payment_released = auction
+                .total_payment_released
+                .checked_add(settled_payment)
+                .ok_or(LauchpadError::MathOverflow)?;
+            if auction.security_deposit_slashed > 0 {
+                let compensation = (auction.security_deposit_slashed as u128
+                    * committed.total_payment_committed() as u128
+                    / auction.total_payment_raised()?.max(1) as u128)
+                    as u64;
+                total_compensation = total_compensation
+                    .checked_add(compensation)
+                    .ok_or(LauchpadError::MathOverflow)?;
+            }
+        }
+
+        emit!(CustodyClaimEvent {
+            auction: auction_key,
+            custody: custody_key,
+            user: user_key,
+            bin_id,
+            sale_tokens,
+            claim_fee,
+            refund_payment_tokens,
+            attribution,
+        });
+    }
+
+    let total_compensation = total_compensation.min(auction.security_deposit_held);
+    if total_compensation > 0 {
+        release_security_deposit(
+            auction,
+            &ctx.accounts.custody.to_account_info(),
+            total_compensation,
+        )?;
+    }
+
+    // Transfer the batch totals to the custody
+    for (vault, destination, seed, bump, amount) in [
+        (
+            &ctx.accounts.vault_sale_token,
+            &ctx.accounts.custody_sale_token,
+            VAULT_SALE_SEED,
+            auction.vault_sale_bump,
+            total_sale_tokens,
+        ),
+        (
+            &ctx.accounts.vault_payment_token,
+            &ctx.accounts.custody_payment_token,
+            VAULT_PAYMENT_SEED,
+            auction.vault_payment_bump,
+            total_refund_payment_tokens,
+        ),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        let vault_seeds = &[seed, auction_key.as_ref(), &[bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: vault.to_account_info(),
+                    to: destination.to_account_info(),
+                    authority: vault.to_account_info(),
+                },
+                &[vault_seeds],
+            ),
+            amount,
+        )?;
+    }
+
+    msg!(
+        "Custody {} claimed {} sale tokens and {} payment refund from bin {} for {} users",
+        custody_key,
+        total_sale_tokens,
+        total_refund_payment_tokens,
+        bin_id,
+        ctx.remaining_accounts.len()
+    );
+    Ok(())
+}
+
+/// Create the `Committed` account of a user committed for by someone else, paid by `payer`
+fn create_committed_account<'info>(
+    committed_info: &'info AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    auction_key: &Pubkey,
+    user_key: &Pubkey,
+    bump: u8,
+) -> Result<()> {
+    let space = Committed::SPACE;
+    let committed_seeds = &[
+        COMMITTED_SEED,
+        auction_key.as_ref(),
+        user_key.as_ref(),
+        &[bump],
+    ];
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::CreateAccount {
+                from: payer.to_account_info(),
+                to: committed_info.clone(),
+            },
+            &[committed_seeds],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &crate::ID,
+    )?;
+
+    let committed_loader =
+        AccountLoader::<Committed>::try_from_unchecked(&crate::ID, committed_info)?;
+    {
+        let mut committed = committed_loader.load_init()?;
+        committed.auction = *auction_key;
+        committed.user = *user_key;
+        committed.bump = bump;
+    }
+    committed_loader.exit(&crate::ID)
+}
+
+/// Load the `Committed` account of an `init_if_needed` context, initializing it if it
+/// was just created
+fn load_committed_init_if_needed<'a>(
+    committed: &'a AccountLoader<'_, Committed>,
+) -> Result<RefMut<'a, Committed>> {
+    let is_new = committed.as_ref().try_borrow_data()?[..8] == [0; 8];
+    if is_new {
+        committed.load_init()
+    } else {
+        committed.load_mut()
+    }
+}
+
+/// Check if the current transaction is authorized by custody account
+/// Returns true if user is custody or has valid custody signature authorization
+fn check_custody_authorization(
+    ctx: &Context<Commit>,
+    user: &Pubkey,
+    auction: &Pubkey,
+    bin_id: u8,
+    payment_token_committed: u64,
+    nonce: u64,
+    expiry: u64,
+) -> Result<bool> {
+    let custody = ctx.accounts.auction.custody;
+
+    // Case 1: User is directly the custody account
+    if *user == custody {
+        return Ok(true);
+    }
+
+    // Case 2: Check for custody signature authorization (if custody_authority provided)
+    if let Some(custody_authority) = &ctx.accounts.custody_authority {
+        // Verify the custody_authority matches the stored custody account
+        require_keys_eq!(
+            custody_authority.key(),
+            custody,
+            LauchpadError::InvalidCustodyAuthority
+        );
+
+        // Verify custody signature using the same mechanism as whitelist
+        if let Some(sysvar_instructions) = &ctx.accounts.sysvar_instructions {
+            ctx.accounts
+                .auction
+                .extensions
+                .verify_signature_authorization(
+                    sysvar_instructions,
+                    user,
+                    auction,
+                    bin_id,
+                    payment_token_committed,
+                    nonce,
+                    expiry,
+                    &[custody_authority.key()],
+                )?;
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Anyone creates the identity group record of `group_id` for an auction, before
+/// its first commitment made with a whitelist signature naming the group
+pub fn init_identity_group(ctx: Context<InitIdentityGroup>, group_id: [u8; 32]) -> Result<()> {
+    let identity_group = &mut ctx.accounts.identity_group;
+    identity_group.auction = ctx.accounts.auction.key();
+    identity_group.group_id = group_id;
+    identity_group.payment_token_committed = 0;
+    identity_group.bump = ctx.bumps.identity_group;
+
+    msg!(
+        "Identity group {} created for auction {}",
+        identity_group.key(),
+        identity_group.auction
+    );
+    Ok(())
+}
+
+/// Whitelist authority revokes a user's outstanding signatures before they expire by
+/// raising the user's nonce to `nonce_floor`
+///
+/// Whitelist and custody signatures name the user's current `Committed` nonce, so every
+/// signature issued for a lower nonce, e.g. a leaked one, becomes unusable without
+/// rotating the authority. The `Committed` account is created if the user hasn't
+/// committed yet.
+pub fn revoke_whitelist_signatures(
+    ctx: Context<RevokeWhitelistSignatures>,
+    user: Pubkey,
+    nonce_floor: u64,
+) -> Result<()> {
+    // CHECK: only the auction's whitelist authorities revoke its signatures
+    require!(
+        ctx.accounts
+            .auction
+            .extensions
+            .whitelist_authorities
+            .contains(&ctx.accounts.whitelist_authority.key()),
+        LauchpadError::WrongWhitelistAuthority
+    );
+
+    let mut committed = load_committed_init_if_needed(&ctx.accounts.committed)?;
+
+    // CHECK: the nonce only moves forward, so revoked signatures stay revoked
+    require!(
+        nonce_floor > committed.nonce,
+        LauchpadError::InvalidNonceFloor
+    );
+
+    if !committed.has_commitments() {
+        committed.auction = ctx.accounts.auction.key();
+        committed.user = user;
+        committed.bump = ctx.bumps.committed;
+    }
+    committed.nonce = nonce_floor;
+
+    msg!(
+        "Whitelist signatures of user {} below nonce {} revoked",
+        user,
+        nonce_floor
+    );
+    Ok(())
+}
+
+/// Authority creates or extends an address lookup table holding the auction's fixed
+/// accounts (see `pda::auction_lookup_table_addresses`), owned by the authority
+///
+/// Multi-account instructions like `custody_commit_batch`, `refund_batch` or
+/// `process_waitlist` then fit v0 transactions referencing the table. A new table is
+/// derived from the authority and `recent_slot`, an existing one is only extended with
+/// the addresses it's missing.
+pub fn register_lut(ctx: Context<RegisterLut>, recent_slot: u64) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    let authority = ctx.accounts.authority.key();
+    let lookup_table_info = ctx.accounts.lookup_table.to_account_info();
+    let account_infos = [
+        lookup_table_info.clone(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.address_lookup_table_program.to_account_info(),
+    ];
+
+    let mut addresses = crate::pda::auction_lookup_table_addresses(
+        &auction.key(),
+        &auction.sale_token_mint,
+        &auction.payment_token_mint,
+    );
+    if lookup_table_info.data_is_empty() {
+        let (create_ix, lookup_table) = lookup_table_create_ix(authority, recent_slot);
+        // CHECK: the new table is the one derived from the authority and slot
+        require_keys_eq!(
+            lookup_table_info.key(),
+            lookup_table,
+            LauchpadError::InvalidLookupTable
+        );
+        anchor_lang::solana_program::program::invoke(&create_ix, &account_infos)?;
+    } else {
+        // CHECK: existing tables must belong to the lookup table program
+        require_keys_eq!(
+            *lookup_table_info.owner,
+            ctx.accounts.address_lookup_table_program.key(),
+            LauchpadError::InvalidLookupTable
+        );
+        let data = lookup_table_info.try_borrow_data()?;
+        let existing = data
+            .get(LOOKUP_TABLE_META_SIZE..)
+            .ok_or(LauchpadError::InvalidLookupTable)?
+            .chunks_exact(32)
+            .map(|address| Pubkey::try_from(address).expect("32-byte chunk"))
+            .collect::<Vec<_>>();
+        addresses.retain(|address| !existing.contains(address));
+    }
+
+    let added = addresses.len();
+    if added > 0 {
+        let extend_ix = lookup_table_extend_ix(lookup_table_info.key(), authority, addresses);
+        anchor_lang::solana_program::program::invoke(&extend_ix, &account_infos)?;
+    }
+
+    msg!(
+        "Lookup table {} registered for auction {}, {} addresses added",
+        lookup_table_info.key(),
+        auction.key(),
+        added
+    );
+    Ok(())
+}
+
+/// Size of the metadata preceding the addresses of a lookup table account
+const LOOKUP_TABLE_META_SIZE: usize = 56;
+
+/// Lookup table program instruction creating a table owned by `authority`
+#[allow(deprecated)]
+fn lookup_table_create_ix(authority: Pubkey, recent_slot: u64) -> (Instruction, Pubkey) {
+    anchor_lang::solana_program::address_lookup_table::instruction::create_lookup_table(
+        authority,
+        authority,
+        recent_slot,
+    )
+}
+
+/// Lookup table program instruction appending `addresses` to a table
+#[allow(deprecated)]
+fn lookup_table_extend_ix(
+    lookup_table: Pubkey,
+    authority: Pubkey,
+    addresses: Vec<Pubkey>,
+) -> Instruction {
+    anchor_lang::solana_program::address_lookup_table::instruction::extend_lookup_table(
+        lookup_table,
+        authority,
+        Some(authority),
+        addresses,
+    )
+}
+
+/// Check the round of a bin is open for commitments, returning the round's
+/// whitelist authority override (if any)
+fn check_round_open(
+    auction: &Account<Auction>,
+    bin_id: u8,
+    round: Option<&Account<Round>>,
+    current_time: i64,
+) -> Result<Option<Pubkey>> {
+    if !auction.has_rounds() {
+        return Ok(None);
+    }
+
+    let bin_round = auction.get_bin(bin_id)?.round;
+    let round = round.ok_or(LauchpadError::InvalidRoundAccount)?;
+    require!(
+        round.auction == auction.key() && round.index == bin_round,
+        LauchpadError::InvalidRoundAccount
+    );
+
+    // CHECK: earlier rounds must be closed so their unsold supply has rolled forward
+    require_eq!(
+        auction.rounds_closed,
+        bin_round,
+        LauchpadError::RoundNotOpen
+    );
+    require!(
+        round.commit_start_time <= current_time && current_time <= round.commit_end_time,
+        LauchpadError::OutOfCommitmentPeriod
+    );
+
+    Ok(round.whitelist_authority)
+}
+
+/// Check the price of a bin is fresh enough to commit at
+///
+/// A stale price emits `PriceStaleEvent` before failing, which stays in the logs of
+/// the failed transaction for keepers refreshing prices.
+fn check_price_fresh(auction: &Account<Auction>, bin_id: u8, current_time: i64) -> Result<()> {
+    let bin = auction.get_bin(bin_id)?;
+    if auction.extensions.is_price_stale(bin, current_time) {
+        emit!(PriceStaleEvent {
+            auction: auction.key(),
+            bin_id,
+            sale_token_price: bin.sale_token_price,
+            price_updated_at: bin.price_updated_at,
+            timestamp: current_time,
+        });
+        return err!(LauchpadError::PriceStale);
+    }
+    Ok(())
+}
+
+/// User decreases a commitment (renamed from revert_commit)
+pub fn decrease_commit(
+    ctx: Context<DecreaseCommit>,
+    bin_id: u8,
+    payment_token_reverted: u64,
+) -> Result<()> {
+    // CHECK: emergency state validation
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_COMMIT,
+    )?;
+
+    let auction = &mut ctx.accounts.auction;
+
+    // CHECK: Timing validation
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        auction.commit_start_time <= current_time && current_time <= auction.commit_end_time,
+        LauchpadError::OutOfCommitmentPeriod
+    );
+
+    // CHECK: commitment amount validation
+    require_neq!(
+        payment_token_reverted,
+        0,
+        LauchpadError::InvalidCommitmentAmount
+    );
+
+    // CHECK: NFT commitments are decreased through `return_nft`
+    require!(
+        !auction.extensions.is_nft_payment_enabled(),
+        LauchpadError::NftPaymentEnabled
+    );
+
+    // CHECK: only sale bin commitments can be decreased, donations are non-refundable
+    require!(
+        auction.get_bin(bin_id)?.kind == BinKind::Sale,
+        LauchpadError::UnsupportedBinKind
+    );
+
+    // CHECK: round validation, the bin's round must be open
+    check_round_open(auction, bin_id, ctx.accounts.round.as_ref(), current_time)?;
+
+    let mut committed = ctx.accounts.committed.load_mut()?;
+
+    // CHECK: Validate sufficient committed amount
+    let committed_bin = committed
+        .find_bin_mut(bin_id)
+        .ok_or(LauchpadError::InvalidBinId)?;
+    require!(
+        committed_bin.payment_token_committed >= payment_token_reverted,
+        LauchpadError::InvalidCommitmentAmount
+    );
+
+    // CHECK: boosted commitments are locked in
+    require!(
+        committed_bin.payment_token_committed - payment_token_reverted
+            >= committed_bin.boosted_committed,
+        LauchpadError::BoostedCommitmentLocked
+    );
+
+    // Update committed account, batch window commitments are decreased first
+    let window_reverted = payment_token_reverted.min(committed_bin.window_committed);
+    committed_bin.payment_token_committed -= payment_token_reverted;
+    committed_bin.window_committed -= window_reverted;
+
+    // Update Auction state
+    let reserve_at_commit = auction.extensions.reserve_at_commit;
+    let bin = auction.get_bin_mut(bin_id)?;
+    bin.payment_token_raised -= payment_token_reverted;
+    bin.window_committed -= window_reverted;
+    if reserve_at_commit && bin.kind == BinKind::Sale {
+        bin.release_reservation(payment_token_reverted)?;
+    }
+
+    // Transfer payment tokens back to user
+    let auction_key = auction.key();
+    let vault_seeds = &[
+        VAULT_PAYMENT_SEED,
+        auction_key.as_ref(),
+        &[auction.vault_payment_bump],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault_payment_token.to_account_info(),
+                to: ctx.accounts.user_payment_token.to_account_info(),
+                authority: ctx.accounts.vault_payment_token.to_account_info(),
+            },
+            &[vault_seeds],
+        ),
+        payment_token_reverted,
+    )?;
+
+    msg!(
+        "User {} decreased commitment by {} tokens from bin {}",
+        ctx.accounts.user.key(),
+        payment_token_reverted,
+        bin_id
+    );
+    Ok(())
+}
+
+/// User boosts their whole commitment to a sale bin during the commit period
+///
+/// Boosted commitments can't be decreased, weigh `commit_boost.weight_bps` in the
+/// allocation of an oversubscribed bin, and their sale tokens unlock
+/// `commit_boost.lock_period` seconds after `claim_start_time`. Later commitments to
+/// the bin aren't boosted until this is called again.
+pub fn boost_commitment(ctx: Context<BoostCommitment>, bin_id: u8) -> Result<()> {
+    // CHECK: emergency state validation
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_COMMIT,
+    )?;
+
+    let auction = &mut ctx.accounts.auction;
+
+    // CHECK: Timing validation
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        auction.commit_start_time <= current_time && current_time <= auction.commit_end_time,
+        LauchpadError::OutOfCommitmentPeriod
+    );
+
+    // CHECK: commitment boosting is enabled
+    require!(
+        auction.extensions.commit_boost.is_some(),
+        LauchpadError::CommitBoostDisabled
+    );
+
+    // CHECK: only sale bin commitments are allocated
+    require!(
+        auction.get_bin(bin_id)?.kind == BinKind::Sale,
+        LauchpadError::UnsupportedBinKind
+    );
+
+    // CHECK: round validation, the bin's round must be open
+    check_round_open(auction, bin_id, ctx.accounts.round.as_ref(), current_time)?;
+
+    let mut committed = ctx.accounts.committed.load_mut()?;
+
+    // CHECK: there is a commitment left to boost
+    let committed_bin = committed
+        .find_bin_mut(bin_id)
+        .ok_or(LauchpadError::InvalidBinId)?;
+    let payment_token_boosted =
+        committed_bin.payment_token_committed - committed_bin.boosted_committed;
+    require_neq!(
+        payment_token_boosted,
+        0,
+        LauchpadError::InvalidCommitmentAmount
+    );
+
+    committed_bin.boosted_committed = committed_bin.payment_token_committed;
+    let bin = auction.get_bin_mut(bin_id)?;
+    bin.boosted_committed = bin
+        .boosted_committed
+        .checked_add(payment_token_boosted)
+        .ok_or(LauchpadError::MathOverflow)?;
+
+    msg!(
+        "User {} boosted {} committed tokens in bin {}",
+        ctx.accounts.user.key(),
+        payment_token_boosted,
+        bin_id
+    );
+    Ok(())
+}
+
+/// User joins the waitlist of a full hard-cap bin, escrowing the payment tokens
+///
+/// `sequence` must be the bin's next waitlist sequence. The escrow is committed
+/// in full by `process_waitlist` once earlier commitments are decreased.
+pub fn join_waitlist(
+    ctx: Context<JoinWaitlist>,
+    bin_id: u8,
+    sequence: u64,
+    payment_token_amount: u64,
+    expiry: u64,
+    terms_hash: [u8; 32],
+) -> Result<()> {
+    // CHECK: emergency state validation
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_COMMIT,
+    )?;
+
+    // CHECK: the user acknowledges the current sale terms
+    require!(
+        terms_hash == ctx.accounts.auction.terms_hash,
+        LauchpadError::TermsHashMismatch
+    );
+
+    let user_key = ctx.accounts.user.key();
+    let auction_key = ctx.accounts.auction.key();
+
+    // CHECK: Timing validation
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.auction.commit_start_time <= current_time
+            && current_time <= ctx.accounts.auction.commit_end_time,
+        LauchpadError::OutOfCommitmentPeriod
+    );
+
+    // CHECK: commitment amount validation
+    require_neq!(
+        payment_token_amount,
+        0,
+        LauchpadError::InvalidCommitmentAmount
+    );
+
+    // CHECK: large commitments must be split across transactions
+    require!(
+        ctx.accounts
+            .auction
+            .extensions
+            .is_within_commit_per_tx(payment_token_amount),
+        LauchpadError::CommitPerTxExceeded
+    );
+
+    // CHECK: NFT payment mode only takes commitments through `commit_nft`
+    require!(
+        !ctx.accounts.auction.extensions.is_nft_payment_enabled(),
+        LauchpadError::NftPaymentEnabled
+    );
+
+    // CHECK: round validation, the bin's round must be open
+    let round_whitelist_authority = check_round_open(
+        &ctx.accounts.auction,
+        bin_id,
+        ctx.accounts.round.as_ref(),
+        current_time,
+    )?;
+
+    // CHECK: the bin's price is fresh
+    check_price_fresh(&ctx.accounts.auction, bin_id, current_time)?;
+
+    // CHECK: PDAs only sign through CPIs, top-level signers are wallets
+    require!(
+        ctx.accounts
+            .auction
+            .extensions
+            .is_participant_allowed(get_stack_height()),
+        LauchpadError::PdaParticipantForbidden
+    );
+
+    // CHECK: the waitlist is only for hard-cap bins without room for the commitment
+    let bin = ctx.accounts.auction.get_bin(bin_id)?;
+    require!(bin.hard_cap, LauchpadError::BinNotHardCapped);
+    if ctx.accounts.auction.extensions.reserve_at_commit {
+        bin.reservable_sale_tokens(payment_token_amount)?;
+    }
+    require!(
+        bin.has_waitlist()
+            || bin
+                .payment_token_raised
+                .checked_add(payment_token_amount)
+                .ok_or(LauchpadError::MathOverflow)?
+                > bin.payment_token_target()?,
+        LauchpadError::BinNotFull
+    );
+    require_eq!(
+        sequence,
+        bin.waitlist_tail,
+        LauchpadError::InvalidWaitlistSequence
+    );
+
+    let mut committed = load_committed_init_if_needed(&ctx.accounts.committed)?;
+
+    // CHECK: Extension validations, the escrow is committed without further checks
+    let attested_cap_bps = ctx.accounts.cap_attestation.as_ref().map(|a| a.cap_bps);
+    let loyalty_tier = ctx.accounts.user_profile.as_ref().map_or(0, |p| p.tier());
+    ctx.accounts.auction.extensions.check_commit_cap_exceeded(
+        &committed,
+        payment_token_amount,
+        attested_cap_bps,
+        loyalty_tier,
+    )?;
+    let whitelist_authorities = ctx
+        .accounts
+        .auction
+        .extensions
+        .commit_whitelist_authorities(&round_whitelist_authority);
+    if !whitelist_authorities.is_empty() {
+        let sysvar_instructions = ctx
+            .accounts
+            .sysvar_instructions
+            .as_ref()
+            .ok_or(LauchpadError::MissingSysvarInstructions)?;
+        ctx.accounts
+            .auction
+            .extensions
+            .verify_signature_authorization(
+                sysvar_instructions,
+                &user_key,
+                &auction_key,
+                bin_id,
+                payment_token_amount,
+                committed.nonce,
+                expiry,
+                whitelist_authorities,
+            )?;
+    }
+
+    // Initialize committed account if it's newly created, promotion commits into it
+    if !committed.has_commitments() {
+        committed.auction = auction_key;
+        committed.user = user_key;
+        committed.bump = ctx.bumps.committed;
+    }
+
+    // Increment nonce to prevent replay attacks
+    committed.nonce = committed
+        .nonce
+        .checked_add(1)
+        .ok_or(LauchpadError::NonceOverflow)?;
+    committed.terms_hash = terms_hash;
+    committed.waitlist_pending = committed
+        .waitlist_pending
+        .checked_add(payment_token_amount)
+        .ok_or(LauchpadError::MathOverflow)?;
+
+    // Record the waitlist entry
+    *ctx.accounts.waitlist_entry = WaitlistEntry {
+        auction: auction_key,
+        user: user_key,
+        bin_id,
+        sequence,
+        payment_token_amount,
+        bump: ctx.bumps.waitlist_entry,
+    };
+    let auction = &mut ctx.accounts.auction;
+    auction.waitlist_escrowed = auction
+        .waitlist_escrowed
+        .checked_add(payment_token_amount)
+        .ok_or(LauchpadError::MathOverflow)?;
+    let bin = auction.get_bin_mut(bin_id)?;
+    bin.waitlist_tail = bin
+        .waitlist_tail
+        .checked_add(1)
+        .ok_or(LauchpadError::MathOverflow)?;
+
+    // Transfer payment tokens to vault
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.user_payment_token.to_account_info(),
+                to: ctx.accounts.vault_payment_token.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        payment_token_amount,
+    )?;
+
+    msg!(
+        "User {} joined the waitlist of bin {} at position {} with {} tokens",
+        user_key,
+        bin_id,
+        sequence,
+        payment_token_amount
+    );
+    Ok(())
+}
+
+/// User leaves a waitlist, taking back the escrowed payment tokens
+///
+/// Not gated by commit pauses, users can always take back their escrow.
+pub fn leave_waitlist(ctx: Context<LeaveWaitlist>) -> Result<()> {
+    let payment_token_amount = ctx.accounts.waitlist_entry.payment_token_amount;
+
+    // Release the escrow from the user's commit cap, unless claims closed the account
+    if let Some(committed) = ctx.accounts.committed.as_ref() {
+        let mut committed = committed.load_mut()?;
+        committed.waitlist_pending = committed
+            .waitlist_pending
+            .checked_sub(payment_token_amount)
+            .ok_or(LauchpadError::MathUnderflow)?;
+    }
+
+    let auction = &mut ctx.accounts.auction;
+    auction.waitlist_escrowed = auction
+        .waitlist_escrowed
+        .checked_sub(payment_token_amount)
+        .ok_or(LauchpadError::MathUnderflow)?;
+
+    // Transfer payment tokens back to user
+    let auction_key = auction.key();
+    let vault_seeds = &[
+        VAULT_PAYMENT_SEED,
+        auction_key.as_ref(),
+        &[auction.vault_payment_bump],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault_payment_token.to_account_info(),
+                to: ctx.accounts.user_payment_token.to_account_info(),
+                authority: ctx.accounts.vault_payment_token.to_account_info(),
+            },
+            &[vault_seeds],
+        ),
+        payment_token_amount,
+    )?;
+
+    msg!(
+        "User {} left the waitlist of bin {} at position {}, {} tokens returned",
+        ctx.accounts.user.key(),
+        ctx.accounts.waitlist_entry.bin_id,
+        ctx.accounts.waitlist_entry.sequence,
+        payment_token_amount
+    );
+    Ok(())
+}
+
+/// Promotes waitlisted commitments of a hard-cap bin in order, while the bin has room
+///
+/// Permissionless. `remaining_accounts` holds up to `MAX_WAITLIST_BATCH_SIZE` triples
+/// of `[waitlist_entry, committed, user]` starting at the bin's waitlist head. Entries
+/// already left by their user are closed accounts and skipped, their other accounts
+/// are ignored. Promotion stops at the first entry that doesn't fit.
+pub fn process_waitlist<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ProcessWaitlist<'info>>,
+    bin_id: u8,
+) -> Result<()> {
+    // CHECK: emergency state validation
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_COMMIT,
+    )?;
+
+    // CHECK: promotions change commitments, so only during the commit period
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.auction.commit_start_time <= current_time
+            && current_time <= ctx.accounts.auction.commit_end_time,
+        LauchpadError::OutOfCommitmentPeriod
+    );
+
+    // CHECK: remaining accounts come in non-empty, bounded triples
+    let batch = ctx.remaining_accounts.chunks_exact(3);
+    let batch_size = batch.len();
+    require!(
+        batch_size > 0 && batch_size <= MAX_WAITLIST_BATCH_SIZE && batch.remainder().is_empty(),
+        LauchpadError::InvalidWaitlistBatch
+    );
+
+    let auction_key = ctx.accounts.auction.key();
+    let auction = &mut ctx.accounts.auction;
+    let reserve_at_commit = auction.extensions.reserve_at_commit;
+
+    // CHECK: closed rounds have settled their supply, their bins take no more commitments
+    require!(
+        !auction.has_rounds() || auction.rounds_closed == auction.get_bin(bin_id)?.round,
+        LauchpadError::RoundNotOpen
+    );
+
+    let mut promoted = 0u64;
+    for accounts in batch {
+        let (entry_info, committed_info, user_info) = (&accounts[0], &accounts[1], &accounts[2]);
+        let bin = auction.get_bin_mut(bin_id)?;
+        if !bin.has_waitlist() {
+            break;
+        }
+
+        // CHECK: entry is the bin's waitlist head
+        let (expected_entry, _) =
+            WaitlistEntry::find_program_address(&auction_key, bin_id, bin.waitlist_head);
+        require_keys_eq!(
+            entry_info.key(),
+            expected_entry,
+            LauchpadError::InvalidWaitlistBatch
+        );
+
+        // Skip entries whose user already left the waitlist
+        if entry_info.data_is_empty() {
+            bin.waitlist_head += 1;
+            continue;
+        }
+
+        let entry = Account::<WaitlistEntry>::try_from(entry_info)?;
+        if !bin.promote_waitlist_head(entry.payment_token_amount, reserve_at_commit)? {
+            break;
+        }
+
+        // CHECK: committed account belongs to this auction and the entry's user
+        let (expected_committed, _) = Committed::find_program_address(&auction_key, &entry.user);
+        require_keys_eq!(
+            committed_info.key(),
+            expected_committed,
+            LauchpadError::InvalidWaitlistBatch
+        );
+        require_keys_eq!(
+            user_info.key(),
+            entry.user,
+            LauchpadError::InvalidWaitlistBatch
+        );
+
+        // Commit the escrow, within the commit cap it was counted against when escrowed
+        let committed_loader = AccountLoader::<Committed>::try_from(committed_info)?;
+        let mut committed = committed_loader.load_mut()?;
+        if committed.find_bin(bin_id).is_none() {
+            bin.add_participant()?;
+        }
+        if !committed.has_commitments() {
+            auction.total_participants = auction
+                .total_participants
+                .checked_add(1)
+                .ok_or(LauchpadError::MathOverflow)?;
+        }
+        let claim_fee_rate = auction.extensions.claim_fee_rate_at(
+            auction.get_bin(bin_id)?,
+            auction.commit_start_time,
+            current_time,
+        );
+        committed.promote_waitlist_escrow(
+            bin_id,
+            entry.payment_token_amount,
+            claim_fee_rate,
+            auction.bins.len(),
+        )?;
+        drop(committed);
+        auction.waitlist_escrowed = auction
+            .waitlist_escrowed
+            .checked_sub(entry.payment_token_amount)
+            .ok_or(LauchpadError::MathUnderflow)?;
+
+        // Close the entry and return the rent to the user
+        entry.close(user_info.clone())?;
+        promoted += 1;
+    }
+
+    // Reward the keeper only for the promotions it made
+    let bounty = pay_keeper_bounty(
+        auction,
+        &ctx.accounts.keeper,
+        promoted,
+        MAX_WAITLIST_BATCH_SIZE as u64,
+    )?;
+
+    let bin = auction.get_bin(bin_id)?;
+    msg!(
+        "Promoted {} waitlisted commitments in bin {}, {} still waiting, {} lamports keeper bounty",
+        promoted,
+        bin_id,
+        bin.waitlist_tail - bin.waitlist_head,
+        bounty
+    );
+    Ok(())
+}
+
+/// User records a wallet-quality attestation signed by the whitelist authority,
+/// raising their commit cap to `cap_bps` of `commit_cap_per_user`
+///
+/// The Ed25519 signature of the `CapAttestationPayload` must be the first
+/// instruction of the transaction. A newer attestation replaces the previous one.
+pub fn attest_commit_cap(ctx: Context<AttestCommitCap>, cap_bps: u16, expiry: u64) -> Result<()> {
+    // CHECK: commit cap scaling must be enabled
+    require!(
+        ctx.accounts.auction.extensions.unattested_cap_bps.is_some(),
+        LauchpadError::CapScalingDisabled
+    );
+
+    // CHECK: attested share validation
+    require!(cap_bps <= 10000, LauchpadError::InvalidCapAttestation);
+
+    let user_key = ctx.accounts.user.key();
+    let auction_key = ctx.accounts.auction.key();
+    let signer = ctx.accounts.auction.extensions.verify_cap_attestation(
+        &ctx.accounts.sysvar_instructions,
+        &user_key,
+        &auction_key,
+        cap_bps,
+        expiry,
+    )?;
+
+    let cap_attestation = &mut ctx.accounts.cap_attestation;
+    cap_attestation.auction = auction_key;
+    cap_attestation.user = user_key;
+    cap_attestation.cap_bps = cap_bps;
+    cap_attestation.bump = ctx.bumps.cap_attestation;
+
+    msg!(
+        "User {} attested for {} bps of the commit cap, signed by {}",
+        user_key,
+        cap_bps,
+        signer
+    );
+    Ok(())
+}
+
+/// User commits to an auction bin by escrowing an NFT from the auction collection
+///
+/// The NFT counts as the configured payment value. Bins can't be oversubscribed
+/// by NFT commitments, so every escrowed NFT is sold in full unless the auction
+/// fails its soft cap. Whitelisted auctions and rounds require a signature over
+/// the payment value, as for `commit`.
+pub fn commit_nft(
+    ctx: Context<CommitNft>,
+    bin_id: u8,
+    expiry: u64,
+    terms_hash: [u8; 32],
+) -> Result<()> {
+    // CHECK: emergency state validation
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_COMMIT,
+    )?;
+
+    // CHECK: the user acknowledges the current sale terms
+    require!(
+        terms_hash == ctx.accounts.auction.terms_hash,
+        LauchpadError::TermsHashMismatch
+    );
+
+    let user_key = ctx.accounts.user.key();
+    let auction_key = ctx.accounts.auction.key();
+    let nft_mint_key = ctx.accounts.nft_mint.key();
+
+    // CHECK: Timing validation
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.auction.commit_start_time <= current_time
+            && current_time <= ctx.accounts.auction.commit_end_time,
+        LauchpadError::OutOfCommitmentPeriod
+    );
+
+    // CHECK: NFT payment mode validation
+    let payment_value = ctx
+        .accounts
+        .auction
+        .extensions
+        .nft_payment
+        .as_ref()
+        .ok_or(LauchpadError::NftPaymentDisabled)?
+        .payment_value;
+
+    // CHECK: NFT validation, a single indivisible token from the auction collection
+    require!(
+        ctx.accounts.nft_mint.decimals == 0 && ctx.accounts.nft_mint.supply == 1,
+        LauchpadError::InvalidNftMint
+    );
+    ctx.accounts
+        .auction
+        .extensions
+        .verify_nft_collection(&ctx.accounts.nft_metadata, &nft_mint_key)?;
+
+    // CHECK: round validation, the bin's round must be open
+    let round_whitelist_authority = check_round_open(
+        &ctx.accounts.auction,
+        bin_id,
+        ctx.accounts.round.as_ref(),
+        current_time,
+    )?;
+
+    // CHECK: the bin's price is fresh
+    check_price_fresh(&ctx.accounts.auction, bin_id, current_time)?;
+
+    // CHECK: PDAs only sign through CPIs, top-level signers are wallets
+    require!(
+        ctx.accounts
+            .auction
+            .extensions
+            .is_participant_allowed(get_stack_height()),
+        LauchpadError::PdaParticipantForbidden
+    );
+
+    let mut committed = load_committed_init_if_needed(&ctx.accounts.committed)?;
+
+    // CHECK: commit cap validation
+    let attested_cap_bps = ctx.accounts.cap_attestation.as_ref().map(|a| a.cap_bps);
+    let loyalty_tier = ctx.accounts.user_profile.as_ref().map_or(0, |p| p.tier());
+    ctx.accounts.auction.extensions.check_commit_cap_exceeded(
+        &committed,
+        payment_value,
+        attested_cap_bps,
+        loyalty_tier,
+    )?;
+
+    // CHECK: whitelist validation, the round whitelist overrides the auction whitelist
+    let whitelist_authorities = ctx
+        .accounts
+        .auction
+        .extensions
+        .commit_whitelist_authorities(&round_whitelist_authority);
+    if !whitelist_authorities.is_empty() {
+        let sysvar_instructions = ctx
+            .accounts
+            .sysvar_instructions
+            .as_ref()
+            .ok_or(LauchpadError::MissingSysvarInstructions)?;
+        ctx.accounts
+            .auction
+            .extensions
+            .verify_signature_authorization(
+                sysvar_instructions,
+                &user_key,
+                &auction_key,
+                bin_id,
+                payment_value,
+                committed.nonce,
+                expiry,
+                whitelist_authorities,
+            )?;
+    }
+
+    // Increment nonce to prevent replay attacks
+    committed.nonce = committed
+        .nonce
+        .checked_add(1)
+        .ok_or(LauchpadError::NonceOverflow)?;
+    committed.terms_hash = terms_hash;
+
+    // CHECK: commitment bin validation, NFTs are only committed to sale bins
+    let auction = &mut ctx.accounts.auction;
+    let bin = auction.get_bin_mut(bin_id)?;
+    require!(bin.kind == BinKind::Sale, LauchpadError::UnsupportedBinKind);
+
+    // CHECK: NFT commitments can't oversubscribe the bin
+    let bin_target = bin
+        .sale_token_cap
+        .checked_mul(bin.sale_token_price)
+        .ok_or(LauchpadError::MathOverflow)?;
+    bin.payment_token_raised = bin
+        .payment_token_raised
+        .checked_add(payment_value)
+        .ok_or(LauchpadError::MathOverflow)?;
+    require!(
+        bin.payment_token_raised <= bin_target,
+        LauchpadError::CommitmentBinCapExceeded
+    );
+
+    // Initialize committed account if it's newly created, keeping a nonce raised
+    // by `revoke_whitelist_signatures`
+    let is_new_participant = !committed.has_commitments();
+    if is_new_participant {
+        committed.auction = auction_key;
+        committed.user = user_key;
+        committed.bump = ctx.bumps.committed;
+        auction.total_participants = auction
+            .total_participants
+            .checked_add(1)
+            .ok_or(LauchpadError::MathOverflow)?;
+    }
+
+    // Update committed account
+    if committed.find_bin(bin_id).is_none() {
+        auction.get_bin_mut(bin_id)?.add_participant()?;
+    }
+    let claim_fee_rate = auction.extensions.claim_fee_rate_at(
+        auction.get_bin(bin_id)?,
+        auction.commit_start_time,
+        current_time,
+    );
+    committed.add_commitment(bin_id, payment_value, claim_fee_rate, auction.bins.len())?;
+
+    // Record the escrow
+    *ctx.accounts.nft_escrow = NftEscrow {
+        auction: auction_key,
+        user: user_key,
+        nft_mint: nft_mint_key,
+        bin_id,
+        vault_bump: ctx.bumps.vault_nft_token,
+        bump: ctx.bumps.nft_escrow,
+    };
+
+    // Transfer the NFT to its vault
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.user_nft_token.to_account_info(),
+                to: ctx.accounts.vault_nft_token.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        1,
+    )?;
+
+    msg!(
+        "User {} escrowed NFT {} worth {} payment tokens to bin {}",
+        user_key,
+        nft_mint_key,
+        payment_value,
+        bin_id
+    );
+    Ok(())
+}
+
+/// User takes back an escrowed NFT, either decreasing the commitment during the
+/// commit period or as a refund after the auction failed its soft cap
+pub fn return_nft(ctx: Context<ReturnNft>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let auction = &mut ctx.accounts.auction;
+
+    // CHECK: NFTs are returned while committing or when the auction only refunds
+    if current_time <= auction.commit_end_time {
+        check_emergency_state(
+            auction,
+            &ctx.accounts.config,
+            EmergencyState::PAUSE_AUCTION_COMMIT,
+        )?;
+        require!(
+            auction.commit_start_time <= current_time,
+            LauchpadError::OutOfCommitmentPeriod
+        );
+    } else {
+        check_emergency_state(
+            auction,
+            &ctx.accounts.config,
+            EmergencyState::PAUSE_AUCTION_CLAIM,
+        )?;
+        require!(
+            auction.is_refund_only(current_time),
+            LauchpadError::InvalidNftEscrowState
+        );
+    }
+
+    let payment_value = auction
+        .extensions
+        .nft_payment
+        .as_ref()
+        .ok_or(LauchpadError::NftPaymentDisabled)?
+        .payment_value;
+    let bin_id = ctx.accounts.nft_escrow.bin_id;
+
+    // Update committed account
+    let mut committed = ctx.accounts.committed.load_mut()?;
+    let committed_bin = committed
+        .find_bin_mut(bin_id)
+        .ok_or(LauchpadError::InvalidBinId)?;
+    committed_bin.payment_token_committed = committed_bin
+        .payment_token_committed
+        .checked_sub(payment_value)
+        .ok_or(LauchpadError::MathUnderflow)?;
+
+    // Update Auction state
+    let bin = auction.get_bin_mut(bin_id)?;
+    bin.payment_token_raised = bin
+        .payment_token_raised
+        .checked_sub(payment_value)
+        .ok_or(LauchpadError::MathUnderflow)?;
+
+    release_escrowed_nft(
+        &ctx.accounts.nft_escrow,
+        &ctx.accounts.vault_nft_token,
+        &ctx.accounts.user_nft_token.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.token_program,
+    )?;
+
+    msg!(
+        "User {} took back NFT {} from bin {}",
+        ctx.accounts.user.key(),
+        ctx.accounts.nft_escrow.nft_mint,
+        bin_id
+    );
+    Ok(())
+}
+
+/// Admin collects an escrowed NFT as payment once the auction succeeded
+pub fn collect_nft(ctx: Context<CollectNft>) -> Result<()> {
+    // CHECK: emergency state validation
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_WITHDRAW_FUNDS,
+    )?;
+
+    // CHECK: NFTs are only collected after a successful commit period
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time > ctx.accounts.auction.commit_end_time,
+        LauchpadError::InCommitmentPeriod
+    );
+    require!(
+        !ctx.accounts.auction.is_refund_only(current_time),
+        LauchpadError::InvalidNftEscrowState
+    );
+
+    release_escrowed_nft(
+        &ctx.accounts.nft_escrow,
+        &ctx.accounts.vault_nft_token,
+        &ctx.accounts.nft_recipient.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.token_program,
+    )?;
+
+    msg!(
+        "Authority collected NFT {} committed by {}",
+        ctx.accounts.nft_escrow.nft_mint,
+        ctx.accounts.nft_escrow.user
+    );
+    Ok(())
+}
+
+/// Transfer an escrowed NFT out of its vault and close the vault, returning the
+/// rent to the user who escrowed it
+fn release_escrowed_nft<'info>(
+    nft_escrow: &NftEscrow,
+    vault_nft_token: &Account<'info, TokenAccount>,
+    recipient: &AccountInfo<'info>,
+    user: &AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+) -> Result<()> {
+    let vault_nft_seeds = &[
+        VAULT_NFT_SEED,
+        nft_escrow.auction.as_ref(),
+        nft_escrow.nft_mint.as_ref(),
+        &[nft_escrow.vault_bump],
+    ];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Transfer {
+                from: vault_nft_token.to_account_info(),
+                to: recipient.clone(),
+                authority: vault_nft_token.to_account_info(),
+            },
+            &[vault_nft_seeds],
+        ),
+        1,
+    )?;
+
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        CloseAccount {
+            account: vault_nft_token.to_account_info(),
+            destination: user.clone(),
+            authority: vault_nft_token.to_account_info(),
+        },
+        &[vault_nft_seeds],
+    ))
+}
+
+/// claims tokens with flexible amounts
+///
+/// Sale bins are allocated at the ratio `finalize_auction` cached, so claims wait
+/// for finalization unless the auction is refund-only.
+pub fn claim(
+    ctx: Context<Claim>,
+    bin_id: u8,
+    sale_token_to_claim: u64,
+    payment_token_to_refund: u64,
+    terms_hash: Option<[u8; 32]>,
+) -> Result<()> {
+    // CHECK: emergency state validation
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_CLAIM,
+    )?;
+
+    // CHECK: the sale mint isn't blocked program-wide
+    require!(
+        !ctx.accounts
+            .config
+            .is_sale_mint_blocked(&ctx.accounts.auction.sale_token_mint),
+        LauchpadError::SaleMintBlocked
+    );
+
+    // CHECK: Timing validation, forced refunds and aborted auctions open immediately
+    let current_time = Clock::get()?.unix_timestamp;
+    let force_refund = ctx.accounts.auction.force_refund;
+    require!(
+        ctx.accounts.auction.claim_start_time <= current_time
+            || force_refund
+            || ctx.accounts.auction.aborted,
+        LauchpadError::OutOfClaimPeriod
+    );
+    require!(
+        ctx.accounts.auction.claims_open(current_time),
+        LauchpadError::ClaimPeriodEnded
+    );
+
+    // CHECK: allocations are settled by `finalize_auction`, refund-only auctions
+    // refund every commitment in full without it
+    require!(
+        ctx.accounts.auction.finalized || ctx.accounts.auction.is_refund_only(current_time),
+        LauchpadError::AuctionNotFinalized
+    );
+
+    // CHECK: Claim amount validation
+    require!(
+        sale_token_to_claim != 0 || payment_token_to_refund != 0,
+        LauchpadError::InvalidClaimAmount
+    );
+
+    // CHECK: forced refund mode freezes sale token claims
+    require!(
+        sale_token_to_claim == 0 || !force_refund,
+        LauchpadError::SaleClaimsFrozen
+    );
+
+    let mut committed = ctx.accounts.committed.load_mut()?;
+
+    // CHECK: an acknowledged terms hash must be the auction's current one
+    if let Some(terms_hash) = terms_hash {
+        require!(
+            terms_hash == ctx.accounts.auction.terms_hash,
+            LauchpadError::TermsHashMismatch
+        );
+        committed.terms_hash = terms_hash;
+    }
+
+    // CHECK: sale tokens are only distributed once the current terms are accepted
+    require!(
+        sale_token_to_claim == 0 || committed.terms_hash == ctx.accounts.auction.terms_hash,
+        LauchpadError::TermsNotAccepted
+    );
+
+    // CHECK: NFT payment mode holds no payment tokens, NFTs are refunded by `return_nft`
+    require!(
+        payment_token_to_refund == 0 || !ctx.accounts.auction.extensions.is_nft_payment_enabled(),
+        LauchpadError::NftRefundRequiresReturn
+    );
+
+    // CHECK: reserved refunds are released to the authority (or the late refund pool)
+    // once refunds close
+    require!(
+        payment_token_to_refund == 0 || ctx.accounts.auction.refunds_open(current_time),
+        LauchpadError::RefundDeadlinePassed
+    );
+
+    // CHECK: Validate authority
+    require_keys_eq!(
+        committed.user,
+        ctx.accounts.user.key(),
+        LauchpadError::Unauthorized
+    );
+
+    // Store keys and values before borrowing mutably
+    let auction_key = ctx.accounts.auction.key();
+    let vault_sale_bump = ctx.accounts.auction.vault_sale_bump;
+    let vault_payment_bump = ctx.accounts.auction.vault_payment_bump;
+    let user_key = ctx.accounts.user.key();
+    let iou_mode = ctx.accounts.auction.extensions.iou_mode;
+    let claim_order = ctx.accounts.auction.extensions.claim_order;
+    let refund_only = ctx.accounts.auction.is_refund_only(current_time);
+    let finalized = ctx.accounts.auction.finalized;
+    let refund_diverted_bps = ctx.accounts.auction.late_refund_diverted_bps(current_time);
+    let frontend_id = committed.frontend_id;
+    let boost_locked = current_time
+        < ctx
+            .accounts
+            .auction
+            .extensions
+            .boost_unlock_time(ctx.accounts.auction.claim_start_time);
+
+    // Create the user's sale token account, paid by the lamport treasury if provided
+    let rent_funded = create_associated_token_account(
+        associated_token::Create {
+            payer: ctx.accounts.user.to_account_info(),
+            associated_token: ctx.accounts.user_sale_token.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+            mint: ctx.accounts.sale_token_mint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        &ctx.accounts.associated_token_program,
+        &auction_key,
+        ctx.accounts.lamport_treasury.as_ref(),
+        ctx.bumps.lamport_treasury,
+    )?;
+    ctx.accounts.auction.total_rent_funded = ctx
+        .accounts
+        .auction
+        .total_rent_funded
+        .checked_add(rent_funded)
+        .ok_or(LauchpadError::MathOverflow)?;
+
+    // Calculate claim fee before entering mutable borrow scope, at the rate recorded on commit
+    let committed_fee_rate = committed
+        .find_bin(bin_id)
+        .ok_or(LauchpadError::InvalidBinId)?
+        .claim_fee_rate;
+    let claim_fee = ctx.accounts.auction.extensions.calculate_claim_fee(
+        &user_key,
+        sale_token_to_claim,
+        committed_fee_rate,
+        ctx.accounts.auction.claim_start_time,
+        current_time,
+    );
+
+    // Late refunds are partly diverted to the late refund pool
+    let refund_diverted =
+        (payment_token_to_refund as u128 * refund_diverted_bps as u128 / 10000) as u64;
+    committed.refund_diverted += refund_diverted;
+
+    // Perform all mutations and calculations in a scoped block
+    let all_bins_fully_claimed = {
+        let auction = &mut ctx.accounts.auction;
+
+        // Find the specific bin commitment
+        let committed_bin = committed
+            .find_bin_mut(bin_id)
+            .ok_or(LauchpadError::InvalidBinId)?;
+
+        // Get the auction bin for calculations
+        let bin = auction.get_bin_mut(bin_id)?;
+
+        // Calculate what user is entitled to based on allocation algorithm using allocation.rs
+        let claimable_amounts = if force_refund {
+            calculate_force_refund_amounts(
+                committed_bin.payment_token_committed,
+                committed_bin.boosted_committed,
+                committed_bin.window_committed,
+                bin,
+                committed_bin.sale_token_claimed,
+                finalized,
+            )?
+        } else {
+            calculate_bin_entitlement(
+                committed_bin.payment_token_committed,
+                committed_bin.boosted_committed,
+                committed_bin.window_committed,
+                bin,
+                refund_only,
+                finalized,
+            )?
+        };
+
+        // Validate the calculation consistency
+        claimable_amounts.validate(committed_bin.payment_token_committed)?;
+
+        let total_sale_tokens_entitled = claimable_amounts.sale_tokens;
+        let total_payment_refund_entitled = claimable_amounts.refund_payment_tokens;
+
+        // CHECK: Validate requested amounts don't exceed entitlements
+        let remaining_sale_tokens =
+            total_sale_tokens_entitled.saturating_sub(committed_bin.sale_token_claimed);
+        let remaining_payment_refund =
+            total_payment_refund_entitled.saturating_sub(committed_bin.payment_token_refunded);
+        require!(
+            sale_token_to_claim <= remaining_sale_tokens
+                && payment_token_to_refund <= remaining_payment_refund,
+            LauchpadError::InvalidClaimAmount
+        );
+
+        // CHECK: sale tokens of boosted commitments stay locked for the boost lock period
+        if boost_locked {
+            require!(
+                committed_bin.sale_token_claimed + sale_token_to_claim
+                    <= total_sale_tokens_entitled - claimable_amounts.boosted_sale_tokens,
+                LauchpadError::SaleTokensLocked
+            );
+        }
+
+        // CHECK: claim order policy, the first leg must settle before the other starts
+        require!(
+            claim_order.allows(
+                sale_token_to_claim,
+                remaining_sale_tokens,
+                payment_token_to_refund,
+                remaining_payment_refund,
+            ),
+            LauchpadError::ClaimOrderViolated
+        );
+
+        // Transfer sale tokens if requested
+        if sale_token_to_claim > 0 {
+            // Actual tokens to transfer to user (after deducting fee)
+            let actual_tokens_to_user = sale_token_to_claim.saturating_sub(claim_fee);
+
+            if let Some(redemption) = ctx.accounts.redemption.as_mut() {
+                redemption.auction = auction_key;
+                redemption.user = user_key;
+                redemption.bump = ctx.bumps.redemption.expect("Redemption account provided");
+            }
+
+            if iou_mode {
+                // Record an IOU instead of transferring, delivered later by `redeem`
+                let redemption = ctx
+                    .accounts
+                    .redemption
+                    .as_mut()
+                    .ok_or(LauchpadError::MissingRedemptionAccount)?;
+                redemption.sale_token_owed = redemption
+                    .sale_token_owed
+                    .checked_add(actual_tokens_to_user)
+                    .ok_or(LauchpadError::MathOverflow)?;
+            } else {
+                let vault_sale_seeds = &[VAULT_SALE_SEED, auction_key.as_ref(), &[vault_sale_bump]];
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        Transfer {
+                            from: ctx.accounts.vault_sale_token.to_account_info(),
+                            to: ctx.accounts.user_sale_token.to_account_info(),
+                            authority: ctx.accounts.vault_sale_token.to_account_info(),
+                        },
+                        &[vault_sale_seeds],
+                    ),
+                    actual_tokens_to_user,
+                )?;
+
+                // Placeholders are swappable for real tokens by the user they were claimed by
+                if let Some(redemption) = ctx.accounts.redemption.as_mut() {
+                    redemption.placeholder_swappable = redemption
+                        .placeholder_swappable
+                        .checked_add(actual_tokens_to_user)
+                        .ok_or(LauchpadError::MathOverflow)?;
+                }
+            }
+
+            // Update state
+            committed_bin.sale_token_claimed += sale_token_to_claim;
+            bin.record_claim(sale_token_to_claim, claim_fee)?;
+            auction.total_fees_collected += claim_fee;
+            accrue_frontend_fee(
+                auction,
+                &ctx.accounts.config,
+                frontend_id,
+                ctx.accounts.frontend_fees.as_deref_mut(),
+                claim_fee,
+            )?;
+        }
+
+        // Transfer payment token refund if requested
+        if payment_token_to_refund > 0 {
+            let vault_payment_seeds = &[
+                VAULT_PAYMENT_SEED,
+                auction_key.as_ref(),
+                &[vault_payment_bump],
+            ];
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.vault_payment_token.to_account_info(),
+                        to: ctx.accounts.user_payment_token.to_account_info(),
+                        authority: ctx.accounts.vault_payment_token.to_account_info(),
+                    },
+                    &[vault_payment_seeds],
+                ),
+                payment_token_to_refund - refund_diverted,
+            )?;
+
+            // Update state
+            committed_bin.payment_token_refunded += payment_token_to_refund;
+            auction.total_payment_refunded += payment_token_to_refund - refund_diverted;
+            auction.total_refund_diverted += refund_diverted;
+        }
+
+        // Check if this bin is fully claimed
+        let current_bin_fully_claimed = committed_bin.sale_token_claimed
+            >= total_sale_tokens_entitled
+            && payment_token_to_refund >= remaining_payment_refund;
+
+        if current_bin_fully_claimed {
+            // Check if all bins are fully claimed using allocation.rs function
+            check_all_bins_fully_claimed(
+                &committed.bins,
+                &auction.bins,
+                refund_only,
+                force_refund,
+                finalized,
+            )?
+        } else {
+            false
+        }
+    };
+
+    // Handle account closure if all bins are fully claimed
+    // Initialize the loyalty profile if it's newly created
+    if let Some(user_profile) = ctx.accounts.user_profile.as_mut() {
+        user_profile.user = user_key;
+        user_profile.bump = ctx.bumps.user_profile.expect("User profile provided");
+    }
+
+    if all_bins_fully_claimed {
+        // Release the settled payment of this commitment to `withdraw_funds`
+        let settled_payment = committed
+            .active_bins()
+            .map(|bin| {
+                bin.payment_token_committed
+                    .saturating_sub(bin.payment_token_refunded)
+            })
+            .sum::<u64>();
+        let auction = &mut ctx.accounts.auction;
+        auction.total_payment_released = auction
+            .total_payment_released
+            .checked_add(settled_payment)
+            .ok_or(LauchpadError::MathOverflow)?;
+
+        // Compensate the user with a share of the slashed security deposit
+        if auction.security_deposit_slashed > 0 {
+            let compensation = (auction.security_deposit_slashed as u128
+                * committed.total_payment_committed() as u128
+                / auction.total_payment_raised()?.max(1) as u128)
+                as u64;
+            let compensation = compensation.min(auction.security_deposit_held);
+            if compensation > 0 {
+                release_security_deposit(
+                    auction,
+                    &ctx.accounts.user.to_account_info(),
+                    compensation,
+                )?;
+            }
+        }
+
+        // Record the participation in the user's loyalty profile
+        if let Some(user_profile) = ctx.accounts.user_profile.as_mut() {
+            user_profile.record_participation(&committed)?;
+        }
+
+        let committed_data = CommittedAccountSnapshot::from_committed(&committed);
+        drop(committed);
+        close_committed_account(
+            &ctx.accounts.committed.to_account_info(),
+            &ctx.accounts.user.to_account_info(),
+            committed_data,
+        )?;
+    }
+
+    msg!(
+        "User {} claimed {} sale tokens and {} payment refund from bin {}",
+        ctx.accounts.user.key(),
+        sale_token_to_claim,
+        payment_token_to_refund,
+        bin_id
+    );
+    Ok(())
+}
+
+/// User claims everything claimable from several auctions at once, e.g. for a wallet's
+/// "claim all" button
+///
+/// `remaining_accounts` holds up to `MAX_CROSS_AUCTION_CLAIM_SIZE` groups of
+/// `CROSS_AUCTION_CLAIM_ACCOUNTS` accounts: the auction, the user's `Committed` account,
+/// the auction's sale and payment vaults, the user's sale and payment token accounts, and
+/// the `FrontendFees` account of the front-end committed through (any account for direct
+/// commitments). Every bin is settled like a full `claim`, auctions with nothing claimable yet are
+/// skipped. Sale tokens are only claimed once the current terms are accepted, refunds
+/// only while refunds are open. IOU and NFT payment auctions settle through `claim`.
+pub fn claim_across_auctions<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimAcrossAuctions<'info>>,
+) -> Result<()> {
+    // CHECK: remaining accounts come in non-empty, bounded groups
+    let batch = ctx
+        .remaining_accounts
+        .chunks_exact(CROSS_AUCTION_CLAIM_ACCOUNTS);
+    let batch_size = batch.len();
+    require!(
+        batch_size > 0
+            && batch_size <= MAX_CROSS_AUCTION_CLAIM_SIZE
+            && batch.remainder().is_empty(),
+        LauchpadError::InvalidCrossAuctionClaim
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let user_key = ctx.accounts.user.key();
+    let mut auctions_claimed = 0usize;
+    for accounts in batch {
+        let (
+            auction_info,
+            committed_info,
+            vault_sale_info,
+            vault_payment_info,
+            user_sale_token_info,
+            user_payment_token_info,
+            frontend_fees_info,
+        ) = (
+            &accounts[0],
+            &accounts[1],
+            &accounts[2],
+            &accounts[3],
+            &accounts[4],
+            &accounts[5],
+            &accounts[6],
+        );
+        let mut auction = Account::<Auction>::try_from(auction_info)?;
+        let auction_key = auction.key();
+
+        // CHECK: emergency state validation
+        check_emergency_state(
+            &auction,
+            &ctx.accounts.config,
+            EmergencyState::PAUSE_AUCTION_CLAIM,
+        )?;
+
+        // CHECK: the sale mint isn't blocked program-wide
+        require!(
+            !ctx.accounts
+                .config
+                .is_sale_mint_blocked(&auction.sale_token_mint),
+            LauchpadError::SaleMintBlocked
+        );
+
+        // CHECK: IOU and NFT payment modes settle through `claim`
+        require!(!auction.extensions.iou_mode, LauchpadError::IouModeEnabled);
+        require!(
+            !auction.extensions.is_nft_payment_enabled(),
+            LauchpadError::NftPaymentEnabled
+        );
+
+        // CHECK: committed account is the user's for this auction
+        let (expected_committed, _) = Committed::find_program_address(&auction_key, &user_key);
+        require_keys_eq!(
+            committed_info.key(),
+            expected_committed,
+            LauchpadError::InvalidCrossAuctionClaim
+        );
+        let committed_loader = AccountLoader::<Committed>::try_from(committed_info)?;
+        let mut committed = committed_loader.load_mut()?;
+
+        // CHECK: vaults are the auction's
+        let vault_sale_key = Pubkey::create_program_address(
+            &[
+                VAULT_SALE_SEED,
+                auction_key.as_ref(),
+                &[auction.vault_sale_bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| LauchpadError::InvalidCrossAuctionClaim)?;
+        let vault_payment_key = Pubkey::create_program_address(
+            &[
+                VAULT_PAYMENT_SEED,
+                auction_key.as_ref(),
+                &[auction.vault_payment_bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| LauchpadError::InvalidCrossAuctionClaim)?;
+        require!(
+            vault_sale_info.key() == vault_sale_key
+                && vault_payment_info.key() == vault_payment_key,
+            LauchpadError::InvalidCrossAuctionClaim
+        );
+
+        // CHECK: tokens go to the user's token accounts of the auction's mints
+        let user_sale_token = Account::<TokenAccount>::try_from(user_sale_token_info)?;
+        let user_payment_token = Account::<TokenAccount>::try_from(user_payment_token_info)?;
+        require!(
+            user_sale_token.mint == auction.sale_token_mint
+                && user_sale_token.owner == user_key
+                && user_payment_token.mint == auction.payment_token_mint
+                && user_payment_token.owner == user_key,
+            LauchpadError::InvalidCrossAuctionClaim
+        );
+
+        // Auctions whose claim period hasn't started or has ended are skipped, as are
+        // unsettled ones until `finalize_auction` (refund-only auctions need no settling)
+        let force_refund = auction.force_refund;
+        let refund_only = auction.is_refund_only(current_time);
+        let finalized = auction.finalized;
+        if !(auction.claim_start_time <= current_time || force_refund || auction.aborted)
+            || !auction.claims_open(current_time)
+            || !(finalized || refund_only)
+        {
+            continue;
+        }
+
+        let refunds_open = auction.refunds_open(current_time);
+        let refund_diverted_bps = auction.late_refund_diverted_bps(current_time);
+        let terms_accepted = committed.terms_hash == auction.terms_hash;
+        let claim_order = auction.extensions.claim_order;
+        let boost_locked = current_time
+            < auction
+                .extensions
+                .boost_unlock_time(auction.claim_start_time);
+
+        let mut sale_tokens = 0u64;
+        let mut refund_payment_tokens = 0u64;
+        let mut refund_diverted_total = 0u64;
+        let mut claim_fees = 0u64;
+        for committed_bin in committed.active_bins_mut() {
+            let bin = auction.get_bin_mut(committed_bin.bin_id)?;
+            let claimable_amounts = if force_refund {
+                calculate_force_refund_amounts(
+                    committed_bin.payment_token_committed,
+                    committed_bin.boosted_committed,
+                    committed_bin.window_committed,
+                    bin,
+                    committed_bin.sale_token_claimed,
+                    finalized,
+                )?
+            } else {
+                calculate_bin_entitlement(
+                    committed_bin.payment_token_committed,
+                    committed_bin.boosted_committed,
+                    committed_bin.window_committed,
+                    bin,
+                    refund_only,
+                    finalized,
+                )?
+            };
+            claimable_amounts.validate(committed_bin.payment_token_committed)?;
+
+            let remaining_sale_tokens = claimable_amounts
+                .sale_tokens
+                .saturating_sub(committed_bin.sale_token_claimed);
+            // Sale tokens of boosted commitments are left for after the boost lock period
+            let unlocked_sale_tokens = if boost_locked {
+                claimable_amounts
+                    .sale_tokens
+                    .saturating_sub(claimable_amounts.boosted_sale_tokens)
+                    .saturating_sub(committed_bin.sale_token_claimed)
+            } else {
+                remaining_sale_tokens
+            };
+            let remaining_payment_refund = claimable_amounts
+                .refund_payment_tokens
+                .saturating_sub(committed_bin.payment_token_refunded);
+            let sale_token_to_claim = if force_refund || !terms_accepted {
+                0
+            } else {
+                unlocked_sale_tokens
+            };
+            let payment_token_to_refund = if refunds_open {
+                remaining_payment_refund
+            } else {
+                0
+            };
+            if sale_token_to_claim == 0 && payment_token_to_refund == 0 {
+                continue;
+            }
+
+            // Bins whose claim order policy holds a leg back are left for `claim`
+            if !claim_order.allows(
+                sale_token_to_claim,
+                remaining_sale_tokens,
+                payment_token_to_refund,
+                remaining_payment_refund,
+            ) {
+                continue;
+            }
+
+            let claim_fee = auction.extensions.calculate_claim_fee(
+                &user_key,
+                sale_token_to_claim,
+                committed_bin.claim_fee_rate,
+                auction.claim_start_time,
+                current_time,
+            );
+
+            // Late refunds are partly diverted to the late refund pool
+            let refund_diverted =
+                (payment_token_to_refund as u128 * refund_diverted_bps as u128 / 10000) as u64;
+
+            // Update state
+            committed_bin.sale_token_claimed += sale_token_to_claim;
+            committed_bin.payment_token_refunded += payment_token_to_refund;
+            refund_diverted_total += refund_diverted;
+            auction
+                .get_bin_mut(committed_bin.bin_id)?
+                .record_claim(sale_token_to_claim, claim_fee)?;
+            auction.total_fees_collected += claim_fee;
+            auction.total_payment_refunded += payment_token_to_refund - refund_diverted;
+            auction.total_refund_diverted += refund_diverted;
+            claim_fees += claim_fee;
+            sale_tokens = sale_tokens
+                .checked_add(sale_token_to_claim.saturating_sub(claim_fee))
+                .ok_or(LauchpadError::MathOverflow)?;
+            refund_payment_tokens = refund_payment_tokens
+                .checked_add(payment_token_to_refund - refund_diverted)
+                .ok_or(LauchpadError::MathOverflow)?;
+        }
+        committed.refund_diverted += refund_diverted_total;
+
+        // Accrue the front-end share of the claim fees
+        if committed.frontend_id != Pubkey::default() && claim_fees > 0 {
+            let mut frontend_fees = Account::<FrontendFees>::try_from(frontend_fees_info)?;
+            require_keys_eq!(
+                frontend_fees.auction,
+                auction_key,
+                LauchpadError::InvalidFrontendFeesAccount
+            );
+            accrue_frontend_fee(
+                &mut auction,
+                &ctx.accounts.config,
+                committed.frontend_id,
+                Some(&mut *frontend_fees),
+                claim_fees,
+            )?;
+            frontend_fees.exit(&crate::ID)?;
+        }
+
+        // Transfer the auction's totals to the user
+        for (vault, destination, seed, bump, amount) in [
+            (
+                vault_sale_info,
+                user_sale_token_info,
+                VAULT_SALE_SEED,
+                auction.vault_sale_bump,
+                sale_tokens,
+            ),
+            (
+                vault_payment_info,
+                user_payment_token_info,
+                VAULT_PAYMENT_SEED,
+                auction.vault_payment_bump,
+                refund_payment_tokens,
+            ),
+        ] {
+            if amount == 0 {
+                continue;
+            }
+            let vault_seeds = &[seed, auction_key.as_ref(), &[bump]];
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: vault.clone(),
+                        to: destination.clone(),
+                        authority: vault.clone(),
+                    },
+                    &[vault_seeds],
+                ),
+                amount,
+            )?;
+        }
+        if sale_tokens > 0 || refund_payment_tokens > 0 {
+            auctions_claimed += 1;
+        }
+
+        if check_all_bins_fully_claimed(
+            &committed.bins,
+            &auction.bins,
+            refund_only,
+            force_refund,
+            finalized,
+        )? {
+            // Release the settled payment of this commitment to `withdraw_funds`
+            let settled_payment = committed
+                .active_bins()
+                .map(|bin| {
+                    bin.payment_token_committed
+                        .saturating_sub(bin.payment_token_refunded)
+                })
+                .sum::<u64>();
+            auction.total_payment_released = auction
+                .total_payment_released
+                .checked_add(settled_payment)
+                .ok_or(LauchpadError::MathOverflow)?;
+
+            // Compensate the user with a share of the slashed security deposit
+            if auction.security_deposit_slashed > 0 {
+                let compensation = (auction.security_deposit_slashed as u128
+                    * committed.total_payment_committed() as u128
+                    / auction.total_payment_raised()?.max(1) as u128)
+                    as u64;
+                let compensation = compensation.min(auction.security_deposit_held);
+                if compensation > 0 {
+                    release_security_deposit(
+                        &mut auction,
+                        &ctx.accounts.user.to_account_info(),
+                        compensation,
+                    )?;
+                }
+            }
+
+            let committed_data = CommittedAccountSnapshot::from_committed(&committed);
+            drop(committed);
+            close_committed_account(
+                committed_info,
+                &ctx.accounts.user.to_account_info(),
+                committed_data,
+            )?;
+        }
+        auction.exit(&crate::ID)?;
+    }
+
+    msg!(
+        "User {} claimed from {} of {} auctions",
+        user_key,
+        auctions_claimed,
+        batch_size
+    );
+    Ok(())
+}
+
+/// User converts part of their unclaimed sale tokens of a bin into a claim voucher
+/// honored by the auction's voucher partner, e.g. for exchange-side distribution
+///
+/// The sale tokens (net of the claim fee) are moved to the partner's account and counted
+/// as claimed. The voucher is emitted through the event authority, so partners can
+/// trust it was issued by the program, with a per-user nonce against double redemption.
+pub fn issue_claim_voucher(
+    ctx: Context<IssueClaimVoucher>,
+    bin_id: u8,
+    sale_token_amount: u64,
+) -> Result<()> {
+    // CHECK: emergency state validation
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_CLAIM,
+    )?;
+
+    // CHECK: the sale mint isn't blocked program-wide
+    require!(
+        !ctx.accounts
+            .config
+            .is_sale_mint_blocked(&ctx.accounts.auction.sale_token_mint),
+        LauchpadError::SaleMintBlocked
+    );
+
+    let auction = &ctx.accounts.auction;
+    let partner = auction
+        .extensions
+        .voucher_partner
+        .ok_or(LauchpadError::VouchersDisabled)?;
+
+    // CHECK: Timing validation
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        auction.claim_start_time <= current_time,
+        LauchpadError::OutOfClaimPeriod
+    );
+    require!(
+        auction.claims_open(current_time),
+        LauchpadError::ClaimPeriodEnded
+    );
+    require!(auction.finalized, LauchpadError::AuctionNotFinalized);
+
+    // CHECK: vouchers only carry sale tokens held in the vault
+    require!(sale_token_amount > 0, LauchpadError::InvalidClaimAmount);
+    require!(!auction.force_refund, LauchpadError::SaleClaimsFrozen);
+    require!(!auction.extensions.iou_mode, LauchpadError::IouModeEnabled);
+
+    let mut committed = ctx.accounts.committed.load_mut()?;
+
+    // CHECK: sale tokens are only distributed once the current terms are accepted
+    require!(
+        committed.terms_hash == auction.terms_hash,
+        LauchpadError::TermsNotAccepted
+    );
+
+    let refund_only = auction.is_refund_only(current_time);
+    let boost_locked = current_time
+        < auction
+            .extensions
+            .boost_unlock_time(auction.claim_start_time);
+    let committed_bin = committed
+        .find_bin(bin_id)
+        .ok_or(LauchpadError::InvalidBinId)?;
+    let claimable_amounts = calculate_bin_entitlement(
+        committed_bin.payment_token_committed,
+        committed_bin.boosted_committed,
+        committed_bin.window_committed,
+        auction.get_bin(bin_id)?,
+        refund_only,
+        true,
+    )?;
+
+    // CHECK: the voucher fits in the unclaimed entitlement
+    let remaining_sale_tokens = claimable_amounts
+        .sale_tokens
+        .saturating_sub(committed_bin.sale_token_claimed);
+    require!(
+        sale_token_amount <= remaining_sale_tokens,
+        LauchpadError::InvalidClaimAmount
+    );
+
+    // CHECK: sale tokens of boosted commitments stay locked for the boost lock period
+    if boost_locked {
+        require!(
+            committed_bin.sale_token_claimed + sale_token_amount
+                <= claimable_amounts.sale_tokens - claimable_amounts.boosted_sale_tokens,
+            LauchpadError::SaleTokensLocked
+        );
+    }
+
+    // CHECK: claim order policy, the first leg must settle before the other starts
+    require!(
+        auction.extensions.claim_order.allows(
+            sale_token_amount,
+            remaining_sale_tokens,
+            0,
+            claimable_amounts
+                .refund_payment_tokens
+                .saturating_sub(committed_bin.payment_token_refunded),
+        ),
+        LauchpadError::ClaimOrderViolated
+    );
+
+    let claim_fee = auction.extensions.calculate_claim_fee(
+        &ctx.accounts.user.key(),
+        sale_token_amount,
+        committed_bin.claim_fee_rate,
+        auction.claim_start_time,
+        current_time,
+    );
+    let partner_sale_tokens = sale_token_amount.saturating_sub(claim_fee);
+
+    // Move the sale tokens to the partner honoring the voucher
+    let auction_key = auction.key();
+    let vault_sale_seeds = &[
+        VAULT_SALE_SEED,
+        auction_key.as_ref(),
+        &[auction.vault_sale_bump],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault_sale_token.to_account_info(),
+                to: ctx.accounts.partner_sale_token.to_account_info(),
+                authority: ctx.accounts.vault_sale_token.to_account_info(),
+            },
+            &[vault_sale_seeds],
+        ),
+        partner_sale_tokens,
+    )?;
+
+    // Count the voucher as claimed
+    committed
+        .find_bin_mut(bin_id)
+        .ok_or(LauchpadError::InvalidBinId)?
+        .sale_token_claimed += sale_token_amount;
+    let nonce = committed.vouchers_issued;
+    committed.vouchers_issued += 1;
+    let frontend_id = committed.frontend_id;
+
+    let auction = &mut ctx.accounts.auction;
+    auction
+        .get_bin_mut(bin_id)?
+        .record_claim(sale_token_amount, claim_fee)?;
+    auction.total_fees_collected += claim_fee;
+    accrue_frontend_fee(
+        auction,
+        &ctx.accounts.config,
+        frontend_id,
+        ctx.accounts.frontend_fees.as_deref_mut(),
+        claim_fee,
+    )?;
+
+    let event = ClaimVoucherEvent {
+        auction: auction_key,
+        user: ctx.accounts.user.key(),
+        partner,
+        bin_id,
+        nonce,
+        sale_token_amount,
+        partner_sale_tokens,
+        claim_fee,
+    };
+    emit_auction_event_cpi(
+        &event,
+        &ctx.accounts.auction.to_account_info(),
+        &ctx.accounts.event_authority.to_account_info(),
+        &ctx.accounts.program.to_account_info(),
+        ctx.bumps.event_authority,
+    )?;
+
+    msg!(
+        "Claim voucher {} of {} sale tokens issued to partner {}",
+        nonce,
+        partner_sale_tokens,
+        partner
+    );
+    Ok(())
+}
+
+/// Create the associated token account of `accounts` if it doesn't exist yet, paid by
+/// the auction lamport treasury when provided and funded, otherwise by `accounts.payer`
+///
+/// Returns the lamports paid by the treasury.
+fn create_associated_token_account<'info>(
+    accounts: associated_token::Create<'info>,
+    associated_token_program: &Program<'info, AssociatedToken>,
+    auction_key: &Pubkey,
+    lamport_treasury: Option<&SystemAccount<'info>>,
+    lamport_treasury_bump: Option<u8>,
+) -> Result<u64> {
+    if !accounts.associated_token.data_is_empty() {
+        return Ok(0);
+    }
+
+    // The treasury pays only if it stays rent exempt afterwards
+    let rent = Rent::get()?;
+    let token_account_rent = rent.minimum_balance(TokenAccount::LEN);
+    match (lamport_treasury, lamport_treasury_bump) {
+        (Some(treasury), Some(bump))
+            if treasury.lamports()
+                >= token_account_rent.saturating_add(rent.minimum_balance(0)) =>
+        {
+            let treasury_seeds = &[LAMPORT_TREASURY_SEED, auction_key.as_ref(), &[bump]];
+            associated_token::create(CpiContext::new_with_signer(
+                associated_token_program.to_account_info(),
+                associated_token::Create {
+                    payer: treasury.to_account_info(),
+                    ..accounts
+                },
+                &[treasury_seeds],
+            ))?;
+            Ok(token_account_rent)
+        }
+        _ => {
+            associated_token::create(CpiContext::new(
+                associated_token_program.to_account_info(),
+                accounts,
+            ))?;
+            Ok(0)
+        }
+    }
+}
+
+/// Close a settled Committed account and return the rent to `destination`
+///
+/// `committed_data` is taken before closing, the account data must no longer be borrowed.
+fn close_committed_account(
+    committed_account_info: &AccountInfo,
+    destination: &AccountInfo,
+    committed_data: CommittedAccountSnapshot,
+) -> Result<()> {
+    let rent_lamports = committed_account_info.lamports();
+
+    // Emit the CommittedAccountClosedEvent with a snapshot of the data before closing the account
+    emit!(CommittedAccountClosedEvent {
+        user_key: committed_data.user,
+        auction_key: committed_data.auction,
+        committed_account_key: committed_account_info.key(),
+        rent_returned: rent_lamports,
+        committed_data,
+    });
+
+    // Close the committed account and return the rent
+    **committed_account_info.try_borrow_mut_lamports()? = 0;
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(rent_lamports)
+        .expect("Math overflow");
+    let mut committed_data = committed_account_info.try_borrow_mut_data()?;
+    for byte in committed_data.iter_mut() {
+        *byte = 0;
+    }
+
+    Ok(())
+}
+
+/// Keepers refund commitments in bulk for a refund-only auction
+///
+/// Permissionless, refunds only go to the users' own token accounts. `remaining_accounts`
+/// holds up to `MAX_REFUND_BATCH_SIZE` triples of `[committed, user_payment_token, user]`
+/// in any order. Each user receives all payment tokens not yet refunded, and the
+/// `Committed` account is closed with rent returned to the user, so no user is refunded
+/// twice. The refund `CrankCursor` records progress for keepers to resume from, and the
+/// keeper bounty is paid pro rata to the batch size.
+pub fn refund_batch<'info>(ctx: Context<'_, '_, 'info, 'info, RefundBatch<'info>>) -> Result<()> {
+    // CHECK: emergency state validation
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_CLAIM,
+    )?;
+
+    // CHECK: only failed or cancelled auctions are refunded in bulk
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.auction.is_refund_only(current_time),
+        LauchpadError::AuctionNotRefundOnly
+    );
+    require!(
+        ctx.accounts.auction.claims_open(current_time),
+        LauchpadError::ClaimPeriodEnded
+    );
+
+    // CHECK: NFT payment mode holds no payment tokens, NFTs are refunded by `return_nft`
+    require!(
+        !ctx.accounts.auction.extensions.is_nft_payment_enabled(),
+        LauchpadError::NftRefundRequiresReturn
+    );
+
+    // CHECK: remaining accounts come in non-empty, bounded triples
+    let batch = ctx.remaining_accounts.chunks_exact(3);
+    let batch_size = batch.len();
+    require!(
+        batch_size > 0 && batch_size <= MAX_REFUND_BATCH_SIZE && batch.remainder().is_empty(),
+        LauchpadError::InvalidRefundBatch
+    );
+
+    let auction_key = ctx.accounts.auction.key();
+    let vault_payment_seeds = &[
+        VAULT_PAYMENT_SEED,
+        auction_key.as_ref(),
+        &[ctx.accounts.auction.vault_payment_bump],
+    ];
+
+    // Initialize the crank cursor if it's newly created
+    let crank_cursor = &mut ctx.accounts.crank_cursor;
+    crank_cursor.auction = auction_key;
+    crank_cursor.kind = CrankKind::Refund;
+    crank_cursor.bump = ctx.bumps.crank_cursor;
+
+    let mut total_refunded = 0u64;
+    for accounts in batch {
+        let (committed_info, user_payment_token_info, user_info) =
+            (&accounts[0], &accounts[1], &accounts[2]);
+
+        // CHECK: committed account belongs to this auction and user
+        let committed_loader = AccountLoader::<Committed>::try_from(committed_info)?;
+        let committed = committed_loader.load()?;
+        require_keys_eq!(
+            committed.auction,
+            auction_key,
+            LauchpadError::InvalidRefundBatch
+        );
+        require_keys_eq!(
+            committed.user,
+            user_info.key(),
+            LauchpadError::InvalidRefundBatch
+        );
+        crank_cursor.advance(&committed.user)?;
+
+        // CHECK: refund goes to the user's payment token account
+        let user_payment_token = Account::<TokenAccount>::try_from(user_payment_token_info)?;
+        require!(
+            user_payment_token.mint == ctx.accounts.auction.payment_token_mint
+                && user_payment_token.owner == committed.user,
+            LauchpadError::InvalidRefundBatch
+        );
+
+        let refund = committed
+            .active_bins()
+            .map(|bin| {
+                bin.payment_token_committed
+                    .saturating_sub(bin.payment_token_refunded)
+            })
+            .sum::<u64>();
+
+        if refund > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.vault_payment_token.to_account_info(),
+                        to: user_payment_token_info.clone(),
+                        authority: ctx.accounts.vault_payment_token.to_account_info(),
+                    },
+                    &[vault_payment_seeds],
+                ),
+                refund,
+            )?;
+        }
+
+        let committed_data = CommittedAccountSnapshot::from_committed(&committed);
+        drop(committed);
+        close_committed_account(committed_info, user_info, committed_data)?;
+
+        ctx.accounts.auction.total_payment_refunded = ctx
+            .accounts
+            .auction
+            .total_payment_refunded
+            .checked_add(refund)
+            .ok_or(LauchpadError::MathOverflow)?;
+        total_refunded = total_refunded
+            .checked_add(refund)
+            .ok_or(LauchpadError::MathOverflow)?;
+    }
+
+    let processed_count = ctx.accounts.crank_cursor.processed_count;
+    let bounty = pay_keeper_bounty(
+        &ctx.accounts.auction,
+        &ctx.accounts.keeper,
+        batch_size as u64,
+        MAX_REFUND_BATCH_SIZE as u64,
+    )?;
+
+    msg!(
+        "Refunded {} payment tokens to {} users, {} users processed in total, {} lamports keeper bounty",
+        total_refunded,
+        batch_size,
+        processed_count,
+        bounty
+    );
+    Ok(())
+}
+
+/// Admin schedules a round of the auction
+///
+/// Rounds are scheduled in order within the auction commit period and must not
+/// overlap, `previous_round` is required for every round but the first.
+pub fn init_round(
+    ctx: Context<InitRound>,
+    index: u8,
+    commit_start_time: i64,
+    commit_end_time: i64,
+    whitelist_authority: Option<Pubkey>,
+) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+
+    // CHECK: round index validation
+    require!(
+        auction.has_rounds() && index < auction.round_count,
+        LauchpadError::InvalidRoundConfig
+    );
+
+    // CHECK: timing validation, require the round within the auction commit period
+    require!(
+        auction.commit_start_time <= commit_start_time
+            && commit_start_time <= commit_end_time
+            && commit_end_time <= auction.commit_end_time,
+        LauchpadError::InvalidRoundConfig
+    );
+
+    // CHECK: rounds are consecutive, require the previous round to end first
+    if index > 0 {
+        let previous_round = ctx
+            .accounts
+            .previous_round
+            .as_ref()
+            .ok_or(LauchpadError::InvalidRoundAccount)?;
+        require!(
+            previous_round.index == index - 1,
+            LauchpadError::InvalidRoundAccount
+        );
+        require!(
+            previous_round.commit_end_time < commit_start_time,
+            LauchpadError::InvalidRoundConfig
+        );
+    }
+
+    *ctx.accounts.round = Round {
+        auction: auction.key(),
+        index,
+        commit_start_time,
+        commit_end_time,
+        whitelist_authority,
+        bump: ctx.bumps.round,
+    };
+
+    msg!(
+        "Round {} scheduled from {} to {}",
+        index,
+        commit_start_time,
+        commit_end_time
+    );
+    Ok(())
+}
+
+/// Closes an ended round, rolling its unsold supply into the next round
+///
+/// Permissionless. Each bin of the round keeps only the sale tokens it sold, and
+/// the remainder is added to the cap of the next round's first sale bin. Without
+/// a next-round sale bin the unsold supply stays for `withdraw_funds`.
+pub fn close_round(ctx: Context<CloseRound>) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let round = &ctx.accounts.round;
+
+    // CHECK: rounds are closed in order, once they've ended
+    require_eq!(
+        round.index,
+        auction.rounds_closed,
+        LauchpadError::InvalidRoundAccount
+    );
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time > round.commit_end_time,
+        LauchpadError::RoundNotEnded
+    );
+
+    let next_bin_id = auction
+        .bins
+        .iter()
+        .position(|bin| bin.round == round.index + 1 && bin.kind == BinKind::Sale);
+
+    let mut rolled_sale_tokens = 0u64;
+    if let Some(next_bin_id) = next_bin_id {
+        for bin in auction
+            .bins
+            .iter_mut()
+            .filter(|bin| bin.round == round.index)
+        {
+            let bin_amounts = match bin.kind {
+                BinKind::Sale => calculate_bin_withdraw_amounts(
+                    bin.payment_token_raised,
+                    bin.sale_token_cap,
+                    bin.sale_token_price,
+                )?,
+                BinKind::FreeClaim => calculate_free_claim_withdraw_amounts(bin)?,
+                BinKind::Donation => continue,
+            };
+            bin.sale_token_cap = bin_amounts.sale_tokens_sold;
+            rolled_sale_tokens = rolled_sale_tokens
+                .checked_add(bin_amounts.unsold_sale_tokens)
+                .ok_or(LauchpadError::MathOverflow)?;
+        }
+
+        let next_bin = &mut auction.bins[next_bin_id];
+        next_bin.sale_token_cap = next_bin
+            .sale_token_cap
+            .checked_add(rolled_sale_tokens)
+            .ok_or(LauchpadError::MathOverflow)?;
+
+        // CHECK: bin targets with the rolled supply fit in u64, so claims can compute them
+        calculate_total_bin_target(
+            auction
+                .bins
+                .iter()
+                .map(|bin| (bin.sale_token_cap, bin.sale_token_price)),
+        )?;
+    }
+
+    auction.rounds_closed += 1;
+
+    msg!(
+        "Round {} closed, {} unsold sale tokens rolled forward",
+        round.index,
+        rolled_sale_tokens
+    );
+    Ok(())
+}
+
+/// Admin withdraws funds from all auction bins
+pub fn withdraw_funds(ctx: Context<WithdrawFunds>) -> Result<()> {
+    // Check emergency state - withdraw funds operations
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_WITHDRAW_FUNDS,
+    )?;
+
+    // CHECK: the sale mint isn't blocked program-wide
+    require!(
+        !ctx.accounts
+            .config
+            .is_sale_mint_blocked(&ctx.accounts.auction.sale_token_mint),
+        LauchpadError::SaleMintBlocked
+    );
+
+    // CHECK: timelocked auctions withdraw through a matured queued action, closed below
+    if ctx.accounts.auction.extensions.admin_timelock.is_some() {
+        let admin_action = ctx
+            .accounts
+            .admin_action
+            .as_ref()
+            .ok_or(LauchpadError::TimelockRequired)?;
+        require!(
+            admin_action.action == AdminActionKind::WithdrawFunds,
+            LauchpadError::InvalidAdminAction
+        );
+        require!(
+            Clock::get()?.unix_timestamp >= admin_action.eta,
+            LauchpadError::AdminActionNotReady
+        );
+    }
+
+    // Create the recipient token accounts, paid by the lamport treasury if provided
+    let auction_key = ctx.accounts.auction.key();
+    let mut rent_funded = 0u64;
+    for (recipient, owner, mint) in [
+        (
+            &ctx.accounts.sale_token_recipient,
+            &ctx.accounts.sale_token_recipient_owner,
+            ctx.accounts.sale_token_mint.to_account_info(),
+        ),
+        (
+            &ctx.accounts.payment_token_recipient,
+            &ctx.accounts.payment_token_recipient_owner,
+            ctx.accounts.payment_token_mint.to_account_info(),
+        ),
+    ] {
+        rent_funded += create_associated_token_account(
+            associated_token::Create {
+                payer: ctx.accounts.authority.to_account_info(),
+                associated_token: recipient.to_account_info(),
+                authority: owner.to_account_info(),
+                mint,
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+            &ctx.accounts.associated_token_program,
+            &auction_key,
+            ctx.accounts.lamport_treasury.as_ref(),
+            ctx.bumps.lamport_treasury,
+        )?;
+    }
+
+    let auction = &mut ctx.accounts.auction;
+    auction.total_rent_funded = auction
+        .total_rent_funded
+        .checked_add(rent_funded)
+        .ok_or(LauchpadError::MathOverflow)?;
+
+    // CHECK: Timing validation - can withdraw after commit period ends
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time > auction.commit_end_time,
+        LauchpadError::InCommitmentPeriod
+    );
+
+    // CHECK: Validate authority
+    require_keys_eq!(
+        auction.role_holder(AuctionRole::FundWithdrawer),
+        ctx.accounts.authority.key(),
+        LauchpadError::Unauthorized
+    );
+
+    // CHECK: entitlements are recorded at finalization
+    require!(auction.finalized, LauchpadError::AuctionNotFinalized);
+
+    // Withdraw everything not owed to users
+    let total_amounts = calculate_withdraw_funds_amounts(auction, current_time)?;
+
+    // CHECK: large withdrawals are co-signed
+    require!(
+        auction.extensions.is_withdraw_approved(
+            total_amounts.total_payment_tokens,
+            ctx.accounts.co_signer.as_ref().map(|signer| signer.key)
+        ),
+        LauchpadError::CoSignerRequired
+    );
+
+    // Security deposits left by a delegated fund withdrawer are returned to the authority
+    let security_deposit_returnable = !auction.force_refund
+        && auction.security_deposit_held > 0
+        && ctx.accounts.authority.key() == auction.authority;
+
+    // CHECK: Prevent double withdrawal, released payment can be withdrawn repeatedly
+    require!(
+        !auction.unsold_sale_tokens_and_effective_payment_tokens_withdrawn
+            || total_amounts.total_payment_tokens > 0
+            || security_deposit_returnable,
+        LauchpadError::DoubleFundsWithdrawal
+    );
+
+    // Transfer payment tokens if any (NFT payments are collected by `collect_nft`)
+    if total_amounts.total_payment_tokens > 0 && !auction.extensions.is_nft_payment_enabled() {
+        let auction_key = auction.key();
+        let vault_payment_seeds = &[
+            VAULT_PAYMENT_SEED,
+            auction_key.as_ref(),
+            &[auction.vault_payment_bump],
+        ];
+
+        // The protocol takes its fee off the top, whatever happens to the proceeds
+        let (protocol_payment_tokens, proceeds_payment_tokens) =
+            match ctx.accounts.global_config.protocol_fee {
+                Some(protocol_fee) => calculate_revenue_share(
+                    total_amounts.total_payment_tokens,
+                    protocol_fee.fee_bps,
+                )?,
+                None => (0, total_amounts.total_payment_tokens),
+            };
+        if protocol_payment_tokens > 0 {
+            // CHECK: the protocol treasury account is provided
+            let protocol_treasury_payment_token = ctx
+                .accounts
+                .protocol_treasury_payment_token
+                .as_ref()
+                .ok_or(LauchpadError::InvalidProtocolFee)?;
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.vault_payment_token.to_account_info(),
+                        to: protocol_treasury_payment_token.to_account_info(),
+                        authority: ctx.accounts.vault_payment_token.to_account_info(),
+                    },
+                    &[vault_payment_seeds],
+                ),
+                protocol_payment_tokens,
+            )?;
+        }
+
+        if auction.extensions.burn_proceeds {
+            token::burn(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Burn {
+                        mint: ctx.accounts.payment_token_mint.to_account_info(),
+                        from: ctx.accounts.vault_payment_token.to_account_info(),
+                        authority: ctx.accounts.vault_payment_token.to_account_info(),
+                    },
+                    &[vault_payment_seeds],
+                ),
+                proceeds_payment_tokens,
+            )?;
+            emit!(ProceedsBurnedEvent {
+                auction: auction_key,
+                payment_tokens_burned: proceeds_payment_tokens,
+            });
+        } else {
+            // The platform treasury takes its share of the proceeds first
+            let (treasury_payment_tokens, project_payment_tokens) =
+                match &auction.extensions.proceeds_split {
+                    Some(split) => {
+                        calculate_revenue_share(proceeds_payment_tokens, split.treasury_bps)?
+                    }
+                    None => (0, proceeds_payment_tokens),
+                };
+            if treasury_payment_tokens > 0 {
+                // CHECK: the platform treasury account is provided
+                let treasury_payment_token = ctx
+                    .accounts
+                    .treasury_payment_token
+                    .as_ref()
+                    .ok_or(LauchpadError::InvalidProceedsSplit)?;
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        Transfer {
+                            from: ctx.accounts.vault_payment_token.to_account_info(),
+                            to: treasury_payment_token.to_account_info(),
+                            authority: ctx.accounts.vault_payment_token.to_account_info(),
+                        },
+                        &[vault_payment_seeds],
+                    ),
+                    treasury_payment_tokens,
+                )?;
+            }
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.vault_payment_token.to_account_info(),
+                        to: ctx.accounts.payment_token_recipient.to_account_info(),
+                        authority: ctx.accounts.vault_payment_token.to_account_info(),
+                    },
+                    &[vault_payment_seeds],
+                ),
+                project_payment_tokens,
+            )?;
+        }
+        auction.total_payment_withdrawn = auction
+            .total_payment_withdrawn
+            .checked_add(total_amounts.total_payment_tokens)
+            .ok_or(LauchpadError::MathOverflow)?;
+    }
+
+    // Transfer unsold sale tokens if any (IOU mode never deposited sale tokens)
+    if total_amounts.total_unsold_sale_tokens > 0 && !auction.extensions.iou_mode {
+        let auction_key = auction.key();
+        let vault_sale_seeds = &[
+            VAULT_SALE_SEED,
+            auction_key.as_ref(),
+            &[auction.vault_sale_bump],
+        ];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault_sale_token.to_account_info(),
+                    to: ctx.accounts.sale_token_recipient.to_account_info(),
+                    authority: ctx.accounts.vault_sale_token.to_account_info(),
+                },
+                &[vault_sale_seeds],
+            ),
+            total_amounts.total_unsold_sale_tokens,
+        )?;
+    }
+
+    // Return the security deposit unless it was slashed
+    if security_deposit_returnable {
+        let security_deposit = auction.security_deposit_held;
+        release_security_deposit(
+            auction,
+            &ctx.accounts.authority.to_account_info(),
+            security_deposit,
+        )?;
+    }
+
+    // Set the flag to true to prevent double withdrawal
+    auction.unsold_sale_tokens_and_effective_payment_tokens_withdrawn = true;
+
+    msg!(
+        "Authority withdrew {} payment tokens and {} unsold sale tokens from all bins",
+        total_amounts.total_payment_tokens,
+        total_amounts.total_unsold_sale_tokens
+    );
+    Ok(())
+}
+
+/// Amounts `withdraw_funds` transfers to the authority for a finalized auction
+fn calculate_withdraw_funds_amounts(
+    auction: &Auction,
+    current_time: i64,
+) -> Result<TotalWithdrawAmounts> {
+    let mut total_amounts = calculate_entitled_withdraw_amounts(
+        &auction.bins,
+        auction.total_sale_entitled,
+        auction.total_refund_entitled,
+    )?;
+
+    // Reserved refunds: payment is released as commitments settle, and in full
+    // after the refund deadline, unsold sale tokens are withdrawn once. Forced
+    // refunds only ever release the payment of settled commitments. Outstanding
+    // refunds of a late refund pool go to the pool instead.
+    let total_payment_releasable = match auction.refund_deadline() {
+        _ if auction.force_refund => Some(auction.total_payment_released),
+        Some(refund_deadline)
+            if current_time > refund_deadline && auction.extensions.late_refund_pool.is_some() =>
+        {
+            Some(
+                auction
+                    .total_payment_raised()?
+                    .checked_sub(auction.total_refund_entitled)
+                    .ok_or(LauchpadError::MathUnderflow)?,
+            )
+        }
+        Some(refund_deadline) if current_time > refund_deadline => Some(
+            auction
+                .total_payment_raised()?
+                .checked_sub(auction.total_payment_refunded)
+                .ok_or(LauchpadError::MathUnderflow)?,
+        ),
+        Some(_) => Some(auction.total_payment_released),
+        None => None,
+    };
+    if let Some(total_payment_releasable) = total_payment_releasable {
+        total_amounts.total_payment_tokens =
+            total_payment_releasable.saturating_sub(auction.total_payment_withdrawn);
+    } else if auction.unsold_sale_tokens_and_effective_payment_tokens_withdrawn {
+        total_amounts.total_payment_tokens = 0;
+    }
+    if auction.unsold_sale_tokens_and_effective_payment_tokens_withdrawn {
+        total_amounts.total_unsold_sale_tokens = 0;
+    }
+
+    Ok(total_amounts)
+}
+
+/// Admin previews the settlement of the auction in its current state, for sanity
+/// checks before withdrawing and for dashboards
+///
+/// Before finalization entitlements are computed on the fly and nothing is
+/// withdrawable yet. Projected fees assume every sold sale token is claimed at its
+/// bin's regular rate, ignoring exemptions and early-commit discounts.
+pub fn simulate_settlement(ctx: Context<SimulateSettlement>) -> Result<SettlementSimulation> {
+    let auction = &ctx.accounts.auction;
+    let current_time = Clock::get()?.unix_timestamp;
+    let refund_only = auction.is_refund_only(current_time);
+
+    let entitlements = if auction.finalized {
+        TotalEntitlements {
+            total_sale_entitled: auction.total_sale_entitled,
+            total_refund_entitled: auction.total_refund_entitled,
+        }
+    } else {
+        calculate_total_entitlements(&auction.bins, refund_only)?
+    };
+    let entitled_amounts = calculate_entitled_withdraw_amounts(
+        &auction.bins,
+        entitlements.total_sale_entitled,
+        entitlements.total_refund_entitled,
+    )?;
+
+    // Forced refunds may return any payment not released yet
+    let total_payment_raised = auction.total_payment_raised()?;
+    let total_refunds_outstanding = if auction.force_refund {
+        total_payment_raised
+            .saturating_sub(auction.total_payment_released)
+            .saturating_sub(auction.total_payment_refunded)
+    } else {
+        entitlements
+            .total_refund_entitled
+            .saturating_sub(auction.total_payment_refunded)
+    };
+
+    // Fees of sold sale tokens not claimed yet
+    let mut total_fees_projected = auction.total_fees_collected;
+    if !refund_only && !auction.force_refund {
+        for bin in auction.bins.iter() {
+            let sale_tokens_sold = match bin.kind {
+                BinKind::Sale => {
+                    calculate_bin_withdraw_amounts(
+                        bin.payment_token_raised,
+                        bin.sale_token_cap,
+                        bin.sale_token_price,
+                    )?
+                    .sale_tokens_sold
+                }
+                BinKind::Donation => 0,
+                BinKind::FreeClaim => calculate_free_claim_withdraw_amounts(bin)?.sale_tokens_sold,
+            };
+            let unclaimed_fees = sale_tokens_sold.saturating_sub(bin.sale_token_claimed) as u128
+                * auction.extensions.bin_claim_fee_rate(bin) as u128
+                / 10000;
+            total_fees_projected = total_fees_projected.saturating_add(unclaimed_fees as u64);
+        }
+    }
+
+    // `withdraw_funds` needs the auction finalized
+    let withdrawable = if auction.finalized && current_time > auction.commit_end_time {
+        calculate_withdraw_funds_amounts(auction, current_time)?
+    } else {
+        TotalWithdrawAmounts {
+            total_payment_tokens: 0,
+            total_unsold_sale_tokens: 0,
+            total_sale_tokens_sold: entitlements.total_sale_entitled,
+        }
+    };
+
+    Ok(SettlementSimulation {
+        total_payment_raised,
+        total_sale_tokens_sold: entitlements.total_sale_entitled,
+        total_unsold_sale_tokens: entitled_amounts.total_unsold_sale_tokens,
+        total_refunds_outstanding,
+        total_fees_collected: auction.total_fees_collected,
+        total_fees_projected,
+        withdrawable_fees: calculate_withdrawable_fees(
+            auction.total_fees_collected,
+            auction.total_fees_withdrawn,
+        )?
+        .saturating_sub(auction.frontend_fees_owed()),
+        withdrawable_payment_tokens: withdrawable.total_payment_tokens,
+        withdrawable_unsold_sale_tokens: withdrawable.total_unsold_sale_tokens,
+    })
+}
+
+/// Admin withdraws `amount` (everything withdrawable if not set) of the fees collected
+/// from all bins, in the sale token, to a token account of the configured fee recipient
+/// (the authority if not set)
+///
+/// A configured fee recipient may also withdraw by itself, so treasury operations
+/// don't need the authority key.
+pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: Option<u64>) -> Result<()> {
+    // Check emergency state - withdraw fees operations
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_WITHDRAW_FEES,
+    )?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time > ctx.accounts.auction.commit_end_time,
+        LauchpadError::InCommitmentPeriod
+    );
+
+    let auction = &mut ctx.accounts.auction;
+
+    // Calculate fees to withdraw using allocation.rs function, fees accrued to front-ends
+    // are withdrawn by them
+    let withdrawable_fees =
+        calculate_withdrawable_fees(auction.total_fees_collected, auction.total_fees_withdrawn)?
+            .saturating_sub(auction.frontend_fees_owed());
+
+    // CHECK: partial withdrawals stay within the withdrawable fees
+    let fees_to_withdraw = amount.unwrap_or(withdrawable_fees);
+    require!(
+        fees_to_withdraw <= withdrawable_fees,
+        LauchpadError::InvalidFeeWithdrawAmount
+    );
+
+    // Transfer fees if any
+    if fees_to_withdraw > 0 {
+        let auction_key = auction.key();
+        let vault_sale_seeds = &[
+            VAULT_SALE_SEED,
+            auction_key.as_ref(),
+            &[auction.vault_sale_bump],
+        ];
+
+        // Route the stakers' share to the staking rewards vault
+        let (staking_fees, recipient_fees) = match ctx.accounts.config.revenue_share {
+            Some(revenue_share) => {
+                calculate_revenue_share(fees_to_withdraw, revenue_share.share_bps)?
+            }
+            None => (0, fees_to_withdraw),
+        };
+        if staking_fees > 0 {
+            // CHECK: the staking rewards vault is provided
+            let staking_rewards_vault = ctx
+                .accounts
+                .staking_rewards_vault
+                .as_ref()
+                .ok_or(LauchpadError::InvalidStakingRewardsVault)?;
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.vault_sale_token.to_account_info(),
+                        to: staking_rewards_vault.to_account_info(),
+                        authority: ctx.accounts.vault_sale_token.to_account_info(),
+                    },
+                    &[vault_sale_seeds],
+                ),
+                staking_fees,
+            )?;
+
+            msg!(
+                "Routed {} fee tokens to staking rewards vault {}",
+                staking_fees,
+                staking_rewards_vault.key()
+            );
+        }
+
+        if recipient_fees > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.vault_sale_token.to_account_info(),
+                        to: ctx.accounts.fee_recipient_account.to_account_info(),
+                        authority: ctx.accounts.vault_sale_token.to_account_info(),
+                    },
+                    &[vault_sale_seeds],
+                ),
+                recipient_fees,
+            )?;
+        }
+
+        // Record the fees in the launchpad-wide stats, valued at the average
+        // price of the bins they were collected from
+        let fees_value: u128 = auction
+            .bins
+            .iter()
+            .map(|bin| bin.fees_collected as u128 * bin.sale_token_price as u128)
+            .sum();
+        let fees_value =
+            fees_value * fees_to_withdraw as u128 / auction.total_fees_collected.max(1) as u128;
+        let stats = &mut ctx.accounts.stats;
+        stats.bump = ctx.bumps.stats;
+        stats.record_fees(
+            &auction.payment_token_mint,
+            fees_value.min(u64::MAX as u128) as u64,
+        )?;
+
+        // Update state
+        auction.total_fees_withdrawn += fees_to_withdraw;
+
+        msg!(
+            "Authority withdrew {} fee tokens to recipient {}",
+            recipient_fees,
+            ctx.accounts.fee_recipient_account.key()
+        );
+    }
+
+    Ok(())
+}
+
+/// Registered front-end opens its fee balance in an auction, to be passed to `commit`
+/// by users committing through it
+pub fn init_frontend_fees(ctx: Context<InitFrontendFees>) -> Result<()> {
+    // CHECK: only registered front-ends accrue fees
+    require!(
+        ctx.accounts
+            .config
+            .is_frontend_registered(&ctx.accounts.frontend.key()),
+        LauchpadError::FrontendNotRegistered
+    );
+
+    let frontend_fees = &mut ctx.accounts.frontend_fees;
+    frontend_fees.auction = ctx.accounts.auction.key();
+    frontend_fees.frontend = ctx.accounts.frontend.key();
+    frontend_fees.bump = ctx.bumps.frontend_fees;
+
+    msg!(
+        "Front-end {} fee balance opened for auction {}",
+        frontend_fees.frontend,
+        frontend_fees.auction
+    );
+    Ok(())
+}
+
+/// Front-end withdraws the share of the claim fees accrued to it in an auction
+pub fn withdraw_frontend_fees(ctx: Context<WithdrawFrontendFees>) -> Result<()> {
+    // Check emergency state - withdraw fees operations
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_WITHDRAW_FEES,
+    )?;
+
+    let frontend_fees = &mut ctx.accounts.frontend_fees;
+    let fees_to_withdraw = frontend_fees
+        .fees_accrued
+        .checked_sub(frontend_fees.fees_withdrawn)
+        .ok_or(LauchpadError::MathUnderflow)?;
+
+    if fees_to_withdraw > 0 {
+        let auction = &mut ctx.accounts.auction;
+        let auction_key = auction.key();
+        let vault_sale_seeds = &[
+            VAULT_SALE_SEED,
+            auction_key.as_ref(),
+            &[auction.vault_sale_bump],
+        ];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault_sale_token.to_account_info(),
+                    to: ctx.accounts.frontend_sale_token.to_account_info(),
+                    authority: ctx.accounts.vault_sale_token.to_account_info(),
+                },
+                &[vault_sale_seeds],
+            ),
+            fees_to_withdraw,
+        )?;
+
+        // Update state
+        frontend_fees.fees_withdrawn += fees_to_withdraw;
+        auction.total_frontend_fees_withdrawn += fees_to_withdraw;
+        auction.total_fees_withdrawn += fees_to_withdraw;
+    }
+
+    msg!(
+        "Front-end {} withdrew {} fee tokens",
+        ctx.accounts.frontend.key(),
+        fees_to_withdraw
+    );
+    Ok(())
+}
+
+/// Anyone transfers the late refunds owed to the late refund pool to its recipient
+pub fn withdraw_late_refund_pool(ctx: Context<WithdrawLateRefundPool>) -> Result<()> {
+    // Check emergency state - withdraw funds operations
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_WITHDRAW_FUNDS,
+    )?;
+
+    // CHECK: the auction pools late refunds
+    require!(
+        ctx.accounts.auction.extensions.late_refund_pool.is_some(),
+        LauchpadError::LateRefundPoolDisabled
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let auction = &mut ctx.accounts.auction;
+    let pool_to_withdraw = auction
+        .late_refund_pool_accrued(current_time)
+        .saturating_sub(auction.total_pool_withdrawn);
+
+    if pool_to_withdraw > 0 {
+        let auction_key = auction.key();
+        let vault_payment_seeds = &[
+            VAULT_PAYMENT_SEED,
+            auction_key.as_ref(),
+            &[auction.vault_payment_bump],
+        ];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault_payment_token.to_account_info(),
+                    to: ctx.accounts.pool_payment_token.to_account_info(),
+                    authority: ctx.accounts.vault_payment_token.to_account_info(),
+                },
+                &[vault_payment_seeds],
+            ),
+            pool_to_withdraw,
+        )?;
+
+        auction.total_pool_withdrawn = auction
+            .total_pool_withdrawn
+            .checked_add(pool_to_withdraw)
+            .ok_or(LauchpadError::MathOverflow)?;
+    }
+
+    msg!(
+        "Withdrew {} payment tokens to the late refund pool, {} in total",
+        pool_to_withdraw,
+        auction.total_pool_withdrawn
+    );
+    Ok(())
+}
+
+/// Admin reconciles the claim fee accounting against the per-bin claimed totals
+///
+/// A bin can't have collected more than its fee rate of the sale tokens claimed from
+/// it, and the auction total must equal the sum of its bins. Bins above the bound are
+/// repaired down to it and the total is recomputed, so `withdraw_fees` can't pay out
+/// fees that were never kept in the vault. Fees already withdrawn can't be repaired,
+/// the total never drops below them.
+pub fn reconcile_fees(ctx: Context<ReconcileFees>) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let previous_total_fees_collected = auction.total_fees_collected;
+
+    let mut bins_repaired = 0u8;
+    let mut total_fees_collected = 0u64;
+    for index in 0..auction.bins.len() {
+        let fee_rate = auction.extensions.bin_claim_fee_rate(&auction.bins[index]);
+        let bin = &mut auction.bins[index];
+        let max_fees = calculate_max_bin_fees(bin.sale_token_claimed, fee_rate);
+        if bin.fees_collected > max_fees {
+            bin.fees_collected = max_fees;
+            bins_repaired += 1;
+        }
+        total_fees_collected = total_fees_collected
+            .checked_add(bin.fees_collected)
+            .ok_or(LauchpadError::MathOverflow)?;
+    }
+    auction.total_fees_collected = total_fees_collected.max(
+        auction
+            .total_fees_withdrawn
+            .saturating_add(auction.frontend_fees_owed()),
+    );
+
+    // Flag a sale vault holding less than it owes, liabilities are only known once
+    // entitlements are recorded and IOU mode never deposits sale tokens
+    let sale_vault_shortfall = if auction.finalized && !auction.extensions.iou_mode {
+        calculate_sale_vault_liabilities(
+            &auction.bins,
+            auction.total_sale_entitled,
+            auction.total_fees_withdrawn,
+            auction.unsold_sale_tokens_and_effective_payment_tokens_withdrawn,
+        )?
+        .saturating_sub(ctx.accounts.vault_sale_token.amount)
+    } else {
+        0
+    };
+
+    emit!(FeesReconciledEvent {
+        auction: auction.key(),
+        previous_total_fees_collected,
+        total_fees_collected: auction.total_fees_collected,
+        bins_repaired,
+        sale_vault_shortfall,
+    });
+
+    msg!(
+        "Auction {} fees reconciled: {} -> {}, {} bins repaired, sale vault shortfall {}",
+        auction.key(),
+        previous_total_fees_collected,
+        auction.total_fees_collected,
+        bins_repaired,
+        sale_vault_shortfall
+    );
+    Ok(())
+}
+
+/// Admin recovers vault balances exceeding every accounted liability, e.g. external
+/// transfers or rounding dust, which no other instruction can reach
+pub fn recover_surplus(ctx: Context<RecoverSurplus>) -> Result<()> {
+    // CHECK: emergency state validation
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_WITHDRAW_FUNDS,
+    )?;
+
+    let auction = &ctx.accounts.auction;
+
+    // CHECK: surplus is only recovered once the auction is settled, like `close_auction`
+    require!(
+        auction.finalized && auction.unsold_sale_tokens_and_effective_payment_tokens_withdrawn,
+        LauchpadError::AuctionNotSettled
+    );
+
+    let (sale_liabilities, payment_liabilities) = vault_liabilities(auction)?;
+    let sale_surplus = ctx
+        .accounts
+        .vault_sale_token
+        .amount
+        .saturating_sub(sale_liabilities);
+    let payment_surplus = ctx
+        .accounts
+        .vault_payment_token
+        .amount
+        .saturating_sub(payment_liabilities);
+
+    let auction_key = auction.key();
+    for (vault, recipient, bump, seed, surplus) in [
+        (
+            &ctx.accounts.vault_sale_token,
+            &ctx.accounts.sale_token_recipient,
+            auction.vault_sale_bump,
+            VAULT_SALE_SEED,
+            sale_surplus,
+        ),
+        (
+            &ctx.accounts.vault_payment_token,
+            &ctx.accounts.payment_token_recipient,
+            auction.vault_payment_bump,
+            VAULT_PAYMENT_SEED,
+            payment_surplus,
+        ),
+    ] {
+        if surplus == 0 {
+            continue;
+        }
+        let vault_seeds = &[seed, auction_key.as_ref(), &[bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: vault.to_account_info(),
+                    to: recipient.to_account_info(),
+                    authority: vault.to_account_info(),
+                },
+                &[vault_seeds],
+            ),
+            surplus,
+        )?;
+    }
+
+    // Placeholder auctions also release the real sale tokens backing placeholders no
+    // user can swap, like those paid as claim fees
+    let mut redemption_surplus = 0;
+    if let Some(vault_redemption) = &ctx.accounts.vault_redemption_token {
+        // CHECK: IOU redemption vaults back recorded IOUs only
+        require!(!auction.extensions.iou_mode, LauchpadError::IouModeEnabled);
+        let recipient = ctx
+            .accounts
+            .redemption_token_recipient
+            .as_ref()
+            .ok_or(LauchpadError::MissingRedemptionAccount)?;
+
+        let redemption_liabilities = calculate_redemption_vault_liabilities(
+            &auction.bins,
+            auction.total_sale_swept,
+            auction.placeholder_swapped,
+        )?;
+        redemption_surplus = vault_redemption
+            .amount
+            .saturating_sub(redemption_liabilities);
+        if redemption_surplus > 0 {
+            let vault_redemption_seeds = &[
+                VAULT_REDEMPTION_SEED,
+                auction_key.as_ref(),
+                &[auction.vault_redemption_bump],
+            ];
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: vault_redemption.to_account_info(),
+                        to: recipient.to_account_info(),
+                        authority: vault_redemption.to_account_info(),
+                    },
+                    &[vault_redemption_seeds],
+                ),
+                redemption_surplus,
+            )?;
+        }
+    }
+
+    emit!(SurplusRecoveredEvent {
+        auction: auction_key,
+        sale_token_liabilities: sale_liabilities,
+        sale_token_surplus: sale_surplus,
+        payment_token_liabilities: payment_liabilities,
+        payment_token_surplus: payment_surplus,
+        redemption_token_surplus: redemption_surplus,
+    });
+
+    msg!(
+        "Authority recovered {} surplus sale tokens, {} surplus payment tokens and {} surplus redemption tokens",
+        sale_surplus,
+        payment_surplus,
+        redemption_surplus
+    );
+    Ok(())
+}
+
+/// Admin sweeps the sale tokens and refunds users left unclaimed at the claim
+/// deadline to the recovery of the claim deadline
+pub fn sweep_unclaimed(ctx: Context<SweepUnclaimed>) -> Result<()> {
+    // CHECK: emergency state validation
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_WITHDRAW_FUNDS,
+    )?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let auction = &mut ctx.accounts.auction;
+
+    // CHECK: entitlements are recorded and the claim deadline has passed
+    require!(auction.finalized, LauchpadError::AuctionNotFinalized);
+    require!(
+        auction.claim_end_time().is_some() && !auction.claims_open(current_time),
+        LauchpadError::ClaimPeriodNotEnded
+    );
+
+    // CHECK: forced refunds settle unclaimed sale tokens as refunds of their own
+    require!(!auction.force_refund, LauchpadError::SaleClaimsFrozen);
+
+    let total_sale_token_claimed = auction
+        .bins
+        .iter()
+        .map(|bin| bin.sale_token_claimed)
+        .sum::<u64>();
+    let sale_tokens_unclaimed = auction
+        .total_sale_entitled
+        .saturating_sub(total_sale_token_claimed)
+        .saturating_sub(auction.total_sale_swept);
+    let payment_tokens_unclaimed = auction
+        .total_refund_entitled
+        .saturating_sub(auction.total_payment_refunded)
+        .saturating_sub(auction.total_payment_swept);
+
+    let auction_key = auction.key();
+    for (vault, recipient, bump, seed, amount) in [
+        (
+            &ctx.accounts.vault_sale_token,
+            &ctx.accounts.sale_token_recovery,
+            auction.vault_sale_bump,
+            VAULT_SALE_SEED,
+            sale_tokens_unclaimed,
+        ),
+        (
+            &ctx.accounts.vault_payment_token,
+            &ctx.accounts.payment_token_recovery,
+            auction.vault_payment_bump,
+            VAULT_PAYMENT_SEED,
+            payment_tokens_unclaimed,
+        ),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        let vault_seeds = &[seed, auction_key.as_ref(), &[bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: vault.to_account_info(),
+                    to: recipient.to_account_info(),
+                    authority: vault.to_account_info(),
+                },
+                &[vault_seeds],
+            ),
+            amount,
+        )?;
+    }
+
+    auction.total_sale_swept = auction
+        .total_sale_swept
+        .checked_add(sale_tokens_unclaimed)
+        .ok_or(LauchpadError::MathOverflow)?;
+    auction.total_payment_swept = auction
+        .total_payment_swept
+        .checked_add(payment_tokens_unclaimed)
+        .ok_or(LauchpadError::MathOverflow)?;
+
+    emit!(UnclaimedSweptEvent {
+        auction: auction_key,
+        sale_tokens_swept: sale_tokens_unclaimed,
+        payment_tokens_swept: payment_tokens_unclaimed,
+    });
+
+    msg!(
+        "Swept {} unclaimed sale tokens and {} unclaimed payment tokens",
+        sale_tokens_unclaimed,
+        payment_tokens_unclaimed
+    );
+    Ok(())
+}
+
+/// Sale and payment tokens a finalized auction's vaults still owe to users and the
+/// authority
+///
+/// IOU mode never deposits sale tokens, NFT payment mode never takes payment tokens.
+fn vault_liabilities(auction: &Auction) -> Result<(u64, u64)> {
+    let sale_liabilities = if auction.extensions.iou_mode {
+        0
+    } else {
+        calculate_sale_vault_liabilities(
+            &auction.bins,
+            auction.total_sale_entitled,
+            auction.total_fees_withdrawn,
+            auction.unsold_sale_tokens_and_effective_payment_tokens_withdrawn,
+        )?
+        .saturating_sub(auction.total_sale_swept)
+    };
+    let payment_liabilities = if auction.extensions.is_nft_payment_enabled() {
+        0
+    } else {
+        calculate_payment_vault_liabilities(
+            auction
+                .total_payment_raised()?
+                .checked_add(auction.waitlist_escrowed)
+                .ok_or(LauchpadError::MathOverflow)?,
+            auction.total_payment_refunded
+                + auction.total_pool_withdrawn
+                + auction.total_payment_swept,
+            auction.total_payment_withdrawn,
+        )
+    };
+    Ok((sale_liabilities, payment_liabilities))
+}
+
+/// Admin withdraws lamports from the auction lamport treasury
+///
+/// The treasury is a system account anyone can fund, it pays the rent of token
+/// accounts created by `claim` and `withdraw_funds` on behalf of their signers.
+pub fn withdraw_lamports(ctx: Context<WithdrawLamports>, amount: u64) -> Result<()> {
+    let auction_key = ctx.accounts.auction.key();
+    let treasury_seeds = &[
+        LAMPORT_TREASURY_SEED,
+        auction_key.as_ref(),
+        &[ctx.bumps.lamport_treasury],
+    ];
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.lamport_treasury.to_account_info(),
+                to: ctx.accounts.authority.to_account_info(),
+            },
+            &[treasury_seeds],
+        ),
+        amount,
+    )?;
+
+    msg!(
+        "Authority withdrew {} lamports from the lamport treasury, {} lamports funded rent so far",
+        amount,
+        ctx.accounts.auction.total_rent_funded
+    );
+    Ok(())
+}
+
+/// Admin sets new price for a bin
+pub fn set_price(ctx: Context<SetPrice>, bin_id: u8, new_price: u64) -> Result<()> {
+    // CHECK: timelocked auctions change prices through `queue_admin_action`
+    require!(
+        ctx.accounts.auction.extensions.admin_timelock.is_none(),
+        LauchpadError::TimelockRequired
+    );
+
+    apply_price(
+        &mut ctx.accounts.auction,
+        &ctx.accounts.config,
+        bin_id,
+        new_price,
+    )
+}
+
+/// Admin queues a sensitive action behind the auction's timelock
+///
+/// Price changes are applied by `execute_admin_action` once the delay has passed,
+/// fund withdrawals by `withdraw_funds` given the matured action.
+pub fn queue_admin_action(
+    ctx: Context<QueueAdminAction>,
+    action_id: u64,
+    action: AdminActionKind,
+) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+
+    // CHECK: the auction is timelocked
+    let delay = auction
+        .extensions
+        .admin_timelock
+        .ok_or(LauchpadError::InvalidAdminAction)?;
+
+    // CHECK: only the holder of the action's role queues it
+    require_keys_eq!(
+        auction.role_holder(action.role()),
+        ctx.accounts.authority.key(),
+        LauchpadError::Unauthorized
+    );
+
+    let eta = Clock::get()?
+        .unix_timestamp
+        .checked_add(delay)
+        .ok_or(LauchpadError::MathOverflow)?;
+    msg!(
+        "Admin action {} queued: {:?}, executable at {}",
+        action_id,
+        action,
+        eta
+    );
+    *ctx.accounts.admin_action = AdminAction {
+        auction: auction.key(),
+        action_id,
+        action,
+        eta,
+        bump: ctx.bumps.admin_action,
+    };
+    Ok(())
+}
+
+/// Anyone applies a queued price change once its timelock has passed
+pub fn execute_admin_action(ctx: Context<ExecuteAdminAction>) -> Result<()> {
+    let admin_action = &ctx.accounts.admin_action;
+
+    // CHECK: the timelock has passed
+    require!(
+        Clock::get()?.unix_timestamp >= admin_action.eta,
+        LauchpadError::AdminActionNotReady
+    );
+
+    match admin_action.action {
+        AdminActionKind::SetPrice { bin_id, new_price } => apply_price(
+            &mut ctx.accounts.auction,
+            &ctx.accounts.config,
+            bin_id,
+            new_price,
+        )?,
+        // CHECK: withdrawals need the recipient accounts of `withdraw_funds`
+        AdminActionKind::WithdrawFunds => return err!(LauchpadError::InvalidAdminAction),
+    }
+
+    msg!("Admin action {} executed", admin_action.action_id);
+    Ok(())
+}
+
+/// Admin cancels a queued action
+pub fn cancel_admin_action(ctx: Context<CancelAdminAction>) -> Result<()> {
+    // CHECK: only the holder of the action's role cancels it
+    require_keys_eq!(
+        ctx.accounts
+            .auction
+            .role_holder(ctx.accounts.admin_action.action.role()),
+        ctx.accounts.authority.key(),
+        LauchpadError::Unauthorized
+    );
+
+    msg!(
+        "Admin action {} cancelled",
+        ctx.accounts.admin_action.action_id
+    );
+    Ok(())
+}
+
+/// Set the price of a sale bin, before finalization
+fn apply_price(
+    auction: &mut Account<Auction>,
+    config: &LaunchpadConfig,
+    bin_id: u8,
+    new_price: u64,
+) -> Result<()> {
+    // CHECK: emergency control
+    check_emergency_state(auction, config, EmergencyState::PAUSE_AUCTION_UPDATION)?;
+
+    // CHECK: Validate new price
+    require!(new_price > 0, LauchpadError::InvalidAuctionBinsPriceOrCap);
+
+    // CHECK: cached allocation ratios depend on the price
+    require!(!auction.finalized, LauchpadError::AuctionAlreadyFinalized);
+
+    let bin = auction.get_bin_mut(bin_id)?;
+
+    // CHECK: only sale bins are priced
+    require!(bin.kind == BinKind::Sale, LauchpadError::UnsupportedBinKind);
+
+    // CHECK: sale tokens reserved at the current price keep it
+    require!(
+        bin.sale_token_reserved == 0 || new_price == bin.sale_token_price,
+        LauchpadError::ReservedBinPriceLocked
+    );
+
+    bin.sale_token_price = new_price;
+    bin.price_updated_at = Clock::get()?.unix_timestamp;
+
+    // CHECK: bin targets at the new price fit in u64, so claims can compute them
+    calculate_total_bin_target(
+        auction
+            .bins
+            .iter()
+            .map(|bin| (bin.sale_token_cap, bin.sale_token_price)),
+    )?;
+
+    msg!("Price for bin {} updated to {}", bin_id, new_price);
+    Ok(())
+}
+
+/// Admin delegates the pauser, price setter, fee manager and fund withdrawer roles,
+/// unset roles stay with the authority
+pub fn set_auction_roles(ctx: Context<SetAuctionRoles>, roles: AuctionRoles) -> Result<()> {
+    // CHECK: emergency control
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_UPDATION,
+    )?;
+
+    let auction = &mut ctx.accounts.auction;
+    auction.roles = roles;
+
+    msg!(
+        "Roles of auction {} updated: pauser {}, price setter {}, fee manager {}, fund withdrawer {}",
+        auction.key(),
+        auction.role_holder(AuctionRole::Pauser),
+        auction.role_holder(AuctionRole::PriceSetter),
+        auction.role_holder(AuctionRole::FeeManager),
+        auction.role_holder(AuctionRole::FundWithdrawer)
+    );
+    Ok(())
+}
+
+/// Admin appends a bin before the commit period starts, depositing its sale tokens
+/// from the seller and growing the auction account
+pub fn add_bin(ctx: Context<AddBin>, params: AuctionBinParams) -> Result<()> {
+    // CHECK: emergency control
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_UPDATION,
+    )?;
+
+    // CHECK: the bin layout is final once commitments open
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time < ctx.accounts.auction.commit_start_time,
+        LauchpadError::BinLayoutLocked
+    );
+
+    // CHECK: the bins stay a valid auction configuration
+    let mut bins: Vec<AuctionBinParams> = ctx
+        .accounts
+        .auction
+        .bins
+        .iter()
+        .map(AuctionBin::to_params)
+        .collect();
+    bins.push(params.clone());
+    let round_count = validate_auction_config(&bins, &ctx.accounts.auction.extensions)?;
+
+    // Deposit the sale tokens of the new bin, pre-TGE auctions have none yet
+    if !ctx.accounts.auction.extensions.iou_mode && params.sale_token_cap > 0 {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.sale_token_seller.to_account_info(),
+                    to: ctx.accounts.vault_sale_token.to_account_info(),
+                    authority: ctx.accounts.sale_token_seller_authority.to_account_info(),
+                },
+            ),
+            params.sale_token_cap,
+        )?;
+    }
+
+    let auction = &mut ctx.accounts.auction;
+    let boost_weight_bps = auction
+        .extensions
+        .commit_boost
+        .as_ref()
+        .map_or(0, |boost| boost.weight_bps);
+    auction.bins.push(AuctionBin::from_params(
+        params,
+        boost_weight_bps,
+        current_time,
+    ));
+    auction.round_count = round_count;
+
+    msg!(
+        "Bin {} added to auction {}",
+        auction.bins.len() - 1,
+        auction.key()
+    );
+    Ok(())
+}
+
+/// Admin changes the sale token cap of a bin before the commit period starts,
+/// depositing the increase from the seller or returning the decrease to it
+pub fn update_bin_cap(ctx: Context<UpdateBinCap>, bin_id: u8, sale_token_cap: u64) -> Result<()> {
+    // CHECK: emergency control
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_UPDATION,
+    )?;
+
+    // CHECK: the bin layout is final once commitments open
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time < ctx.accounts.auction.commit_start_time,
+        LauchpadError::BinLayoutLocked
+    );
+
+    // CHECK: the bins stay a valid auction configuration
+    let previous_cap = ctx.accounts.auction.get_bin(bin_id)?.sale_token_cap;
+    let mut bins: Vec<AuctionBinParams> = ctx
+        .accounts
+        .auction
+        .bins
+        .iter()
+        .map(AuctionBin::to_params)
+        .collect();
+    bins[bin_id as usize].sale_token_cap = sale_token_cap;
+    validate_auction_config(&bins, &ctx.accounts.auction.extensions)?;
+
+    // Move the cap difference, pre-TGE auctions hold no sale tokens yet
+    if !ctx.accounts.auction.extensions.iou_mode {
+        if sale_token_cap > previous_cap {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.sale_token_seller.to_account_info(),
+                        to: ctx.accounts.vault_sale_token.to_account_info(),
+                        authority: ctx.accounts.sale_token_seller_authority.to_account_info(),
+                    },
+                ),
+                sale_token_cap - previous_cap,
+            )?;
+        } else if sale_token_cap < previous_cap {
+            let auction_key = ctx.accounts.auction.key();
+            let vault_sale_seeds = &[
+                VAULT_SALE_SEED,
+                auction_key.as_ref(),
+                &[ctx.accounts.auction.vault_sale_bump],
+            ];
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.vault_sale_token.to_account_info(),
+                        to: ctx.accounts.sale_token_seller.to_account_info(),
+                        authority: ctx.accounts.vault_sale_token.to_account_info(),
+                    },
+                    &[vault_sale_seeds],
+                ),
+                previous_cap - sale_token_cap,
+            )?;
+        }
+    }
+
+    let auction = &mut ctx.accounts.auction;
+    auction.get_bin_mut(bin_id)?.sale_token_cap = sale_token_cap;
+
+    msg!(
+        "Bin {} cap of auction {} updated from {} to {}",
+        bin_id,
+        auction.key(),
+        previous_cap,
+        sale_token_cap
+    );
+    Ok(())
+}
+
+/// Admin adds sale tokens to a sale bin until the commit period ends, raising its cap
+/// so an oversubscribed bin allocates more to its committers
+pub fn top_up_sale_tokens(ctx: Context<TopUpSaleTokens>, bin_id: u8, amount: u64) -> Result<()> {
+    // CHECK: emergency control
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_UPDATION,
+    )?;
+
+    // CHECK: allocations are final once the commit period ends, claims may start
+    // before finalization
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time <= ctx.accounts.auction.commit_end_time,
+        LauchpadError::OutOfCommitmentPeriod
+    );
+
+    // CHECK: refund-only auctions sell nothing
+    require!(
+        !ctx.accounts.auction.is_refund_only(current_time),
+        LauchpadError::RefundModeAlreadyEnabled
+    );
+
+    // CHECK: only sale bins allocate sale tokens pro-rata
+    let bin = ctx.accounts.auction.get_bin(bin_id)?;
+    require!(
+        amount > 0 && bin.kind == BinKind::Sale,
+        LauchpadError::InvalidTopUp
+    );
+    let sale_token_cap = bin
+        .sale_token_cap
+        .checked_add(amount)
+        .ok_or(LauchpadError::MathOverflow)?;
+
+    // Deposit the sale tokens, pre-TGE auctions have none yet
+    if !ctx.accounts.auction.extensions.iou_mode {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.sale_token_seller.to_account_info(),
+                    to: ctx.accounts.vault_sale_token.to_account_info(),
+                    authority: ctx.accounts.sale_token_seller_authority.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+
+    let auction = &mut ctx.accounts.auction;
+    auction.get_bin_mut(bin_id)?.sale_token_cap = sale_token_cap;
+
+    // CHECK: bin targets still fit in u64, so claims can compute them
+    calculate_total_bin_target(
+        auction
+            .bins
+            .iter()
+            .map(|bin| (bin.sale_token_cap, bin.sale_token_price)),
+    )?;
+    let allocation_ratio = calculate_bin_allocation_ratio(auction.get_bin(bin_id)?)?.raw_ratio();
+
+    emit!(SaleTokensToppedUpEvent {
+        auction: auction.key(),
+        bin_id,
+        amount,
+        sale_token_cap,
+        allocation_ratio,
+    });
+
+    msg!(
+        "Bin {} of auction {} topped up by {} sale tokens, cap {}",
+        bin_id,
+        auction.key(),
+        amount,
+        sale_token_cap
+    );
+    Ok(())
+}
+
+/// Admin sets the hash of the sale terms users acknowledge when committing
+///
+/// Terms can be updated until claims start, users who committed under earlier terms
+/// then pass the new hash to `claim` once before claiming sale tokens.
+pub fn set_terms_hash(ctx: Context<SetTermsHash>, terms_hash: [u8; 32]) -> Result<()> {
+    // CHECK: emergency control
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_UPDATION,
+    )?;
+
+    let auction = &mut ctx.accounts.auction;
+
+    // CHECK: terms are final once claims start
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time < auction.claim_start_time,
+        LauchpadError::TermsHashLocked
+    );
+
+    auction.terms_hash = terms_hash;
+
+    msg!("Terms hash of auction {} updated", auction.key());
+    Ok(())
+}
+
+/// Admin pushes auction times later, e.g. extending the commit window after an RPC
+/// outage during launch
+///
+/// Each time can only move later, and only until it has passed: the commit start
+/// before commitments open, the commit end while they are open and the claim start
+/// before claims open. The times must stay ordered and keep the claim grace period.
+pub fn update_times(
+    ctx: Context<UpdateTimes>,
+    commit_start_time: i64,
+    commit_end_time: i64,
+    claim_start_time: i64,
+) -> Result<()> {
+    // CHECK: emergency control
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_UPDATION,
+    )?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let auction = &mut ctx.accounts.auction;
+
+    // CHECK: times only move later, and only before they have passed
+    let updatable = |old_time: i64, new_time: i64, ended: bool| {
+        new_time == old_time || (new_time > old_time && !ended)
+    };
+    require!(
+        updatable(
+            auction.commit_start_time,
+            commit_start_time,
+            current_time >= auction.commit_start_time
+        ) && updatable(
+            auction.commit_end_time,
+            commit_end_time,
+            current_time > auction.commit_end_time
+        ) && updatable(
+            auction.claim_start_time,
+            claim_start_time,
+            current_time >= auction.claim_start_time
+        ),
+        LauchpadError::InvalidTimeUpdate
+    );
+
+    // CHECK: timing validation, require commit_start_time <= commit_end_time <= claim_start_time
+    require!(
+        commit_start_time <= commit_end_time && commit_end_time <= claim_start_time,
+        LauchpadError::InvalidAuctionTimeRange
+    );
+
+    // CHECK: claim grace period validation, leave time to finalize before claims open
+    require!(
+        claim_start_time
+            .checked_sub(commit_end_time)
+            .ok_or(LauchpadError::MathUnderflow)?
+            >= ctx.accounts.config.min_claim_grace_period,
+        LauchpadError::ClaimGracePeriodTooShort
+    );
+
+    emit!(TimesUpdatedEvent {
+        auction: auction.key(),
+        authority: ctx.accounts.authority.key(),
+        previous_commit_start_time: auction.commit_start_time,
+        previous_commit_end_time: auction.commit_end_time,
+        previous_claim_start_time: auction.claim_start_time,
+        commit_start_time,
+        commit_end_time,
+        claim_start_time,
+    });
+
+    auction.commit_start_time = commit_start_time;
+    auction.commit_end_time = commit_end_time;
+    auction.claim_start_time = claim_start_time;
+
+    msg!(
+        "Auction {} times updated: commit {} - {}, claim from {}",
+        auction.key(),
+        commit_start_time,
+        commit_end_time,
+        claim_start_time
+    );
+    Ok(())
+}
+
+/// Admin enables redemption of IOUs by depositing the real sale tokens
+pub fn enable_redemption(ctx: Context<EnableRedemption>) -> Result<()> {
+    // CHECK: only pre-TGE auctions record IOUs
+    require!(
+        ctx.accounts.auction.extensions.iou_mode,
+        LauchpadError::IouModeDisabled
+    );
+
+    fund_redemption_vault(ctx)
+}
+
+/// Admin sets the real mint that placeholder sale tokens are swapped into
+pub fn set_redemption_mint(ctx: Context<EnableRedemption>) -> Result<()> {
+    // CHECK: IOU auctions never distribute placeholder tokens
+    require!(
+        !ctx.accounts.auction.extensions.iou_mode,
+        LauchpadError::IouModeEnabled
+    );
+
+    fund_redemption_vault(ctx)
+}
+
+/// Deposit the real sale tokens backing every sold sale token into the redemption vault
+///
+/// Claim fees aren't known until claims end, so the vault backs them too. Placeholder
+/// auctions release what users can no longer swap by `recover_surplus`.
+fn fund_redemption_vault(ctx: Context<EnableRedemption>) -> Result<()> {
+    // CHECK: emergency control
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_UPDATION,
+    )?;
+
+    let auction = &mut ctx.accounts.auction;
+
+    // CHECK: redemption can only be enabled once
+    require!(
+        auction.redemption_mint.is_none(),
+        LauchpadError::RedemptionAlreadyEnabled
+    );
+
+    // CHECK: Timing validation - sold amounts are final after commit period ends
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time > auction.commit_end_time,
+        LauchpadError::InCommitmentPeriod
+    );
+
+    // Deposit the real sale tokens backing every sold sale token
+    let total_amounts = calculate_total_withdraw_amounts(&auction.bins)?;
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.redemption_token_source.to_account_info(),
+                to: ctx.accounts.vault_redemption_token.to_account_info(),
+                authority: ctx
+                    .accounts
+                    .redemption_token_source_authority
+                    .to_account_info(),
+            },
+        ),
+        total_amounts.total_sale_tokens_sold,
+    )?;
+
+    auction.redemption_mint = Some(ctx.accounts.redemption_mint.key());
+    auction.vault_redemption_bump = ctx.bumps.vault_redemption_token;
+
+    msg!(
+        "Redemption enabled with mint {}, {} sale tokens deposited",
+        ctx.accounts.redemption_mint.key(),
+        total_amounts.total_sale_tokens_sold
+    );
+    Ok(())
+}
+
+/// User redeems recorded IOUs for the real sale tokens
+pub fn redeem(ctx: Context<Redeem>) -> Result<()> {
+    // CHECK: emergency state validation
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_CLAIM,
+    )?;
+
+    let sale_token_owed = ctx.accounts.redemption.sale_token_owed;
+    require!(sale_token_owed > 0, LauchpadError::NothingToRedeem);
+
+    let auction_key = ctx.accounts.auction.key();
+    let vault_redemption_seeds = &[
+        VAULT_REDEMPTION_SEED,
+        auction_key.as_ref(),
+        &[ctx.accounts.auction.vault_redemption_bump],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault_redemption_token.to_account_info(),
+                to: ctx.accounts.user_redemption_token.to_account_info(),
+                authority: ctx.accounts.vault_redemption_token.to_account_info(),
+            },
+            &[vault_redemption_seeds],
+        ),
+        sale_token_owed,
+    )?;
+
+    msg!(
+        "User {} redeemed {} sale tokens",
+        ctx.accounts.user.key(),
+        sale_token_owed
+    );
+    Ok(())
+}
+
+/// User burns placeholder sale tokens and receives the real sale tokens 1:1
+///
+/// Swaps are bounded by the placeholders the user claimed net of claim fees, recorded
+/// in their redemption ledger, the redemption vault doesn't back the unsold or fee
+/// placeholders of the authority. The ledger is closed once fully swapped.
+pub fn swap_placeholder(ctx: Context<SwapPlaceholder>, amount: u64) -> Result<()> {
+    // CHECK: emergency state validation
+    check_emergency_state(
+        &ctx.accounts.auction,
+        &ctx.accounts.config,
+        EmergencyState::PAUSE_AUCTION_CLAIM,
+    )?;
+
+    // CHECK: swap amount validation
+    require_neq!(amount, 0, LauchpadError::InvalidClaimAmount);
+
+    // CHECK: only placeholders the user claimed are backed by the redemption vault
+    let redemption = &mut ctx.accounts.redemption;
+    redemption.placeholder_swappable = redemption
+        .placeholder_swappable
+        .checked_sub(amount)
+        .ok_or(LauchpadError::PlaceholderSwapExceeded)?;
+    let auction = &mut ctx.accounts.auction;
+    auction.placeholder_swapped = auction
+        .placeholder_swapped
+        .checked_add(amount)
+        .ok_or(LauchpadError::MathOverflow)?;
+
+    // Burn the placeholder tokens
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.sale_token_mint.to_account_info(),
+                from: ctx.accounts.user_sale_token.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    // Deliver the real tokens
+    let auction_key = ctx.accounts.auction.key();
+    let vault_redemption_seeds = &[
+        VAULT_REDEMPTION_SEED,
+        auction_key.as_ref(),
+        &[ctx.accounts.auction.vault_redemption_bump],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault_redemption_token.to_account_info(),
+                to: ctx.accounts.user_redemption_token.to_account_info(),
+                authority: ctx.accounts.vault_redemption_token.to_account_info(),
+            },
+            &[vault_redemption_seeds],
+        ),
+        amount,
+    )?;
+
+    let redemption = &ctx.accounts.redemption;
+    if redemption.placeholder_swappable == 0 && redemption.sale_token_owed == 0 {
+        redemption.close(ctx.accounts.user.to_account_info())?;
+    }
+
+    msg!(
+        "User {} swapped {} placeholder tokens",
+        ctx.accounts.user.key(),
+        amount
+    );
+    Ok(())
+}
+
+/// Finalize the auction after the commit period, caching every bin's allocation ratio
+/// and the total entitlements of all users, and publishing them as an `AuctionResult`
+pub fn finalize_auction(ctx: Context<FinalizeAuction>) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+
+    // CHECK: finalization happens once
+    require!(!auction.finalized, LauchpadError::AuctionAlreadyFinalized);
+
+    // CHECK: Timing validation - raised amounts are final after commit period ends
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time > auction.commit_end_time,
+        LauchpadError::InCommitmentPeriod
+    );
+
+    for bin in auction.bins.iter_mut() {
+        bin.allocation_ratio = calculate_bin_allocation_ratio(bin)?.raw_ratio();
+    }
+
+    // Record what is owed to users, `withdraw_funds` takes only the rest
+    let entitlements =
+        calculate_total_entitlements(&auction.bins, auction.is_refund_only(current_time))?;
+    auction.total_sale_entitled = entitlements.total_sale_entitled;
+    auction.total_refund_entitled = entitlements.total_refund_entitled;
+    auction.finalized = true;
+
+    // Publish the settlement for other programs
+    let result = &mut ctx.accounts.auction_result;
+    result.auction = auction.key();
+    result.sale_token_mint = auction.sale_token_mint;
+    result.payment_token_mint = auction.payment_token_mint;
+    result.finalized_at = current_time;
+    result.refund_only = auction.is_refund_only(current_time);
+    result.total_payment_raised = auction.total_payment_raised()?;
+    result.total_sale_entitled = entitlements.total_sale_entitled;
+    result.total_refund_entitled = entitlements.total_refund_entitled;
+    result.bins = auction
+        .bins
+        .iter()
+        .map(|bin| BinResult {
+            sale_token_price: bin.sale_token_price,
+            sale_token_cap: bin.sale_token_cap,
+            payment_token_raised: bin.payment_token_raised,
+            allocation_ratio: bin.allocation_ratio,
+        })
+        .collect();
+    result.bump = ctx.bumps.auction_result;
+
+    // Record the settled auction in the launchpad-wide stats
+    let stats = &mut ctx.accounts.stats;
+    stats.bump = ctx.bumps.stats;
+    stats.record_auction(
+        &auction.payment_token_mint,
+        auction
+            .total_payment_raised()?
+            .saturating_sub(entitlements.total_refund_entitled),
+    )?;
+
+    let bounty = pay_keeper_bounty(auction, &ctx.accounts.keeper, 1, 1)?;
+
+    msg!(
+        "Auction {} finalized, {} lamports keeper bounty",
+        auction.key(),
+        bounty
+    );
+    Ok(())
+}
+
+/// Pay the keeper bounty of a permissionless crank from the auction account's lamports
+/// above rent exemption, capped by what is available, returning the amount paid
+///
+/// Batched cranks earn the bounty pro rata to the `processed` items out of a full batch of
+/// `batch_capacity`, so splitting a batch into many small calls doesn't multiply it.
+fn pay_keeper_bounty(
+    auction: &Account<Auction>,
+    keeper: &Signer,
+    processed: u64,
+    batch_capacity: u64,
+) -> Result<u64> {
+    let Some(full_bounty) = auction.extensions.keeper_bounty else {
+        return Ok(0);
+    };
+    let bounty = u64::try_from(
+        (full_bounty as u128)
+            .checked_mul(processed.min(batch_capacity) as u128)
+            .ok_or(LauchpadError::MathOverflow)?
+            .checked_div(batch_capacity as u128)
+            .ok_or(LauchpadError::DivisionByZero)?,
+    )
+    .map_err(|_| LauchpadError::MathOverflow)?;
+
+    let auction_info = auction.to_account_info();
+    // The escrowed security deposit isn't available for bounties
+    let reserved_lamports = Rent::get()?
+        .minimum_balance(auction_info.data_len())
+        .saturating_add(auction.security_deposit_held);
+    let paid = bounty.min(auction_info.lamports().saturating_sub(reserved_lamports));
+    if paid > 0 {
+        **auction_info.try_borrow_mut_lamports()? -= paid;
+        **keeper.try_borrow_mut_lamports()? = keeper
+            .lamports()
+            .checked_add(paid)
+            .ok_or(LauchpadError::MathOverflow)?;
+    }
+
+    Ok(paid)
+}
+
+/// Get what a user can still claim from a bin, i.e. the largest `claim` amounts that pass
+/// the `InvalidClaimAmount` check
+pub fn get_remaining_entitlement(
+    ctx: Context<GetRemainingEntitlement>,
+    _user: Pubkey,
+    bin_id: u8,
+) -> Result<RemainingEntitlement> {
+    let auction = &ctx.accounts.auction;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let committed = ctx.accounts.committed.load()?;
+    let committed_bin = committed
+        .find_bin(bin_id)
+        .ok_or(LauchpadError::InvalidBinId)?;
+    let claimable_amounts = calculate_user_bin_entitlement(auction, committed_bin, current_time)?;
+
+    // NFT payment mode refunds by `return_nft`, never by `claim`
+    let refund_payment_tokens = if auction.extensions.is_nft_payment_enabled() {
+        0
+    } else {
+        claimable_amounts
+            .refund_payment_tokens
+            .saturating_sub(committed_bin.payment_token_refunded)
+    };
+
+    Ok(RemainingEntitlement {
+        sale_tokens: claimable_amounts
+            .sale_tokens
+            .saturating_sub(committed_bin.sale_token_claimed),
+        refund_payment_tokens,
+    })
+}
+
+/// Get the unlock timeline of a user's sale tokens in a bin
+///
+/// Sale tokens unlock at `claim_start_time`, except those of boosted commitments,
+/// which unlock once the boost lock period has passed. Wallets can render countdowns
+/// from this without duplicating the unlock rules.
+pub fn get_vesting_schedule(
+    ctx: Context<GetRemainingEntitlement>,
+    _user: Pubkey,
+    bin_id: u8,
+) -> Result<VestingSchedule> {
+    let auction = &ctx.accounts.auction;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let committed = ctx.accounts.committed.load()?;
+    let committed_bin = committed
+        .find_bin(bin_id)
+        .ok_or(LauchpadError::InvalidBinId)?;
+    let amounts = calculate_user_bin_entitlement(auction, committed_bin, current_time)?;
+    let total_sale_tokens = amounts.sale_tokens;
+    let boosted_sale_tokens = amounts.boosted_sale_tokens;
+    let regular_sale_tokens = total_sale_tokens - boosted_sale_tokens;
+    let boost_unlock_time = auction
+        .extensions
+        .boost_unlock_time(auction.claim_start_time);
+
+    let unlocked_sale_tokens = if current_time >= boost_unlock_time {
+        total_sale_tokens
+    } else if current_time >= auction.claim_start_time {
+        regular_sale_tokens
+    } else {
+        0
+    };
+    let (next_unlock_time, next_unlock_amount) =
+        if current_time < auction.claim_start_time && regular_sale_tokens > 0 {
+            (Some(auction.claim_start_time), regular_sale_tokens)
+        } else if current_time < boost_unlock_time && boosted_sale_tokens > 0 {
+            (Some(boost_unlock_time), boosted_sale_tokens)
+        } else {
+            (None, 0)
+        };
+
+    Ok(VestingSchedule {
+        total_sale_tokens,
+        unlocked_sale_tokens,
+        claimed_sale_tokens: committed_bin.sale_token_claimed,
+        cliff_time: auction.claim_start_time,
+        next_unlock_time,
+        next_unlock_amount,
+        fully_vested_time: if boosted_sale_tokens > 0 {
+            boost_unlock_time
+        } else {
+            auction.claim_start_time
+        },
+    })
+}
+
+/// A user's total entitlement in a bin under the current auction state
+fn calculate_user_bin_entitlement(
+    auction: &Auction,
+    committed_bin: &CommittedBin,
+    current_time: i64,
+) -> Result<ClaimableAmounts> {
+    let bin = auction.get_bin(committed_bin.bin_id)?;
+    if auction.force_refund {
+        calculate_force_refund_amounts(
+            committed_bin.payment_token_committed,
+            committed_bin.boosted_committed,
+            committed_bin.window_committed,
+            bin,
+            committed_bin.sale_token_claimed,
+            auction.finalized,
+        )
+    } else {
+        calculate_bin_entitlement(
+            committed_bin.payment_token_committed,
+            committed_bin.boosted_committed,
+            committed_bin.window_committed,
+            bin,
+            auction.is_refund_only(current_time),
+            auction.finalized,
+        )
+    }
+}
+
+/// Check whether a user could commit to a bin now, returning why not and the
+/// relevant limit instead of failing, so wallets can explain a rejection
+///
+/// Whitelist and custody signatures are not evaluated.
+pub fn can_commit(
+    ctx: Context<CanCommit>,
+    user: Pubkey,
+    bin_id: u8,
+    payment_token_committed: u64,
+) -> Result<CommitEligibility> {
+    use CommitDenialReason as Reason;
+
+    let auction = &ctx.accounts.auction;
+    let committed = ctx
+        .accounts
+        .committed
+        .as_ref()
+        .map(|committed| committed.load())
+        .transpose()?;
+    let committed = committed.as_deref();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    if auction
+        .emergency_state
+        .is_paused(EmergencyState::PAUSE_AUCTION_COMMIT)
+    {
+        return Ok(CommitEligibility::denied(Reason::Paused, 0));
+    }
+    if auction.cancelled {
+        return Ok(CommitEligibility::denied(Reason::Cancelled, 0));
+    }
+    if auction.aborted {
+        return Ok(CommitEligibility::denied(Reason::Aborted, 0));
+    }
+    if current_time < auction.commit_start_time {
+        return Ok(CommitEligibility::denied(
+            Reason::NotStarted,
+            auction.commit_start_time as u64,
+        ));
+    }
+    if current_time > auction.commit_end_time {
+        return Ok(CommitEligibility::denied(
+            Reason::Ended,
+            auction.commit_end_time as u64,
+        ));
+    }
+    let Ok(bin) = auction.get_bin(bin_id) else {
+        return Ok(CommitEligibility::denied(
+            Reason::InvalidBin,
+            auction.bins.len() as u64,
+        ));
+    };
+    if check_round_open(auction, bin_id, ctx.accounts.round.as_ref(), current_time).is_err() {
+        return Ok(CommitEligibility::denied(
+            Reason::RoundNotOpen,
+            bin.round as u64,
+        ));
+    }
+    if auction.extensions.is_price_stale(bin, current_time) {
+        return Ok(CommitEligibility::denied(
+            Reason::PriceStale,
+            bin.price_updated_at as u64,
+        ));
+    }
+    if (payment_token_committed == 0) != (bin.kind == BinKind::FreeClaim) {
+        return Ok(CommitEligibility::denied(Reason::InvalidAmount, 0));
+    }
+    if !auction
+        .extensions
+        .is_within_commit_per_tx(payment_token_committed)
+    {
+        return Ok(CommitEligibility::denied(
+            Reason::PerTxLimitExceeded,
+            auction.extensions.max_commit_per_tx.unwrap_or(u64::MAX),
+        ));
+    }
+    if payment_token_committed > 0 && auction.extensions.is_nft_payment_enabled() {
+        return Ok(CommitEligibility::denied(Reason::NftPaymentOnly, 0));
+    }
+    let user_bin = committed.and_then(|committed| committed.find_bin(bin_id));
+    if bin.kind == BinKind::FreeClaim && user_bin.is_some() {
+        return Ok(CommitEligibility::denied(Reason::AlreadyRegistered, 0));
+    }
+    if bin.has_waitlist() {
+        return Ok(CommitEligibility::denied(
+            Reason::WaitlistNotEmpty,
+            bin.waitlist_tail - bin.waitlist_head,
+        ));
+    }
+
+    // Custody commitments bypass the per-user cap
+    if user != auction.custody {
+        let attested_cap_bps = ctx.accounts.cap_attestation.as_ref().map(|a| a.cap_bps);
+        let loyalty_tier = ctx.accounts.user_profile.as_ref().map_or(0, |p| p.tier());
+        if let Some(commit_cap) = auction
+            .extensions
+            .effective_commit_cap(attested_cap_bps, loyalty_tier)
+        {
+            let total_payment_committed =
+                committed.map_or(0, |committed| committed.total_payment_committed());
+            if total_payment_committed.saturating_add(payment_token_committed) > commit_cap {
+                return Ok(CommitEligibility::denied(
+                    Reason::UserCapExceeded,
+                    commit_cap.saturating_sub(total_payment_committed),
+                ));
+            }
+        }
+    }
+
+    if bin.kind == BinKind::FreeClaim {
+        let sale_tokens_reserved = bin
+            .free_claim_registered
+            .saturating_add(1)
+            .saturating_mul(bin.free_claim_amount);
+        if sale_tokens_reserved > bin.sale_token_cap {
+            return Ok(CommitEligibility::denied(Reason::BinCapExceeded, 0));
+        }
+    } else if bin.hard_cap {
+        let bin_target = bin.payment_token_target()?;
+        if bin
+            .payment_token_raised
+            .saturating_add(payment_token_committed)
+            > bin_target
+        {
+            return Ok(CommitEligibility::denied(
+                Reason::BinCapExceeded,
+                bin_target.saturating_sub(bin.payment_token_raised),
+            ));
+        }
+    }
+
+    Ok(CommitEligibility::ALLOWED)
+}
+
+/// Get the cached allocation ratio of a bin, scaled by `PRECISION_FACTOR`
+pub fn get_allocation_ratio(ctx: Context<GetAuctionInfo>, bin_id: u8) -> Result<u64> {
+    let auction = &ctx.accounts.auction;
+
+    // CHECK: ratios are cached at finalization
+    require!(auction.finalized, LauchpadError::AuctionNotFinalized);
+
+    Ok(auction.get_bin(bin_id)?.allocation_ratio)
+}
+
+/// Get an overview of an auction for project-team dashboards in a single call
+pub fn get_project_summary(ctx: Context<GetAuctionInfo>) -> Result<ProjectSummary> {
+    let auction = &ctx.accounts.auction;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let entitlements = if auction.finalized {
+        TotalEntitlements {
+            total_sale_entitled: auction.total_sale_entitled,
+            total_refund_entitled: auction.total_refund_entitled,
+        }
+    } else {
+        calculate_total_entitlements(&auction.bins, auction.is_refund_only(current_time))?
+    };
+    let total_sale_claimed = auction
+        .bins
+        .iter()
+        .map(|bin| bin.sale_token_claimed)
+        .sum::<u64>();
+    let progress_bps = |done: u64, total: u64| match total {
+        0 => 0,
+        _ => (done as u128 * 10000 / total as u128).min(10000) as u16,
+    };
+
+    Ok(ProjectSummary {
+        bins: auction
+            .bins
+            .iter()
+            .map(|bin| BinSummary {
+                payment_token_raised: bin.payment_token_raised,
+                participants: bin.participants,
+                sale_token_claimed: bin.sale_token_claimed,
+                fees_collected: bin.fees_collected,
+            })
+            .collect(),
+        total_payment_raised: auction.total_payment_raised()?,
+        total_participants: auction.total_participants,
+        total_fees_collected: auction.total_fees_collected,
+        total_fees_withdrawn: auction.total_fees_withdrawn,
+        sale_claim_progress_bps: progress_bps(total_sale_claimed, entitlements.total_sale_entitled),
+        refund_progress_bps: progress_bps(
+            auction.total_payment_refunded,
+            entitlements.total_refund_entitled,
+        ),
+        finalized: auction.finalized,
+        funds_withdrawn: auction.unsold_sale_tokens_and_effective_payment_tokens_withdrawn,
+        total_payment_withdrawn: auction.total_payment_withdrawn,
+    })
+}
+
+/// Get whether the program upgrade authority is the governance configured in the
+/// launchpad config, for integrators surfacing upgrade risk
+pub fn verify_program_authority(
+    ctx: Context<VerifyProgramAuthority>,
+) -> Result<ProgramAuthorityStatus> {
+    let upgrade_authority = ctx.accounts.program_data.upgrade_authority_address;
+    let governance_authority = ctx.accounts.config.governance_authority;
+
+    Ok(ProgramAuthorityStatus {
+        upgrade_authority,
+        governance_authority,
+        is_governed: upgrade_authority.is_some() && upgrade_authority == governance_authority,
+    })
+}
+
+/// Emit the full current auction state as a re-sync point for indexers
+pub fn register_indexer(ctx: Context<RegisterIndexer>) -> Result<()> {
+    let event = AuctionStateEvent {
+        auction_key: ctx.accounts.auction.key(),
+        slot: Clock::get()?.slot,
+        auction: ctx.accounts.auction.clone().into_inner(),
+    };
+
+    emit_auction_event_cpi(
+        &event,
+        &ctx.accounts.auction.to_account_info(),
+        &ctx.accounts.event_authority.to_account_info(),
+        &ctx.accounts.program.to_account_info(),
+        ctx.bumps.event_authority,
+    )
+}
+
+/// Emit the auction's ledger as a fixed sequence of events: a header, one event per
+/// bin in bin ID order, then the auction-wide refunds, withdrawals and fees
+///
+/// Sold amounts follow the current entitlements, nothing is sold by refund-only
+/// auctions. Forced refunds are flagged in the header, their refunds and releases
+/// are in the totals.
+pub fn export_accounting(ctx: Context<ExportAccounting>) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    let auction_key = auction.key();
+    let clock = Clock::get()?;
+    let refund_only = auction.is_refund_only(clock.unix_timestamp);
+
+    emit!(AccountingHeaderEvent {
+        auction: auction_key,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+        sale_token_mint: auction.sale_token_mint,
+        payment_token_mint: auction.payment_token_mint,
+        bin_count: auction.bins.len() as u8,
+        finalized: auction.finalized,
+        force_refund: auction.force_refund,
+        refund_only,
+    });
+
+    for (bin_id, bin) in auction.bins.iter().enumerate() {
+        let (sale_tokens_sold, effective_payment_tokens) = if refund_only {
+            (0, 0)
+        } else {
+            let bin_amounts = calculate_bin_kind_withdraw_amounts(bin)?;
+            (
+                bin_amounts.sale_tokens_sold,
+                bin_amounts.payment_tokens_to_withdraw,
+            )
+        };
+        emit!(BinAccountingEvent {
+            auction: auction_key,
+            bin_id: bin_id as u8,
+            kind: bin.kind,
+            sale_token_deposited: bin.sale_token_cap,
+            payment_token_deposited: bin.payment_token_raised,
+            sale_tokens_sold,
+            effective_payment_tokens,
+            refund_entitled: bin
+                .payment_token_raised
+                .saturating_sub(effective_payment_tokens),
+            sale_token_claimed: bin.sale_token_claimed,
+            fees_collected: bin.fees_collected,
+        });
+    }
+
+    emit!(AccountingTotalsEvent {
+        auction: auction_key,
+        total_payment_refunded: auction.total_payment_refunded,
+        total_refund_diverted: auction.total_refund_diverted,
+        total_payment_released: auction.total_payment_released,
+        total_payment_withdrawn: auction.total_payment_withdrawn,
+        total_pool_withdrawn: auction.total_pool_withdrawn,
+        total_sale_swept: auction.total_sale_swept,
+        total_payment_swept: auction.total_payment_swept,
+        unsold_sale_tokens_withdrawn: auction
+            .unsold_sale_tokens_and_effective_payment_tokens_withdrawn,
+        total_fees_collected: auction.total_fees_collected,
+        total_fees_withdrawn: auction.total_fees_withdrawn,
+        total_rent_funded: auction.total_rent_funded,
+        security_deposit_held: auction.security_deposit_held,
+        security_deposit_slashed: auction.security_deposit_slashed,
+    });
+
+    Ok(())
+}
+
+/// Authority closes a settled auction, reclaiming the rent of the auction account and
+/// its vaults after archiving it
+///
+/// The auction must be finalized, its funds and fees withdrawn and every claim and
+/// refund settled. An `AuctionArchivedEvent` with the hash of the final account data
+/// and the key totals is emitted through the event authority first, so historical
+/// queries remain possible once the accounts are gone. Rounding dust left in the
+/// vaults is swept to the authority's token accounts before they are closed.
+pub fn close_auction(ctx: Context<CloseAuction>) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+
+    // CHECK: nothing is owed to users or the authority anymore
+    require!(
+        auction.finalized && auction.unsold_sale_tokens_and_effective_payment_tokens_withdrawn,
+        LauchpadError::AuctionNotSettled
+    );
+    require!(
+        vault_liabilities(auction)? == (0, 0),
+        LauchpadError::AuctionNotSettled
+    );
+
+    let clock = Clock::get()?;
+    let state_hash = hash(&auction.to_account_info().try_borrow_data()?).to_bytes();
+    let event = AuctionArchivedEvent {
+        auction: auction.key(),
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+        state_hash,
+        sale_token_mint: auction.sale_token_mint,
+        payment_token_mint: auction.payment_token_mint,
+        total_participants: auction.total_participants,
+        total_payment_raised: auction.total_payment_raised()?,
+        total_sale_entitled: auction.total_sale_entitled,
+        total_refund_entitled: auction.total_refund_entitled,
+        total_payment_withdrawn: auction.total_payment_withdrawn,
+        total_fees_collected: auction.total_fees_collected,
+    };
+    emit_auction_event_cpi(
+        &event,
+        &auction.to_account_info(),
+        &ctx.accounts.event_authority.to_account_info(),
+        &ctx.accounts.program.to_account_info(),
+        ctx.bumps.event_authority,
+    )?;
+
+    // Sweep the dust and close the vaults, the auction account is closed on exit
+    let auction_key = auction.key();
+    for (vault, recipient, seed, bump) in [
+        (
+            &ctx.accounts.vault_sale_token,
+            &ctx.accounts.sale_token_recipient,
+            VAULT_SALE_SEED,
+            auction.vault_sale_bump,
+        ),
+        (
+            &ctx.accounts.vault_payment_token,
+            &ctx.accounts.payment_token_recipient,
+            VAULT_PAYMENT_SEED,
+            auction.vault_payment_bump,
+        ),
+    ] {
+        if vault.amount > 0 {
+            // CHECK: dust needs an account of the authority to go to
+            let recipient = recipient
+                .as_ref()
+                .ok_or(LauchpadError::DustRecipientRequired)?;
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: vault.to_account_info(),
+                        to: recipient.to_account_info(),
+                        authority: vault.to_account_info(),
+                    },
+                    &[&[seed, auction_key.as_ref(), &[bump]]],
+                ),
+                vault.amount,
+            )?;
+        }
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: vault.to_account_info(),
+                destination: ctx.accounts.authority.to_account_info(),
+                authority: vault.to_account_info(),
+            },
+            &[&[seed, auction_key.as_ref(), &[bump]]],
+        ))?;
+    }
+
+    msg!(
+        "Auction {} archived and closed, state hash {:?}",
+        auction_key,
+        state_hash
+    );
+    Ok(())
+}
+
+/// Target of event self-CPIs, carries the serialized event in its instruction data
+pub fn log_event(_ctx: Context<LogEvent>, _event_data: Vec<u8>) -> Result<()> {
+    Ok(())
+}
+
+/// Emit an event through a self-CPI signed by the auction's event authority, so
+/// indexers can read it from inner instructions instead of truncatable logs
+fn emit_auction_event_cpi<'info, E: anchor_lang::Event>(
+    event: &E,
+    auction: &AccountInfo<'info>,
+    event_authority: &AccountInfo<'info>,
+    program: &AccountInfo<'info>,
+    event_authority_bump: u8,
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(event_authority.key(), true),
+            AccountMeta::new_readonly(auction.key(), false),
+        ],
+        data: anchor_lang::InstructionData::data(&crate::instruction::LogEvent {
+            event_data: anchor_lang::Event::data(event),
+        }),
+    };
+
+    let auction_key = auction.key();
+    let event_authority_seeds = &[
+        EVENT_AUTHORITY_SEED,
+        auction_key.as_ref(),
+        &[event_authority_bump],
+    ];
+    invoke_signed(
+        &ix,
+        &[event_authority.clone(), auction.clone(), program.clone()],
+        &[event_authority_seeds],
+    )?;
+
+    Ok(())
+}
+
+/// Get the LaunchpadAdmin public key from the global config
+pub fn get_launchpad_admin(ctx: Context<GetLaunchpadAdmin>) -> Result<Pubkey> {
+    Ok(ctx.accounts.global_config.admin)
+}
+
+/// Full auction state, emitted by `register_indexer` through the event authority
+#[event]
+pub struct AuctionStateEvent {
+    pub auction_key: Pubkey,
+    pub slot: u64,
+    pub auction: Auction,
+}
+
+/// Emergency control event
+#[event]
+pub struct EmergencyControlEvent {
     pub auction: Pubkey,
     pub authority: Pubkey,
     pub paused_operations: u64,
+    pub reason_code: u16,
+    pub message: String,
+}
+
+/// Buy-and-burn proceeds event
+#[event]
+pub struct ProceedsBurnedEvent {
+    pub auction: Pubkey,
+    pub payment_tokens_burned: u64,
+}
+
+/// Forced refund mode event
+#[event]
+pub struct ForceRefundModeEvent {
+    pub auction: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+    pub security_deposit_slashed: u64,
+}
+
+/// Auction cancellation event
+#[event]
+pub struct AuctionCancelledEvent {
+    pub auction: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+    pub sale_token_recipient: Pubkey,
+    pub sale_tokens_returned: u64,
+}
+
+/// Auction abort event
+#[event]
+pub struct AuctionAbortedEvent {
+    pub auction: Pubkey,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+    pub total_payment_raised: u64,
+}
+
+/// Auction times update event
+#[event]
+pub struct TimesUpdatedEvent {
+    pub auction: Pubkey,
+    pub authority: Pubkey,
+    pub previous_commit_start_time: i64,
+    pub previous_commit_end_time: i64,
+    pub previous_claim_start_time: i64,
+    pub commit_start_time: i64,
+    pub commit_end_time: i64,
+    pub claim_start_time: i64,
+}
+
+/// Fee reconciliation event
+#[event]
+pub struct FeesReconciledEvent {
+    pub auction: Pubkey,
+    pub previous_total_fees_collected: u64,
+    pub total_fees_collected: u64,
+    pub bins_repaired: u8,
+    pub sale_vault_shortfall: u64,
+}
+
+/// Surplus recovery event
+#[event]
+pub struct SurplusRecoveredEvent {
+    pub auction: Pubkey,
+    pub sale_token_liabilities: u64,
+    pub sale_token_surplus: u64,
+    pub payment_token_liabilities: u64,
+    pub payment_token_surplus: u64,
+    pub redemption_token_surplus: u64,
+}
+
+/// Unclaimed tokens swept after the claim deadline
+#[event]
+pub struct UnclaimedSweptEvent {
+    pub auction: Pubkey,
+    pub sale_tokens_swept: u64,
+    pub payment_tokens_swept: u64,
+}
+
+/// First event of `export_accounting`
+#[event]
+pub struct AccountingHeaderEvent {
+    pub auction: Pubkey,
+    pub slot: u64,
+    pub timestamp: i64,
+    pub sale_token_mint: Pubkey,
+    pub payment_token_mint: Pubkey,
+    /// Number of `BinAccountingEvent`s following
+    pub bin_count: u8,
+    pub finalized: bool,
+    pub force_refund: bool,
+    pub refund_only: bool,
+}
+
+/// Ledger of one bin, emitted by `export_accounting` in bin ID order
+#[event]
+pub struct BinAccountingEvent {
+    pub auction: Pubkey,
+    pub bin_id: u8,
+    pub kind: BinKind,
+    /// Sale tokens deposited into the bin
+    pub sale_token_deposited: u64,
+    /// Payment tokens committed to the bin
+    pub payment_token_deposited: u64,
+    pub sale_tokens_sold: u64,
+    /// Payment tokens paying for the sold sale tokens, or donated
+    pub effective_payment_tokens: u64,
+    /// Payment tokens owed back to the bin's participants
+    pub refund_entitled: u64,
+    pub sale_token_claimed: u64,
+    pub fees_collected: u64,
+}
+
+/// Auction-wide refunds, withdrawals and fees, last event of `export_accounting`
+#[event]
+pub struct AccountingTotalsEvent {
+    pub auction: Pubkey,
+    pub total_payment_refunded: u64,
+    pub total_refund_diverted: u64,
+    pub total_payment_released: u64,
+    pub total_payment_withdrawn: u64,
+    pub total_pool_withdrawn: u64,
+    pub total_sale_swept: u64,
+    pub total_payment_swept: u64,
+    pub unsold_sale_tokens_withdrawn: bool,
+    pub total_fees_collected: u64,
+    pub total_fees_withdrawn: u64,
+    pub total_rent_funded: u64,
+    pub security_deposit_held: u64,
+    pub security_deposit_slashed: u64,
+}
+
+/// Sale token top-up event
+#[event]
+pub struct SaleTokensToppedUpEvent {
+    pub auction: Pubkey,
+    pub bin_id: u8,
+    pub amount: u64,
+    pub sale_token_cap: u64,
+    /// Allocation ratio of the bin with its current commitments, scaled by `PRECISION_FACTOR`
+    pub allocation_ratio: u64,
+}
+
+/// Claim voucher honored by the voucher partner, emitted through the event authority
+#[event]
+pub struct ClaimVoucherEvent {
+    pub auction: Pubkey,
+    pub user: Pubkey,
+    pub partner: Pubkey,
+    pub bin_id: u8,
+    /// Index of the voucher among the user's vouchers of this auction
+    pub nonce: u64,
+    /// Entitlement consumed by the voucher
+    pub sale_token_amount: u64,
+    /// Sale tokens moved to the partner, net of the claim fee
+    pub partner_sale_tokens: u64,
+    pub claim_fee: u64,
+}
+
+/// Archival record of a closed auction, emitted through the event authority
+#[event]
+pub struct AuctionArchivedEvent {
+    pub auction: Pubkey,
+    pub slot: u64,
+    pub timestamp: i64,
+    /// SHA-256 of the auction account data when it was closed
+    pub state_hash: [u8; 32],
+    pub sale_token_mint: Pubkey,
+    pub payment_token_mint: Pubkey,
+    pub total_participants: u64,
+    pub total_payment_raised: u64,
+    pub total_sale_entitled: u64,
+    pub total_refund_entitled: u64,
+    pub total_payment_withdrawn: u64,
+    pub total_fees_collected: u64,
+}
+
+/// Commitment refused at a stale bin price
+#[event]
+pub struct PriceStaleEvent {
+    pub auction: Pubkey,
+    pub bin_id: u8,
+    pub sale_token_price: u64,
+    pub price_updated_at: i64,
+    pub timestamp: i64,
+}
+
+// Context structures
+
+#[derive(Accounts)]
+#[instruction(
+    commit_start_time: i64,
+    commit_end_time: i64,
+    claim_start_time: i64,
+    bins: Vec<AuctionBinParams>,
+)]
+pub struct InitAuction<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [GLOBAL_CONFIG_SEED], bump = global_config.bump)]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Auction::space_for_bins(bins.len()),
+        seeds = [AUCTION_SEED, sale_token_mint.key().as_ref()],
+        bump, // unique seeds and bump to ensure auction is only initialized once
+    )]
+    pub auction: Account<'info, Auction>,
+
+    pub sale_token_mint: Account<'info, Mint>,
+    pub payment_token_mint: Account<'info, Mint>,
+
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    /// Sale token seller's account (source for initial vault funding)
+    #[account(
+        mut,
+        constraint = sale_token_seller.mint == sale_token_mint.key()
+    )]
+    pub sale_token_seller: Account<'info, TokenAccount>,
+
+    /// Authority of the sale token seller account
+    #[account(mut)]
+    pub sale_token_seller_authority: Signer<'info>,
+
+    /// Vault to hold sale tokens (created as PDA)
+    #[account(
+        init,
+        payer = authority,
+        token::mint = sale_token_mint,
+        token::authority = vault_sale_token,
+        seeds = [VAULT_SALE_SEED, auction.key().as_ref()],
+        bump
+    )]
+    pub vault_sale_token: Account<'info, TokenAccount>,
+
+    /// Vault to hold payment tokens (created as PDA)
+    #[account(
+        init,
+        payer = authority,
+        token::mint = payment_token_mint,
+        token::authority = vault_payment_token,
+        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
+        bump
+    )]
+    pub vault_payment_token: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloneAuction<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [GLOBAL_CONFIG_SEED], bump = global_config.bump)]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub template: Account<'info, Template>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Auction::space_for_bins(template.bins.len()),
+        seeds = [AUCTION_SEED, sale_token_mint.key().as_ref()],
+        bump, // unique seeds and bump to ensure auction is only initialized once
+    )]
+    pub auction: Account<'info, Auction>,
+
+    pub sale_token_mint: Account<'info, Mint>,
+    pub payment_token_mint: Account<'info, Mint>,
+
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    /// Sale token seller's account (source for initial vault funding)
+    #[account(
+        mut,
+        constraint = sale_token_seller.mint == sale_token_mint.key()
+    )]
+    pub sale_token_seller: Account<'info, TokenAccount>,
+
+    /// Authority of the sale token seller account
+    #[account(mut)]
+    pub sale_token_seller_authority: Signer<'info>,
+
+    /// Vault to hold sale tokens (created as PDA)
+    #[account(
+        init,
+        payer = authority,
+        token::mint = sale_token_mint,
+        token::authority = vault_sale_token,
+        seeds = [VAULT_SALE_SEED, auction.key().as_ref()],
+        bump
+    )]
+    pub vault_sale_token: Account<'info, TokenAccount>,
+
+    /// Vault to hold payment tokens (created as PDA)
+    #[account(
+        init,
+        payer = authority,
+        token::mint = payment_token_mint,
+        token::authority = vault_payment_token,
+        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
+        bump
+    )]
+    pub vault_payment_token: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = GlobalConfig::SPACE,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+        has_one = admin @ LauchpadError::OnlyLaunchpadAdmin
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+#[derive(Accounts)]
+pub struct TransferAuctionAuthority<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+        has_one = admin @ LauchpadError::OnlyLaunchpadAdmin
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
+}
+
+#[derive(Accounts)]
+pub struct SetLaunchpadConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [GLOBAL_CONFIG_SEED], bump = global_config.bump)]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = LaunchpadConfig::SPACE,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetSaleMintBlocked<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [GLOBAL_CONFIG_SEED], bump = global_config.bump)]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+}
+
+#[derive(Accounts)]
+pub struct SetRevenueShare<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [GLOBAL_CONFIG_SEED], bump = global_config.bump)]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+}
+
+/// Shared by `set_frontend_registered` and `set_frontend_fee_share`
+#[derive(Accounts)]
+pub struct UpdateFrontends<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [GLOBAL_CONFIG_SEED], bump = global_config.bump)]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+}
+
+#[derive(Accounts)]
+pub struct SetGlobalPause<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [GLOBAL_CONFIG_SEED], bump = global_config.bump)]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(template_id: u64, bins: Vec<AuctionBinParams>)]
+pub struct CreateTemplate<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [GLOBAL_CONFIG_SEED], bump = global_config.bump)]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Template::space_for_bins(bins.len()),
+        seeds = [TEMPLATE_SEED, template_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub template: Account<'info, Template>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(bin_id: u8, payment_token_committed: u64, expiry: u64)]
+pub struct Commit<'info> {
+    /// Wallet or program PDA signing through a CPI (unless the auction is EOA-only),
+    /// paying for the `Committed` account: a PDA must be a system account without data
+    /// holding the rent. wSOL commitments wrap any shortfall from its lamports
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = auction.extensions.is_eoa_participant(user.owner, get_stack_height())
+            @ LauchpadError::ContractParticipantForbidden
+    )]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [COMMITTED_SEED, auction.key().as_ref(), user.key().as_ref()],
+        bump,
+        space = Committed::SPACE
+    )]
+    pub committed: AccountLoader<'info, Committed>,
+
+    #[account(
+        mut,
+        constraint = user_payment_token.mint == auction.payment_token_mint,
+        constraint = user_payment_token.owner == user.key()
+    )]
+    pub user_payment_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
+        bump = auction.vault_payment_bump
+    )]
+    pub vault_payment_token: Account<'info, TokenAccount>,
+
+    /// CHECK: 白名单授权公钥，仅用于比较（只有启用白名单时才需要）
+    pub whitelist_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Custody authorization account (only needed when custody authorization is used)
+    pub custody_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: sysvar instructions（只有启用白名单时才需要）
+    pub sysvar_instructions: Option<UncheckedAccount<'info>>,
+
+    /// User's wallet-quality attestation (only needed when commit cap scaling is enabled)
+    #[account(
+        seeds = [CAP_ATTESTATION_SEED, auction.key().as_ref(), user.key().as_ref()],
+        bump = cap_attestation.bump
+    )]
+    pub cap_attestation: Option<Account<'info, CommitCapAttestation>>,
+
+    /// User's loyalty profile (only needed when loyalty cap multipliers are enabled)
+    #[account(
+        seeds = [USER_PROFILE_SEED, user.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    /// Round of the bin (only needed for auctions with rounds)
+    pub round: Option<Account<'info, Round>>,
+
+    /// Identity group of the user's wallet (only needed when the whitelist signature names one)
+    #[account(
+        mut,
+        seeds = [IDENTITY_GROUP_SEED, auction.key().as_ref(), identity_group.group_id.as_ref()],
+        bump = identity_group.bump
+    )]
+    pub identity_group: Option<Account<'info, IdentityGroup>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+
+    /// Fee balance of the front-end committed through (optional), set up by the
+    /// front-end with `init_frontend_fees`
+    #[account(
+        seeds = [FRONTEND_FEES_SEED, auction.key().as_ref(), frontend_fees.frontend.as_ref()],
+        bump = frontend_fees.bump
+    )]
+    pub frontend_fees: Option<Account<'info, FrontendFees>>,
+}
+
+#[derive(Accounts)]
+#[instruction(group_id: [u8; 32])]
+pub struct InitIdentityGroup<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = IdentityGroup::SPACE,
+        seeds = [IDENTITY_GROUP_SEED, auction.key().as_ref(), group_id.as_ref()],
+        bump
+    )]
+    pub identity_group: Account<'info, IdentityGroup>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct RevokeWhitelistSignatures<'info> {
+    #[account(mut)]
+    pub whitelist_authority: Signer<'info>,
+
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        init_if_needed,
+        payer = whitelist_authority,
+        seeds = [COMMITTED_SEED, auction.key().as_ref(), user.as_ref()],
+        bump,
+        space = Committed::SPACE
+    )]
+    pub committed: AccountLoader<'info, Committed>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterLut<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(has_one = authority @ LauchpadError::Unauthorized)]
+    pub auction: Account<'info, Auction>,
+
+    /// CHECK: lookup table, created or extended by the lookup table program
+    #[account(mut)]
+    pub lookup_table: UncheckedAccount<'info>,
+
+    /// CHECK: address lookup table program
+    #[account(address = ADDRESS_LOOKUP_TABLE_PROGRAM_ID)]
+    pub address_lookup_table_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelAuction<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(mut, has_one = authority @ LauchpadError::Unauthorized)]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SALE_SEED, auction.key().as_ref()],
+        bump = auction.vault_sale_bump
+    )]
+    pub vault_sale_token: Account<'info, TokenAccount>,
+
+    /// Seller-designated account receiving the sale tokens
+    #[account(
+        mut,
+        constraint = sale_token_recipient.mint == auction.sale_token_mint
+    )]
+    pub sale_token_recipient: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct AbortAuction<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(seeds = [GLOBAL_CONFIG_SEED], bump = global_config.bump)]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
+
+    /// Settlement published by `finalize_auction` (required once finalized)
+    #[account(
+        mut,
+        seeds = [AUCTION_RESULT_SEED, auction.key().as_ref()],
+        bump = auction_result.bump
+    )]
+    pub auction_result: Option<Account<'info, AuctionResult>>,
+
+    /// Launchpad-wide stats the finalized auction was recorded in (required once finalized)
+    #[account(mut, seeds = [STATS_SEED], bump = stats.bump)]
+    pub stats: Option<Account<'info, LaunchpadStats>>,
+}
+
+#[derive(Accounts)]
+pub struct ForceRefundMode<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(mut, has_one = authority @ LauchpadError::Unauthorized)]
+    pub auction: Account<'info, Auction>,
+
+    /// CHECK: receives the slashed security deposit, checked against the configured
+    /// treasury (only needed when the deposit has a treasury)
+    #[account(mut)]
+    pub security_deposit_treasury: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct CustodyCommitBatch<'info> {
+    /// Auction custody, pays the commitments and the rent of new `Committed` accounts
+    #[account(mut)]
+    pub custody: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = auction.custody == custody.key() @ LauchpadError::InvalidCustodyAuthority
+    )]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    #[account(
+        mut,
+        constraint = custody_payment_token.mint == auction.payment_token_mint,
+        constraint = custody_payment_token.owner == custody.key()
+    )]
+    pub custody_payment_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
+        bump = auction.vault_payment_bump
+    )]
+    pub vault_payment_token: Account<'info, TokenAccount>,
+
+    /// Round of the bin (only needed for auctions with rounds)
+    pub round: Option<Account<'info, Round>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimToCustody<'info> {
+    /// Auction custody, receives the claimed tokens
+    #[account(mut)]
+    pub custody: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = auction.custody == custody.key() @ LauchpadError::InvalidCustodyAuthority
+    )]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, blocking claims of exploited sale mints
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    #[account(address = auction.sale_token_mint)]
+    pub sale_token_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = custody,
+        associated_token::mint = sale_token_mint,
+        associated_token::authority = custody
+    )]
+    pub custody_sale_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = custody_payment_token.mint == auction.payment_token_mint,
+        constraint = custody_payment_token.owner == custody.key()
+    )]
+    pub custody_payment_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SALE_SEED, auction.key().as_ref()],
+        bump = auction.vault_sale_bump
+    )]
+    pub vault_sale_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
+        bump = auction.vault_payment_bump
+    )]
+    pub vault_payment_token: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DecreaseCommit<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    #[account(mut, has_one = user)]
+    pub committed: AccountLoader<'info, Committed>,
+
+    #[account(mut)]
+    pub user_payment_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
+        bump = auction.vault_payment_bump
+    )]
+    pub vault_payment_token: Account<'info, TokenAccount>,
+
+    /// Round of the bin (only needed for auctions with rounds)
+    pub round: Option<Account<'info, Round>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct BoostCommitment<'info> {
+    pub user: Signer<'info>,
+
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    #[account(
+        mut,
+        has_one = user,
+        seeds = [COMMITTED_SEED, auction.key().as_ref(), user.key().as_ref()],
+        bump = committed.load()?.bump
+    )]
+    pub committed: AccountLoader<'info, Committed>,
+
+    /// Round of the bin (only needed for auctions with rounds)
+    pub round: Option<Account<'info, Round>>,
+}
+
+#[derive(Accounts)]
+pub struct CommitNft<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = auction.extensions.is_eoa_participant(user.owner, get_stack_height())
+            @ LauchpadError::ContractParticipantForbidden
+    )]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [COMMITTED_SEED, auction.key().as_ref(), user.key().as_ref()],
+        bump,
+        space = Committed::SPACE
+    )]
+    pub committed: AccountLoader<'info, Committed>,
+
+    pub nft_mint: Account<'info, Mint>,
+
+    /// CHECK: Metaplex metadata of the NFT, verified by `verify_nft_collection`
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = user_nft_token.mint == nft_mint.key(),
+        constraint = user_nft_token.owner == user.key()
+    )]
+    pub user_nft_token: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = user,
+        token::mint = nft_mint,
+        token::authority = vault_nft_token,
+        seeds = [VAULT_NFT_SEED, auction.key().as_ref(), nft_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_nft_token: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = user,
+        space = NftEscrow::SPACE,
+        seeds = [NFT_ESCROW_SEED, auction.key().as_ref(), nft_mint.key().as_ref()],
+        bump
+    )]
+    pub nft_escrow: Account<'info, NftEscrow>,
+
+    /// CHECK: sysvar instructions (only needed when the whitelist is enabled)
+    pub sysvar_instructions: Option<UncheckedAccount<'info>>,
+
+    /// User's wallet-quality attestation (only needed when commit cap scaling is enabled)
+    #[account(
+        seeds = [CAP_ATTESTATION_SEED, auction.key().as_ref(), user.key().as_ref()],
+        bump = cap_attestation.bump
+    )]
+    pub cap_attestation: Option<Account<'info, CommitCapAttestation>>,
+
+    /// User's loyalty profile (only needed when loyalty cap multipliers are enabled)
+    #[account(
+        seeds = [USER_PROFILE_SEED, user.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    /// Round of the bin (only needed for auctions with rounds)
+    pub round: Option<Account<'info, Round>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(bin_id: u8, sequence: u64)]
+pub struct JoinWaitlist<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = auction.extensions.is_eoa_participant(user.owner, get_stack_height())
+            @ LauchpadError::ContractParticipantForbidden
+    )]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [COMMITTED_SEED, auction.key().as_ref(), user.key().as_ref()],
+        bump,
+        space = Committed::SPACE
+    )]
+    pub committed: AccountLoader<'info, Committed>,
+
+    #[account(
+        init,
+        payer = user,
+        space = WaitlistEntry::SPACE,
+        seeds = [WAITLIST_SEED, auction.key().as_ref(), &[bin_id], &sequence.to_le_bytes()],
+        bump
+    )]
+    pub waitlist_entry: Account<'info, WaitlistEntry>,
+
+    #[account(
+        mut,
+        constraint = user_payment_token.mint == auction.payment_token_mint,
+        constraint = user_payment_token.owner == user.key()
+    )]
+    pub user_payment_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
+        bump = auction.vault_payment_bump
+    )]
+    pub vault_payment_token: Account<'info, TokenAccount>,
+
+    /// CHECK: sysvar instructions (only needed when the whitelist is enabled)
+    pub sysvar_instructions: Option<UncheckedAccount<'info>>,
+
+    /// User's wallet-quality attestation (only needed when commit cap scaling is enabled)
+    #[account(
+        seeds = [CAP_ATTESTATION_SEED, auction.key().as_ref(), user.key().as_ref()],
+        bump = cap_attestation.bump
+    )]
+    pub cap_attestation: Option<Account<'info, CommitCapAttestation>>,
+
+    /// User's loyalty profile (only needed when loyalty cap multipliers are enabled)
+    #[account(
+        seeds = [USER_PROFILE_SEED, user.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    /// Round of the bin (only needed for auctions with rounds)
+    pub round: Option<Account<'info, Round>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct LeaveWaitlist<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        close = user,
+        has_one = user,
+        seeds = [
+            WAITLIST_SEED,
+            auction.key().as_ref(),
+            &[waitlist_entry.bin_id],
+            &waitlist_entry.sequence.to_le_bytes()
+        ],
+        bump = waitlist_entry.bump
+    )]
+    pub waitlist_entry: Account<'info, WaitlistEntry>,
+
+    /// User's commitments, tracking the escrow against the commit cap (only omitted
+    /// once claims closed the account)
+    #[account(
+        mut,
+        seeds = [COMMITTED_SEED, auction.key().as_ref(), user.key().as_ref()],
+        bump = committed.load()?.bump
+    )]
+    pub committed: Option<AccountLoader<'info, Committed>>,
+
+    #[account(
+        mut,
+        constraint = user_payment_token.mint == auction.payment_token_mint,
+        constraint = user_payment_token.owner == user.key()
+    )]
+    pub user_payment_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
+        bump = auction.vault_payment_bump
+    )]
+    pub vault_payment_token: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ProcessWaitlist<'info> {
+    /// Permissionless caller, paid the keeper bounty (if enabled)
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+}
+
+#[derive(Accounts)]
+pub struct AttestCommitCap<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = CommitCapAttestation::SPACE,
+        seeds = [CAP_ATTESTATION_SEED, auction.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub cap_attestation: Account<'info, CommitCapAttestation>,
+
+    /// CHECK: sysvar instructions, holding the Ed25519 attestation signature
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub sysvar_instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReturnNft<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    #[account(
+        mut,
+        has_one = user,
+        seeds = [COMMITTED_SEED, auction.key().as_ref(), user.key().as_ref()],
+        bump = committed.load()?.bump
+    )]
+    pub committed: AccountLoader<'info, Committed>,
+
+    #[account(
+        mut,
+        close = user,
+        has_one = user,
+        seeds = [NFT_ESCROW_SEED, auction.key().as_ref(), nft_escrow.nft_mint.as_ref()],
+        bump = nft_escrow.bump
+    )]
+    pub nft_escrow: Account<'info, NftEscrow>,
+
+    #[account(
+        mut,
+        constraint = user_nft_token.mint == nft_escrow.nft_mint,
+        constraint = user_nft_token.owner == user.key()
+    )]
+    pub user_nft_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_NFT_SEED, auction.key().as_ref(), nft_escrow.nft_mint.as_ref()],
+        bump = nft_escrow.vault_bump
+    )]
+    pub vault_nft_token: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CollectNft<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(has_one = authority)]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    /// CHECK: User who escrowed the NFT, receives the escrow and vault rent
+    #[account(mut, address = nft_escrow.user)]
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [NFT_ESCROW_SEED, auction.key().as_ref(), nft_escrow.nft_mint.as_ref()],
+        bump = nft_escrow.bump
+    )]
+    pub nft_escrow: Account<'info, NftEscrow>,
+
+    #[account(address = nft_escrow.nft_mint)]
+    pub nft_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_NFT_SEED, auction.key().as_ref(), nft_escrow.nft_mint.as_ref()],
+        bump = nft_escrow.vault_bump
+    )]
+    pub vault_nft_token: Account<'info, TokenAccount>,
+
+    /// NFT recipient account (will be created if needed)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = nft_mint,
+        associated_token::authority = authority
+    )]
+    pub nft_recipient: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimAcrossAuctions<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    /// Wallet or program PDA signing through a CPI, owner of the sale token ATA created
+    /// here (off-curve owners are supported) and receiving the `Committed` rent
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, blocking claims of exploited sale mints
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    #[account(mut)]
+    pub committed: AccountLoader<'info, Committed>,
+
+    /// Sale token mint
+    pub sale_token_mint: Account<'info, Mint>,
+
+    /// CHECK: User's sale token account, created by the handler if needed
+    #[account(
+        mut,
+        address = get_associated_token_address(&user.key(), &sale_token_mint.key())
+    )]
+    pub user_sale_token: UncheckedAccount<'info>,
+
+    /// User's payment token account for refunds
+    #[account(
+        mut,
+        constraint = user_payment_token.mint == auction.payment_token_mint,
+        constraint = user_payment_token.owner == user.key()
+    )]
+    pub user_payment_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SALE_SEED, auction.key().as_ref()],
+        bump = auction.vault_sale_bump
+    )]
+    pub vault_sale_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
+        bump = auction.vault_payment_bump
+    )]
+    pub vault_payment_token: Account<'info, TokenAccount>,
+
+    /// User's redemption ledger, required in IOU mode, otherwise recording the claimed
+    /// sale tokens as placeholders the user can swap once a redemption mint is set
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = Redemption::SPACE,
+        seeds = [REDEMPTION_SEED, auction.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub redemption: Option<Account<'info, Redemption>>,
+
+    /// User's loyalty profile, updated once the commitment is fully claimed (optional)
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserProfile::SPACE,
+        seeds = [USER_PROFILE_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    /// Auction lamport treasury, pays for the user's sale token account (optional)
+    #[account(
+        mut,
+        seeds = [LAMPORT_TREASURY_SEED, auction.key().as_ref()],
+        bump
+    )]
+    pub lamport_treasury: Option<SystemAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// Fee balance of the front-end committed through (only needed for such commitments)
+    #[account(
+        mut,
+        seeds = [FRONTEND_FEES_SEED, auction.key().as_ref(), committed.load()?.frontend_id.as_ref()],
+        bump = frontend_fees.bump
+    )]
+    pub frontend_fees: Option<Account<'info, FrontendFees>>,
+}
+
+#[derive(Accounts)]
+pub struct IssueClaimVoucher<'info> {
+    pub user: Signer<'info>,
+
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, blocking claims of exploited sale mints
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    #[account(
+        mut,
+        seeds = [COMMITTED_SEED, auction.key().as_ref(), user.key().as_ref()],
+        bump = committed.load()?.bump
+    )]
+    pub committed: AccountLoader<'info, Committed>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SALE_SEED, auction.key().as_ref()],
+        bump = auction.vault_sale_bump
+    )]
+    pub vault_sale_token: Account<'info, TokenAccount>,
+
+    /// Sale token account of the voucher partner
+    #[account(
+        mut,
+        constraint = partner_sale_token.mint == auction.sale_token_mint,
+        constraint = Some(partner_sale_token.owner) == auction.extensions.voucher_partner
+            @ LauchpadError::VouchersDisabled
+    )]
+    pub partner_sale_token: Account<'info, TokenAccount>,
+
+    /// CHECK: per-auction event authority PDA, only signs event self-CPIs
+    #[account(
+        seeds = [EVENT_AUTHORITY_SEED, auction.key().as_ref()],
+        bump
+    )]
+    pub event_authority: UncheckedAccount<'info>,
+
+    pub program: Program<'info, crate::program::LaunchpadProgram>,
+    pub token_program: Program<'info, Token>,
+
+    /// Fee balance of the front-end committed through (only needed for such commitments)
+    #[account(
+        mut,
+        seeds = [FRONTEND_FEES_SEED, auction.key().as_ref(), committed.load()?.frontend_id.as_ref()],
+        bump = frontend_fees.bump
+    )]
+    pub frontend_fees: Option<Account<'info, FrontendFees>>,
+}
+
+#[derive(Accounts)]
+#[instruction(index: u8)]
+pub struct InitRound<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(has_one = authority)]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Round::SPACE,
+        seeds = [ROUND_SEED, auction.key().as_ref(), &[index]],
+        bump
+    )]
+    pub round: Account<'info, Round>,
+
+    /// Previous round (only needed for rounds after the first)
+    #[account(
+        seeds = [ROUND_SEED, auction.key().as_ref(), &[previous_round.index]],
+        bump = previous_round.bump
+    )]
+    pub previous_round: Option<Account<'info, Round>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseRound<'info> {
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        seeds = [ROUND_SEED, auction.key().as_ref(), &[round.index]],
+        bump = round.bump
+    )]
+    pub round: Account<'info, Round>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFunds<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = auction.role_holder(AuctionRole::FundWithdrawer) == authority.key()
+            @ LauchpadError::Unauthorized
+    )]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, blocking withdrawals of exploited sale mints
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    /// Global config, holding the protocol fee
+    #[account(seeds = [GLOBAL_CONFIG_SEED], bump = global_config.bump)]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Sale token mint
+    pub sale_token_mint: Account<'info, Mint>,
+
+    /// Payment token mint, burned from for buy-and-burn launches
+    #[account(mut, address = auction.payment_token_mint)]
+    pub payment_token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SALE_SEED, auction.key().as_ref()],
+        bump = auction.vault_sale_bump
+    )]
+    pub vault_sale_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
+        bump = auction.vault_payment_bump
+    )]
+    pub vault_payment_token: Account<'info, TokenAccount>,
+
+    /// CHECK: Owner of the sale token recipient, the configured recipient or the authority
+    #[account(
+        address = auction.extensions.unsold_sale_token_recipient.unwrap_or(auction.authority)
+            @ LauchpadError::InvalidWithdrawRecipient
+    )]
+    pub sale_token_recipient_owner: UncheckedAccount<'info>,
+
+    /// CHECK: Sale token recipient account, created by the handler if needed
+    #[account(
+        mut,
+        address = get_associated_token_address(&sale_token_recipient_owner.key(), &sale_token_mint.key())
+    )]
+    pub sale_token_recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Owner of the payment token recipient, the configured recipient or the authority
+    #[account(
+        address = auction.extensions.proceeds_recipient.unwrap_or(auction.authority)
+            @ LauchpadError::InvalidWithdrawRecipient
+    )]
+    pub payment_token_recipient_owner: UncheckedAccount<'info>,
+
+    /// CHECK: Payment token recipient account, created by the handler if needed
+    #[account(
+        mut,
+        address = get_associated_token_address(&payment_token_recipient_owner.key(), &payment_token_mint.key())
+    )]
+    pub payment_token_recipient: UncheckedAccount<'info>,
+
+    /// Auction lamport treasury, pays for the recipient token accounts (optional)
+    #[account(
+        mut,
+        seeds = [LAMPORT_TREASURY_SEED, auction.key().as_ref()],
+        bump
+    )]
+    pub lamport_treasury: Option<SystemAccount<'info>>,
+
+    /// Matured withdrawal queued by `queue_admin_action`, required by timelocked auctions
+    #[account(
+        mut,
+        has_one = auction @ LauchpadError::InvalidAdminAction,
+        close = authority
+    )]
+    pub admin_action: Option<Account<'info, AdminAction>>,
+
+    /// Co-signer of withdrawals above the co-approval threshold (only needed for those)
+    pub co_signer: Option<Signer<'info>>,
+
+    /// Platform treasury's payment token account (only needed with a proceeds split)
+    #[account(
+        mut,
+        constraint = treasury_payment_token.mint == auction.payment_token_mint
+            && auction.extensions.proceeds_split.as_ref().map(|split| split.treasury)
+                == Some(treasury_payment_token.owner)
+            @ LauchpadError::InvalidProceedsSplit
+    )]
+    pub treasury_payment_token: Option<Account<'info, TokenAccount>>,
+
+    /// Protocol treasury's payment token account (only needed with a protocol fee)
+    #[account(
+        mut,
+        constraint = protocol_treasury_payment_token.mint == auction.payment_token_mint
+            && global_config.protocol_fee.map(|fee| fee.treasury)
+                == Some(protocol_treasury_payment_token.owner)
+            @ LauchpadError::InvalidProtocolFee
+    )]
+    pub protocol_treasury_payment_token: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitFrontendFees<'info> {
+    #[account(mut)]
+    pub frontend: Signer<'info>,
+
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, registering front-ends
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    #[account(
+        init,
+        payer = frontend,
+        space = FrontendFees::SPACE,
+        seeds = [FRONTEND_FEES_SEED, auction.key().as_ref(), frontend.key().as_ref()],
+        bump
+    )]
+    pub frontend_fees: Account<'info, FrontendFees>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFrontendFees<'info> {
+    pub frontend: Signer<'info>,
+
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    #[account(
+        mut,
+        seeds = [FRONTEND_FEES_SEED, auction.key().as_ref(), frontend.key().as_ref()],
+        bump = frontend_fees.bump
+    )]
+    pub frontend_fees: Account<'info, FrontendFees>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SALE_SEED, auction.key().as_ref()],
+        bump = auction.vault_sale_bump
+    )]
+    pub vault_sale_token: Account<'info, TokenAccount>,
+
+    /// Any sale token account of the front-end
+    #[account(
+        mut,
+        constraint = frontend_sale_token.mint == auction.sale_token_mint,
+        constraint = frontend_sale_token.owner == frontend.key()
+    )]
+    pub frontend_sale_token: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    /// Fee manager, or the configured fee recipient withdrawing to itself
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = auction.role_holder(AuctionRole::FeeManager) == authority.key()
+            || auction.extensions.fee_recipient == Some(authority.key())
+            @ LauchpadError::Unauthorized
+    )]
+    pub auction: Account<'info, Auction>,
+
+    /// Sale token mint, the currency claim fees are collected in
+    #[account(address = auction.sale_token_mint @ LauchpadError::InvalidWithdrawRecipient)]
+    pub sale_token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SALE_SEED, auction.key().as_ref()],
+        bump = auction.vault_sale_bump
+    )]
+    pub vault_sale_token: Account<'info, TokenAccount>,
+
+    /// CHECK: Owner of the fee recipient account, the configured recipient or the authority
+    #[account(
+        address = auction.extensions.fee_recipient.unwrap_or(auction.authority)
+            @ LauchpadError::InvalidWithdrawRecipient
+    )]
+    pub fee_recipient_owner: UncheckedAccount<'info>,
+
+    /// Any sale token account of the fee recipient
+    #[account(
+        mut,
+        token::mint = sale_token_mint,
+        token::authority = fee_recipient_owner
+    )]
+    pub fee_recipient_account: Account<'info, TokenAccount>,
+
+    /// Launchpad config, routing a share of the fees to stakers
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    /// Staking rewards vault for the sale mint, required with a revenue share
+    #[account(
+        mut,
+        constraint = config
+            .revenue_share
+            .is_some_and(|share| share.staking_rewards_authority == staking_rewards_vault.owner)
+            && staking_rewards_vault.mint == sale_token_mint.key()
+            @ LauchpadError::InvalidStakingRewardsVault
+    )]
+    pub staking_rewards_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = LaunchpadStats::SPACE,
+        seeds = [STATS_SEED],
+        bump
+    )]
+    pub stats: Account<'info, LaunchpadStats>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawLateRefundPool<'info> {
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
+        bump = auction.vault_payment_bump
+    )]
+    pub vault_payment_token: Account<'info, TokenAccount>,
+
+    /// Payment token account of the late refund pool recipient
+    #[account(
+        mut,
+        constraint = pool_payment_token.mint == auction.payment_token_mint,
+        constraint = auction
+            .extensions
+            .late_refund_pool
+            .as_ref()
+            .map_or(true, |pool| pool_payment_token.owner == pool.recipient)
+            @ LauchpadError::InvalidWithdrawRecipient
+    )]
+    pub pool_payment_token: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ReconcileFees<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = auction.role_holder(AuctionRole::FeeManager) == authority.key()
+            @ LauchpadError::Unauthorized
+    )]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        seeds = [VAULT_SALE_SEED, auction.key().as_ref()],
+        bump = auction.vault_sale_bump
+    )]
+    pub vault_sale_token: Account<'info, TokenAccount>,
+}
+
+#[derive(Accounts)]
+pub struct RecoverSurplus<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(has_one = authority)]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SALE_SEED, auction.key().as_ref()],
+        bump = auction.vault_sale_bump
+    )]
+    pub vault_sale_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
+        bump = auction.vault_payment_bump
+    )]
+    pub vault_payment_token: Account<'info, TokenAccount>,
+
+    /// Authority's sale token account
+    #[account(
+        mut,
+        constraint = sale_token_recipient.mint == auction.sale_token_mint,
+        constraint = sale_token_recipient.owner == authority.key()
+    )]
+    pub sale_token_recipient: Account<'info, TokenAccount>,
+
+    /// Authority's payment token account
+    #[account(
+        mut,
+        constraint = payment_token_recipient.mint == auction.payment_token_mint,
+        constraint = payment_token_recipient.owner == authority.key()
+    )]
+    pub payment_token_recipient: Account<'info, TokenAccount>,
+
+    /// Redemption vault of a placeholder auction (optional)
+    #[account(
+        mut,
+        seeds = [VAULT_REDEMPTION_SEED, auction.key().as_ref()],
+        bump = auction.vault_redemption_bump
+    )]
+    pub vault_redemption_token: Option<Account<'info, TokenAccount>>,
+
+    /// Authority's real sale token account (required with the redemption vault)
+    #[account(
+        mut,
+        constraint = auction.redemption_mint == Some(redemption_token_recipient.mint),
+        constraint = redemption_token_recipient.owner == authority.key()
+    )]
+    pub redemption_token_recipient: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SweepUnclaimed<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(mut, has_one = authority)]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SALE_SEED, auction.key().as_ref()],
+        bump = auction.vault_sale_bump
+    )]
+    pub vault_sale_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
+        bump = auction.vault_payment_bump
+    )]
+    pub vault_payment_token: Account<'info, TokenAccount>,
+
+    /// Recovery's sale token account
+    #[account(
+        mut,
+        constraint = sale_token_recovery.mint == auction.sale_token_mint,
+        constraint = auction.extensions.claim_deadline.as_ref().map(|deadline| deadline.recovery)
+            == Some(sale_token_recovery.owner) @ LauchpadError::InvalidClaimDeadline
+    )]
+    pub sale_token_recovery: Account<'info, TokenAccount>,
+
+    /// Recovery's payment token account
+    #[account(
+        mut,
+        constraint = payment_token_recovery.mint == auction.payment_token_mint,
+        constraint = auction.extensions.claim_deadline.as_ref().map(|deadline| deadline.recovery)
+            == Some(payment_token_recovery.owner) @ LauchpadError::InvalidClaimDeadline
+    )]
+    pub payment_token_recovery: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawLamports<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(has_one = authority)]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        seeds = [LAMPORT_TREASURY_SEED, auction.key().as_ref()],
+        bump
+    )]
+    pub lamport_treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetPrice<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = auction.role_holder(AuctionRole::PriceSetter) == authority.key()
+            @ LauchpadError::Unauthorized
+    )]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+}
+
+#[derive(Accounts)]
+pub struct AddBin<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ LauchpadError::Unauthorized,
+        realloc = Auction::space_for_bins(auction.bins.len() + 1),
+        realloc::payer = authority,
+        realloc::zero = false
+    )]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    /// Sale token seller's account (source of the new bin's sale tokens)
+    #[account(
+        mut,
+        constraint = sale_token_seller.mint == auction.sale_token_mint
+    )]
+    pub sale_token_seller: Account<'info, TokenAccount>,
+
+    /// Authority of the sale token seller account
+    pub sale_token_seller_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SALE_SEED, auction.key().as_ref()],
+        bump = auction.vault_sale_bump
+    )]
+    pub vault_sale_token: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateBinCap<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(mut, has_one = authority @ LauchpadError::Unauthorized)]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    /// Sale token seller's account, depositing a cap increase or receiving a decrease
+    #[account(
+        mut,
+        constraint = sale_token_seller.mint == auction.sale_token_mint
+    )]
+    pub sale_token_seller: Account<'info, TokenAccount>,
+
+    /// Authority of the sale token seller account
+    pub sale_token_seller_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SALE_SEED, auction.key().as_ref()],
+        bump = auction.vault_sale_bump
+    )]
+    pub vault_sale_token: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct TopUpSaleTokens<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(mut, has_one = authority @ LauchpadError::Unauthorized)]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    /// Sale token seller's account (source of the top-up)
+    #[account(
+        mut,
+        constraint = sale_token_seller.mint == auction.sale_token_mint
+    )]
+    pub sale_token_seller: Account<'info, TokenAccount>,
+
+    /// Authority of the sale token seller account
+    pub sale_token_seller_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SALE_SEED, auction.key().as_ref()],
+        bump = auction.vault_sale_bump
+    )]
+    pub vault_sale_token: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(action_id: u64)]
+pub struct QueueAdminAction<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = AdminAction::SPACE,
+        seeds = [ADMIN_ACTION_SEED, auction.key().as_ref(), action_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteAdminAction<'info> {
+    /// Receives the rent of the executed action
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    #[account(
+        mut,
+        has_one = auction @ LauchpadError::InvalidAdminAction,
+        close = payer
+    )]
+    pub admin_action: Account<'info, AdminAction>,
 }
 
-// Context structures
+#[derive(Accounts)]
+pub struct CancelAdminAction<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        has_one = auction @ LauchpadError::InvalidAdminAction,
+        close = authority
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+}
+
+#[derive(Accounts)]
+pub struct SetAuctionRoles<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority
+    )]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+}
 
 #[derive(Accounts)]
-#[instruction(
-    commit_start_time: i64,
-    commit_end_time: i64,
-    claim_start_time: i64,
-    bins: Vec<AuctionBinParams>,
-)]
-pub struct InitAuction<'info> {
-    #[account(mut)]
+pub struct SetTermsHash<'info> {
     pub authority: Signer<'info>,
 
     #[account(
-        init,
-        payer = authority,
-        space = Auction::space_for_bins(bins.len()),
-        seeds = [AUCTION_SEED, sale_token_mint.key().as_ref()],
-        bump, // unique seeds and bump to ensure auction is only initialized once
+        mut,
+        has_one = authority
     )]
     pub auction: Account<'info, Auction>,
 
-    pub sale_token_mint: Account<'info, Mint>,
-    pub payment_token_mint: Account<'info, Mint>,
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateTimes<'info> {
+    pub authority: Signer<'info>,
 
-    /// Sale token seller's account (source for initial vault funding)
     #[account(
         mut,
-        constraint = sale_token_seller.mint == sale_token_mint.key()
+        has_one = authority
     )]
-    pub sale_token_seller: Account<'info, TokenAccount>,
+    pub auction: Account<'info, Auction>,
 
-    /// Authority of the sale token seller account
+    /// Launchpad config, holding the minimum claim grace period
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+}
+
+#[derive(Accounts)]
+pub struct RefundBatch<'info> {
+    /// Permissionless caller, paid the keeper bounty (if enabled)
     #[account(mut)]
-    pub sale_token_seller_authority: Signer<'info>,
+    pub keeper: Signer<'info>,
+
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
+
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
 
-    /// Vault to hold sale tokens (created as PDA)
     #[account(
-        init,
-        payer = authority,
-        token::mint = sale_token_mint,
-        token::authority = vault_sale_token,
-        seeds = [VAULT_SALE_SEED, auction.key().as_ref()],
-        bump
+        mut,
+        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
+        bump = auction.vault_payment_bump
     )]
-    pub vault_sale_token: Account<'info, TokenAccount>,
+    pub vault_payment_token: Account<'info, TokenAccount>,
 
-    /// Vault to hold payment tokens (created as PDA)
+    /// Refund crank progress, shared by every caller of this crank
     #[account(
-        init,
-        payer = authority,
-        token::mint = payment_token_mint,
-        token::authority = vault_payment_token,
-        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
+        init_if_needed,
+        payer = keeper,
+        space = CrankCursor::SPACE,
+        seeds = [CRANK_CURSOR_SEED, auction.key().as_ref(), &[CrankKind::Refund as u8]],
         bump
     )]
-    pub vault_payment_token: Account<'info, TokenAccount>,
+    pub crank_cursor: Account<'info, CrankCursor>,
 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
+/// Shared by `enable_redemption` (IOU mode) and `set_redemption_mint` (placeholder mode)
 #[derive(Accounts)]
-#[instruction(bin_id: u8, payment_token_committed: u64, expiry: u64)]
-pub struct Commit<'info> {
-    #[account(mut)]
-    pub user: Signer<'info>,
-
+pub struct EnableRedemption<'info> {
     #[account(mut)]
-    pub auction: Account<'info, Auction>,
-
-    #[account(
-        init_if_needed,
-        payer = user,
-        seeds = [COMMITTED_SEED, auction.key().as_ref(), user.key().as_ref()],
-        bump,
-        space = Committed::space_for_bins(1)
-    )]
-    pub committed: Account<'info, Committed>,
+    pub authority: Signer<'info>,
 
     #[account(
         mut,
-        constraint = user_payment_token.mint == auction.payment_token_mint,
-        constraint = user_payment_token.owner == user.key()
+        has_one = authority
     )]
-    pub user_payment_token: Account<'info, TokenAccount>,
+    pub auction: Account<'info, Auction>,
 
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
+
+    /// Real sale token mint that IOUs are redeemed for
+    pub redemption_mint: Account<'info, Mint>,
+
+    /// Source of the real sale tokens backing the IOUs
     #[account(
         mut,
-        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
-        bump = auction.vault_payment_bump
+        constraint = redemption_token_source.mint == redemption_mint.key()
     )]
-    pub vault_payment_token: Account<'info, TokenAccount>,
-
-    /// CHECK: 白名单授权公钥，仅用于比较（只有启用白名单时才需要）
-    pub whitelist_authority: Option<UncheckedAccount<'info>>,
+    pub redemption_token_source: Account<'info, TokenAccount>,
 
-    /// CHECK: Custody authorization account (only needed when custody authorization is used)
-    pub custody_authority: Option<UncheckedAccount<'info>>,
+    /// Authority of the redemption token source account
+    pub redemption_token_source_authority: Signer<'info>,
 
-    /// CHECK: sysvar instructions（只有启用白名单时才需要）
-    pub sysvar_instructions: Option<UncheckedAccount<'info>>,
+    /// Vault to hold the real sale tokens (created as PDA)
+    #[account(
+        init,
+        payer = authority,
+        token::mint = redemption_mint,
+        token::authority = vault_redemption_token,
+        seeds = [VAULT_REDEMPTION_SEED, auction.key().as_ref()],
+        bump
+    )]
+    pub vault_redemption_token: Account<'info, TokenAccount>,
 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct DecreaseCommit<'info> {
+pub struct Redeem<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
-    #[account(mut)]
     pub auction: Account<'info, Auction>,
 
-    #[account(mut, has_one = user)]
-    pub committed: Account<'info, Committed>,
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
 
-    #[account(mut)]
-    pub user_payment_token: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        close = user,
+        has_one = user,
+        has_one = auction,
+        seeds = [REDEMPTION_SEED, auction.key().as_ref(), user.key().as_ref()],
+        bump = redemption.bump
+    )]
+    pub redemption: Account<'info, Redemption>,
+
+    /// Real sale token mint enabled by `enable_redemption`
+    #[account(
+        constraint = auction.redemption_mint == Some(redemption_mint.key()) @ LauchpadError::RedemptionNotEnabled
+    )]
+    pub redemption_mint: Account<'info, Mint>,
+
+    /// User's real sale token account (will be created if needed)
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = redemption_mint,
+        associated_token::authority = user
+    )]
+    pub user_redemption_token: Account<'info, TokenAccount>,
 
     #[account(
         mut,
-        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
-        bump = auction.vault_payment_bump
+        seeds = [VAULT_REDEMPTION_SEED, auction.key().as_ref()],
+        bump = auction.vault_redemption_bump
     )]
-    pub vault_payment_token: Account<'info, TokenAccount>,
+    pub vault_redemption_token: Account<'info, TokenAccount>,
 
     pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct Claim<'info> {
+pub struct SwapPlaceholder<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
     #[account(mut)]
     pub auction: Account<'info, Auction>,
 
-    #[account(mut)]
-    pub committed: Account<'info, Committed>,
+    /// Launchpad config, pausing operations program-wide
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
 
-    /// Sale token mint
+    /// Placeholder sale token mint
+    #[account(
+        mut,
+        address = auction.sale_token_mint
+    )]
     pub sale_token_mint: Account<'info, Mint>,
 
-    /// User's sale token account (will be created if needed)
+    /// User's placeholder sale token account
     #[account(
-        init_if_needed,
-        payer = user,
-        associated_token::mint = sale_token_mint,
-        associated_token::authority = user
+        mut,
+        constraint = user_sale_token.mint == auction.sale_token_mint,
+        constraint = user_sale_token.owner == user.key()
     )]
     pub user_sale_token: Account<'info, TokenAccount>,
 
-    /// User's payment token account for refunds
+    /// User's redemption ledger, recording the placeholders they claimed
     #[account(
         mut,
-        constraint = user_payment_token.mint == auction.payment_token_mint,
-        constraint = user_payment_token.owner == user.key()
+        has_one = user,
+        has_one = auction,
+        seeds = [REDEMPTION_SEED, auction.key().as_ref(), user.key().as_ref()],
+        bump = redemption.bump
     )]
-    pub user_payment_token: Account<'info, TokenAccount>,
+    pub redemption: Account<'info, Redemption>,
 
+    /// Real sale token mint set by `set_redemption_mint`
     #[account(
-        mut,
-        seeds = [VAULT_SALE_SEED, auction.key().as_ref()],
-        bump = auction.vault_sale_bump
+        constraint = auction.redemption_mint == Some(redemption_mint.key()) @ LauchpadError::RedemptionNotEnabled
     )]
-    pub vault_sale_token: Account<'info, TokenAccount>,
+    pub redemption_mint: Account<'info, Mint>,
+
+    /// User's real sale token account (will be created if needed)
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = redemption_mint,
+        associated_token::authority = user
+    )]
+    pub user_redemption_token: Account<'info, TokenAccount>,
 
     #[account(
         mut,
-        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
-        bump = auction.vault_payment_bump
+        seeds = [VAULT_REDEMPTION_SEED, auction.key().as_ref()],
+        bump = auction.vault_redemption_bump
     )]
-    pub vault_payment_token: Account<'info, TokenAccount>,
+    pub vault_redemption_token: Account<'info, TokenAccount>,
 
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -969,73 +9128,126 @@ pub struct Claim<'info> {
 }
 
 #[derive(Accounts)]
-pub struct WithdrawFunds<'info> {
+pub struct FinalizeAuction<'info> {
+    /// Permissionless caller, paid the keeper bounty (if enabled)
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub keeper: Signer<'info>,
+
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
 
+    /// Settlement published for other programs, paid by the keeper
     #[account(
-        mut,
-        has_one = authority
+        init,
+        payer = keeper,
+        space = AuctionResult::space_for_bins(auction.bins.len()),
+        seeds = [AUCTION_RESULT_SEED, auction.key().as_ref()],
+        bump
+    )]
+    pub auction_result: Account<'info, AuctionResult>,
+
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = LaunchpadStats::SPACE,
+        seeds = [STATS_SEED],
+        bump
     )]
+    pub stats: Account<'info, LaunchpadStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for read-only getters on an auction
+#[derive(Accounts)]
+pub struct GetAuctionInfo<'info> {
     pub auction: Account<'info, Auction>,
+}
 
-    /// Sale token mint
-    pub sale_token_mint: Account<'info, Mint>,
+/// Context for read-only getters on a user's commitment
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct GetRemainingEntitlement<'info> {
+    pub auction: Account<'info, Auction>,
 
-    /// Payment token mint  
-    pub payment_token_mint: Account<'info, Mint>,
+    #[account(
+        seeds = [COMMITTED_SEED, auction.key().as_ref(), user.as_ref()],
+        bump = committed.load()?.bump
+    )]
+    pub committed: AccountLoader<'info, Committed>,
+}
+
+/// Context for previewing the settlement of an auction
+#[derive(Accounts)]
+pub struct SimulateSettlement<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(has_one = authority @ LauchpadError::Unauthorized)]
+    pub auction: Account<'info, Auction>,
+}
+
+/// Context for checking whether a user could commit
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct CanCommit<'info> {
+    pub auction: Account<'info, Auction>,
 
+    /// User's commitment (omit if the user hasn't committed yet)
     #[account(
-        mut,
-        seeds = [VAULT_SALE_SEED, auction.key().as_ref()],
-        bump = auction.vault_sale_bump
+        seeds = [COMMITTED_SEED, auction.key().as_ref(), user.as_ref()],
+        bump = committed.load()?.bump
     )]
-    pub vault_sale_token: Account<'info, TokenAccount>,
+    pub committed: Option<AccountLoader<'info, Committed>>,
 
+    /// User's wallet-quality attestation (only needed when commit cap scaling is enabled)
     #[account(
-        mut,
-        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
-        bump = auction.vault_payment_bump
+        seeds = [CAP_ATTESTATION_SEED, auction.key().as_ref(), user.as_ref()],
+        bump = cap_attestation.bump
     )]
-    pub vault_payment_token: Account<'info, TokenAccount>,
+    pub cap_attestation: Option<Account<'info, CommitCapAttestation>>,
 
-    /// Sale token recipient account (will be created if needed)
+    /// User's loyalty profile (only needed when loyalty cap multipliers are enabled)
     #[account(
-        init_if_needed,
-        payer = authority,
-        associated_token::mint = sale_token_mint,
-        associated_token::authority = authority
+        seeds = [USER_PROFILE_SEED, user.as_ref()],
+        bump = user_profile.bump
     )]
-    pub sale_token_recipient: Account<'info, TokenAccount>,
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    /// Round of the bin (only needed for auctions with rounds)
+    pub round: Option<Account<'info, Round>>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterIndexer<'info> {
+    pub auction: Account<'info, Auction>,
 
-    /// Payment token recipient account (will be created if needed)
+    /// CHECK: per-auction event authority PDA, only signs event self-CPIs
     #[account(
-        init_if_needed,
-        payer = authority,
-        associated_token::mint = payment_token_mint,
-        associated_token::authority = authority
+        seeds = [EVENT_AUTHORITY_SEED, auction.key().as_ref()],
+        bump
     )]
-    pub payment_token_recipient: Account<'info, TokenAccount>,
+    pub event_authority: UncheckedAccount<'info>,
 
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub system_program: Program<'info, System>,
+    pub program: Program<'info, crate::program::LaunchpadProgram>,
 }
 
 #[derive(Accounts)]
-pub struct WithdrawFees<'info> {
+pub struct ExportAccounting<'info> {
+    pub auction: Account<'info, Auction>,
+}
+
+#[derive(Accounts)]
+pub struct CloseAuction<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
         mut,
-        has_one = authority
+        has_one = authority @ LauchpadError::Unauthorized,
+        close = authority
     )]
     pub auction: Account<'info, Auction>,
 
-    /// Sale token mint
-    pub sale_token_mint: Account<'info, Mint>,
-
     #[account(
         mut,
         seeds = [VAULT_SALE_SEED, auction.key().as_ref()],
@@ -1043,47 +9255,85 @@ pub struct WithdrawFees<'info> {
     )]
     pub vault_sale_token: Account<'info, TokenAccount>,
 
-    /// Fee recipient account (will be created if needed)
     #[account(
-        init_if_needed,
-        payer = authority,
-        associated_token::mint = sale_token_mint,
-        associated_token::authority = authority
+        mut,
+        seeds = [VAULT_PAYMENT_SEED, auction.key().as_ref()],
+        bump = auction.vault_payment_bump
     )]
-    pub fee_recipient_account: Account<'info, TokenAccount>,
+    pub vault_payment_token: Account<'info, TokenAccount>,
+
+    /// Authority's sale token account receiving sale vault dust (if any)
+    #[account(
+        mut,
+        constraint = sale_token_recipient.mint == auction.sale_token_mint,
+        constraint = sale_token_recipient.owner == authority.key()
+    )]
+    pub sale_token_recipient: Option<Account<'info, TokenAccount>>,
+
+    /// Authority's payment token account receiving payment vault dust (if any)
+    #[account(
+        mut,
+        constraint = payment_token_recipient.mint == auction.payment_token_mint,
+        constraint = payment_token_recipient.owner == authority.key()
+    )]
+    pub payment_token_recipient: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: per-auction event authority PDA, only signs event self-CPIs
+    #[account(
+        seeds = [EVENT_AUTHORITY_SEED, auction.key().as_ref()],
+        bump
+    )]
+    pub event_authority: UncheckedAccount<'info>,
 
+    pub program: Program<'info, crate::program::LaunchpadProgram>,
     pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub system_program: Program<'info, System>,
 }
 
+/// Only the program itself can sign for the event authority, so events can't be forged
 #[derive(Accounts)]
-pub struct SetPrice<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
+pub struct LogEvent<'info> {
+    #[account(
+        seeds = [EVENT_AUTHORITY_SEED, auction.key().as_ref()],
+        bump
+    )]
+    pub event_authority: Signer<'info>,
+
+    /// CHECK: only used to derive the event authority
+    pub auction: UncheckedAccount<'info>,
+}
+
+/// Context for checking the program upgrade authority
+#[derive(Accounts)]
+pub struct VerifyProgramAuthority<'info> {
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, LaunchpadConfig>,
 
     #[account(
-        mut,
-        has_one = authority
+        constraint = program.programdata_address()? == Some(program_data.key())
+            @ LauchpadError::InvalidProgramData
     )]
-    pub auction: Account<'info, Auction>,
+    pub program: Program<'info, crate::program::LaunchpadProgram>,
+
+    pub program_data: Account<'info, ProgramData>,
 }
 
 #[derive(Accounts)]
-pub struct GetLaunchpadAdmin {
-    // No accounts needed for this read-only instruction
+pub struct GetLaunchpadAdmin<'info> {
+    #[account(seeds = [GLOBAL_CONFIG_SEED], bump = global_config.bump)]
+    pub global_config: Account<'info, GlobalConfig>,
 }
 
 /// Emergency control context
 #[derive(Accounts)]
 pub struct EmergencyControl<'info> {
-    /// Only auction authority can control emergency state
+    /// Only the auction's pauser can control emergency state
     #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
         mut,
-        has_one = authority @ LauchpadError::OnlyLaunchpadAdmin
+        constraint = auction.role_holder(AuctionRole::Pauser) == authority.key()
+            @ LauchpadError::OnlyLaunchpadAdmin
     )]
     pub auction: Account<'info, Auction>,
 }
@@ -7,8 +7,13 @@ pub mod consts;
 pub mod errors;
 pub mod extensions;
 pub mod instructions;
+pub mod pda;
 pub mod state;
 
+#[cfg(feature = "no-entrypoint")]
+pub mod signer_utils;
+#[cfg(feature = "simulation")]
+pub mod simulation;
 #[cfg(feature = "testing")]
 pub mod testing;
 
@@ -46,6 +51,107 @@ pub mod launchpad_program {
         )
     }
 
+    /// Create a new auction from a template with a new sale token and times
+    pub fn clone_auction(
+        ctx: Context<CloneAuction>,
+        commit_start_time: i64,
+        commit_end_time: i64,
+        claim_start_time: i64,
+    ) -> Result<()> {
+        instructions::clone_auction(ctx, commit_start_time, commit_end_time, claim_start_time)
+    }
+
+    /// Create the global config holding the LaunchpadAdmin, signed by the deploy-time admin
+    pub fn init_config(ctx: Context<InitConfig>, admin: Pubkey) -> Result<()> {
+        instructions::init_config(ctx, admin)
+    }
+
+    /// Rotate the LaunchpadAdmin stored in the global config
+    pub fn update_config(ctx: Context<UpdateConfig>, admin: Pubkey) -> Result<()> {
+        instructions::update_config(ctx, admin)
+    }
+
+    /// Hand an auction's authority over, e.g. to the admin after a rotation
+    pub fn transfer_auction_authority(
+        ctx: Context<TransferAuctionAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::transfer_auction_authority(ctx, new_authority)
+    }
+
+    /// Set the platform fee taken from the proceeds of every auction by `withdraw_funds`
+    pub fn set_protocol_fee(
+        ctx: Context<UpdateConfig>,
+        protocol_fee: Option<ProtocolFee>,
+    ) -> Result<()> {
+        instructions::set_protocol_fee(ctx, protocol_fee)
+    }
+
+    /// Set the payment mints and claim grace period of new auctions, and the
+    /// governance expected to hold the program upgrade authority
+    pub fn set_launchpad_config(
+        ctx: Context<SetLaunchpadConfig>,
+        approved_payment_mints: Vec<Pubkey>,
+        allow_unlisted_payment_mints: bool,
+        min_claim_grace_period: i64,
+        governance_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_launchpad_config(
+            ctx,
+            approved_payment_mints,
+            allow_unlisted_payment_mints,
+            min_claim_grace_period,
+            governance_authority,
+        )
+    }
+
+    /// Block or unblock claims and withdrawals of every auction selling a sale mint
+    pub fn set_sale_mint_blocked(
+        ctx: Context<SetSaleMintBlocked>,
+        sale_token_mint: Pubkey,
+        blocked: bool,
+    ) -> Result<()> {
+        instructions::set_sale_mint_blocked(ctx, sale_token_mint, blocked)
+    }
+
+    /// Route a share of withdrawn fees to the staking rewards vaults of the launchpad token
+    pub fn set_revenue_share(
+        ctx: Context<SetRevenueShare>,
+        revenue_share: Option<RevenueShare>,
+    ) -> Result<()> {
+        instructions::set_revenue_share(ctx, revenue_share)
+    }
+
+    /// Register or unregister an integrating front-end users may commit through
+    pub fn set_frontend_registered(
+        ctx: Context<UpdateFrontends>,
+        frontend_id: Pubkey,
+        registered: bool,
+    ) -> Result<()> {
+        instructions::set_frontend_registered(ctx, frontend_id, registered)
+    }
+
+    /// Set the share of the claim fees accrued to the front-end committed through
+    pub fn set_frontend_fee_share(ctx: Context<UpdateFrontends>, fee_share_bps: u16) -> Result<()> {
+        instructions::set_frontend_fee_share(ctx, fee_share_bps)
+    }
+
+    /// Pause operations on every auction at once, same flags as `emergency_control`
+    pub fn set_global_pause(ctx: Context<SetGlobalPause>, paused_operations: u64) -> Result<()> {
+        instructions::set_global_pause(ctx, paused_operations)
+    }
+
+    /// Save a reusable auction configuration for `clone_auction`
+    pub fn create_template(
+        ctx: Context<CreateTemplate>,
+        template_id: u64,
+        bins: Vec<AuctionBinParams>,
+        custody: Pubkey,
+        extensions: AuctionExtensions,
+    ) -> Result<()> {
+        instructions::create_template(ctx, template_id, bins, custody, extensions)
+    }
+
     /// Emergency control for pausing/resuming auction operations
     pub fn emergency_control(
         ctx: Context<EmergencyControl>,
@@ -54,14 +160,30 @@ pub mod launchpad_program {
         instructions::emergency_control(ctx, params)
     }
 
-    /// User commits to an auction bin
+    /// Pull the launch before its commit period, returning the sale tokens to the seller
+    pub fn cancel_auction(ctx: Context<CancelAuction>) -> Result<()> {
+        instructions::cancel_auction(ctx)
+    }
+
+    /// Abort an auction for incident response, refunding every commitment in full
+    pub fn abort_auction(ctx: Context<AbortAuction>) -> Result<()> {
+        instructions::abort_auction(ctx)
+    }
+
+    /// Freeze sale token claims and refund the effective payment of unclaimed sale tokens
+    pub fn force_refund_mode(ctx: Context<ForceRefundMode>) -> Result<()> {
+        instructions::force_refund_mode(ctx)
+    }
+
+    /// User commits to an auction bin, returning the receipt of the accepted commitment
     pub fn commit(
         ctx: Context<Commit>,
         bin_id: u8,
         payment_token_committed: u64,
         expiry: u64,
-    ) -> Result<()> {
-        instructions::commit(ctx, bin_id, payment_token_committed, expiry)
+        terms_hash: [u8; 32],
+    ) -> Result<CommitReceipt> {
+        instructions::commit(ctx, bin_id, payment_token_committed, expiry, terms_hash)
     }
 
     /// User decreases a commitment (renamed from revert_commit)
@@ -73,24 +195,213 @@ pub mod launchpad_program {
         instructions::decrease_commit(ctx, bin_id, payment_token_reverted)
     }
 
+    /// User boosts a commitment, locking its sale tokens for longer for more allocation weight
+    pub fn boost_commitment(ctx: Context<BoostCommitment>, bin_id: u8) -> Result<()> {
+        instructions::boost_commitment(ctx, bin_id)
+    }
+
+    /// User joins the waitlist of a full hard-cap bin, escrowing payment tokens
+    pub fn join_waitlist(
+        ctx: Context<JoinWaitlist>,
+        bin_id: u8,
+        sequence: u64,
+        payment_token_amount: u64,
+        expiry: u64,
+        terms_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::join_waitlist(
+            ctx,
+            bin_id,
+            sequence,
+            payment_token_amount,
+            expiry,
+            terms_hash,
+        )
+    }
+
+    /// User leaves a waitlist and takes back the escrowed payment tokens
+    pub fn leave_waitlist(ctx: Context<LeaveWaitlist>) -> Result<()> {
+        instructions::leave_waitlist(ctx)
+    }
+
+    /// Custody commits to a bin on behalf of many users at once
+    pub fn custody_commit_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CustodyCommitBatch<'info>>,
+        bin_id: u8,
+        entries: Vec<CustodyCommitEntry>,
+    ) -> Result<()> {
+        instructions::custody_commit_batch(ctx, bin_id, entries)
+    }
+
+    /// Permissionless crank promoting waitlisted commitments into freed bin room
+    pub fn process_waitlist<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProcessWaitlist<'info>>,
+        bin_id: u8,
+    ) -> Result<()> {
+        instructions::process_waitlist(ctx, bin_id)
+    }
+
+    /// User records a whitelist-authority attestation that scales their commit cap
+    pub fn attest_commit_cap(
+        ctx: Context<AttestCommitCap>,
+        cap_bps: u16,
+        expiry: u64,
+    ) -> Result<()> {
+        instructions::attest_commit_cap(ctx, cap_bps, expiry)
+    }
+
+    /// Custody claims a bin's entitlements of many users it committed for
+    pub fn claim_to_custody<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimToCustody<'info>>,
+        bin_id: u8,
+    ) -> Result<()> {
+        instructions::claim_to_custody(ctx, bin_id)
+    }
+
+    /// Whitelist authority revokes a user's outstanding signatures by raising their nonce
+    pub fn revoke_whitelist_signatures(
+        ctx: Context<RevokeWhitelistSignatures>,
+        user: Pubkey,
+        nonce_floor: u64,
+    ) -> Result<()> {
+        instructions::revoke_whitelist_signatures(ctx, user, nonce_floor)
+    }
+
+    /// Create or extend an address lookup table of the auction's fixed accounts
+    pub fn register_lut(ctx: Context<RegisterLut>, recent_slot: u64) -> Result<()> {
+        instructions::register_lut(ctx, recent_slot)
+    }
+
+    /// Create the record sharing the commit cap across an identity group's wallets
+    pub fn init_identity_group(ctx: Context<InitIdentityGroup>, group_id: [u8; 32]) -> Result<()> {
+        instructions::init_identity_group(ctx, group_id)
+    }
+
+    /// User commits to an auction bin by escrowing an NFT (NFT payment mode)
+    pub fn commit_nft(
+        ctx: Context<CommitNft>,
+        bin_id: u8,
+        expiry: u64,
+        terms_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::commit_nft(ctx, bin_id, expiry, terms_hash)
+    }
+
+    /// User takes back an escrowed NFT while committing or after a failed auction
+    pub fn return_nft(ctx: Context<ReturnNft>) -> Result<()> {
+        instructions::return_nft(ctx)
+    }
+
+    /// Admin collects an escrowed NFT as payment after a successful auction
+    pub fn collect_nft(ctx: Context<CollectNft>) -> Result<()> {
+        instructions::collect_nft(ctx)
+    }
+
     /// User claims tokens with flexible amounts (merged claim functionality)
     pub fn claim(
         ctx: Context<Claim>,
         bin_id: u8,
         sale_token_to_claim: u64,
         payment_token_to_refund: u64,
+        terms_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::claim(
+            ctx,
+            bin_id,
+            sale_token_to_claim,
+            payment_token_to_refund,
+            terms_hash,
+        )
+    }
+
+    /// User claims everything claimable from several auctions at once
+    pub fn claim_across_auctions<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimAcrossAuctions<'info>>,
     ) -> Result<()> {
-        instructions::claim(ctx, bin_id, sale_token_to_claim, payment_token_to_refund)
+        instructions::claim_across_auctions(ctx)
     }
 
-    /// Admin withdraws funds from all auction bins
+    /// User converts unclaimed sale tokens into a claim voucher honored by the voucher partner
+    pub fn issue_claim_voucher(
+        ctx: Context<IssueClaimVoucher>,
+        bin_id: u8,
+        sale_token_amount: u64,
+    ) -> Result<()> {
+        instructions::issue_claim_voucher(ctx, bin_id, sale_token_amount)
+    }
+
+    /// Keepers refund commitments in bulk for a refund-only auction (permissionless)
+    pub fn refund_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RefundBatch<'info>>,
+    ) -> Result<()> {
+        instructions::refund_batch(ctx)
+    }
+
+    /// Admin schedules a round of a round-based auction
+    pub fn init_round(
+        ctx: Context<InitRound>,
+        index: u8,
+        commit_start_time: i64,
+        commit_end_time: i64,
+        whitelist_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::init_round(
+            ctx,
+            index,
+            commit_start_time,
+            commit_end_time,
+            whitelist_authority,
+        )
+    }
+
+    /// Permissionless crank closing an ended round and rolling unsold supply forward
+    pub fn close_round(ctx: Context<CloseRound>) -> Result<()> {
+        instructions::close_round(ctx)
+    }
+
+    /// Admin withdraws funds from all auction bins, after finalization
     pub fn withdraw_funds(ctx: Context<WithdrawFunds>) -> Result<()> {
         instructions::withdraw_funds(ctx)
     }
 
-    /// Admin withdraws collected fees from all bins
-    pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
-        instructions::withdraw_fees(ctx)
+    /// Admin withdraws collected fees from all bins, all of them unless `amount` is set
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: Option<u64>) -> Result<()> {
+        instructions::withdraw_fees(ctx, amount)
+    }
+
+    /// Registered front-end opens its fee balance in an auction
+    pub fn init_frontend_fees(ctx: Context<InitFrontendFees>) -> Result<()> {
+        instructions::init_frontend_fees(ctx)
+    }
+
+    /// Front-end withdraws the claim fees accrued to it in an auction
+    pub fn withdraw_frontend_fees(ctx: Context<WithdrawFrontendFees>) -> Result<()> {
+        instructions::withdraw_frontend_fees(ctx)
+    }
+
+    /// Transfer the late refunds owed to the late refund pool, callable by anyone
+    pub fn withdraw_late_refund_pool(ctx: Context<WithdrawLateRefundPool>) -> Result<()> {
+        instructions::withdraw_late_refund_pool(ctx)
+    }
+
+    /// Repair claim fee accounting exceeding what the per-bin claims can have collected
+    pub fn reconcile_fees(ctx: Context<ReconcileFees>) -> Result<()> {
+        instructions::reconcile_fees(ctx)
+    }
+
+    /// Admin recovers vault balances exceeding every accounted liability
+    pub fn recover_surplus(ctx: Context<RecoverSurplus>) -> Result<()> {
+        instructions::recover_surplus(ctx)
+    }
+
+    /// Admin sweeps tokens left unclaimed at the claim deadline to its recovery
+    pub fn sweep_unclaimed(ctx: Context<SweepUnclaimed>) -> Result<()> {
+        instructions::sweep_unclaimed(ctx)
+    }
+
+    /// Admin withdraws lamports from the auction lamport treasury
+    pub fn withdraw_lamports(ctx: Context<WithdrawLamports>, amount: u64) -> Result<()> {
+        instructions::withdraw_lamports(ctx, amount)
     }
 
     /// Admin sets new price for a bin
@@ -98,9 +409,167 @@ pub mod launchpad_program {
         instructions::set_price(ctx, bin_id, new_price)
     }
 
-    /// Get the hardcoded LaunchpadAdmin public key
-    pub fn get_launchpad_admin(_ctx: Context<GetLaunchpadAdmin>) -> Result<Pubkey> {
-        instructions::get_launchpad_admin()
+    /// Queue a price change or fund withdrawal behind the auction's timelock
+    pub fn queue_admin_action(
+        ctx: Context<QueueAdminAction>,
+        action_id: u64,
+        action: AdminActionKind,
+    ) -> Result<()> {
+        instructions::queue_admin_action(ctx, action_id, action)
+    }
+
+    /// Apply a queued price change once its timelock has passed, callable by anyone
+    pub fn execute_admin_action(ctx: Context<ExecuteAdminAction>) -> Result<()> {
+        instructions::execute_admin_action(ctx)
+    }
+
+    /// Cancel a queued admin action
+    pub fn cancel_admin_action(ctx: Context<CancelAdminAction>) -> Result<()> {
+        instructions::cancel_admin_action(ctx)
+    }
+
+    /// Admin delegates pausing, pricing, fee and fund withdrawal operations to other keys
+    pub fn set_auction_roles(ctx: Context<SetAuctionRoles>, roles: AuctionRoles) -> Result<()> {
+        instructions::set_auction_roles(ctx, roles)
+    }
+
+    /// Admin appends a bin before the commit period starts
+    pub fn add_bin(ctx: Context<AddBin>, params: AuctionBinParams) -> Result<()> {
+        instructions::add_bin(ctx, params)
+    }
+
+    /// Admin changes the sale token cap of a bin before the commit period starts
+    pub fn update_bin_cap(
+        ctx: Context<UpdateBinCap>,
+        bin_id: u8,
+        sale_token_cap: u64,
+    ) -> Result<()> {
+        instructions::update_bin_cap(ctx, bin_id, sale_token_cap)
+    }
+
+    /// Admin adds sale tokens to a sale bin until the commit period ends, raising its cap
+    pub fn top_up_sale_tokens(
+        ctx: Context<TopUpSaleTokens>,
+        bin_id: u8,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::top_up_sale_tokens(ctx, bin_id, amount)
+    }
+
+    /// Admin sets the hash of the sale terms acknowledged by committing users
+    pub fn set_terms_hash(ctx: Context<SetTermsHash>, terms_hash: [u8; 32]) -> Result<()> {
+        instructions::set_terms_hash(ctx, terms_hash)
+    }
+
+    /// Admin pushes auction times later, each only until it has passed
+    pub fn update_times(
+        ctx: Context<UpdateTimes>,
+        commit_start_time: i64,
+        commit_end_time: i64,
+        claim_start_time: i64,
+    ) -> Result<()> {
+        instructions::update_times(ctx, commit_start_time, commit_end_time, claim_start_time)
+    }
+
+    /// Admin enables IOU redemption by depositing the real sale tokens
+    pub fn enable_redemption(ctx: Context<EnableRedemption>) -> Result<()> {
+        instructions::enable_redemption(ctx)
+    }
+
+    /// User redeems IOUs for the real sale tokens
+    pub fn redeem(ctx: Context<Redeem>) -> Result<()> {
+        instructions::redeem(ctx)
+    }
+
+    /// Admin sets the real mint that placeholder sale tokens are swapped into
+    pub fn set_redemption_mint(ctx: Context<EnableRedemption>) -> Result<()> {
+        instructions::set_redemption_mint(ctx)
+    }
+
+    /// User swaps placeholder sale tokens for the real sale tokens 1:1
+    pub fn swap_placeholder(ctx: Context<SwapPlaceholder>, amount: u64) -> Result<()> {
+        instructions::swap_placeholder(ctx, amount)
+    }
+
+    /// Finalize the auction after the commit period (permissionless)
+    pub fn finalize_auction(ctx: Context<FinalizeAuction>) -> Result<()> {
+        instructions::finalize_auction(ctx)
+    }
+
+    /// Get the sale tokens and refund a user can still claim from a bin
+    pub fn get_remaining_entitlement(
+        ctx: Context<GetRemainingEntitlement>,
+        user: Pubkey,
+        bin_id: u8,
+    ) -> Result<RemainingEntitlement> {
+        instructions::get_remaining_entitlement(ctx, user, bin_id)
+    }
+
+    /// Preview the settlement of the auction in its current state
+    pub fn simulate_settlement(ctx: Context<SimulateSettlement>) -> Result<SettlementSimulation> {
+        instructions::simulate_settlement(ctx)
+    }
+
+    /// Get the unlock timeline of a user's sale tokens in a bin
+    pub fn get_vesting_schedule(
+        ctx: Context<GetRemainingEntitlement>,
+        user: Pubkey,
+        bin_id: u8,
+    ) -> Result<VestingSchedule> {
+        instructions::get_vesting_schedule(ctx, user, bin_id)
+    }
+
+    /// Check whether a user could commit, with the reason and limit if not
+    pub fn can_commit(
+        ctx: Context<CanCommit>,
+        user: Pubkey,
+        bin_id: u8,
+        payment_token_committed: u64,
+    ) -> Result<CommitEligibility> {
+        instructions::can_commit(ctx, user, bin_id, payment_token_committed)
+    }
+
+    /// Get the cached allocation ratio of a bin
+    pub fn get_allocation_ratio(ctx: Context<GetAuctionInfo>, bin_id: u8) -> Result<u64> {
+        instructions::get_allocation_ratio(ctx, bin_id)
+    }
+
+    /// Get raised amounts, participants, fees, claim progress and withdrawal status at once
+    pub fn get_project_summary(ctx: Context<GetAuctionInfo>) -> Result<ProjectSummary> {
+        instructions::get_project_summary(ctx)
+    }
+
+    /// Check whether the program upgrade authority is the configured governance
+    pub fn verify_program_authority(
+        ctx: Context<VerifyProgramAuthority>,
+    ) -> Result<ProgramAuthorityStatus> {
+        instructions::verify_program_authority(ctx)
+    }
+
+    /// Emit the full auction state through the event authority for indexer re-sync
+    pub fn register_indexer(ctx: Context<RegisterIndexer>) -> Result<()> {
+        instructions::register_indexer(ctx)
+    }
+
+    /// Emit the auction's ledger per bin as a fixed sequence of events for accounting
+    pub fn export_accounting(ctx: Context<ExportAccounting>) -> Result<()> {
+        instructions::export_accounting(ctx)
+    }
+
+    /// Archive a settled auction through the event authority, sweep vault dust and close
+    /// its accounts
+    pub fn close_auction(ctx: Context<CloseAuction>) -> Result<()> {
+        instructions::close_auction(ctx)
+    }
+
+    /// Event self-CPI target, only callable by the program through an event authority
+    pub fn log_event(ctx: Context<LogEvent>, event_data: Vec<u8>) -> Result<()> {
+        instructions::log_event(ctx, event_data)
+    }
+
+    /// Get the LaunchpadAdmin public key from the global config
+    pub fn get_launchpad_admin(ctx: Context<GetLaunchpadAdmin>) -> Result<Pubkey> {
+        instructions::get_launchpad_admin(ctx)
     }
 
     /// Set auction times (only available in testing builds)
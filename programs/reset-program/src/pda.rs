@@ -0,0 +1,177 @@
+//! PDA derivation for every account of the program
+//!
+//! Each function mirrors the `seeds` of the matching account constraint in
+//! `instructions.rs`, and the `find_program_address` helpers in `state.rs`
+//! delegate here, so clients and the program derive addresses from one place.
+
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Auction of a sale token: ["auction", sale_token_mint]
+pub fn find_auction_address(sale_token_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AUCTION_SEED, sale_token_mint.as_ref()], &crate::ID)
+}
+
+/// Sale token vault of an auction: ["vault_sale", auction]
+pub fn find_vault_sale_address(auction: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SALE_SEED, auction.as_ref()], &crate::ID)
+}
+
+/// Payment token vault of an auction: ["vault_payment", auction]
+pub fn find_vault_payment_address(auction: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_PAYMENT_SEED, auction.as_ref()], &crate::ID)
+}
+
+/// Redemption vault of an auction: ["vault_redemption", auction]
+pub fn find_vault_redemption_address(auction: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_REDEMPTION_SEED, auction.as_ref()], &crate::ID)
+}
+
+/// Lamport treasury of an auction: ["lamport_treasury", auction]
+pub fn find_lamport_treasury_address(auction: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LAMPORT_TREASURY_SEED, auction.as_ref()], &crate::ID)
+}
+
+/// Event authority of an auction: ["event_authority", auction]
+pub fn find_event_authority_address(auction: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EVENT_AUTHORITY_SEED, auction.as_ref()], &crate::ID)
+}
+
+/// User commitment: ["committed", auction, user]
+pub fn find_committed_address(auction: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[COMMITTED_SEED, auction.as_ref(), user.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// User IOU ledger: ["redemption", auction, user]
+pub fn find_redemption_address(auction: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[REDEMPTION_SEED, auction.as_ref(), user.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Front-end fee balance: ["frontend_fees", auction, frontend_id]
+pub fn find_frontend_fees_address(auction: &Pubkey, frontend_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[FRONTEND_FEES_SEED, auction.as_ref(), frontend_id.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// User commit cap attestation: ["cap_attestation", auction, user]
+pub fn find_cap_attestation_address(auction: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[CAP_ATTESTATION_SEED, auction.as_ref(), user.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Crank cursor: ["crank_cursor", auction, kind]
+pub fn find_crank_cursor_address(auction: &Pubkey, kind: CrankKind) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[CRANK_CURSOR_SEED, auction.as_ref(), &[kind as u8]],
+        &crate::ID,
+    )
+}
+
+/// Auction round: ["round", auction, index]
+pub fn find_round_address(auction: &Pubkey, index: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ROUND_SEED, auction.as_ref(), &[index]], &crate::ID)
+}
+
+/// Waitlist entry of a bin: ["waitlist", auction, bin_id, sequence]
+pub fn find_waitlist_entry_address(auction: &Pubkey, bin_id: u8, sequence: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            WAITLIST_SEED,
+            auction.as_ref(),
+            &[bin_id],
+            &sequence.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// NFT escrow record: ["nft_escrow", auction, nft_mint]
+pub fn find_nft_escrow_address(auction: &Pubkey, nft_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[NFT_ESCROW_SEED, auction.as_ref(), nft_mint.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Token account holding an escrowed NFT: ["vault_nft", auction, nft_mint]
+pub fn find_vault_nft_address(auction: &Pubkey, nft_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VAULT_NFT_SEED, auction.as_ref(), nft_mint.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Auction template: ["template", template_id]
+pub fn find_template_address(template_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TEMPLATE_SEED, &template_id.to_le_bytes()], &crate::ID)
+}
+
+/// User loyalty profile: ["user_profile", user]
+pub fn find_user_profile_address(user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[USER_PROFILE_SEED, user.as_ref()], &crate::ID)
+}
+
+/// Identity group: ["identity_group", auction, group_id]
+pub fn find_identity_group_address(auction: &Pubkey, group_id: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[IDENTITY_GROUP_SEED, auction.as_ref(), group_id.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Settlement of an auction: ["auction_result", auction]
+pub fn find_auction_result_address(auction: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AUCTION_RESULT_SEED, auction.as_ref()], &crate::ID)
+}
+
+/// Global launchpad stats: ["stats"]
+pub fn find_stats_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STATS_SEED], &crate::ID)
+}
+
+/// Global launchpad config: ["config"]
+pub fn find_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_SEED], &crate::ID)
+}
+
+/// Global config holding the LaunchpadAdmin: ["global_config"]
+pub fn find_global_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GLOBAL_CONFIG_SEED], &crate::ID)
+}
+
+/// Fixed accounts of an auction, shared by the heavy instructions of every user, to
+/// load into an address lookup table so those instructions fit v0 transactions
+///
+/// `register_lut` fills the auction's table with exactly these addresses.
+pub fn auction_lookup_table_addresses(
+    auction: &Pubkey,
+    sale_token_mint: &Pubkey,
+    payment_token_mint: &Pubkey,
+) -> Vec<Pubkey> {
+    vec![
+        *auction,
+        *sale_token_mint,
+        *payment_token_mint,
+        find_vault_sale_address(auction).0,
+        find_vault_payment_address(auction).0,
+        find_lamport_treasury_address(auction).0,
+        find_event_authority_address(auction).0,
+        find_config_address().0,
+        find_stats_address().0,
+        crate::ID,
+        anchor_spl::token::ID,
+        anchor_spl::associated_token::ID,
+        anchor_lang::system_program::ID,
+        anchor_lang::solana_program::sysvar::instructions::ID,
+    ]
+}
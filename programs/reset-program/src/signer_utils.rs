@@ -0,0 +1,63 @@
+//! Payload construction for backend signers of whitelist and custody authorizations
+//!
+//! Only built with the `no-entrypoint` feature, for off-chain services depending on
+//! the program crate. Messages are serialized from the same payload types and the
+//! Ed25519 instruction is laid out with the same `Ed25519SignatureOffsets` that
+//! `extensions.rs` parses, so signers can't drift from the byte layout the program
+//! expects.
+
+use crate::extensions::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{ed25519_program, instruction::Instruction};
+
+/// Message signed by the whitelist (or custody) authority to authorize a commitment
+pub fn build_whitelist_message(payload: &WhitelistPayload) -> Vec<u8> {
+    let mut message = Vec::new();
+    payload
+        .serialize(&mut message)
+        .expect("Whitelist payload serialization is infallible");
+    message
+}
+
+/// Message signed by the whitelist authority to authorize a commitment counted
+/// against an identity group
+pub fn build_identity_group_message(payload: &IdentityGroupWhitelistPayload) -> Vec<u8> {
+    let mut message = Vec::new();
+    payload
+        .serialize(&mut message)
+        .expect("Identity group payload serialization is infallible");
+    message
+}
+
+/// Ed25519 verification instruction carrying `signature` of `message` by `authority`,
+/// to be placed anywhere before the commit instruction in its transaction
+///
+/// Laid out like the Ed25519 program's own helper: one `Ed25519SignatureOffsets`
+/// followed by the public key, the signature and the message, all in this instruction.
+pub fn build_ed25519_ix(authority: &Pubkey, signature: &[u8; 64], message: &[u8]) -> Instruction {
+    let public_key_offset = ED25519_SIGNATURE_OFFSETS_START + ED25519_SIGNATURE_OFFSETS_SIZE;
+    let signature_offset = public_key_offset + 32;
+    let message_data_offset = signature_offset + 64;
+    let offsets = Ed25519SignatureOffsets {
+        signature_offset: signature_offset as u16,
+        signature_instruction_index: u16::MAX,
+        public_key_offset: public_key_offset as u16,
+        public_key_instruction_index: u16::MAX,
+        message_data_offset: message_data_offset as u16,
+        message_data_size: message.len() as u16,
+        message_instruction_index: u16::MAX,
+    };
+
+    let mut data = Vec::with_capacity(message_data_offset + message.len());
+    data.extend_from_slice(&[1, 0]); // num_signatures, padding
+    data.extend_from_slice(&offsets.to_le_bytes());
+    data.extend_from_slice(authority.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: Vec::new(),
+        data,
+    }
+}
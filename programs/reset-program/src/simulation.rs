@@ -0,0 +1,42 @@
+//! Simulation build (devnet load testing only)
+//!
+//! Swaps the token CPIs of the instructions for no-ops, so every validation and all
+//! state math run without moving tokens: allocation and account growth can be load
+//! tested on devnet with real traffic patterns. Vault balances no longer follow the
+//! accounting, never deploy a simulation build where real tokens are at stake.
+
+/// `anchor_spl::token` with transfers, burns, account closes and native syncs skipped
+pub mod token {
+    use anchor_lang::prelude::*;
+    pub use anchor_spl::token::*;
+
+    /// Skipped `anchor_spl::token::transfer`
+    pub fn transfer<'info>(
+        _ctx: CpiContext<'_, '_, '_, 'info, Transfer<'info>>,
+        _amount: u64,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Skipped `anchor_spl::token::burn`
+    pub fn burn<'info>(
+        _ctx: CpiContext<'_, '_, '_, 'info, Burn<'info>>,
+        _amount: u64,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Skipped `anchor_spl::token::close_account`
+    pub fn close_account<'info>(
+        _ctx: CpiContext<'_, '_, '_, 'info, CloseAccount<'info>>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Skipped `anchor_spl::token::sync_native`
+    pub fn sync_native<'info>(
+        _ctx: CpiContext<'_, '_, '_, 'info, SyncNative<'info>>,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
@@ -6,6 +6,74 @@ pub const AUCTION_SEED: &[u8] = b"auction";
 pub const COMMITTED_SEED: &[u8] = b"committed";
 pub const VAULT_SALE_SEED: &[u8] = b"vault_sale";
 pub const VAULT_PAYMENT_SEED: &[u8] = b"vault_payment";
+pub const VAULT_REDEMPTION_SEED: &[u8] = b"vault_redemption";
+pub const REDEMPTION_SEED: &[u8] = b"redemption";
+pub const CRANK_CURSOR_SEED: &[u8] = b"crank_cursor";
+pub const EVENT_AUTHORITY_SEED: &[u8] = b"event_authority";
+pub const VAULT_NFT_SEED: &[u8] = b"vault_nft";
+pub const NFT_ESCROW_SEED: &[u8] = b"nft_escrow";
+pub const CAP_ATTESTATION_SEED: &[u8] = b"cap_attestation";
+pub const WAITLIST_SEED: &[u8] = b"waitlist";
+pub const ROUND_SEED: &[u8] = b"round";
+pub const TEMPLATE_SEED: &[u8] = b"template";
+pub const USER_PROFILE_SEED: &[u8] = b"user_profile";
+pub const CONFIG_SEED: &[u8] = b"config";
+pub const GLOBAL_CONFIG_SEED: &[u8] = b"global_config";
+pub const LAMPORT_TREASURY_SEED: &[u8] = b"lamport_treasury";
+pub const IDENTITY_GROUP_SEED: &[u8] = b"identity_group";
+pub const STATS_SEED: &[u8] = b"stats";
+pub const ADMIN_ACTION_SEED: &[u8] = b"admin_action";
+pub const AUCTION_RESULT_SEED: &[u8] = b"auction_result";
+pub const FRONTEND_FEES_SEED: &[u8] = b"frontend_fees";
+
+/// Fully claimed auctions needed to reach each `UserProfile` loyalty tier
+pub const LOYALTY_TIER_THRESHOLDS: [u64; LOYALTY_TIER_COUNT] = [1, 3, 10];
+pub const LOYALTY_TIER_COUNT: usize = 3;
+pub const MAX_WAITLIST_BATCH_SIZE: usize = 10;
+
+/// Metaplex Token Metadata program, owner of NFT metadata accounts
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// Address lookup table program
+pub const ADDRESS_LOOKUP_TABLE_PROGRAM_ID: Pubkey =
+    pubkey!("AddressLookupTab1e1111111111111111111111111");
+
+/// Maximum number of bins of an auction, every `Committed` account has a slot for each
+pub const MAX_AUCTION_BINS: usize = 10;
+
+/// Maximum number of `Committed` accounts processed by one `refund_batch` call
+pub const MAX_REFUND_BATCH_SIZE: usize = 10;
+
+/// Maximum number of auctions settled by one `claim_across_auctions` call
+pub const MAX_CROSS_AUCTION_CLAIM_SIZE: usize = 4;
+
+/// Accounts passed per auction to `claim_across_auctions`
+pub const CROSS_AUCTION_CLAIM_ACCOUNTS: usize = 7;
+
+/// Maximum number of users committed for by one `custody_commit_batch` call
+pub const MAX_CUSTODY_COMMIT_BATCH_SIZE: usize = 10;
+
+/// Maximum number of payment mints approved by `LaunchpadConfig`
+pub const MAX_APPROVED_PAYMENT_MINTS: usize = 16;
+
+/// Maximum number of sale mints blocked by `LaunchpadConfig`
+pub const MAX_BLOCKED_SALE_MINTS: usize = 8;
+
+/// Maximum number of integrating front-ends registered by `LaunchpadConfig`
+pub const MAX_REGISTERED_FRONTENDS: usize = 16;
+
+/// Maximum number of payment mints tracked separately by `LaunchpadStats`
+pub const MAX_STATS_PAYMENT_MINTS: usize = 16;
+
+/// Maximum number of users exempt from claim fees in `AuctionExtensions`
+pub const MAX_CLAIM_FEE_EXEMPT_USERS: usize = 8;
+
+/// Maximum number of whitelist authorities in `AuctionExtensions`
+pub const MAX_WHITELIST_AUTHORITIES: usize = 3;
+
+/// Maximum length in bytes of the emergency control pause message
+pub const MAX_PAUSE_MESSAGE_LEN: usize = 64;
 
 /// Core auction data account
 /// PDA: ["auction", sale_token_mint]
@@ -42,21 +110,129 @@ pub struct Auction {
     /// withdrawn, which is used to prevent double withdrawal by `withdraw_funds`
     pub unsold_sale_tokens_and_effective_payment_tokens_withdrawn: bool,
 
+    /// Whether `finalize_auction` has cached the allocation ratio of every bin
+    pub finalized: bool,
+
+    /// Whether `force_refund_mode` froze sale token claims, refunding the
+    /// effective payment of unclaimed sale tokens instead
+    pub force_refund: bool,
+
+    /// Whether `cancel_auction` pulled the launch before its commit period, blocking
+    /// every further operation
+    pub cancelled: bool,
+
+    /// Whether `abort_auction` turned the auction refund-only, refunding every
+    /// commitment in full and blocking further commits
+    pub aborted: bool,
+
+    /// Number of consecutive rounds the bins are split into (1 without rounds)
+    pub round_count: u8,
+    /// Number of rounds closed by `close_round`, i.e. the index of the open round
+    pub rounds_closed: u8,
+
+    /// Sale tokens owed to all users, recorded by `finalize_auction`
+    pub total_sale_entitled: u64,
+    /// Payment tokens refunded to all users, recorded by `finalize_auction`
+    pub total_refund_entitled: u64,
+
+    /// Payment tokens refunded to users by `claim` and `refund_batch`
+    pub total_payment_refunded: u64,
+    /// Payment tokens of fully claimed commitments not refunded, i.e. settled proceeds
+    pub total_payment_released: u64,
+    /// Payment tokens withdrawn by `withdraw_funds`
+    pub total_payment_withdrawn: u64,
+    /// Payment tokens of late refunds diverted to the late refund pool by `claim`
+    pub total_refund_diverted: u64,
+    /// Payment tokens withdrawn by `withdraw_late_refund_pool`
+    pub total_pool_withdrawn: u64,
+    /// Sale tokens left unclaimed at the claim deadline, swept by `sweep_unclaimed`
+    pub total_sale_swept: u64,
+    /// Refunds left unclaimed at the claim deadline, swept by `sweep_unclaimed`
+    pub total_payment_swept: u64,
+    /// Payment tokens escrowed by waitlist entries not promoted or left yet
+    pub waitlist_escrowed: u64,
+    /// Lamports paid by the lamport treasury for token accounts created on behalf of users
+    pub total_rent_funded: u64,
+
     /// Total fees collected from claimed sale tokens
     pub total_fees_collected: u64,
     /// Fees withdrawn already
     pub total_fees_withdrawn: u64,
+    /// Share of the claim fees accrued to front-ends users committed through
+    pub total_frontend_fees_accrued: u64,
+    /// Front-end fees withdrawn by `withdraw_frontend_fees`, also in `total_fees_withdrawn`
+    pub total_frontend_fees_withdrawn: u64,
+
+    /// Security deposit lamports still escrowed in the auction account
+    pub security_deposit_held: u64,
+    /// Security deposit lamports slashed to participants by `force_refund_mode`
+    pub security_deposit_slashed: u64,
+
+    /// Real sale token mint delivered by `redeem` (IOU mode) or `swap_placeholder`,
+    /// set by `enable_redemption` / `set_redemption_mint`
+    pub redemption_mint: Option<Pubkey>,
+    /// Placeholder sale tokens swapped by `swap_placeholder`, bounded per user by the
+    /// placeholders recorded in their redemption ledger
+    pub placeholder_swapped: u64,
+
+    /// Hash of the sale terms users acknowledge by passing it to `commit`, set by
+    /// `set_terms_hash` (all zeros until then). Users who acknowledged earlier terms
+    /// accept the updated ones on `claim` before claiming sale tokens
+    pub terms_hash: [u8; 32],
+
+    /// Keys delegated individual operations of the authority, set by `set_auction_roles`
+    pub roles: AuctionRoles,
 
     /// Vault PDA bump seeds for derivation
     pub vault_sale_bump: u8,
     pub vault_payment_bump: u8,
+    pub vault_redemption_bump: u8,
     /// PDA bump seed
     pub bump: u8,
 }
 
 impl Auction {
-    pub const BASE_SPACE: usize = 8 + 32 * 4 + 8 * 3 + 4 + (33 + 9 + 9) + 8 + 8 + 1 + 1 + 1;
-    pub const SPACE_PER_BIN: usize = 8 + 8 + 8 + 8 + 1; // 33 bytes per bin
+    pub const BASE_SPACE: usize = 8
+        + 32 * 4
+        + 8 * 3
+        + 4
+        + AuctionExtensions::SPACE
+        + EmergencyState::SPACE
+        + 8
+        + 1
+        + 1
+        + 1
+        + 1
+        + 1
+        + 1
+        + 1
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 33
+        + 8
+        + 32
+        + AuctionRoles::SPACE
+        + 1
+        + 1
+        + 1
+        + 1;
+    pub const SPACE_PER_BIN: usize =
+        8 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 8 + 8 + 1 + 9 + 8 + 8 + 8 + 8 + 2 + 8 + 8 + 8 + 8; // 150 bytes per bin
 
     /// Calculate space needed for auction with given number of bins
     pub fn space_for_bins(bin_count: usize) -> usize {
@@ -65,17 +241,149 @@ impl Auction {
 
     /// Find the PDA address for an auction
     pub fn find_program_address(sale_token: &Pubkey) -> (Pubkey, u8) {
-        Pubkey::find_program_address(&[AUCTION_SEED, sale_token.as_ref()], &crate::ID)
+        crate::pda::find_auction_address(sale_token)
     }
 
     /// Find the PDA address for sale vault
     pub fn derive_sale_vault_pda(auction_pda: &Pubkey) -> (Pubkey, u8) {
-        Pubkey::find_program_address(&[VAULT_SALE_SEED, auction_pda.as_ref()], &crate::ID)
+        crate::pda::find_vault_sale_address(auction_pda)
     }
 
     /// Find the PDA address for payment vault
     pub fn derive_payment_vault_pda(auction_pda: &Pubkey) -> (Pubkey, u8) {
-        Pubkey::find_program_address(&[VAULT_PAYMENT_SEED, auction_pda.as_ref()], &crate::ID)
+        crate::pda::find_vault_payment_address(auction_pda)
+    }
+
+    /// Find the PDA address for the auction's lamport treasury, a system account
+    /// funding token account rent, see `create_associated_token_account`
+    pub fn derive_lamport_treasury_pda(auction_pda: &Pubkey) -> (Pubkey, u8) {
+        crate::pda::find_lamport_treasury_address(auction_pda)
+    }
+
+    /// Find the PDA address for the auction's event authority
+    pub fn derive_event_authority_pda(auction_pda: &Pubkey) -> (Pubkey, u8) {
+        crate::pda::find_event_authority_address(auction_pda)
+    }
+
+    /// Find the PDA address for redemption vault
+    pub fn derive_redemption_vault_pda(auction_pda: &Pubkey) -> (Pubkey, u8) {
+        crate::pda::find_vault_redemption_address(auction_pda)
+    }
+
+    /// Calculate total payment tokens raised across all bins
+    pub fn total_payment_raised(&self) -> Result<u64> {
+        let mut total_payment_raised = 0u64;
+        for bin in self.bins.iter() {
+            total_payment_raised = total_payment_raised
+                .checked_add(bin.payment_token_raised)
+                .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+        }
+        Ok(total_payment_raised)
+    }
+
+    /// Whether the bins are split into consecutive rounds, each with a `Round` account
+    pub fn has_rounds(&self) -> bool {
+        self.round_count > 1
+    }
+
+    /// End of the refund reservation, after which refunds can't be claimed and
+    /// `withdraw_funds` releases the whole payment vault (None without reservation)
+    pub fn refund_deadline(&self) -> Option<i64> {
+        self.extensions
+            .reserved_refund_window
+            .map(|window| self.claim_start_time.saturating_add(window))
+    }
+
+    /// End of the claim period, after which `sweep_unclaimed` recovers what users left
+    /// (None without claim deadline)
+    pub fn claim_end_time(&self) -> Option<i64> {
+        self.extensions
+            .claim_deadline
+            .as_ref()
+            .map(|deadline| self.claim_start_time.saturating_add(deadline.claim_window))
+    }
+
+    /// Front-end fees accrued but not withdrawn yet, reserved from `withdraw_fees`
+    pub fn frontend_fees_owed(&self) -> u64 {
+        self.total_frontend_fees_accrued
+            .saturating_sub(self.total_frontend_fees_withdrawn)
+    }
+
+    /// Whether sale tokens and refunds can still be claimed at `current_time`
+    pub fn claims_open(&self, current_time: i64) -> bool {
+        self.claim_end_time()
+            .map_or(true, |claim_end_time| current_time <= claim_end_time)
+    }
+
+    /// End of refund claims: the refund deadline, extended by the ramp of the late
+    /// refund pool (None without reservation)
+    pub fn refund_close_time(&self) -> Option<i64> {
+        let refund_deadline = self.refund_deadline()?;
+        Some(match &self.extensions.late_refund_pool {
+            Some(pool) => refund_deadline.saturating_add(pool.ramp_period),
+            None => refund_deadline,
+        })
+    }
+
+    /// Whether refunds can be claimed at `current_time`
+    pub fn refunds_open(&self, current_time: i64) -> bool {
+        match self.refund_close_time() {
+            Some(close_time) => self.force_refund || self.aborted || current_time <= close_time,
+            None => true,
+        }
+    }
+
+    /// Share in basis points of a refund claimed at `current_time` diverted to the
+    /// late refund pool, growing linearly over the ramp after the refund deadline
+    pub fn late_refund_diverted_bps(&self, current_time: i64) -> u64 {
+        match (&self.extensions.late_refund_pool, self.refund_deadline()) {
+            (Some(pool), Some(refund_deadline))
+                if !self.force_refund && !self.aborted && current_time > refund_deadline =>
+            {
+                let late = (current_time - refund_deadline) as u128;
+                (late * 10000 / pool.ramp_period as u128).min(10000) as u64
+            }
+            _ => 0,
+        }
+    }
+
+    /// Payment tokens owed to the late refund pool at `current_time`: the diverted
+    /// share of late refunds, and every refund left unclaimed once refunds close
+    pub fn late_refund_pool_accrued(&self, current_time: i64) -> u64 {
+        match self.refund_close_time() {
+            Some(close_time)
+                if self.extensions.late_refund_pool.is_some()
+                    && !self.aborted
+                    && current_time > close_time =>
+            {
+                self.total_refund_entitled
+                    .saturating_sub(self.total_payment_refunded)
+            }
+            _ => self.total_refund_diverted,
+        }
+    }
+
+    /// Key allowed to perform the operations of `role`, the authority unless delegated
+    pub fn role_holder(&self, role: AuctionRole) -> Pubkey {
+        let delegate = match role {
+            AuctionRole::Pauser => self.roles.pauser,
+            AuctionRole::PriceSetter => self.roles.price_setter,
+            AuctionRole::FeeManager => self.roles.fee_manager,
+            AuctionRole::FundWithdrawer => self.roles.fund_withdrawer,
+        };
+        delegate.unwrap_or(self.authority)
+    }
+
+    /// Whether the auction only refunds commitments and distributes no sale tokens,
+    /// i.e. it was aborted or the commit period has ended below the configured soft cap
+    pub fn is_refund_only(&self, current_time: i64) -> bool {
+        self.aborted
+            || current_time > self.commit_end_time
+                && self.extensions.soft_cap.is_some_and(|soft_cap| {
+                    // Raising more than `u64::MAX` clears any soft cap
+                    self.total_payment_raised()
+                        .is_ok_and(|total_payment_raised| total_payment_raised < soft_cap)
+                })
     }
 
     /// Get a specific bin by ID
@@ -93,12 +401,29 @@ impl Auction {
     }
 }
 
-/// Check if an operation is paused by emergency control
-pub fn check_emergency_state(auction: &Auction, operation_flag: u64) -> Result<()> {
+/// Check if an operation is paused by emergency control, on the auction or program-wide,
+/// the auction is cancelled, or a commit targets an aborted auction
+pub fn check_emergency_state(
+    auction: &Auction,
+    config: &LaunchpadConfig,
+    operation_flag: u64,
+) -> Result<()> {
+    require!(
+        !auction.cancelled,
+        crate::errors::LauchpadError::AuctionCancelled
+    );
+    require!(
+        !auction.aborted || operation_flag != EmergencyState::PAUSE_AUCTION_COMMIT,
+        crate::errors::LauchpadError::AuctionAborted
+    );
     require!(
         !auction.emergency_state.is_paused(operation_flag),
         crate::errors::LauchpadError::OperationPaused
     );
+    require!(
+        !config.is_paused(operation_flag),
+        crate::errors::LauchpadError::GloballyPaused
+    );
 
     Ok(())
 }
@@ -114,6 +439,218 @@ pub struct AuctionBin {
     pub payment_token_raised: u64,
     /// Sale tokens already claimed from this bin
     pub sale_token_claimed: u64,
+    /// Allocation ratio scaled by `PRECISION_FACTOR`, cached by `finalize_auction`
+    pub allocation_ratio: u64,
+    /// Fixed sale tokens each registered user claims (free-claim bins only)
+    pub free_claim_amount: u64,
+    /// Number of users registered for the free claim (free-claim bins only)
+    pub free_claim_registered: u64,
+    /// Kind of the bin
+    pub kind: BinKind,
+    /// Rejects commitments beyond the cap instead of allocating pro-rata (sale bins only)
+    pub hard_cap: bool,
+    /// Sequence number of the next waitlist entry to promote
+    pub waitlist_head: u64,
+    /// Sequence number assigned to the next waitlist entry
+    pub waitlist_tail: u64,
+    /// Round the bin sells in
+    pub round: u8,
+    /// Claim fee rate of this bin, overriding the auction `claim_fee_rate` (if set)
+    pub claim_fee_rate: Option<u64>,
+    /// Claim fees collected from this bin
+    pub fees_collected: u64,
+    /// Number of distinct users who committed to this bin
+    pub participants: u64,
+    /// Payment tokens committed through the custody, directly or by its authorization
+    pub custody_committed: u64,
+    /// Payment tokens of commitments boosted by `boost_commitment`
+    pub boosted_committed: u64,
+    /// Allocation weight of boosted commitments in basis points (0 if boosting is disabled)
+    pub boost_weight_bps: u16,
+    /// Time the price was last set, at creation or by `set_price`
+    pub price_updated_at: i64,
+    /// Payment tokens committed in the batch window, allowed past the hard cap and
+    /// sharing what earlier commitments left of the target pro-rata
+    pub window_committed: u64,
+    /// Sale tokens claimed net of the claim fees, i.e. what claims took out of the vault
+    pub sale_token_distributed: u64,
+    /// Sale tokens reserved by commitments in reservation mode
+    pub sale_token_reserved: u64,
+}
+
+impl AuctionBin {
+    /// Create a bin without commitments from its parameters
+    pub fn from_params(params: AuctionBinParams, boost_weight_bps: u16, current_time: i64) -> Self {
+        Self {
+            sale_token_price: params.sale_token_price,
+            sale_token_cap: params.sale_token_cap,
+            payment_token_raised: 0,
+            sale_token_claimed: 0,
+            allocation_ratio: 0,
+            free_claim_amount: params.free_claim_amount,
+            free_claim_registered: 0,
+            kind: params.kind,
+            hard_cap: params.hard_cap,
+            waitlist_head: 0,
+            waitlist_tail: 0,
+            round: params.round,
+            claim_fee_rate: params.claim_fee_rate,
+            fees_collected: 0,
+            participants: 0,
+            custody_committed: 0,
+            boosted_committed: 0,
+            boost_weight_bps,
+            price_updated_at: current_time,
+            window_committed: 0,
+            sale_token_distributed: 0,
+            sale_token_reserved: 0,
+        }
+    }
+
+    /// Parameters the bin was created from
+    pub fn to_params(&self) -> AuctionBinParams {
+        AuctionBinParams {
+            sale_token_price: self.sale_token_price,
+            sale_token_cap: self.sale_token_cap,
+            free_claim_amount: self.free_claim_amount,
+            kind: self.kind,
+            hard_cap: self.hard_cap,
+            round: self.round,
+            claim_fee_rate: self.claim_fee_rate,
+        }
+    }
+
+    /// Payment tokens needed to sell the whole cap
+    pub fn payment_token_target(&self) -> Result<u64> {
+        self.sale_token_cap
+            .checked_mul(self.sale_token_price)
+            .ok_or(crate::errors::LauchpadError::MathOverflow.into())
+    }
+
+    /// Whether users are waiting for room in this bin
+    pub fn has_waitlist(&self) -> bool {
+        self.waitlist_head < self.waitlist_tail
+    }
+
+    /// Promote the waitlist head escrowing `payment_tokens` into the raise, returning
+    /// whether it fits the target (the waitlist doesn't advance otherwise)
+    pub fn promote_waitlist_head(
+        &mut self,
+        payment_tokens: u64,
+        reserve_at_commit: bool,
+    ) -> Result<bool> {
+        let raised = self
+            .payment_token_raised
+            .checked_add(payment_tokens)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+        if raised > self.payment_token_target()? {
+            return Ok(false);
+        }
+        if reserve_at_commit {
+            self.reserve(payment_tokens)?;
+        }
+        self.payment_token_raised = raised;
+        self.waitlist_head = self
+            .waitlist_head
+            .checked_add(1)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+        Ok(true)
+    }
+
+    /// Record a claim of `sale_tokens` from this bin, of which the vault keeps `claim_fee`
+    pub fn record_claim(&mut self, sale_tokens: u64, claim_fee: u64) -> Result<()> {
+        let distributed = sale_tokens
+            .checked_sub(claim_fee)
+            .ok_or(crate::errors::LauchpadError::MathUnderflow)?;
+        self.sale_token_claimed = self
+            .sale_token_claimed
+            .checked_add(sale_tokens)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+        self.sale_token_distributed = self
+            .sale_token_distributed
+            .checked_add(distributed)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+        self.fees_collected = self
+            .fees_collected
+            .checked_add(claim_fee)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Sale tokens `payment_tokens` pays for at the bin price, failing unless whole
+    pub fn reservable_sale_tokens(&self, payment_tokens: u64) -> Result<u64> {
+        require!(
+            self.sale_token_price > 0 && payment_tokens % self.sale_token_price == 0,
+            crate::errors::LauchpadError::PartialSaleTokenReservation
+        );
+        Ok(payment_tokens / self.sale_token_price)
+    }
+
+    /// Reserve the sale tokens a commitment of `payment_tokens` pays for, within the cap
+    pub fn reserve(&mut self, payment_tokens: u64) -> Result<()> {
+        let sale_token_reserved = self
+            .sale_token_reserved
+            .checked_add(self.reservable_sale_tokens(payment_tokens)?)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+        require!(
+            sale_token_reserved <= self.sale_token_cap,
+            crate::errors::LauchpadError::CommitmentBinCapExceeded
+        );
+        self.sale_token_reserved = sale_token_reserved;
+        Ok(())
+    }
+
+    /// Release the sale tokens reserved by `payment_tokens` of a decreased commitment
+    pub fn release_reservation(&mut self, payment_tokens: u64) -> Result<()> {
+        self.sale_token_reserved = self
+            .sale_token_reserved
+            .checked_sub(self.reservable_sale_tokens(payment_tokens)?)
+            .ok_or(crate::errors::LauchpadError::MathUnderflow)?;
+        Ok(())
+    }
+
+    /// Count a user committing to this bin for the first time
+    pub fn add_participant(&mut self) -> Result<()> {
+        self.participants = self
+            .participants
+            .checked_add(1)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Record a custody-routed commitment, keeping custody commitments within
+    /// `custody_allocation_limit` basis points of the bin target (if enabled)
+    pub fn add_custody_commitment(
+        &mut self,
+        payment_token_committed: u64,
+        custody_allocation_limit: Option<u16>,
+    ) -> Result<()> {
+        self.custody_committed = self
+            .custody_committed
+            .checked_add(payment_token_committed)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+        if let Some(limit_bps) = custody_allocation_limit {
+            let custody_allocation =
+                self.payment_token_target()? as u128 * limit_bps as u128 / 10000;
+            require!(
+                self.custody_committed as u128 <= custody_allocation,
+                crate::errors::LauchpadError::CustodyAllocationLimitExceeded
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Kind of an auction bin
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinKind {
+    /// Sells sale tokens at a price, oversubscription is refunded
+    Sale,
+    /// Non-refundable donation that grants no sale tokens, excluded from allocation math
+    Donation,
+    /// Zero-price airdrop, whitelisted users register by committing nothing and
+    /// claim `free_claim_amount` each
+    FreeClaim,
 }
 
 /// Parameters for creating auction bins
@@ -121,73 +658,960 @@ pub struct AuctionBin {
 pub struct AuctionBinParams {
     pub sale_token_price: u64,
     pub sale_token_cap: u64,
+    /// Fixed sale tokens per registered user, only for free-claim bins
+    pub free_claim_amount: u64,
+    pub kind: BinKind,
+    /// Reject commitments beyond the cap, only for sale bins
+    pub hard_cap: bool,
+    /// Round the bin sells in, bins must be ordered by round starting from 0
+    pub round: u8,
+    /// Claim fee rate overriding the auction `claim_fee_rate` for this bin
+    pub claim_fee_rate: Option<u64>,
+}
+
+/// Global launchpad configuration maintained by the LaunchpadAdmin
+/// PDA: ["config"]
+#[account]
+pub struct LaunchpadConfig {
+    /// Payment mints new auctions may raise in
+    pub approved_payment_mints: Vec<Pubkey>,
+    /// Whether new auctions may raise in mints missing from `approved_payment_mints`
+    pub allow_unlisted_payment_mints: bool,
+    /// Minimum seconds new auctions must leave between commit end and claim start
+    pub min_claim_grace_period: i64,
+    /// Sale mints whose auctions can't `claim` or `withdraw_funds`, e.g. exploited tokens
+    pub blocked_sale_mints: Vec<Pubkey>,
+    /// Governance or multisig expected to hold the program upgrade authority (if set)
+    pub governance_authority: Option<Pubkey>,
+    /// Share of withdrawn fees routed to the launchpad token stakers (if set)
+    pub revenue_share: Option<RevenueShare>,
+    /// Operations paused on every auction, same flags as `EmergencyState`
+    pub paused_operations: u64,
+    /// Integrating front-ends users may `commit` through
+    pub registered_frontends: Vec<Pubkey>,
+    /// Share of the claim fees of commitments made through a front-end accrued to it,
+    /// in basis points
+    pub frontend_fee_share_bps: u16,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl LaunchpadConfig {
+    pub const SPACE: usize = 8
+        + 4
+        + 32 * MAX_APPROVED_PAYMENT_MINTS
+        + 1
+        + 8
+        + 4
+        + 32 * MAX_BLOCKED_SALE_MINTS
+        + 33
+        + 1
+        + RevenueShare::SPACE
+        + 8
+        + 4
+        + 32 * MAX_REGISTERED_FRONTENDS
+        + 2
+        + 1;
+
+    /// Find the PDA address for the launchpad config
+    pub fn find_program_address() -> (Pubkey, u8) {
+        crate::pda::find_config_address()
+    }
+
+    /// Whether an operation is paused program-wide
+    pub fn is_paused(&self, operation_flag: u64) -> bool {
+        self.paused_operations & operation_flag != 0
+    }
+
+    /// Whether new auctions may raise in `payment_token_mint`
+    pub fn is_payment_mint_allowed(&self, payment_token_mint: &Pubkey) -> bool {
+        self.allow_unlisted_payment_mints
+            || self.approved_payment_mints.contains(payment_token_mint)
+    }
+
+    /// Whether auctions selling `sale_token_mint` are blocked program-wide
+    pub fn is_sale_mint_blocked(&self, sale_token_mint: &Pubkey) -> bool {
+        self.blocked_sale_mints.contains(sale_token_mint)
+    }
+
+    /// Whether users may commit through the front-end `frontend_id`
+    pub fn is_frontend_registered(&self, frontend_id: &Pubkey) -> bool {
+        self.registered_frontends.contains(frontend_id)
+    }
+}
+
+/// Fee routing to the staking pool of the launchpad token
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RevenueShare {
+    /// Owner of the staking rewards vaults, one token account per fee mint
+    pub staking_rewards_authority: Pubkey,
+    /// Share of every `withdraw_fees` sent to the staking rewards vault, in basis points
+    pub share_bps: u16,
+}
+
+impl RevenueShare {
+    pub const SPACE: usize = 32 + 2;
 }
 
-/// Individual bin commitment data within a user's commitment
+/// Platform fee taken from the payment tokens of every `withdraw_funds`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProtocolFee {
+    /// Owner of the platform treasury, one token account per payment mint
+    pub treasury: Pubkey,
+    /// Share of the withdrawn payment tokens sent to the treasury, in basis points
+    pub fee_bps: u16,
+}
+
+impl ProtocolFee {
+    pub const SPACE: usize = 32 + 2;
+}
+
+/// Program-wide admin, rotatable without redeploying
+/// PDA: ["global_config"]
+#[account]
+pub struct GlobalConfig {
+    /// LaunchpadAdmin, authority of new auctions and of the launchpad config
+    pub admin: Pubkey,
+    /// Platform fee on the proceeds of every auction (if set)
+    pub protocol_fee: Option<ProtocolFee>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl GlobalConfig {
+    pub const SPACE: usize = 8 + 32 + 1 + ProtocolFee::SPACE + 1;
+
+    /// Find the PDA address for the global config
+    pub fn find_program_address() -> (Pubkey, u8) {
+        crate::pda::find_global_config_address()
+    }
+}
+
+/// Launchpad-wide aggregate metrics, updated as auctions settle
+/// PDA: ["stats"]
+#[account]
+pub struct LaunchpadStats {
+    /// Number of finalized auctions
+    pub total_auctions: u64,
+    /// Totals per payment mint, the first `MAX_STATS_PAYMENT_MINTS` mints settled
+    pub payment_mints: Vec<PaymentMintStats>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Launchpad-wide totals of auctions raising in one payment mint
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PaymentMintStats {
+    /// Payment token mint
+    pub mint: Pubkey,
+    /// Number of finalized auctions raising in the mint
+    pub auctions: u64,
+    /// Payment tokens raised and kept, i.e. excluding refunds
+    pub total_raised: u64,
+    /// Claim fees withdrawn, valued in payment tokens at the price of their bins
+    pub total_fees: u64,
+}
+
+impl LaunchpadStats {
+    pub const SPACE: usize = 8 + 8 + 4 + (32 + 8 * 3) * MAX_STATS_PAYMENT_MINTS + 1;
+
+    /// Find the PDA address for the launchpad stats
+    pub fn find_program_address() -> (Pubkey, u8) {
+        crate::pda::find_stats_address()
+    }
+
+    /// Totals of `mint`, started if there is room for another mint
+    fn payment_mint_mut(&mut self, mint: &Pubkey) -> Option<&mut PaymentMintStats> {
+        let index = match self
+            .payment_mints
+            .iter()
+            .position(|stats| stats.mint == *mint)
+        {
+            Some(index) => index,
+            None if self.payment_mints.len() < MAX_STATS_PAYMENT_MINTS => {
+                self.payment_mints.push(PaymentMintStats {
+                    mint: *mint,
+                    auctions: 0,
+                    total_raised: 0,
+                    total_fees: 0,
+                });
+                self.payment_mints.len() - 1
+            }
+            None => return None,
+        };
+        self.payment_mints.get_mut(index)
+    }
+
+    /// Record a finalized auction and the payment tokens it kept
+    pub fn record_auction(&mut self, payment_token_mint: &Pubkey, raised: u64) -> Result<()> {
+        self.total_auctions = self
+            .total_auctions
+            .checked_add(1)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+        if let Some(stats) = self.payment_mint_mut(payment_token_mint) {
+            stats.auctions = stats
+                .auctions
+                .checked_add(1)
+                .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+            stats.total_raised = stats
+                .total_raised
+                .checked_add(raised)
+                .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+        }
+        Ok(())
+    }
+
+    /// Take back the payment tokens a finalized auction kept once it is aborted,
+    /// it still counts as a settled auction
+    pub fn record_abort(&mut self, payment_token_mint: &Pubkey, kept: u64) -> Result<()> {
+        if let Some(stats) = self
+            .payment_mints
+            .iter_mut()
+            .find(|stats| stats.mint == *payment_token_mint)
+        {
+            stats.total_raised = stats
+                .total_raised
+                .checked_sub(kept)
+                .ok_or(crate::errors::LauchpadError::MathUnderflow)?;
+        }
+        Ok(())
+    }
+
+    /// Record withdrawn claim fees, valued in `payment_token_mint`
+    pub fn record_fees(&mut self, payment_token_mint: &Pubkey, fees: u64) -> Result<()> {
+        if let Some(stats) = self.payment_mint_mut(payment_token_mint) {
+            stats.total_fees = stats
+                .total_fees
+                .checked_add(fees)
+                .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+        }
+        Ok(())
+    }
+}
+
+/// Cross-auction loyalty profile of a user, updated when a commitment is fully claimed
+/// PDA: ["user_profile", user_key]
+#[account]
+pub struct UserProfile {
+    /// Profile owner
+    pub user: Pubkey,
+    /// Number of auctions fully claimed
+    pub auctions_participated: u64,
+    /// Cumulative payment tokens committed across those auctions
+    pub total_payment_committed: u64,
+    /// Cumulative sale tokens claimed across those auctions
+    pub total_sale_tokens_claimed: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl UserProfile {
+    pub const SPACE: usize = 8 + 32 + 8 * 3 + 1;
+
+    /// Find the PDA address for a user's profile
+    pub fn find_program_address(user: &Pubkey) -> (Pubkey, u8) {
+        crate::pda::find_user_profile_address(user)
+    }
+
+    /// Loyalty tier, 0 until the first threshold in `LOYALTY_TIER_THRESHOLDS` is reached
+    pub fn tier(&self) -> u8 {
+        LOYALTY_TIER_THRESHOLDS
+            .iter()
+            .filter(|threshold| self.auctions_participated >= **threshold)
+            .count() as u8
+    }
+
+    /// Record a fully claimed commitment
+    pub fn record_participation(&mut self, committed: &Committed) -> Result<()> {
+        self.auctions_participated = self
+            .auctions_participated
+            .checked_add(1)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+        self.total_payment_committed = self
+            .total_payment_committed
+            .checked_add(committed.total_payment_committed())
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+        let sale_tokens_claimed = committed
+            .active_bins()
+            .map(|bin| bin.sale_token_claimed)
+            .sum::<u64>();
+        self.total_sale_tokens_claimed = self
+            .total_sale_tokens_claimed
+            .checked_add(sale_tokens_claimed)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+        Ok(())
+    }
+}
+
+/// Reusable auction configuration for `clone_auction`
+/// PDA: ["template", template_id]
+#[account]
+pub struct Template {
+    /// Template identifier
+    pub template_id: u64,
+    /// Bins of auctions cloned from this template
+    pub bins: Vec<AuctionBinParams>,
+    /// Custody account of auctions cloned from this template
+    pub custody: Pubkey,
+    /// Extension configuration, including fee settings
+    pub extensions: AuctionExtensions,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Template {
+    pub const BASE_SPACE: usize = 8 + 8 + 4 + 32 + AuctionExtensions::SPACE + 1;
+    pub const SPACE_PER_BIN: usize = 8 + 8 + 8 + 1 + 1 + 1 + 9; // 36 bytes per bin
+
+    /// Calculate space needed for a template with given number of bins
+    pub fn space_for_bins(bin_count: usize) -> usize {
+        Self::BASE_SPACE + (bin_count * Self::SPACE_PER_BIN)
+    }
+
+    /// Find the PDA address for a template
+    pub fn find_program_address(template_id: u64) -> (Pubkey, u8) {
+        crate::pda::find_template_address(template_id)
+    }
+}
+
+/// Individual bin commitment data within a user's commitment, kept in the slot of its
+/// bin in `Committed::bins`
+#[zero_copy]
+#[derive(Debug)]
 pub struct CommittedBin {
-    /// Bin ID
-    pub bin_id: u8,
     /// Amount of payment tokens committed to this bin
     pub payment_token_committed: u64,
     /// Amount of sale tokens already claimed from this bin
     pub sale_token_claimed: u64,
     /// Payment tokens already refunded from this bin
     pub payment_token_refunded: u64,
+    /// Claim fee rate recorded at commit time, weighted by amount across commitments
+    pub claim_fee_rate: u64,
+    /// Part of `payment_token_committed` boosted by `boost_commitment`, allocated at the
+    /// boosted weight and locked for the boost lock period after `claim_start_time`
+    pub boosted_committed: u64,
+    /// Part of `payment_token_committed` committed in the batch window of a hard-cap bin
+    pub window_committed: u64,
+    /// Attribution tag of the latest custody commitment to this bin, e.g. the hash of an
+    /// exchange user id, for reconciling the exchange's distribution (see `attribution()`)
+    pub attribution: [u8; 32],
+    /// Bin ID
+    pub bin_id: u8,
+    /// Whether the user committed to this bin, unused slots are all zeros
+    pub active: u8,
+    /// Whether `attribution` is set
+    pub has_attribution: u8,
+    pub _padding: [u8; 5],
+}
+
+impl CommittedBin {
+    /// Whether the user committed to this bin
+    pub fn is_active(&self) -> bool {
+        self.active != 0
+    }
+
+    /// Attribution tag of the latest custody commitment to this bin (if any)
+    pub fn attribution(&self) -> Option<[u8; 32]> {
+        (self.has_attribution != 0).then_some(self.attribution)
+    }
+
+    /// Record the attribution tag of a custody commitment to this bin
+    pub fn set_attribution(&mut self, attribution: [u8; 32]) {
+        self.attribution = attribution;
+        self.has_attribution = 1;
+    }
+}
+
+/// Amounts a user can still claim from a bin, returned by `get_remaining_entitlement`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RemainingEntitlement {
+    /// Sale tokens not claimed yet
+    pub sale_tokens: u64,
+    /// Payment tokens not refunded yet
+    pub refund_payment_tokens: u64,
+}
+
+/// Settlement preview of an auction, returned by `simulate_settlement`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SettlementSimulation {
+    /// Payment tokens raised across all bins
+    pub total_payment_raised: u64,
+    /// Sale tokens owed to users
+    pub total_sale_tokens_sold: u64,
+    /// Sale tokens left unsold
+    pub total_unsold_sale_tokens: u64,
+    /// Payment tokens still to be refunded to users
+    pub total_refunds_outstanding: u64,
+    /// Claim fees collected so far
+    pub total_fees_collected: u64,
+    /// Claim fees collected once every sold sale token is claimed
+    pub total_fees_projected: u64,
+    /// Claim fees `withdraw_fees` would transfer now
+    pub withdrawable_fees: u64,
+    /// Payment tokens `withdraw_funds` would transfer now
+    pub withdrawable_payment_tokens: u64,
+    /// Unsold sale tokens `withdraw_funds` would transfer now
+    pub withdrawable_unsold_sale_tokens: u64,
+}
+
+/// Upgrade-risk status of the program, returned by `verify_program_authority`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ProgramAuthorityStatus {
+    /// Current upgrade authority, none if the program is immutable
+    pub upgrade_authority: Option<Pubkey>,
+    /// Governance or multisig configured in `LaunchpadConfig` (if set)
+    pub governance_authority: Option<Pubkey>,
+    /// Whether the upgrade authority is the configured governance
+    pub is_governed: bool,
+}
+
+/// Dashboard overview of an auction for its project team, returned by `get_project_summary`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ProjectSummary {
+    /// Per-bin progress, indexed by bin ID
+    pub bins: Vec<BinSummary>,
+    /// Payment tokens raised across all bins
+    pub total_payment_raised: u64,
+    /// Number of distinct users who committed
+    pub total_participants: u64,
+    /// Claim fees collected so far
+    pub total_fees_collected: u64,
+    /// Claim fees withdrawn by `withdraw_fees`
+    pub total_fees_withdrawn: u64,
+    /// Share of sold sale tokens claimed, in basis points
+    pub sale_claim_progress_bps: u16,
+    /// Share of owed refunds paid out, in basis points
+    pub refund_progress_bps: u16,
+    /// Whether `finalize_auction` has run
+    pub finalized: bool,
+    /// Whether `withdraw_funds` has run
+    pub funds_withdrawn: bool,
+    /// Payment tokens withdrawn by `withdraw_funds`
+    pub total_payment_withdrawn: u64,
+}
+
+/// Progress of one auction bin in a `ProjectSummary`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BinSummary {
+    /// Payment tokens raised in the bin
+    pub payment_token_raised: u64,
+    /// Number of distinct users who committed to the bin
+    pub participants: u64,
+    /// Sale tokens claimed from the bin
+    pub sale_token_claimed: u64,
+    /// Claim fees collected from the bin
+    pub fees_collected: u64,
+}
+
+/// Unlock timeline of a user's sale tokens in a bin, returned by `get_vesting_schedule`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VestingSchedule {
+    /// Sale tokens the user is entitled to
+    pub total_sale_tokens: u64,
+    /// Sale tokens unlocked so far, claimed or not
+    pub unlocked_sale_tokens: u64,
+    /// Sale tokens claimed so far
+    pub claimed_sale_tokens: u64,
+    /// Time before which nothing unlocks
+    pub cliff_time: i64,
+    /// Time of the next unlock (if any sale tokens are still locked)
+    pub next_unlock_time: Option<i64>,
+    /// Sale tokens unlocking at `next_unlock_time`
+    pub next_unlock_amount: u64,
+    /// Time at which every sale token is unlocked
+    pub fully_vested_time: i64,
 }
 
-/// User commitment data for all auction bins
+/// Why a commitment would be rejected, see `CommitEligibility::limit` for the
+/// limit reported with each reason
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommitDenialReason {
+    /// The commitment would be accepted (limit: 0)
+    None,
+    /// Commitments are paused by emergency control (limit: 0)
+    Paused,
+    /// The auction was cancelled before its commit period (limit: 0)
+    Cancelled,
+    /// The auction was aborted and refunds every commitment (limit: 0)
+    Aborted,
+    /// The commit period has not started (limit: commit start time)
+    NotStarted,
+    /// The commit period has ended (limit: commit end time)
+    Ended,
+    /// The bin doesn't exist (limit: number of bins)
+    InvalidBin,
+    /// The round of the bin is not open (limit: round of the bin)
+    RoundNotOpen,
+    /// Free-claim bins take zero-amount registrations, other bins a positive amount (limit: 0)
+    InvalidAmount,
+    /// The amount exceeds the per-transaction limit (limit: per-transaction limit)
+    PerTxLimitExceeded,
+    /// Payment tokens are only accepted through `commit_nft` (limit: 0)
+    NftPaymentOnly,
+    /// The user already registered for the free-claim bin (limit: 0)
+    AlreadyRegistered,
+    /// Users are waiting for room in the hard-cap bin (limit: waitlist length)
+    WaitlistNotEmpty,
+    /// The amount exceeds the user's commit cap (limit: remaining cap)
+    UserCapExceeded,
+    /// The amount exceeds the room left in the bin (limit: remaining room)
+    BinCapExceeded,
+    /// The bin's price is older than the maximum price age (limit: time the price was set)
+    PriceStale,
+}
+
+/// Result of `can_commit`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CommitEligibility {
+    pub reason: CommitDenialReason,
+    /// Limit relevant to `reason`, documented on each reason
+    pub limit: u64,
+}
+
+impl CommitEligibility {
+    pub const ALLOWED: Self = Self::denied(CommitDenialReason::None, 0);
+
+    pub const fn denied(reason: CommitDenialReason, limit: u64) -> Self {
+        Self { reason, limit }
+    }
+
+    pub fn is_allowed(&self) -> bool {
+        self.reason == CommitDenialReason::None
+    }
+}
+
+/// User commitment data for all auction bins, zero-copy with a slot for every bin an
+/// auction can have so the account never grows
 /// PDA: ["committed", auction_key, user_key]
-#[account]
+#[account(zero_copy)]
 pub struct Committed {
     /// Reference to the auction account
     pub auction: Pubkey,
     /// User who made the commitment
     pub user: Pubkey,
-    /// All bins this user has committed to
-    pub bins: Vec<CommittedBin>,
     /// User's nonce for whitelist signature verification (prevents replay attacks)
     pub nonce: u64,
+    /// Hash of the sale terms the user last acknowledged, by any commitment or `claim`
+    pub terms_hash: [u8; 32],
+    /// Payment tokens of the user's late refunds diverted to the late refund pool
+    pub refund_diverted: u64,
+    /// Claim vouchers issued by `issue_claim_voucher`, the nonce of the next one
+    pub vouchers_issued: u64,
+    /// Payment tokens escrowed in the user's waitlist entries, counted against the
+    /// commit cap until promoted or taken back
+    pub waitlist_pending: u64,
+    /// Front-end the user first committed through, accruing a share of the claim fees
+    /// (all zeros for direct commitments)
+    pub frontend_id: Pubkey,
+    /// Commitments of the user, the slot of each bin at index `bin_id`
+    pub bins: [CommittedBin; MAX_AUCTION_BINS],
     /// PDA bump seed
     pub bump: u8,
+    pub _padding: [u8; 7],
 }
 
 impl Committed {
-    pub const BASE_SPACE: usize = 8 + 32 * 2 + 4 + 8 + 1; // 85 bytes base
-    pub const SPACE_PER_BIN: usize = 1 + 8 + 8 + 8; // 25 bytes per CommittedBin
-
-    /// Calculate space needed for commitment with given number of bins
-    pub fn space_for_bins(bin_count: usize) -> usize {
-        Self::BASE_SPACE + (bin_count * Self::SPACE_PER_BIN)
-    }
+    pub const SPACE: usize = 8 + std::mem::size_of::<Committed>(); // 1056 bytes
 
     /// Find the PDA address for a user commitment (no bin_id)
     pub fn find_program_address(auction: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
-        Pubkey::find_program_address(
-            &[COMMITTED_SEED, auction.as_ref(), user.as_ref()],
-            &crate::ID,
-        )
+        crate::pda::find_committed_address(auction, user)
     }
 
     /// Find a specific bin commitment
     pub fn find_bin(&self, bin_id: u8) -> Option<&CommittedBin> {
-        self.bins.iter().find(|bin| bin.bin_id == bin_id)
+        self.bins
+            .get(bin_id as usize)
+            .filter(|committed_bin| committed_bin.is_active())
     }
 
     /// Find a specific bin commitment (mutable)
     pub fn find_bin_mut(&mut self, bin_id: u8) -> Option<&mut CommittedBin> {
-        self.bins.iter_mut().find(|bin| bin.bin_id == bin_id)
+        self.bins
+            .get_mut(bin_id as usize)
+            .filter(|committed_bin| committed_bin.is_active())
     }
 
-    /// Calculate total payment tokens committed across all bins
-    pub fn total_payment_committed(&self) -> u64 {
+    /// Bins the user committed to, by `bin_id`
+    pub fn active_bins(&self) -> impl Iterator<Item = &CommittedBin> {
         self.bins
             .iter()
+            .filter(|committed_bin| committed_bin.is_active())
+    }
+
+    /// Bins the user committed to, by `bin_id` (mutable)
+    pub fn active_bins_mut(&mut self) -> impl Iterator<Item = &mut CommittedBin> {
+        self.bins
+            .iter_mut()
+            .filter(|committed_bin| committed_bin.is_active())
+    }
+
+    /// Whether the user committed to any bin yet
+    pub fn has_commitments(&self) -> bool {
+        self.active_bins().next().is_some()
+    }
+
+    /// Add a commitment to a bin at the claim fee rate in effect, averaging the
+    /// recorded rate by amount with earlier commitments to the bin
+    pub fn add_commitment(
+        &mut self,
+        bin_id: u8,
+        payment_token_committed: u64,
+        claim_fee_rate: u64,
+        bin_count: usize,
+    ) -> Result<()> {
+        // CHECK: commitments are to a bin of the auction
+        require!(
+            (bin_id as usize) < bin_count.min(MAX_AUCTION_BINS),
+            crate::errors::LauchpadError::InvalidBinId
+        );
+        let committed_bin = &mut self.bins[bin_id as usize];
+        if committed_bin.is_active() {
+            let total_committed = committed_bin
+                .payment_token_committed
+                .checked_add(payment_token_committed)
+                .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+            if total_committed > 0 {
+                committed_bin.claim_fee_rate = ((committed_bin.payment_token_committed as u128
+                    * committed_bin.claim_fee_rate as u128
+                    + payment_token_committed as u128 * claim_fee_rate as u128)
+                    / total_committed as u128)
+                    as u64;
+            }
+            committed_bin.payment_token_committed = total_committed;
+        } else {
+            *committed_bin = CommittedBin {
+                payment_token_committed,
+                claim_fee_rate,
+                bin_id,
+                active: 1,
+                ..bytemuck::Zeroable::zeroed()
+            };
+        }
+        Ok(())
+    }
+
+    /// Commit a promoted waitlist escrow to a bin, no longer pending
+    pub fn promote_waitlist_escrow(
+        &mut self,
+        bin_id: u8,
+        payment_token_committed: u64,
+        claim_fee_rate: u64,
+        bin_count: usize,
+    ) -> Result<()> {
+        self.waitlist_pending = self
+            .waitlist_pending
+            .checked_sub(payment_token_committed)
+            .ok_or(crate::errors::LauchpadError::MathUnderflow)?;
+        self.add_commitment(bin_id, payment_token_committed, claim_fee_rate, bin_count)
+    }
+
+    /// Calculate total payment tokens committed across all bins
+    pub fn total_payment_committed(&self) -> u64 {
+        self.active_bins()
             .map(|bin| bin.payment_token_committed)
             .sum()
     }
 }
 
+/// IOU balance of a user in a pre-TGE auction, or the placeholder sale tokens they
+/// claimed from an auction selling a placeholder mint
+/// PDA: ["redemption", auction_key, user_key]
+#[account]
+pub struct Redemption {
+    /// Reference to the auction account
+    pub auction: Pubkey,
+    /// User who owns the IOU
+    pub user: Pubkey,
+    /// Sale tokens owed to the user, delivered by `redeem` once redemption is enabled
+    pub sale_token_owed: u64,
+    /// Placeholder sale tokens claimed by the user, net of claim fees, not yet swapped
+    /// for real ones by `swap_placeholder`
+    pub placeholder_swappable: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Redemption {
+    pub const SPACE: usize = 8 + 32 * 2 + 8 * 2 + 1;
+
+    /// Find the PDA address for a user's IOU ledger
+    pub fn find_program_address(auction: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+        crate::pda::find_redemption_address(auction, user)
+    }
+}
+
+/// Claim fee share of a front-end in an auction, accrued by `claim`,
+/// `claim_across_auctions` and `issue_claim_voucher` for commitments made through it
+/// PDA: ["frontend_fees", auction_key, frontend_id]
+#[account]
+pub struct FrontendFees {
+    /// Reference to the auction account
+    pub auction: Pubkey,
+    /// Front-end, withdraws the fees with `withdraw_frontend_fees`
+    pub frontend: Pubkey,
+    /// Sale tokens of claim fees accrued to the front-end
+    pub fees_accrued: u64,
+    /// Sale tokens withdrawn already
+    pub fees_withdrawn: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl FrontendFees {
+    pub const SPACE: usize = 8 + 32 * 2 + 8 * 2 + 1;
+
+    /// Find the PDA address for a front-end's fee balance in an auction
+    pub fn find_program_address(auction: &Pubkey, frontend_id: &Pubkey) -> (Pubkey, u8) {
+        crate::pda::find_frontend_fees_address(auction, frontend_id)
+    }
+}
+
+/// Wallet-quality attestation scaling a user's commit cap
+/// PDA: ["cap_attestation", auction_key, user_key]
+#[account]
+pub struct CommitCapAttestation {
+    /// Reference to the auction account
+    pub auction: Pubkey,
+    /// Attested user
+    pub user: Pubkey,
+    /// Share of the per-user commit cap granted, in basis points
+    pub cap_bps: u16,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CommitCapAttestation {
+    pub const SPACE: usize = 8 + 32 * 2 + 2 + 1;
+
+    /// Find the PDA address for a user's cap attestation
+    pub fn find_program_address(auction: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+        crate::pda::find_cap_attestation_address(auction, user)
+    }
+}
+
+/// Cumulative commitments of wallets linked to one identity, e.g. a KYC'd user
+/// PDA: ["identity_group", auction_key, group_id]
+///
+/// Wallets are linked by a whitelist signature naming the group, which then
+/// shares a single `commit_cap_per_user` across all of them.
+#[account]
+pub struct IdentityGroup {
+    /// Reference to the auction account
+    pub auction: Pubkey,
+    /// Opaque group id assigned by the whitelist authority
+    pub group_id: [u8; 32],
+    /// Payment tokens committed by all wallets of the group
+    pub payment_token_committed: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl IdentityGroup {
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + 1;
+
+    /// Find the PDA address for an identity group
+    pub fn find_program_address(auction: &Pubkey, group_id: &[u8; 32]) -> (Pubkey, u8) {
+        crate::pda::find_identity_group_address(auction, group_id)
+    }
+}
+
+/// One of consecutive rounds (seed, strategic, public...) of an auction
+/// PDA: ["round", auction_key, index]
+///
+/// Rounds sell their own bins against the auction's single sale-token vault.
+/// Closing a round rolls its unsold supply forward into the next round.
+#[account]
+pub struct Round {
+    /// Reference to the auction account
+    pub auction: Pubkey,
+    /// Round index, matching `AuctionBin::round`
+    pub index: u8,
+    /// Round commit period, within the auction commit period
+    pub commit_start_time: i64,
+    pub commit_end_time: i64,
+    /// Whitelist authority overriding the auction's for this round (if set)
+    pub whitelist_authority: Option<Pubkey>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Round {
+    pub const SPACE: usize = 8 + 32 + 1 + 8 * 2 + 33 + 1;
+
+    /// Find the PDA address for an auction round
+    pub fn find_program_address(auction: &Pubkey, index: u8) -> (Pubkey, u8) {
+        crate::pda::find_round_address(auction, index)
+    }
+}
+
+/// Payment tokens escrowed while waiting for room in a full hard-cap bin
+/// PDA: ["waitlist", auction_key, bin_id, sequence]
+#[account]
+pub struct WaitlistEntry {
+    /// Reference to the auction account
+    pub auction: Pubkey,
+    /// Waiting user
+    pub user: Pubkey,
+    /// Bin the user waits for
+    pub bin_id: u8,
+    /// Position in the bin's waitlist
+    pub sequence: u64,
+    /// Payment tokens escrowed, committed in full on promotion
+    pub payment_token_amount: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl WaitlistEntry {
+    pub const SPACE: usize = 8 + 32 * 2 + 1 + 8 + 8 + 1;
+
+    /// Find the PDA address for a waitlist entry
+    pub fn find_program_address(auction: &Pubkey, bin_id: u8, sequence: u64) -> (Pubkey, u8) {
+        crate::pda::find_waitlist_entry_address(auction, bin_id, sequence)
+    }
+}
+
+/// NFT escrowed as a commitment in NFT payment mode
+/// PDA: ["nft_escrow", auction_key, nft_mint]
+#[account]
+pub struct NftEscrow {
+    /// Reference to the auction account
+    pub auction: Pubkey,
+    /// User who escrowed the NFT
+    pub user: Pubkey,
+    /// Mint of the escrowed NFT
+    pub nft_mint: Pubkey,
+    /// Bin the NFT is committed to
+    pub bin_id: u8,
+    /// NFT vault PDA bump seed
+    pub vault_bump: u8,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl NftEscrow {
+    pub const SPACE: usize = 8 + 32 * 3 + 1 + 1 + 1;
+
+    /// Find the PDA address for an NFT escrow record
+    pub fn find_program_address(auction: &Pubkey, nft_mint: &Pubkey) -> (Pubkey, u8) {
+        crate::pda::find_nft_escrow_address(auction, nft_mint)
+    }
+
+    /// Find the PDA address for the token account holding an escrowed NFT
+    pub fn find_vault_address(auction: &Pubkey, nft_mint: &Pubkey) -> (Pubkey, u8) {
+        crate::pda::find_vault_nft_address(auction, nft_mint)
+    }
+}
+
+/// Kinds of paginated crank instructions over an auction's users, each tracked
+/// by its own cursor
+///
+/// Only `refund_batch` walks users. Other cranks keep their progress on the
+/// auction itself, like `process_waitlist` on the bin's waitlist head and
+/// `close_round` on `rounds_closed`, and `sweep_unclaimed` settles in a single call.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CrankKind {
+    /// `refund_batch`
+    Refund,
+}
+
+/// Progress of a paginated crank over an auction's users
+/// PDA: ["crank_cursor", auction_key, crank_kind]
+///
+/// Informational only, keepers may process users in any order. Each crank closes
+/// or marks what it processed, so a user is never processed twice, and the cursor
+/// lets keepers monitor and resume progress.
+#[account]
+pub struct CrankCursor {
+    /// Reference to the auction account
+    pub auction: Pubkey,
+    /// Crank this cursor belongs to
+    pub kind: CrankKind,
+    /// Number of users processed so far
+    pub processed_count: u64,
+    /// Last processed user key
+    pub last_processed_user: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CrankCursor {
+    pub const SPACE: usize = 8 + 32 + 1 + 8 + 32 + 1;
+
+    /// Find the PDA address for a crank cursor
+    pub fn find_program_address(auction: &Pubkey, kind: CrankKind) -> (Pubkey, u8) {
+        crate::pda::find_crank_cursor_address(auction, kind)
+    }
+
+    /// Record `user` as processed
+    pub fn advance(&mut self, user: &Pubkey) -> Result<()> {
+        self.last_processed_user = *user;
+        self.processed_count = self
+            .processed_count
+            .checked_add(1)
+            .ok_or(crate::errors::LauchpadError::MathOverflow)?;
+        Ok(())
+    }
+}
+
+/// Accepted commitment, emitted in `CommitEvent` and returned by `commit`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CommitReceipt {
+    /// The auction committed to
+    pub auction: Pubkey,
+    /// User who made the commitment
+    pub user: Pubkey,
+    /// Bin committed to
+    pub bin_id: u8,
+    /// Payment tokens accepted by this commitment
+    pub payment_token_committed: u64,
+    /// User's total payment tokens committed to the bin afterwards
+    pub user_bin_payment_token_committed: u64,
+    /// Bin's total payment tokens raised afterwards
+    pub bin_payment_token_raised: u64,
+    /// User's commit sequence, i.e. the `Committed` nonce consumed by this commitment
+    pub sequence: u64,
+    /// Attribution tag of a custody commitment (if any)
+    pub attribution: Option<[u8; 32]>,
+    /// Hash of the sale terms in effect, acknowledged by the user for direct commitments
+    pub terms_hash: [u8; 32],
+    /// Whitelist authority whose signature authorized the commitment (if any)
+    pub whitelist_signer: Option<Pubkey>,
+    /// Batch window the commitment was settled in (if any), identified by its start time
+    pub batch_window_id: Option<i64>,
+}
+
+/// Commitment made by the custody on behalf of a user in `custody_commit_batch`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CustodyCommitEntry {
+    /// Payment tokens committed for the user
+    pub payment_token_committed: u64,
+    /// Attribution tag recorded with the commitment, e.g. the hash of an exchange user id
+    pub attribution: [u8; 32],
+}
+
+/// Event emitted when a commitment is accepted
+#[event]
+pub struct CommitEvent {
+    pub receipt: CommitReceipt,
+}
+
+/// Event emitted for each user settled by `claim_to_custody`
+#[event]
+pub struct CustodyClaimEvent {
+    pub auction: Pubkey,
+    pub custody: Pubkey,
+    pub user: Pubkey,
+    pub bin_id: u8,
+    /// Sale tokens transferred to the custody, net of the claim fee
+    pub sale_tokens: u64,
+    pub claim_fee: u64,
+    pub refund_payment_tokens: u64,
+    /// Attribution tag of the user's custody commitments
+    pub attribution: [u8; 32],
+}
+
 /// Event emitted when a user's Committed account is fully claimed and closed
 #[event]
 pub struct CommittedAccountClosedEvent {
@@ -211,7 +1635,7 @@ pub struct CommittedAccountSnapshot {
     /// User who made the commitment
     pub user: Pubkey,
     /// All bins this user committed to
-    pub bins: Vec<CommittedBin>,
+    pub bins: Vec<CommittedBinSnapshot>,
     /// PDA bump seed
     pub bump: u8,
     /// Total payment tokens committed across all bins
@@ -225,15 +1649,17 @@ impl CommittedAccountSnapshot {
     pub fn from_committed(committed: &Committed) -> Self {
         let total_payment_committed = committed.total_payment_committed();
         let total_sale_tokens_claimed = committed
-            .bins
-            .iter()
+            .active_bins()
             .map(|bin| bin.sale_token_claimed)
             .sum();
 
         Self {
             auction: committed.auction,
             user: committed.user,
-            bins: committed.bins.clone(),
+            bins: committed
+                .active_bins()
+                .map(CommittedBinSnapshot::from_committed_bin)
+                .collect(),
             bump: committed.bump,
             total_payment_committed,
             total_sale_tokens_claimed,
@@ -241,14 +1667,179 @@ impl CommittedAccountSnapshot {
     }
 }
 
+/// Snapshot of a bin the user committed to, for the closure event
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CommittedBinSnapshot {
+    /// Bin ID
+    pub bin_id: u8,
+    /// Amount of payment tokens committed to this bin
+    pub payment_token_committed: u64,
+    /// Amount of sale tokens claimed from this bin
+    pub sale_token_claimed: u64,
+    /// Payment tokens refunded from this bin
+    pub payment_token_refunded: u64,
+    /// Claim fee rate recorded at commit time
+    pub claim_fee_rate: u64,
+    /// Attribution tag of the latest custody commitment to this bin (if any)
+    pub attribution: Option<[u8; 32]>,
+    /// Part of `payment_token_committed` boosted by `boost_commitment`
+    pub boosted_committed: u64,
+    /// Part of `payment_token_committed` committed in the batch window of a hard-cap bin
+    pub window_committed: u64,
+}
+
+impl CommittedBinSnapshot {
+    /// Create a snapshot from a bin of a Committed account
+    pub fn from_committed_bin(committed_bin: &CommittedBin) -> Self {
+        Self {
+            bin_id: committed_bin.bin_id,
+            payment_token_committed: committed_bin.payment_token_committed,
+            sale_token_claimed: committed_bin.sale_token_claimed,
+            payment_token_refunded: committed_bin.payment_token_refunded,
+            claim_fee_rate: committed_bin.claim_fee_rate,
+            attribution: committed_bin.attribution(),
+            boosted_committed: committed_bin.boosted_committed,
+            window_committed: committed_bin.window_committed,
+        }
+    }
+}
+
+/// Operations of the auction authority that can be delegated to another key
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuctionRole {
+    /// `emergency_control`
+    Pauser,
+    /// `set_price`
+    PriceSetter,
+    /// `withdraw_fees` and `reconcile_fees`
+    FeeManager,
+    /// `withdraw_funds`
+    FundWithdrawer,
+}
+
+/// Compact settlement of an auction, written once by `finalize_auction` and only
+/// modified by `abort_auction` turning it refund-only, for other programs to read
+/// instead of the mutable `Auction` account
+/// PDA: ["auction_result", auction]
+#[account]
+pub struct AuctionResult {
+    /// Settled auction
+    pub auction: Pubkey,
+    pub sale_token_mint: Pubkey,
+    pub payment_token_mint: Pubkey,
+    /// Time of `finalize_auction`
+    pub finalized_at: i64,
+    /// Whether the auction failed its soft cap or was aborted, and refunds every commitment
+    pub refund_only: bool,
+    /// Payment tokens raised across all bins
+    pub total_payment_raised: u64,
+    /// Sale tokens owed to all users
+    pub total_sale_entitled: u64,
+    /// Payment tokens refunded to all users
+    pub total_refund_entitled: u64,
+    pub bins: Vec<BinResult>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AuctionResult {
+    pub const BASE_SPACE: usize = 8 + 32 * 3 + 8 + 1 + 8 * 3 + 4 + 1;
+
+    /// Calculate space needed for the result of an auction with given number of bins
+    pub fn space_for_bins(bin_count: usize) -> usize {
+        Self::BASE_SPACE + bin_count * BinResult::SPACE
+    }
+
+    /// Find the PDA address for the result of an auction
+    pub fn find_program_address(auction: &Pubkey) -> (Pubkey, u8) {
+        crate::pda::find_auction_result_address(auction)
+    }
+}
+
+/// Final price and allocation of a bin
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BinResult {
+    /// Final price per sale token (in payment tokens)
+    pub sale_token_price: u64,
+    /// Maximum sale tokens the bin sold
+    pub sale_token_cap: u64,
+    /// Payment tokens raised in the bin
+    pub payment_token_raised: u64,
+    /// Allocation ratio scaled by `PRECISION_FACTOR`
+    pub allocation_ratio: u64,
+}
+
+impl BinResult {
+    pub const SPACE: usize = 8 * 4;
+}
+
+/// Sensitive admin action waiting for the auction's timelock
+/// PDA: ["admin_action", auction, action_id]
+#[account]
+pub struct AdminAction {
+    /// Auction the action applies to
+    pub auction: Pubkey,
+    /// Id chosen by the queuer, so several actions can be pending
+    pub action_id: u64,
+    pub action: AdminActionKind,
+    /// Earliest execution time
+    pub eta: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AdminAction {
+    pub const SPACE: usize = 8 + 32 + 8 + AdminActionKind::SPACE + 8 + 1;
+}
+
+/// Admin operation queued in an `AdminAction`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdminActionKind {
+    /// `set_price` of a bin
+    SetPrice { bin_id: u8, new_price: u64 },
+    /// `withdraw_funds`
+    WithdrawFunds,
+}
+
+impl AdminActionKind {
+    pub const SPACE: usize = 1 + 1 + 8;
+
+    /// Role allowed to queue and cancel the action
+    pub fn role(&self) -> AuctionRole {
+        match self {
+            AdminActionKind::SetPrice { .. } => AuctionRole::PriceSetter,
+            AdminActionKind::WithdrawFunds => AuctionRole::FundWithdrawer,
+        }
+    }
+}
+
+/// Role delegates of an auction (embedded in Auction), the authority where unset
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct AuctionRoles {
+    pub pauser: Option<Pubkey>,
+    pub price_setter: Option<Pubkey>,
+    pub fee_manager: Option<Pubkey>,
+    pub fund_withdrawer: Option<Pubkey>,
+}
+
+impl AuctionRoles {
+    pub const SPACE: usize = 33 * 4;
+}
+
 /// Emergency control state (embedded in Auction)
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
 pub struct EmergencyState {
     /// Paused operations bitmask
     pub paused_operations: u64,
+    /// Integrator-defined code for why operations are paused (0 when unspecified)
+    pub reason_code: u16,
+    /// Short human-readable reason, at most `MAX_PAUSE_MESSAGE_LEN` bytes
+    pub message: String,
 }
 
 impl EmergencyState {
+    pub const SPACE: usize = 8 + 2 + 4 + MAX_PAUSE_MESSAGE_LEN;
+
     /// Emergency control operation flags
     pub const PAUSE_AUCTION_COMMIT: u64 = 1 << 0; // 0x01
     pub const PAUSE_AUCTION_CLAIM: u64 = 1 << 1; // 0x02
@@ -273,4 +1864,165 @@ pub struct EmergencyControlParams {
     pub pause_auction_withdraw_fees: bool,
     pub pause_auction_withdraw_funds: bool,
     pub pause_auction_updation: bool,
+    pub reason_code: u16,
+    pub message: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn committed() -> Committed {
+        bytemuck::Zeroable::zeroed()
+    }
+
+    fn bin_ids(committed: &Committed) -> Vec<u8> {
+        committed.active_bins().map(|bin| bin.bin_id).collect()
+    }
+
+    #[test]
+    fn test_add_commitment_keeps_bins_sorted() {
+        let mut committed = committed();
+        for bin_id in [3, 0, 4, 1] {
+            committed.add_commitment(bin_id, 1_000, 0, 5).unwrap();
+        }
+        assert_eq!(bin_ids(&committed), vec![0, 1, 3, 4]);
+
+        committed.add_commitment(2, 1_000, 0, 5).unwrap();
+        assert_eq!(bin_ids(&committed), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_add_commitment_dedups_bins() {
+        let mut committed = committed();
+        committed.add_commitment(1, 1_000, 100, 3).unwrap();
+        committed.add_commitment(0, 500, 0, 3).unwrap();
+        committed.add_commitment(1, 3_000, 200, 3).unwrap();
+
+        assert_eq!(bin_ids(&committed), vec![0, 1]);
+        let bin = committed.find_bin(1).unwrap();
+        assert_eq!(bin.payment_token_committed, 4_000);
+        // Rate averaged by amount: (1000 * 100 + 3000 * 200) / 4000
+        assert_eq!(bin.claim_fee_rate, 175);
+    }
+
+    #[test]
+    fn test_add_commitment_bounded_by_bin_count() {
+        let mut committed = committed();
+        committed.add_commitment(0, 1_000, 0, 2).unwrap();
+        committed.add_commitment(1, 1_000, 0, 2).unwrap();
+
+        // Bins beyond the auction's bin count are rejected
+        assert!(committed.add_commitment(2, 1_000, 0, 2).is_err());
+        assert_eq!(committed.active_bins().count(), 2);
+
+        // Existing bins still take commitments
+        committed.add_commitment(1, 1_000, 0, 2).unwrap();
+        assert_eq!(
+            committed.find_bin(1).unwrap().payment_token_committed,
+            2_000
+        );
+    }
+
+    #[test]
+    fn test_promote_waitlist_escrow() {
+        let mut committed = committed();
+        committed.waitlist_pending = 3_000;
+
+        // Promotion moves the escrow from pending to committed
+        committed.promote_waitlist_escrow(1, 1_000, 100, 3).unwrap();
+        assert_eq!(committed.waitlist_pending, 2_000);
+        assert_eq!(committed.total_payment_committed(), 1_000);
+        assert_eq!(committed.find_bin(1).unwrap().claim_fee_rate, 100);
+
+        // Only pending escrows are promoted
+        assert!(committed.promote_waitlist_escrow(1, 2_001, 100, 3).is_err());
+        committed.promote_waitlist_escrow(1, 2_000, 100, 3).unwrap();
+        assert_eq!(committed.waitlist_pending, 0);
+        assert_eq!(committed.total_payment_committed(), 3_000);
+    }
+
+    #[test]
+    fn test_promote_waitlist_head() {
+        let params = AuctionBinParams {
+            sale_token_price: 100,
+            sale_token_cap: 10,
+            free_claim_amount: 0,
+            kind: BinKind::Sale,
+            hard_cap: true,
+            round: 0,
+            claim_fee_rate: None,
+        };
+        let mut bin = AuctionBin::from_params(params, 0, 0);
+        bin.payment_token_raised = 600;
+        bin.waitlist_tail = 3;
+
+        // The head is promoted while it fits the target
+        assert!(bin.promote_waitlist_head(300, true).unwrap());
+        assert_eq!(bin.payment_token_raised, 900);
+        assert_eq!(bin.sale_token_reserved, 3);
+        assert_eq!(bin.waitlist_head, 1);
+
+        // An entry that doesn't fit holds the waitlist
+        assert!(!bin.promote_waitlist_head(200, true).unwrap());
+        assert_eq!(bin.payment_token_raised, 900);
+        assert_eq!(bin.waitlist_head, 1);
+        assert!(bin.promote_waitlist_head(100, false).unwrap());
+        assert_eq!(bin.payment_token_raised, 1_000);
+        assert_eq!(bin.waitlist_head, 2);
+        assert!(bin.has_waitlist());
+    }
+
+    #[test]
+    fn test_reserve_whole_sale_tokens_within_cap() {
+        let params = AuctionBinParams {
+            sale_token_price: 100,
+            sale_token_cap: 10,
+            free_claim_amount: 0,
+            kind: BinKind::Sale,
+            hard_cap: false,
+            round: 0,
+            claim_fee_rate: None,
+        };
+        let mut bin = AuctionBin::from_params(params, 0, 0);
+
+        bin.reserve(600).unwrap();
+        assert_eq!(bin.sale_token_reserved, 6);
+
+        // Partial sale tokens and reservations beyond the cap are rejected
+        assert!(bin.reserve(150).is_err());
+        assert!(bin.reserve(500).is_err());
+
+        bin.release_reservation(200).unwrap();
+        bin.reserve(600).unwrap();
+        assert_eq!(bin.sale_token_reserved, 10);
+    }
+
+    #[test]
+    fn test_bin_space_matches_layout() {
+        // Per-bin space is summed by hand, every option set serializes the largest bin
+        let params = AuctionBinParams {
+            sale_token_price: 1,
+            sale_token_cap: 1,
+            free_claim_amount: 0,
+            kind: BinKind::Sale,
+            hard_cap: false,
+            round: 0,
+            claim_fee_rate: Some(100),
+        };
+        assert_eq!(params.try_to_vec().unwrap().len(), Template::SPACE_PER_BIN);
+        assert_eq!(
+            AuctionBin::from_params(params, 0, 0)
+                .try_to_vec()
+                .unwrap()
+                .len(),
+            Auction::SPACE_PER_BIN
+        );
+
+        // The zero-copy layout is part of the account format, a slot for every bin
+        assert_eq!(
+            Committed::SPACE,
+            8 + 32 * 2 + 8 + 32 + 8 + 8 + 8 + 32 + MAX_AUCTION_BINS * 88 + 8
+        );
+    }
 }
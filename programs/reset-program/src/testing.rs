@@ -1,3 +1,6 @@
+#[cfg(not(target_os = "solana"))]
+pub mod fixtures;
+
 use crate::state::*;
 use anchor_lang::prelude::*;
 
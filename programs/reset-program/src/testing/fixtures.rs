@@ -0,0 +1,261 @@
+//! Deterministic localnet fixtures (testing only)
+//!
+//! Builds the accounts of a fully populated auction, mid-commit with an
+//! oversubscribed bin, as JSON files loadable with
+//! `solana-test-validator --account <ADDRESS> <FILE>`, so clients can develop
+//! against realistic state without scripting the transactions producing it.
+
+use crate::consts::LAUNCHPAD_ADMIN;
+use crate::extensions::AuctionExtensions;
+use crate::pda;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{program_option::COption, program_pack::Pack};
+use anchor_spl::token::spl_token;
+
+/// Sale token mint of the fixture auction
+pub const SALE_TOKEN_MINT: Pubkey = Pubkey::new_from_array([1; 32]);
+/// Payment token mint of the fixture auction
+pub const PAYMENT_TOKEN_MINT: Pubkey = Pubkey::new_from_array([2; 32]);
+/// Users committed to the fixture auction
+pub const USERS: [Pubkey; 3] = [
+    Pubkey::new_from_array([11; 32]),
+    Pubkey::new_from_array([12; 32]),
+    Pubkey::new_from_array([13; 32]),
+];
+
+/// Payment tokens committed by each of `USERS`, as (bin_id, amount) pairs:
+/// bin 0 is 150% subscribed, bin 1 is 60% subscribed
+const COMMITMENTS: [&[(u8, u64)]; 3] = [
+    &[(0, 6_000_000), (1, 2_000_000)],
+    &[(0, 9_000_000)],
+    &[(1, 4_000_000)],
+];
+
+/// An account to load into a local validator
+#[derive(Clone, Debug)]
+pub struct AccountFixture {
+    pub address: Pubkey,
+    pub owner: Pubkey,
+    pub lamports: u64,
+    pub data: Vec<u8>,
+}
+
+impl AccountFixture {
+    /// Rent-exempt account holding `data`
+    fn new(address: Pubkey, owner: Pubkey, data: Vec<u8>) -> Self {
+        Self {
+            address,
+            owner,
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+        }
+    }
+
+    /// Program account holding `account` serialized with its discriminator, padded to `space`
+    fn program_account<T: AccountSerialize>(address: Pubkey, account: &T, space: usize) -> Self {
+        let mut data = Vec::with_capacity(space);
+        account
+            .try_serialize(&mut data)
+            .expect("Fixture account serializes");
+        data.resize(space, 0);
+        Self::new(address, crate::ID, data)
+    }
+
+    /// Zero-copy program account holding `account`
+    fn zero_copy_account<T: anchor_lang::ZeroCopy>(address: Pubkey, account: &T) -> Self {
+        let mut data = T::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(account));
+        Self::new(address, crate::ID, data)
+    }
+
+    /// SPL token program account holding `state`
+    fn token_account<T: Pack>(address: Pubkey, state: T) -> Self {
+        let mut data = vec![0; T::LEN];
+        state.pack_into_slice(&mut data);
+        Self::new(address, spl_token::ID, data)
+    }
+
+    /// JSON in the format of `solana account --output json`, read by
+    /// `solana-test-validator --account`
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"pubkey":"{}","account":{{"lamports":{},"data":["{}","base64"],"owner":"{}","executable":false,"rentEpoch":0,"space":{}}}}}"#,
+            self.address,
+            self.lamports,
+            base64_encode(&self.data),
+            self.owner,
+            self.data.len()
+        )
+    }
+}
+
+/// Accounts of an auction in the middle of its commit period at `now`
+///
+/// Includes the global config, both mints, the auction, its vaults and a
+/// `Committed` account per user in `USERS`. Bin 0 is oversubscribed, bin 1 is not.
+pub fn oversubscribed_auction(now: i64) -> Vec<AccountFixture> {
+    let (auction_address, auction_bump) = pda::find_auction_address(&SALE_TOKEN_MINT);
+    let (vault_sale, vault_sale_bump) = pda::find_vault_sale_address(&auction_address);
+    let (vault_payment, vault_payment_bump) = pda::find_vault_payment_address(&auction_address);
+
+    let mut bins = vec![sale_bin(1_000, 10_000), sale_bin(2_000, 5_000)];
+    let mut committed_accounts = Vec::with_capacity(USERS.len());
+    for (user, commitments) in USERS.iter().zip(COMMITMENTS) {
+        let (address, bump) = pda::find_committed_address(&auction_address, user);
+        let mut committed = Committed {
+            auction: auction_address,
+            user: *user,
+            nonce: commitments.len() as u64,
+            bump,
+            ..bytemuck::Zeroable::zeroed()
+        };
+        for &(bin_id, amount) in commitments {
+            if committed.find_bin(bin_id).is_none() {
+                bins[bin_id as usize].participants += 1;
+            }
+            committed
+                .add_commitment(bin_id, amount, 0, bins.len())
+                .expect("Fixture commitment is valid");
+            bins[bin_id as usize].payment_token_raised += amount;
+        }
+        committed_accounts.push(AccountFixture::zero_copy_account(address, &committed));
+    }
+
+    let auction = Auction {
+        authority: LAUNCHPAD_ADMIN,
+        custody: LAUNCHPAD_ADMIN,
+        sale_token_mint: SALE_TOKEN_MINT,
+        payment_token_mint: PAYMENT_TOKEN_MINT,
+        commit_start_time: now - 3_600,
+        commit_end_time: now + 3_600,
+        claim_start_time: now + 7_200,
+        extensions: AuctionExtensions::default(),
+        emergency_state: EmergencyState::default(),
+        total_participants: USERS.len() as u64,
+        unsold_sale_tokens_and_effective_payment_tokens_withdrawn: false,
+        finalized: false,
+        force_refund: false,
+        cancelled: false,
+        aborted: false,
+        round_count: 1,
+        rounds_closed: 0,
+        total_sale_entitled: 0,
+        total_refund_entitled: 0,
+        total_payment_refunded: 0,
+        total_payment_released: 0,
+        total_payment_withdrawn: 0,
+        total_refund_diverted: 0,
+        total_pool_withdrawn: 0,
+        total_sale_swept: 0,
+        total_payment_swept: 0,
+        waitlist_escrowed: 0,
+        total_rent_funded: 0,
+        total_fees_collected: 0,
+        total_fees_withdrawn: 0,
+        total_frontend_fees_accrued: 0,
+        total_frontend_fees_withdrawn: 0,
+        security_deposit_held: 0,
+        security_deposit_slashed: 0,
+        redemption_mint: None,
+        placeholder_swapped: 0,
+        terms_hash: [0; 32],
+        roles: AuctionRoles::default(),
+        vault_sale_bump,
+        vault_payment_bump,
+        vault_redemption_bump: 0,
+        bump: auction_bump,
+        bins,
+    };
+    let sale_token_deposited = auction.bins.iter().map(|bin| bin.sale_token_cap).sum();
+    let payment_token_raised = auction
+        .total_payment_raised()
+        .expect("Fixture raise fits in u64");
+
+    let (global_config_address, global_config_bump) = pda::find_global_config_address();
+    let global_config = GlobalConfig {
+        admin: LAUNCHPAD_ADMIN,
+        protocol_fee: None,
+        bump: global_config_bump,
+    };
+
+    let mut fixtures = vec![
+        AccountFixture::program_account(global_config_address, &global_config, GlobalConfig::SPACE),
+        AccountFixture::token_account(SALE_TOKEN_MINT, mint(sale_token_deposited)),
+        AccountFixture::token_account(PAYMENT_TOKEN_MINT, mint(payment_token_raised)),
+        AccountFixture::token_account(
+            vault_sale,
+            token_account(SALE_TOKEN_MINT, vault_sale, sale_token_deposited),
+        ),
+        AccountFixture::token_account(
+            vault_payment,
+            token_account(PAYMENT_TOKEN_MINT, vault_payment, payment_token_raised),
+        ),
+        AccountFixture::program_account(
+            auction_address,
+            &auction,
+            Auction::space_for_bins(auction.bins.len()),
+        ),
+    ];
+    fixtures.extend(committed_accounts);
+    fixtures
+}
+
+fn sale_bin(sale_token_price: u64, sale_token_cap: u64) -> AuctionBin {
+    AuctionBin::from_params(
+        AuctionBinParams {
+            sale_token_price,
+            sale_token_cap,
+            free_claim_amount: 0,
+            kind: BinKind::Sale,
+            hard_cap: false,
+            round: 0,
+            claim_fee_rate: None,
+        },
+        0,
+        0,
+    )
+}
+
+fn mint(supply: u64) -> spl_token::state::Mint {
+    spl_token::state::Mint {
+        mint_authority: COption::Some(LAUNCHPAD_ADMIN),
+        supply,
+        decimals: 6,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+}
+
+fn token_account(mint: Pubkey, owner: Pubkey, amount: u64) -> spl_token::state::Account {
+    spl_token::state::Account {
+        mint,
+        owner,
+        amount,
+        delegate: COption::None,
+        state: spl_token::state::AccountState::Initialized,
+        is_native: COption::None,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    }
+}
+
+/// Standard base64 with padding, as expected in account JSON data
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let triple = (chunk[0] as u32) << 16
+            | (*chunk.get(1).unwrap_or(&0) as u32) << 8
+            | *chunk.get(2).unwrap_or(&0) as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(triple >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
@@ -14,158 +14,80 @@ export type LaunchpadProgram = {
   },
   "instructions": [
     {
-      "name": "claim",
+      "name": "abortAuction",
       "docs": [
-        "User claims tokens with flexible amounts (merged claim functionality)"
+        "Abort an auction for incident response, refunding every commitment in full"
       ],
       "discriminator": [
-        62,
-        198,
-        214,
-        193,
-        213,
-        159,
-        108,
-        210
+        97,
+        4,
+        86,
+        156,
+        252,
+        84,
+        9,
+        186
       ],
       "accounts": [
         {
-          "name": "user",
-          "writable": true,
+          "name": "admin",
           "signer": true
         },
         {
-          "name": "auction",
-          "writable": true
-        },
-        {
-          "name": "committed",
-          "writable": true
-        },
-        {
-          "name": "saleTokenMint",
-          "docs": [
-            "Sale token mint"
-          ]
-        },
-        {
-          "name": "userSaleToken",
-          "docs": [
-            "User's sale token account (will be created if needed)"
-          ],
-          "writable": true,
+          "name": "globalConfig",
           "pda": {
             "seeds": [
-              {
-                "kind": "account",
-                "path": "user"
-              },
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
-                  101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
                   95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "saleTokenMint"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         },
         {
-          "name": "userPaymentToken",
-          "docs": [
-            "User's payment token account for refunds"
-          ],
+          "name": "auction",
           "writable": true
         },
         {
-          "name": "vaultSaleToken",
+          "name": "auctionResult",
+          "docs": [
+            "Settlement published by `finalize_auction` (required once finalized)"
+          ],
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
                   97,
                   117,
-                  108,
+                  99,
                   116,
+                  105,
+                  111,
+                  110,
                   95,
+                  114,
+                  101,
                   115,
-                  97,
+                  117,
                   108,
-                  101
+                  116
                 ]
               },
               {
@@ -176,91 +98,63 @@ export type LaunchpadProgram = {
           }
         },
         {
-          "name": "vaultPaymentToken",
+          "name": "stats",
+          "docs": [
+            "Launchpad-wide stats the finalized auction was recorded in (required once finalized)"
+          ],
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  97,
-                  117,
-                  108,
+                  115,
                   116,
-                  95,
-                  112,
                   97,
-                  121,
-                  109,
-                  101,
-                  110,
-                  116
+                  116,
+                  115
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "auction"
               }
             ]
           }
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "binId",
-          "type": "u8"
-        },
-        {
-          "name": "saleTokenToClaim",
-          "type": "u64"
-        },
-        {
-          "name": "paymentTokenToRefund",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "commit",
+      "name": "addBin",
       "docs": [
-        "User commits to an auction bin"
+        "Admin appends a bin before the commit period starts"
       ],
       "discriminator": [
-        223,
-        140,
-        142,
-        165,
-        229,
-        208,
-        156,
-        74
+        37,
+        99,
+        243,
+        249,
+        58,
+        96,
+        157,
+        218
       ],
       "accounts": [
         {
-          "name": "user",
+          "name": "authority",
           "writable": true,
-          "signer": true
+          "signer": true,
+          "relations": [
+            "auction"
+          ]
         },
         {
           "name": "auction",
           "writable": true
         },
         {
-          "name": "committed",
-          "writable": true,
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
           "pda": {
             "seeds": [
               {
@@ -268,32 +162,31 @@ export type LaunchpadProgram = {
                 "value": [
                   99,
                   111,
-                  109,
-                  109,
+                  110,
+                  102,
                   105,
-                  116,
-                  116,
-                  101,
-                  100
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "auction"
-              },
-              {
-                "kind": "account",
-                "path": "user"
               }
             ]
           }
         },
         {
-          "name": "userPaymentToken",
+          "name": "saleTokenSeller",
+          "docs": [
+            "Sale token seller's account (source of the new bin's sale tokens)"
+          ],
           "writable": true
         },
         {
-          "name": "vaultPaymentToken",
+          "name": "saleTokenSellerAuthority",
+          "docs": [
+            "Authority of the sale token seller account"
+          ],
+          "signer": true
+        },
+        {
+          "name": "vaultSaleToken",
           "writable": true,
           "pda": {
             "seeds": [
@@ -306,13 +199,10 @@ export type LaunchpadProgram = {
                   108,
                   116,
                   95,
-                  112,
+                  115,
                   97,
-                  121,
-                  109,
-                  101,
-                  110,
-                  116
+                  108,
+                  101
                 ]
               },
               {
@@ -322,18 +212,6 @@ export type LaunchpadProgram = {
             ]
           }
         },
-        {
-          "name": "whitelistAuthority",
-          "optional": true
-        },
-        {
-          "name": "custodyAuthority",
-          "optional": true
-        },
-        {
-          "name": "sysvarInstructions",
-          "optional": true
-        },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
@@ -345,392 +223,387 @@ export type LaunchpadProgram = {
       ],
       "args": [
         {
-          "name": "binId",
-          "type": "u8"
-        },
-        {
-          "name": "paymentTokenCommitted",
-          "type": "u64"
-        },
-        {
-          "name": "expiry",
-          "type": "u64"
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "auctionBinParams"
+            }
+          }
         }
       ]
     },
     {
-      "name": "decreaseCommit",
+      "name": "attestCommitCap",
       "docs": [
-        "User decreases a commitment (renamed from revert_commit)"
+        "User records a whitelist-authority attestation that scales their commit cap"
       ],
       "discriminator": [
-        134,
-        84,
-        49,
-        225,
-        22,
-        251,
-        110,
-        176
+        111,
+        196,
+        27,
+        205,
+        85,
+        191,
+        231,
+        206
       ],
       "accounts": [
         {
           "name": "user",
           "writable": true,
-          "signer": true,
-          "relations": [
-            "committed"
-          ]
-        },
-        {
-          "name": "auction",
-          "writable": true
-        },
-        {
-          "name": "committed",
-          "writable": true
+          "signer": true
         },
         {
-          "name": "userPaymentToken",
-          "writable": true
+          "name": "auction"
         },
         {
-          "name": "vaultPaymentToken",
+          "name": "capAttestation",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
+                  99,
                   97,
-                  117,
-                  108,
-                  116,
-                  95,
                   112,
+                  95,
                   97,
-                  121,
-                  109,
+                  116,
+                  116,
                   101,
-                  110,
-                  116
+                  115,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
                 ]
               },
               {
                 "kind": "account",
                 "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "user"
               }
             ]
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "sysvarInstructions",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "binId",
-          "type": "u8"
+          "name": "capBps",
+          "type": "u16"
         },
         {
-          "name": "paymentTokenReverted",
+          "name": "expiry",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "emergencyControl",
+      "name": "boostCommitment",
       "docs": [
-        "Emergency control for pausing/resuming auction operations"
+        "User boosts a commitment, locking its sale tokens for longer for more allocation weight"
       ],
       "discriminator": [
-        40,
-        126,
-        46,
-        161,
-        192,
-        12,
-        37,
-        0
+        85,
+        168,
+        50,
+        212,
+        129,
+        125,
+        141,
+        62
       ],
       "accounts": [
         {
-          "name": "authority",
-          "docs": [
-            "Only auction authority can control emergency state"
-          ],
-          "writable": true,
+          "name": "user",
           "signer": true,
           "relations": [
-            "auction"
+            "committed"
           ]
         },
         {
           "name": "auction",
           "writable": true
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "params",
-          "type": {
-            "defined": {
-              "name": "emergencyControlParams"
-            }
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
           }
-        }
-      ]
-    },
-    {
-      "name": "getLaunchpadAdmin",
-      "docs": [
-        "Get the hardcoded LaunchpadAdmin public key"
-      ],
-      "discriminator": [
-        8,
-        113,
-        195,
-        137,
-        50,
-        1,
-        35,
-        38
-      ],
-      "accounts": [],
-      "args": [],
-      "returns": "pubkey"
-    },
-    {
-      "name": "initAuction",
-      "docs": [
-        "Create a new auction with automatic vault creation"
-      ],
-      "discriminator": [
-        73,
-        108,
-        200,
-        53,
-        221,
-        115,
-        20,
-        41
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true
         },
         {
-          "name": "auction",
+          "name": "committed",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  97,
-                  117,
                   99,
-                  116,
-                  105,
                   111,
-                  110
+                  109,
+                  109,
+                  105,
+                  116,
+                  116,
+                  101,
+                  100
                 ]
               },
               {
                 "kind": "account",
-                "path": "saleTokenMint"
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "user"
               }
             ]
           }
         },
         {
-          "name": "saleTokenMint"
-        },
-        {
-          "name": "paymentTokenMint"
-        },
-        {
-          "name": "saleTokenSeller",
+          "name": "round",
           "docs": [
-            "Sale token seller's account (source for initial vault funding)"
+            "Round of the bin (only needed for auctions with rounds)"
           ],
-          "writable": true
-        },
+          "optional": true
+        }
+      ],
+      "args": [
         {
-          "name": "saleTokenSellerAuthority",
-          "docs": [
-            "Authority of the sale token seller account"
-          ],
-          "writable": true,
-          "signer": true
+          "name": "binId",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "canCommit",
+      "docs": [
+        "Check whether a user could commit, with the reason and limit if not"
+      ],
+      "discriminator": [
+        117,
+        178,
+        58,
+        173,
+        196,
+        161,
+        174,
+        62
+      ],
+      "accounts": [
+        {
+          "name": "auction"
         },
         {
-          "name": "vaultSaleToken",
+          "name": "committed",
           "docs": [
-            "Vault to hold sale tokens (created as PDA)"
+            "User's commitment (omit if the user hasn't committed yet)"
           ],
-          "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  97,
-                  117,
-                  108,
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
                   116,
-                  95,
-                  115,
-                  97,
-                  108,
-                  101
+                  116,
+                  101,
+                  100
                 ]
               },
               {
                 "kind": "account",
                 "path": "auction"
+              },
+              {
+                "kind": "arg",
+                "path": "user"
               }
             ]
           }
         },
         {
-          "name": "vaultPaymentToken",
+          "name": "capAttestation",
           "docs": [
-            "Vault to hold payment tokens (created as PDA)"
+            "User's wallet-quality attestation (only needed when commit cap scaling is enabled)"
           ],
-          "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
+                  99,
                   97,
-                  117,
-                  108,
-                  116,
-                  95,
                   112,
+                  95,
                   97,
-                  121,
-                  109,
+                  116,
+                  116,
                   101,
-                  110,
-                  116
-                ]
-              },
-              {
+                  115,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
                 "kind": "account",
                 "path": "auction"
+              },
+              {
+                "kind": "arg",
+                "path": "user"
               }
             ]
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "userProfile",
+          "docs": [
+            "User's loyalty profile (only needed when loyalty cap multipliers are enabled)"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "user"
+              }
+            ]
+          }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "round",
+          "docs": [
+            "Round of the bin (only needed for auctions with rounds)"
+          ],
+          "optional": true
         }
       ],
       "args": [
         {
-          "name": "commitStartTime",
-          "type": "i64"
-        },
-        {
-          "name": "commitEndTime",
-          "type": "i64"
-        },
-        {
-          "name": "claimStartTime",
-          "type": "i64"
-        },
-        {
-          "name": "bins",
-          "type": {
-            "vec": {
-              "defined": {
-                "name": "auctionBinParams"
-              }
-            }
-          }
+          "name": "user",
+          "type": "pubkey"
         },
         {
-          "name": "custody",
-          "type": "pubkey"
+          "name": "binId",
+          "type": "u8"
         },
         {
-          "name": "extensions",
-          "type": {
-            "defined": {
-              "name": "auctionExtensions"
-            }
-          }
+          "name": "paymentTokenCommitted",
+          "type": "u64"
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "commitEligibility"
+        }
+      }
     },
     {
-      "name": "setPrice",
+      "name": "cancelAdminAction",
       "docs": [
-        "Admin sets new price for a bin"
+        "Cancel a queued admin action"
       ],
       "discriminator": [
-        16,
-        19,
-        182,
-        8,
-        149,
-        83,
-        72,
-        181
+        160,
+        33,
+        64,
+        71,
+        237,
+        85,
+        1,
+        251
       ],
       "accounts": [
         {
           "name": "authority",
           "writable": true,
-          "signer": true,
+          "signer": true
+        },
+        {
+          "name": "auction",
           "relations": [
-            "auction"
+            "adminAction"
           ]
         },
         {
-          "name": "auction",
+          "name": "adminAction",
           "writable": true
         }
       ],
-      "args": [
-        {
-          "name": "binId",
-          "type": "u8"
-        },
-        {
-          "name": "newPrice",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "withdrawFees",
+      "name": "cancelAuction",
       "docs": [
-        "Admin withdraws collected fees from all bins"
+        "Pull the launch before its commit period, returning the sale tokens to the seller"
       ],
       "discriminator": [
-        198,
-        212,
-        171,
-        109,
-        144,
-        215,
-        174,
-        89
+        156,
+        43,
+        197,
+        110,
+        218,
+        105,
+        143,
+        182
       ],
       "accounts": [
         {
@@ -745,12 +618,6 @@ export type LaunchpadProgram = {
           "name": "auction",
           "writable": true
         },
-        {
-          "name": "saleTokenMint",
-          "docs": [
-            "Sale token mint"
-          ]
-        },
         {
           "name": "vaultSaleToken",
           "writable": true,
@@ -779,153 +646,90 @@ export type LaunchpadProgram = {
           }
         },
         {
-          "name": "feeRecipientAccount",
+          "name": "saleTokenRecipient",
           "docs": [
-            "Fee recipient account (will be created if needed)"
+            "Seller-designated account receiving the sale tokens"
           ],
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "path": "authority"
-              },
-              {
-                "kind": "const",
-                "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
-                  101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
-                  95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "saleTokenMint"
-              }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
-          }
+          "writable": true
         },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
       "args": []
     },
     {
-      "name": "withdrawFunds",
+      "name": "claim",
       "docs": [
-        "Admin withdraws funds from all auction bins"
+        "User claims tokens with flexible amounts (merged claim functionality)"
       ],
       "discriminator": [
-        241,
-        36,
-        29,
-        111,
-        208,
-        31,
-        104,
-        217
+        62,
+        198,
+        214,
+        193,
+        213,
+        159,
+        108,
+        210
       ],
       "accounts": [
         {
-          "name": "authority",
+          "name": "user",
+          "docs": [
+            "Wallet or program PDA signing through a CPI, owner of the sale token ATA created",
+            "here (off-curve owners are supported) and receiving the `Committed` rent"
+          ],
           "writable": true,
-          "signer": true,
-          "relations": [
-            "auction"
-          ]
+          "signer": true
         },
         {
           "name": "auction",
           "writable": true
         },
         {
-          "name": "saleTokenMint",
+          "name": "config",
           "docs": [
-            "Sale token mint"
-          ]
+            "Launchpad config, blocking claims of exploited sale mints"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "paymentTokenMint",
+          "name": "committed",
+          "writable": true
+        },
+        {
+          "name": "saleTokenMint",
           "docs": [
-            "Payment token mint"
+            "Sale token mint"
           ]
         },
+        {
+          "name": "userSaleToken",
+          "writable": true
+        },
+        {
+          "name": "userPaymentToken",
+          "docs": [
+            "User's payment token account for refunds"
+          ],
+          "writable": true
+        },
         {
           "name": "vaultSaleToken",
           "writable": true,
@@ -984,189 +788,109 @@ export type LaunchpadProgram = {
           }
         },
         {
-          "name": "saleTokenRecipient",
+          "name": "redemption",
           "docs": [
-            "Sale token recipient account (will be created if needed)"
+            "User's redemption ledger, required in IOU mode, otherwise recording the claimed",
+            "sale tokens as placeholders the user can swap once a redemption mint is set"
           ],
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  100,
+                  101,
+                  109,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "auction"
               },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userProfile",
+          "docs": [
+            "User's loyalty profile, updated once the commitment is fully claimed (optional)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
+                  117,
+                  115,
                   101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
+                  114,
                   95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "saleTokenMint"
+                "path": "user"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         },
         {
-          "name": "paymentTokenRecipient",
+          "name": "lamportTreasury",
           "docs": [
-            "Payment token recipient account (will be created if needed)"
+            "Auction lamport treasury, pays for the user's sale token account (optional)"
           ],
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
-              {
-                "kind": "account",
-                "path": "authority"
-              },
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
-                  101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
+                  108,
+                  97,
+                  109,
+                  112,
+                  111,
+                  114,
+                  116,
                   95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
                 ]
               },
               {
                 "kind": "account",
-                "path": "paymentTokenMint"
+                "path": "auction"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         },
         {
@@ -1180,390 +904,11766 @@ export type LaunchpadProgram = {
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "frontendFees",
+          "docs": [
+            "Fee balance of the front-end committed through (only needed for such commitments)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  114,
+                  111,
+                  110,
+                  116,
+                  101,
+                  110,
+                  100,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "committed"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "binId",
+          "type": "u8"
+        },
+        {
+          "name": "saleTokenToClaim",
+          "type": "u64"
+        },
+        {
+          "name": "paymentTokenToRefund",
+          "type": "u64"
+        },
+        {
+          "name": "termsHash",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimAcrossAuctions",
+      "docs": [
+        "User claims everything claimable from several auctions at once"
+      ],
+      "discriminator": [
+        142,
+        106,
+        211,
+        46,
+        6,
+        55,
+        5,
+        147
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         }
       ],
       "args": []
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "auction",
+      "name": "claimToCustody",
+      "docs": [
+        "Custody claims a bin's entitlements of many users it committed for"
+      ],
       "discriminator": [
-        218,
-        94,
-        247,
-        242,
-        126,
-        233,
-        131,
-        81
-      ]
+        132,
+        93,
+        249,
+        86,
+        197,
+        221,
+        196,
+        128
+      ],
+      "accounts": [
+        {
+          "name": "custody",
+          "docs": [
+            "Auction custody, receives the claimed tokens"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, blocking claims of exploited sale mints"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "saleTokenMint"
+        },
+        {
+          "name": "custodySaleToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "custody"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "saleTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "custodyPaymentToken",
+          "writable": true
+        },
+        {
+          "name": "vaultSaleToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  97,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultPaymentToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "binId",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "cloneAuction",
+      "docs": [
+        "Create a new auction from a template with a new sale token and times"
+      ],
+      "discriminator": [
+        147,
+        202,
+        208,
+        79,
+        194,
+        26,
+        229,
+        195
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "template"
+        },
+        {
+          "name": "auction",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "saleTokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "saleTokenMint"
+        },
+        {
+          "name": "paymentTokenMint"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "saleTokenSeller",
+          "docs": [
+            "Sale token seller's account (source for initial vault funding)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "saleTokenSellerAuthority",
+          "docs": [
+            "Authority of the sale token seller account"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vaultSaleToken",
+          "docs": [
+            "Vault to hold sale tokens (created as PDA)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  97,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultPaymentToken",
+          "docs": [
+            "Vault to hold payment tokens (created as PDA)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "commitStartTime",
+          "type": "i64"
+        },
+        {
+          "name": "commitEndTime",
+          "type": "i64"
+        },
+        {
+          "name": "claimStartTime",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "closeAuction",
+      "docs": [
+        "Archive a settled auction through the event authority, sweep vault dust and close",
+        "its accounts"
+      ],
+      "discriminator": [
+        225,
+        129,
+        91,
+        48,
+        215,
+        73,
+        203,
+        172
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "vaultSaleToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  97,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultPaymentToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "saleTokenRecipient",
+          "docs": [
+            "Authority's sale token account receiving sale vault dust (if any)"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "paymentTokenRecipient",
+          "docs": [
+            "Authority's payment token account receiving payment vault dust (if any)"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "address": "5dhQapnBy7pXnuPR9fTbgvFt4SsZCWiwQ4qtMEVSMDvZ"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeRound",
+      "docs": [
+        "Permissionless crank closing an ended round and rolling unsold supply forward"
+      ],
+      "discriminator": [
+        149,
+        14,
+        81,
+        88,
+        230,
+        226,
+        234,
+        37
+      ],
+      "accounts": [
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "round"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "collectNft",
+      "docs": [
+        "Admin collects an escrowed NFT as payment after a successful auction"
+      ],
+      "discriminator": [
+        189,
+        247,
+        13,
+        162,
+        15,
+        100,
+        34,
+        122
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "auction"
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "user",
+          "writable": true
+        },
+        {
+          "name": "nftEscrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  110,
+                  102,
+                  116,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "nft_escrow.nft_mint",
+                "account": "nftEscrow"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nftMint"
+        },
+        {
+          "name": "vaultNftToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "nft_escrow.nft_mint",
+                "account": "nftEscrow"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nftRecipient",
+          "docs": [
+            "NFT recipient account (will be created if needed)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "commit",
+      "docs": [
+        "User commits to an auction bin, returning the receipt of the accepted commitment"
+      ],
+      "discriminator": [
+        223,
+        140,
+        142,
+        165,
+        229,
+        208,
+        156,
+        74
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "docs": [
+            "Wallet or program PDA signing through a CPI (unless the auction is EOA-only),",
+            "paying for the `Committed` account: a PDA must be a system account without data",
+            "holding the rent. wSOL commitments wrap any shortfall from its lamports"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "committed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  116,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userPaymentToken",
+          "writable": true
+        },
+        {
+          "name": "vaultPaymentToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "whitelistAuthority",
+          "optional": true
+        },
+        {
+          "name": "custodyAuthority",
+          "optional": true
+        },
+        {
+          "name": "sysvarInstructions",
+          "optional": true
+        },
+        {
+          "name": "capAttestation",
+          "docs": [
+            "User's wallet-quality attestation (only needed when commit cap scaling is enabled)"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  112,
+                  95,
+                  97,
+                  116,
+                  116,
+                  101,
+                  115,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userProfile",
+          "docs": [
+            "User's loyalty profile (only needed when loyalty cap multipliers are enabled)"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "round",
+          "docs": [
+            "Round of the bin (only needed for auctions with rounds)"
+          ],
+          "optional": true
+        },
+        {
+          "name": "identityGroup",
+          "docs": [
+            "Identity group of the user's wallet (only needed when the whitelist signature names one)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  100,
+                  101,
+                  110,
+                  116,
+                  105,
+                  116,
+                  121,
+                  95,
+                  103,
+                  114,
+                  111,
+                  117,
+                  112
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "identity_group.group_id",
+                "account": "identityGroup"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "frontendFees",
+          "docs": [
+            "Fee balance of the front-end committed through (optional), set up by the",
+            "front-end with `init_frontend_fees`"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  114,
+                  111,
+                  110,
+                  116,
+                  101,
+                  110,
+                  100,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "frontend_fees.frontend",
+                "account": "frontendFees"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "binId",
+          "type": "u8"
+        },
+        {
+          "name": "paymentTokenCommitted",
+          "type": "u64"
+        },
+        {
+          "name": "expiry",
+          "type": "u64"
+        },
+        {
+          "name": "termsHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "commitReceipt"
+        }
+      }
+    },
+    {
+      "name": "commitNft",
+      "docs": [
+        "User commits to an auction bin by escrowing an NFT (NFT payment mode)"
+      ],
+      "discriminator": [
+        89,
+        150,
+        17,
+        236,
+        184,
+        217,
+        230,
+        150
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "committed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  116,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nftMint"
+        },
+        {
+          "name": "nftMetadata"
+        },
+        {
+          "name": "userNftToken",
+          "writable": true
+        },
+        {
+          "name": "vaultNftToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nftEscrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  110,
+                  102,
+                  116,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sysvarInstructions",
+          "optional": true
+        },
+        {
+          "name": "capAttestation",
+          "docs": [
+            "User's wallet-quality attestation (only needed when commit cap scaling is enabled)"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  112,
+                  95,
+                  97,
+                  116,
+                  116,
+                  101,
+                  115,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userProfile",
+          "docs": [
+            "User's loyalty profile (only needed when loyalty cap multipliers are enabled)"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "round",
+          "docs": [
+            "Round of the bin (only needed for auctions with rounds)"
+          ],
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "binId",
+          "type": "u8"
+        },
+        {
+          "name": "expiry",
+          "type": "u64"
+        },
+        {
+          "name": "termsHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "createTemplate",
+      "docs": [
+        "Save a reusable auction configuration for `clone_auction`"
+      ],
+      "discriminator": [
+        245,
+        51,
+        247,
+        234,
+        31,
+        9,
+        40,
+        227
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "template",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  101,
+                  109,
+                  112,
+                  108,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "templateId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "templateId",
+          "type": "u64"
+        },
+        {
+          "name": "bins",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "auctionBinParams"
+              }
+            }
+          }
+        },
+        {
+          "name": "custody",
+          "type": "pubkey"
+        },
+        {
+          "name": "extensions",
+          "type": {
+            "defined": {
+              "name": "auctionExtensions"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "custodyCommitBatch",
+      "docs": [
+        "Custody commits to a bin on behalf of many users at once"
+      ],
+      "discriminator": [
+        94,
+        24,
+        34,
+        152,
+        229,
+        198,
+        141,
+        234
+      ],
+      "accounts": [
+        {
+          "name": "custody",
+          "docs": [
+            "Auction custody, pays the commitments and the rent of new `Committed` accounts"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "custodyPaymentToken",
+          "writable": true
+        },
+        {
+          "name": "vaultPaymentToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "round",
+          "docs": [
+            "Round of the bin (only needed for auctions with rounds)"
+          ],
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "binId",
+          "type": "u8"
+        },
+        {
+          "name": "entries",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "custodyCommitEntry"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "decreaseCommit",
+      "docs": [
+        "User decreases a commitment (renamed from revert_commit)"
+      ],
+      "discriminator": [
+        134,
+        84,
+        49,
+        225,
+        22,
+        251,
+        110,
+        176
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "committed"
+          ]
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "committed",
+          "writable": true
+        },
+        {
+          "name": "userPaymentToken",
+          "writable": true
+        },
+        {
+          "name": "vaultPaymentToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "round",
+          "docs": [
+            "Round of the bin (only needed for auctions with rounds)"
+          ],
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "binId",
+          "type": "u8"
+        },
+        {
+          "name": "paymentTokenReverted",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "emergencyControl",
+      "docs": [
+        "Emergency control for pausing/resuming auction operations"
+      ],
+      "discriminator": [
+        40,
+        126,
+        46,
+        161,
+        192,
+        12,
+        37,
+        0
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Only the auction's pauser can control emergency state"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "auction",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "emergencyControlParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "enableRedemption",
+      "docs": [
+        "Admin enables IOU redemption by depositing the real sale tokens"
+      ],
+      "discriminator": [
+        211,
+        139,
+        16,
+        132,
+        109,
+        189,
+        155,
+        48
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Real sale token mint that IOUs are redeemed for"
+          ]
+        },
+        {
+          "name": "redemptionTokenSource",
+          "docs": [
+            "Source of the real sale tokens backing the IOUs"
+          ],
+          "writable": true
+        },
+        {
+          "name": "redemptionTokenSourceAuthority",
+          "docs": [
+            "Authority of the redemption token source account"
+          ],
+          "signer": true
+        },
+        {
+          "name": "vaultRedemptionToken",
+          "docs": [
+            "Vault to hold the real sale tokens (created as PDA)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  100,
+                  101,
+                  109,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeAdminAction",
+      "docs": [
+        "Apply a queued price change once its timelock has passed, callable by anyone"
+      ],
+      "discriminator": [
+        137,
+        32,
+        74,
+        105,
+        59,
+        159,
+        150,
+        197
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Receives the rent of the executed action"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "auction",
+          "writable": true,
+          "relations": [
+            "adminAction"
+          ]
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminAction",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "exportAccounting",
+      "docs": [
+        "Emit the auction's ledger per bin as a fixed sequence of events for accounting"
+      ],
+      "discriminator": [
+        31,
+        47,
+        142,
+        159,
+        43,
+        239,
+        254,
+        159
+      ],
+      "accounts": [
+        {
+          "name": "auction"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalizeAuction",
+      "docs": [
+        "Finalize the auction after the commit period (permissionless)"
+      ],
+      "discriminator": [
+        220,
+        209,
+        175,
+        193,
+        57,
+        132,
+        241,
+        168
+      ],
+      "accounts": [
+        {
+          "name": "keeper",
+          "docs": [
+            "Permissionless caller, paid the keeper bounty (if enabled)"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "auctionResult",
+          "docs": [
+            "Settlement published for other programs, paid by the keeper"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  114,
+                  101,
+                  115,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "forceRefundMode",
+      "docs": [
+        "Freeze sale token claims and refund the effective payment of unclaimed sale tokens"
+      ],
+      "discriminator": [
+        200,
+        99,
+        27,
+        36,
+        142,
+        148,
+        54,
+        60
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "securityDepositTreasury",
+          "docs": [
+            "treasury (only needed when the deposit has a treasury)"
+          ],
+          "writable": true,
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "getAllocationRatio",
+      "docs": [
+        "Get the cached allocation ratio of a bin"
+      ],
+      "discriminator": [
+        140,
+        113,
+        159,
+        151,
+        248,
+        186,
+        187,
+        21
+      ],
+      "accounts": [
+        {
+          "name": "auction"
+        }
+      ],
+      "args": [
+        {
+          "name": "binId",
+          "type": "u8"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "getLaunchpadAdmin",
+      "docs": [
+        "Get the LaunchpadAdmin public key from the global config"
+      ],
+      "discriminator": [
+        8,
+        113,
+        195,
+        137,
+        50,
+        1,
+        35,
+        38
+      ],
+      "accounts": [
+        {
+          "name": "globalConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [],
+      "returns": "pubkey"
+    },
+    {
+      "name": "getProjectSummary",
+      "docs": [
+        "Get raised amounts, participants, fees, claim progress and withdrawal status at once"
+      ],
+      "discriminator": [
+        102,
+        76,
+        9,
+        14,
+        232,
+        251,
+        27,
+        204
+      ],
+      "accounts": [
+        {
+          "name": "auction"
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "projectSummary"
+        }
+      }
+    },
+    {
+      "name": "getRemainingEntitlement",
+      "docs": [
+        "Get the sale tokens and refund a user can still claim from a bin"
+      ],
+      "discriminator": [
+        255,
+        22,
+        87,
+        27,
+        177,
+        246,
+        47,
+        225
+      ],
+      "accounts": [
+        {
+          "name": "auction"
+        },
+        {
+          "name": "committed",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  116,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "arg",
+                "path": "user"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "user",
+          "type": "pubkey"
+        },
+        {
+          "name": "binId",
+          "type": "u8"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "remainingEntitlement"
+        }
+      }
+    },
+    {
+      "name": "getVestingSchedule",
+      "docs": [
+        "Get the unlock timeline of a user's sale tokens in a bin"
+      ],
+      "discriminator": [
+        100,
+        157,
+        203,
+        62,
+        57,
+        105,
+        187,
+        123
+      ],
+      "accounts": [
+        {
+          "name": "auction"
+        },
+        {
+          "name": "committed",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  116,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "arg",
+                "path": "user"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "user",
+          "type": "pubkey"
+        },
+        {
+          "name": "binId",
+          "type": "u8"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "vestingSchedule"
+        }
+      }
+    },
+    {
+      "name": "initAuction",
+      "docs": [
+        "Create a new auction with automatic vault creation"
+      ],
+      "discriminator": [
+        73,
+        108,
+        200,
+        53,
+        221,
+        115,
+        20,
+        41
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "auction",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "saleTokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "saleTokenMint"
+        },
+        {
+          "name": "paymentTokenMint"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "saleTokenSeller",
+          "docs": [
+            "Sale token seller's account (source for initial vault funding)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "saleTokenSellerAuthority",
+          "docs": [
+            "Authority of the sale token seller account"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vaultSaleToken",
+          "docs": [
+            "Vault to hold sale tokens (created as PDA)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  97,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultPaymentToken",
+          "docs": [
+            "Vault to hold payment tokens (created as PDA)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "commitStartTime",
+          "type": "i64"
+        },
+        {
+          "name": "commitEndTime",
+          "type": "i64"
+        },
+        {
+          "name": "claimStartTime",
+          "type": "i64"
+        },
+        {
+          "name": "bins",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "auctionBinParams"
+              }
+            }
+          }
+        },
+        {
+          "name": "custody",
+          "type": "pubkey"
+        },
+        {
+          "name": "extensions",
+          "type": {
+            "defined": {
+              "name": "auctionExtensions"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initConfig",
+      "docs": [
+        "Create the global config holding the LaunchpadAdmin, signed by the deploy-time admin"
+      ],
+      "discriminator": [
+        23,
+        235,
+        115,
+        232,
+        168,
+        96,
+        1,
+        231
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "admin",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "initFrontendFees",
+      "docs": [
+        "Registered front-end opens its fee balance in an auction"
+      ],
+      "discriminator": [
+        52,
+        242,
+        147,
+        10,
+        10,
+        127,
+        239,
+        18
+      ],
+      "accounts": [
+        {
+          "name": "frontend",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "auction"
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, registering front-ends"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "frontendFees",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  114,
+                  111,
+                  110,
+                  116,
+                  101,
+                  110,
+                  100,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "frontend"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initIdentityGroup",
+      "docs": [
+        "Create the record sharing the commit cap across an identity group's wallets"
+      ],
+      "discriminator": [
+        236,
+        57,
+        22,
+        231,
+        40,
+        149,
+        198,
+        192
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "auction"
+        },
+        {
+          "name": "identityGroup",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  100,
+                  101,
+                  110,
+                  116,
+                  105,
+                  116,
+                  121,
+                  95,
+                  103,
+                  114,
+                  111,
+                  117,
+                  112
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "arg",
+                "path": "groupId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "groupId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "initRound",
+      "docs": [
+        "Admin schedules a round of a round-based auction"
+      ],
+      "discriminator": [
+        147,
+        244,
+        62,
+        18,
+        218,
+        248,
+        44,
+        234
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "auction"
+        },
+        {
+          "name": "round",
+          "writable": true
+        },
+        {
+          "name": "previousRound",
+          "docs": [
+            "Previous round (only needed for rounds after the first)"
+          ],
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u8"
+        },
+        {
+          "name": "commitStartTime",
+          "type": "i64"
+        },
+        {
+          "name": "commitEndTime",
+          "type": "i64"
+        },
+        {
+          "name": "whitelistAuthority",
+          "type": {
+            "option": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "issueClaimVoucher",
+      "docs": [
+        "User converts unclaimed sale tokens into a claim voucher honored by the voucher partner"
+      ],
+      "discriminator": [
+        216,
+        89,
+        24,
+        75,
+        246,
+        12,
+        40,
+        121
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "signer": true
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, blocking claims of exploited sale mints"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "committed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  116,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultSaleToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  97,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "partnerSaleToken",
+          "docs": [
+            "Sale token account of the voucher partner"
+          ],
+          "writable": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "address": "5dhQapnBy7pXnuPR9fTbgvFt4SsZCWiwQ4qtMEVSMDvZ"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "frontendFees",
+          "docs": [
+            "Fee balance of the front-end committed through (only needed for such commitments)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  114,
+                  111,
+                  110,
+                  116,
+                  101,
+                  110,
+                  100,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "committed"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "binId",
+          "type": "u8"
+        },
+        {
+          "name": "saleTokenAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "joinWaitlist",
+      "docs": [
+        "User joins the waitlist of a full hard-cap bin, escrowing payment tokens"
+      ],
+      "discriminator": [
+        44,
+        90,
+        151,
+        255,
+        199,
+        17,
+        177,
+        44
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "committed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  116,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "waitlistEntry",
+          "writable": true
+        },
+        {
+          "name": "userPaymentToken",
+          "writable": true
+        },
+        {
+          "name": "vaultPaymentToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sysvarInstructions",
+          "optional": true
+        },
+        {
+          "name": "capAttestation",
+          "docs": [
+            "User's wallet-quality attestation (only needed when commit cap scaling is enabled)"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  112,
+                  95,
+                  97,
+                  116,
+                  116,
+                  101,
+                  115,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userProfile",
+          "docs": [
+            "User's loyalty profile (only needed when loyalty cap multipliers are enabled)"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  115,
+                  101,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "round",
+          "docs": [
+            "Round of the bin (only needed for auctions with rounds)"
+          ],
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "binId",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        },
+        {
+          "name": "paymentTokenAmount",
+          "type": "u64"
+        },
+        {
+          "name": "expiry",
+          "type": "u64"
+        },
+        {
+          "name": "termsHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "leaveWaitlist",
+      "docs": [
+        "User leaves a waitlist and takes back the escrowed payment tokens"
+      ],
+      "discriminator": [
+        115,
+        211,
+        169,
+        27,
+        12,
+        153,
+        253,
+        122
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "waitlistEntry"
+          ]
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "waitlistEntry",
+          "writable": true
+        },
+        {
+          "name": "committed",
+          "docs": [
+            "User's commitments, tracking the escrow against the commit cap (only omitted",
+            "once claims closed the account)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  116,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userPaymentToken",
+          "writable": true
+        },
+        {
+          "name": "vaultPaymentToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "logEvent",
+      "docs": [
+        "Event self-CPI target, only callable by the program through an event authority"
+      ],
+      "discriminator": [
+        5,
+        9,
+        90,
+        141,
+        223,
+        134,
+        57,
+        217
+      ],
+      "accounts": [
+        {
+          "name": "eventAuthority",
+          "signer": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auction"
+        }
+      ],
+      "args": [
+        {
+          "name": "eventData",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "processWaitlist",
+      "docs": [
+        "Permissionless crank promoting waitlisted commitments into freed bin room"
+      ],
+      "discriminator": [
+        78,
+        168,
+        30,
+        66,
+        30,
+        33,
+        229,
+        188
+      ],
+      "accounts": [
+        {
+          "name": "keeper",
+          "docs": [
+            "Permissionless caller, paid the keeper bounty (if enabled)"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "binId",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "queueAdminAction",
+      "docs": [
+        "Queue a price change or fund withdrawal behind the auction's timelock"
+      ],
+      "discriminator": [
+        133,
+        176,
+        217,
+        14,
+        35,
+        198,
+        156,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "auction"
+        },
+        {
+          "name": "adminAction",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  97,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "arg",
+                "path": "actionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "actionId",
+          "type": "u64"
+        },
+        {
+          "name": "action",
+          "type": {
+            "defined": {
+              "name": "adminActionKind"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "reconcileFees",
+      "docs": [
+        "Repair claim fee accounting exceeding what the per-bin claims can have collected"
+      ],
+      "discriminator": [
+        229,
+        55,
+        97,
+        168,
+        202,
+        84,
+        167,
+        27
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "vaultSaleToken",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  97,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "recoverSurplus",
+      "docs": [
+        "Admin recovers vault balances exceeding every accounted liability"
+      ],
+      "discriminator": [
+        139,
+        222,
+        216,
+        117,
+        189,
+        233,
+        251,
+        158
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "auction"
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultSaleToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  97,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultPaymentToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "saleTokenRecipient",
+          "docs": [
+            "Authority's sale token account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "paymentTokenRecipient",
+          "docs": [
+            "Authority's payment token account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "vaultRedemptionToken",
+          "docs": [
+            "Redemption vault of a placeholder auction (optional)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  100,
+                  101,
+                  109,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "redemptionTokenRecipient",
+          "docs": [
+            "Authority's real sale token account (required with the redemption vault)"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redeem",
+      "docs": [
+        "User redeems IOUs for the real sale tokens"
+      ],
+      "discriminator": [
+        184,
+        12,
+        86,
+        149,
+        70,
+        196,
+        97,
+        225
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "redemption"
+          ]
+        },
+        {
+          "name": "auction",
+          "relations": [
+            "redemption"
+          ]
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "redemption",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  100,
+                  101,
+                  109,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Real sale token mint enabled by `enable_redemption`"
+          ]
+        },
+        {
+          "name": "userRedemptionToken",
+          "docs": [
+            "User's real sale token account (will be created if needed)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "redemptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultRedemptionToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  100,
+                  101,
+                  109,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refundBatch",
+      "docs": [
+        "Keepers refund commitments in bulk for a refund-only auction (permissionless)"
+      ],
+      "discriminator": [
+        227,
+        54,
+        194,
+        2,
+        78,
+        8,
+        104,
+        29
+      ],
+      "accounts": [
+        {
+          "name": "keeper",
+          "docs": [
+            "Permissionless caller, paid the keeper bounty (if enabled)"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultPaymentToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "crankCursor",
+          "docs": [
+            "Refund crank progress, shared by every caller of this crank"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "registerIndexer",
+      "docs": [
+        "Emit the full auction state through the event authority for indexer re-sync"
+      ],
+      "discriminator": [
+        201,
+        178,
+        152,
+        21,
+        57,
+        232,
+        215,
+        145
+      ],
+      "accounts": [
+        {
+          "name": "auction"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "address": "5dhQapnBy7pXnuPR9fTbgvFt4SsZCWiwQ4qtMEVSMDvZ"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "registerLut",
+      "docs": [
+        "Create or extend an address lookup table of the auction's fixed accounts"
+      ],
+      "discriminator": [
+        134,
+        229,
+        183,
+        141,
+        109,
+        148,
+        76,
+        154
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "auction"
+        },
+        {
+          "name": "lookupTable",
+          "writable": true
+        },
+        {
+          "name": "addressLookupTableProgram",
+          "address": "AddressLookupTab1e1111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "recentSlot",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "returnNft",
+      "docs": [
+        "User takes back an escrowed NFT while committing or after a failed auction"
+      ],
+      "discriminator": [
+        224,
+        67,
+        212,
+        248,
+        72,
+        43,
+        233,
+        1
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "committed",
+            "nftEscrow"
+          ]
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "committed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  116,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nftEscrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  110,
+                  102,
+                  116,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "nft_escrow.nft_mint",
+                "account": "nftEscrow"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userNftToken",
+          "writable": true
+        },
+        {
+          "name": "vaultNftToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "nft_escrow.nft_mint",
+                "account": "nftEscrow"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revokeWhitelistSignatures",
+      "docs": [
+        "Whitelist authority revokes a user's outstanding signatures by raising their nonce"
+      ],
+      "discriminator": [
+        175,
+        158,
+        151,
+        135,
+        191,
+        40,
+        249,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "whitelistAuthority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "auction"
+        },
+        {
+          "name": "committed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  109,
+                  105,
+                  116,
+                  116,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "arg",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "user",
+          "type": "pubkey"
+        },
+        {
+          "name": "nonceFloor",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setAuctionRoles",
+      "docs": [
+        "Admin delegates pausing, pricing, fee and fund withdrawal operations to other keys"
+      ],
+      "discriminator": [
+        38,
+        101,
+        238,
+        86,
+        28,
+        196,
+        189,
+        40
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "roles",
+          "type": {
+            "defined": {
+              "name": "auctionRoles"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setFrontendFeeShare",
+      "docs": [
+        "Set the share of the claim fees accrued to the front-end committed through"
+      ],
+      "discriminator": [
+        106,
+        86,
+        60,
+        76,
+        28,
+        134,
+        50,
+        192
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "feeShareBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setFrontendRegistered",
+      "docs": [
+        "Register or unregister an integrating front-end users may commit through"
+      ],
+      "discriminator": [
+        223,
+        84,
+        215,
+        145,
+        222,
+        100,
+        39,
+        233
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "frontendId",
+          "type": "pubkey"
+        },
+        {
+          "name": "registered",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setGlobalPause",
+      "docs": [
+        "Pause operations on every auction at once, same flags as `emergency_control`"
+      ],
+      "discriminator": [
+        32,
+        234,
+        28,
+        216,
+        67,
+        76,
+        116,
+        231
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "pausedOperations",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setLaunchpadConfig",
+      "docs": [
+        "Set the payment mints and claim grace period of new auctions, and the",
+        "governance expected to hold the program upgrade authority"
+      ],
+      "discriminator": [
+        16,
+        162,
+        227,
+        194,
+        103,
+        68,
+        217,
+        149
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "approvedPaymentMints",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "allowUnlistedPaymentMints",
+          "type": "bool"
+        },
+        {
+          "name": "minClaimGracePeriod",
+          "type": "i64"
+        },
+        {
+          "name": "governanceAuthority",
+          "type": {
+            "option": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPrice",
+      "docs": [
+        "Admin sets new price for a bin"
+      ],
+      "discriminator": [
+        16,
+        19,
+        182,
+        8,
+        149,
+        83,
+        72,
+        181
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "binId",
+          "type": "u8"
+        },
+        {
+          "name": "newPrice",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setProtocolFee",
+      "docs": [
+        "Set the platform fee taken from the proceeds of every auction by `withdraw_funds`"
+      ],
+      "discriminator": [
+        173,
+        239,
+        83,
+        242,
+        136,
+        43,
+        144,
+        217
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "globalConfig"
+          ]
+        },
+        {
+          "name": "globalConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "protocolFee",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "protocolFee"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setRedemptionMint",
+      "docs": [
+        "Admin sets the real mint that placeholder sale tokens are swapped into"
+      ],
+      "discriminator": [
+        103,
+        32,
+        159,
+        121,
+        170,
+        26,
+        248,
+        188
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Real sale token mint that IOUs are redeemed for"
+          ]
+        },
+        {
+          "name": "redemptionTokenSource",
+          "docs": [
+            "Source of the real sale tokens backing the IOUs"
+          ],
+          "writable": true
+        },
+        {
+          "name": "redemptionTokenSourceAuthority",
+          "docs": [
+            "Authority of the redemption token source account"
+          ],
+          "signer": true
+        },
+        {
+          "name": "vaultRedemptionToken",
+          "docs": [
+            "Vault to hold the real sale tokens (created as PDA)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  100,
+                  101,
+                  109,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setRevenueShare",
+      "docs": [
+        "Route a share of withdrawn fees to the staking rewards vaults of the launchpad token"
+      ],
+      "discriminator": [
+        117,
+        157,
+        85,
+        210,
+        97,
+        47,
+        57,
+        174
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "revenueShare",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "revenueShare"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setSaleMintBlocked",
+      "docs": [
+        "Block or unblock claims and withdrawals of every auction selling a sale mint"
+      ],
+      "discriminator": [
+        65,
+        35,
+        238,
+        56,
+        209,
+        238,
+        156,
+        217
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "saleTokenMint",
+          "type": "pubkey"
+        },
+        {
+          "name": "blocked",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setTermsHash",
+      "docs": [
+        "Admin sets the hash of the sale terms acknowledged by committing users"
+      ],
+      "discriminator": [
+        48,
+        115,
+        17,
+        76,
+        41,
+        185,
+        97,
+        181
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "termsHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "simulateSettlement",
+      "docs": [
+        "Preview the settlement of the auction in its current state"
+      ],
+      "discriminator": [
+        193,
+        26,
+        4,
+        163,
+        200,
+        252,
+        8,
+        176
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "auction"
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "settlementSimulation"
+        }
+      }
+    },
+    {
+      "name": "swapPlaceholder",
+      "docs": [
+        "User swaps placeholder sale tokens for the real sale tokens 1:1"
+      ],
+      "discriminator": [
+        122,
+        147,
+        180,
+        191,
+        202,
+        117,
+        76,
+        88
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "redemption"
+          ]
+        },
+        {
+          "name": "auction",
+          "writable": true,
+          "relations": [
+            "redemption"
+          ]
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "saleTokenMint",
+          "docs": [
+            "Placeholder sale token mint"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userSaleToken",
+          "docs": [
+            "User's placeholder sale token account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "redemption",
+          "docs": [
+            "User's redemption ledger, recording the placeholders they claimed"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  100,
+                  101,
+                  109,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Real sale token mint set by `set_redemption_mint`"
+          ]
+        },
+        {
+          "name": "userRedemptionToken",
+          "docs": [
+            "User's real sale token account (will be created if needed)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "redemptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultRedemptionToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  114,
+                  101,
+                  100,
+                  101,
+                  109,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "sweepUnclaimed",
+      "docs": [
+        "Admin sweeps tokens left unclaimed at the claim deadline to its recovery"
+      ],
+      "discriminator": [
+        64,
+        168,
+        221,
+        224,
+        42,
+        216,
+        138,
+        144
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultSaleToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  97,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultPaymentToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "saleTokenRecovery",
+          "docs": [
+            "Recovery's sale token account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "paymentTokenRecovery",
+          "docs": [
+            "Recovery's payment token account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "topUpSaleTokens",
+      "docs": [
+        "Admin adds sale tokens to a sale bin until the commit period ends, raising its cap"
+      ],
+      "discriminator": [
+        19,
+        48,
+        98,
+        86,
+        6,
+        190,
+        93,
+        156
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "saleTokenSeller",
+          "docs": [
+            "Sale token seller's account (source of the top-up)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "saleTokenSellerAuthority",
+          "docs": [
+            "Authority of the sale token seller account"
+          ],
+          "signer": true
+        },
+        {
+          "name": "vaultSaleToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  97,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "binId",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "transferAuctionAuthority",
+      "docs": [
+        "Hand an auction's authority over, e.g. to the admin after a rotation"
+      ],
+      "discriminator": [
+        86,
+        199,
+        88,
+        55,
+        20,
+        123,
+        144,
+        172
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "globalConfig"
+          ]
+        },
+        {
+          "name": "globalConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "auction",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "updateBinCap",
+      "docs": [
+        "Admin changes the sale token cap of a bin before the commit period starts"
+      ],
+      "discriminator": [
+        13,
+        18,
+        217,
+        129,
+        79,
+        131,
+        148,
+        221
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "saleTokenSeller",
+          "docs": [
+            "Sale token seller's account, depositing a cap increase or receiving a decrease"
+          ],
+          "writable": true
+        },
+        {
+          "name": "saleTokenSellerAuthority",
+          "docs": [
+            "Authority of the sale token seller account"
+          ],
+          "signer": true
+        },
+        {
+          "name": "vaultSaleToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  97,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "binId",
+          "type": "u8"
+        },
+        {
+          "name": "saleTokenCap",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateConfig",
+      "docs": [
+        "Rotate the LaunchpadAdmin stored in the global config"
+      ],
+      "discriminator": [
+        29,
+        158,
+        252,
+        191,
+        10,
+        83,
+        219,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "globalConfig"
+          ]
+        },
+        {
+          "name": "globalConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "admin",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "updateTimes",
+      "docs": [
+        "Admin pushes auction times later, each only until it has passed"
+      ],
+      "discriminator": [
+        71,
+        0,
+        145,
+        173,
+        123,
+        205,
+        66,
+        140
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, holding the minimum claim grace period"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "commitStartTime",
+          "type": "i64"
+        },
+        {
+          "name": "commitEndTime",
+          "type": "i64"
+        },
+        {
+          "name": "claimStartTime",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "verifyProgramAuthority",
+      "docs": [
+        "Check whether the program upgrade authority is the configured governance"
+      ],
+      "discriminator": [
+        94,
+        69,
+        125,
+        120,
+        111,
+        114,
+        0,
+        164
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "address": "5dhQapnBy7pXnuPR9fTbgvFt4SsZCWiwQ4qtMEVSMDvZ"
+        },
+        {
+          "name": "programData"
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "programAuthorityStatus"
+        }
+      }
+    },
+    {
+      "name": "withdrawFees",
+      "docs": [
+        "Admin withdraws collected fees from all bins, all of them unless `amount` is set"
+      ],
+      "discriminator": [
+        198,
+        212,
+        171,
+        109,
+        144,
+        215,
+        174,
+        89
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Fee manager, or the configured fee recipient withdrawing to itself"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "saleTokenMint",
+          "docs": [
+            "Sale token mint, the currency claim fees are collected in"
+          ]
+        },
+        {
+          "name": "vaultSaleToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  97,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeRecipientOwner"
+        },
+        {
+          "name": "feeRecipientAccount",
+          "docs": [
+            "Any sale token account of the fee recipient"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, routing a share of the fees to stakers"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakingRewardsVault",
+          "docs": [
+            "Staking rewards vault for the sale mint, required with a revenue share"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "stats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdrawFrontendFees",
+      "docs": [
+        "Front-end withdraws the claim fees accrued to it in an auction"
+      ],
+      "discriminator": [
+        220,
+        180,
+        234,
+        246,
+        229,
+        108,
+        150,
+        208
+      ],
+      "accounts": [
+        {
+          "name": "frontend",
+          "signer": true
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "frontendFees",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  114,
+                  111,
+                  110,
+                  116,
+                  101,
+                  110,
+                  100,
+                  95,
+                  102,
+                  101,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              },
+              {
+                "kind": "account",
+                "path": "frontend"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultSaleToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  97,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "frontendSaleToken",
+          "docs": [
+            "Any sale token account of the front-end"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawFunds",
+      "docs": [
+        "Admin withdraws funds from all auction bins, after finalization"
+      ],
+      "discriminator": [
+        241,
+        36,
+        29,
+        111,
+        208,
+        31,
+        104,
+        217
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "auction",
+          "writable": true,
+          "relations": [
+            "adminAction"
+          ]
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, blocking withdrawals of exploited sale mints"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "globalConfig",
+          "docs": [
+            "Global config, holding the protocol fee"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  108,
+                  111,
+                  98,
+                  97,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "saleTokenMint",
+          "docs": [
+            "Sale token mint"
+          ]
+        },
+        {
+          "name": "paymentTokenMint",
+          "docs": [
+            "Payment token mint, burned from for buy-and-burn launches"
+          ],
+          "writable": true
+        },
+        {
+          "name": "vaultSaleToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  97,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultPaymentToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "saleTokenRecipientOwner"
+        },
+        {
+          "name": "saleTokenRecipient",
+          "writable": true
+        },
+        {
+          "name": "paymentTokenRecipientOwner"
+        },
+        {
+          "name": "paymentTokenRecipient",
+          "writable": true
+        },
+        {
+          "name": "lamportTreasury",
+          "docs": [
+            "Auction lamport treasury, pays for the recipient token accounts (optional)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  97,
+                  109,
+                  112,
+                  111,
+                  114,
+                  116,
+                  95,
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminAction",
+          "docs": [
+            "Matured withdrawal queued by `queue_admin_action`, required by timelocked auctions"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "coSigner",
+          "docs": [
+            "Co-signer of withdrawals above the co-approval threshold (only needed for those)"
+          ],
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "treasuryPaymentToken",
+          "docs": [
+            "Platform treasury's payment token account (only needed with a proceeds split)"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "protocolTreasuryPaymentToken",
+          "docs": [
+            "Protocol treasury's payment token account (only needed with a protocol fee)"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawLamports",
+      "docs": [
+        "Admin withdraws lamports from the auction lamport treasury"
+      ],
+      "discriminator": [
+        251,
+        144,
+        115,
+        229,
+        113,
+        247,
+        206,
+        64
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "auction"
+        },
+        {
+          "name": "lamportTreasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  97,
+                  109,
+                  112,
+                  111,
+                  114,
+                  116,
+                  95,
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawLateRefundPool",
+      "docs": [
+        "Transfer the late refunds owed to the late refund pool, callable by anyone"
+      ],
+      "discriminator": [
+        139,
+        31,
+        50,
+        253,
+        245,
+        58,
+        123,
+        95
+      ],
+      "accounts": [
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Launchpad config, pausing operations program-wide"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultPaymentToken",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction"
+              }
+            ]
+          }
+        },
+        {
+          "name": "poolPaymentToken",
+          "docs": [
+            "Payment token account of the late refund pool recipient"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "adminAction",
+      "discriminator": [
+        21,
+        121,
+        247,
+        106,
+        40,
+        20,
+        114,
+        122
+      ]
+    },
+    {
+      "name": "auction",
+      "discriminator": [
+        218,
+        94,
+        247,
+        242,
+        126,
+        233,
+        131,
+        81
+      ]
+    },
+    {
+      "name": "auctionResult",
+      "discriminator": [
+        182,
+        105,
+        71,
+        113,
+        228,
+        147,
+        117,
+        135
+      ]
+    },
+    {
+      "name": "commitCapAttestation",
+      "discriminator": [
+        0,
+        91,
+        22,
+        3,
+        64,
+        115,
+        139,
+        32
+      ]
+    },
+    {
+      "name": "committed",
+      "discriminator": [
+        110,
+        101,
+        81,
+        18,
+        224,
+        16,
+        150,
+        18
+      ]
+    },
+    {
+      "name": "crankCursor",
+      "discriminator": [
+        54,
+        51,
+        14,
+        243,
+        111,
+        112,
+        166,
+        203
+      ]
+    },
+    {
+      "name": "frontendFees",
+      "discriminator": [
+        13,
+        243,
+        213,
+        4,
+        241,
+        127,
+        74,
+        156
+      ]
+    },
+    {
+      "name": "globalConfig",
+      "discriminator": [
+        149,
+        8,
+        156,
+        202,
+        160,
+        252,
+        176,
+        217
+      ]
+    },
+    {
+      "name": "identityGroup",
+      "discriminator": [
+        26,
+        26,
+        54,
+        242,
+        216,
+        233,
+        12,
+        231
+      ]
+    },
+    {
+      "name": "launchpadConfig",
+      "discriminator": [
+        205,
+        61,
+        113,
+        174,
+        159,
+        51,
+        248,
+        24
+      ]
+    },
+    {
+      "name": "launchpadStats",
+      "discriminator": [
+        181,
+        113,
+        152,
+        87,
+        81,
+        232,
+        141,
+        181
+      ]
+    },
+    {
+      "name": "nftEscrow",
+      "discriminator": [
+        57,
+        233,
+        220,
+        115,
+        252,
+        74,
+        56,
+        49
+      ]
+    },
+    {
+      "name": "redemption",
+      "discriminator": [
+        112,
+        75,
+        232,
+        189,
+        22,
+        114,
+        156,
+        203
+      ]
+    },
+    {
+      "name": "round",
+      "discriminator": [
+        87,
+        127,
+        165,
+        51,
+        73,
+        78,
+        116,
+        174
+      ]
+    },
+    {
+      "name": "template",
+      "discriminator": [
+        43,
+        26,
+        88,
+        69,
+        69,
+        96,
+        9,
+        79
+      ]
+    },
+    {
+      "name": "userProfile",
+      "discriminator": [
+        32,
+        37,
+        119,
+        205,
+        179,
+        180,
+        13,
+        194
+      ]
+    },
+    {
+      "name": "waitlistEntry",
+      "discriminator": [
+        115,
+        50,
+        18,
+        157,
+        178,
+        220,
+        23,
+        34
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "accountingHeaderEvent",
+      "discriminator": [
+        245,
+        85,
+        130,
+        179,
+        75,
+        199,
+        25,
+        232
+      ]
+    },
+    {
+      "name": "accountingTotalsEvent",
+      "discriminator": [
+        73,
+        123,
+        229,
+        95,
+        223,
+        145,
+        188,
+        113
+      ]
+    },
+    {
+      "name": "auctionAbortedEvent",
+      "discriminator": [
+        143,
+        38,
+        74,
+        36,
+        98,
+        167,
+        173,
+        124
+      ]
+    },
+    {
+      "name": "auctionArchivedEvent",
+      "discriminator": [
+        155,
+        135,
+        67,
+        64,
+        141,
+        154,
+        103,
+        35
+      ]
+    },
+    {
+      "name": "auctionCancelledEvent",
+      "discriminator": [
+        227,
+        104,
+        113,
+        229,
+        217,
+        101,
+        252,
+        120
+      ]
+    },
+    {
+      "name": "auctionStateEvent",
+      "discriminator": [
+        235,
+        17,
+        111,
+        230,
+        164,
+        38,
+        83,
+        64
+      ]
+    },
+    {
+      "name": "binAccountingEvent",
+      "discriminator": [
+        151,
+        150,
+        10,
+        212,
+        126,
+        124,
+        192,
+        8
+      ]
+    },
+    {
+      "name": "claimVoucherEvent",
+      "discriminator": [
+        167,
+        153,
+        176,
+        137,
+        51,
+        16,
+        188,
+        93
+      ]
+    },
+    {
+      "name": "commitEvent",
+      "discriminator": [
+        252,
+        78,
+        246,
+        83,
+        244,
+        83,
+        218,
+        56
+      ]
+    },
+    {
+      "name": "committedAccountClosedEvent",
+      "discriminator": [
+        99,
+        216,
+        87,
+        242,
+        221,
+        79,
+        123,
+        30
+      ]
+    },
+    {
+      "name": "custodyClaimEvent",
+      "discriminator": [
+        64,
+        125,
+        219,
+        93,
+        154,
+        246,
+        209,
+        65
+      ]
+    },
+    {
+      "name": "emergencyControlEvent",
+      "discriminator": [
+        3,
+        252,
+        56,
+        49,
+        253,
+        0,
+        21,
+        181
+      ]
+    },
+    {
+      "name": "feesReconciledEvent",
+      "discriminator": [
+        121,
+        161,
+        135,
+        1,
+        59,
+        50,
+        87,
+        16
+      ]
+    },
+    {
+      "name": "forceRefundModeEvent",
+      "discriminator": [
+        184,
+        103,
+        81,
+        78,
+        245,
+        50,
+        22,
+        128
+      ]
+    },
+    {
+      "name": "priceStaleEvent",
+      "discriminator": [
+        218,
+        146,
+        105,
+        132,
+        33,
+        167,
+        197,
+        40
+      ]
+    },
+    {
+      "name": "proceedsBurnedEvent",
+      "discriminator": [
+        9,
+        44,
+        78,
+        119,
+        0,
+        128,
+        183,
+        83
+      ]
+    },
+    {
+      "name": "saleTokensToppedUpEvent",
+      "discriminator": [
+        18,
+        29,
+        87,
+        208,
+        106,
+        142,
+        20,
+        29
+      ]
+    },
+    {
+      "name": "surplusRecoveredEvent",
+      "discriminator": [
+        11,
+        153,
+        89,
+        46,
+        201,
+        36,
+        13,
+        234
+      ]
+    },
+    {
+      "name": "timesUpdatedEvent",
+      "discriminator": [
+        136,
+        34,
+        204,
+        85,
+        217,
+        137,
+        97,
+        37
+      ]
+    },
+    {
+      "name": "unclaimedSweptEvent",
+      "discriminator": [
+        138,
+        160,
+        227,
+        190,
+        109,
+        143,
+        160,
+        78
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 12000,
+      "name": "operationPaused",
+      "msg": "Operation is paused by emergency control"
+    },
+    {
+      "code": 12001,
+      "name": "onlyLaunchpadAdmin",
+      "msg": "Only LaunchpadAdmin can access this function"
+    },
+    {
+      "code": 12002,
+      "name": "pauseMessageTooLong",
+      "msg": "Pause message is too long"
+    },
+    {
+      "code": 12003,
+      "name": "saleMintBlocked",
+      "msg": "Sale token mint is blocked by the launchpad config"
+    },
+    {
+      "code": 12004,
+      "name": "invalidProgramData",
+      "msg": "Program data account doesn't belong to the program"
+    },
+    {
+      "code": 12005,
+      "name": "timelockRequired",
+      "msg": "Timelocked auctions require a queued admin action"
+    },
+    {
+      "code": 12006,
+      "name": "invalidAdminAction",
+      "msg": "Invalid admin action"
+    },
+    {
+      "code": 12007,
+      "name": "adminActionNotReady",
+      "msg": "Admin action timelock has not passed"
+    },
+    {
+      "code": 12008,
+      "name": "auctionCancelled",
+      "msg": "Auction is cancelled"
+    },
+    {
+      "code": 12009,
+      "name": "cancelAfterCommitStart",
+      "msg": "Auctions can only be cancelled before the commit period starts"
+    },
+    {
+      "code": 12010,
+      "name": "abortWindowClosed",
+      "msg": "Auctions can only be aborted from commit start until claims or withdrawals"
+    },
+    {
+      "code": 12011,
+      "name": "auctionAborted",
+      "msg": "Auction is aborted"
+    },
+    {
+      "code": 12012,
+      "name": "globallyPaused",
+      "msg": "Operation is paused program-wide by the launchpad config"
+    },
+    {
+      "code": 12013,
+      "name": "missingAuctionResult",
+      "msg": "Aborting a finalized auction requires its result and the launchpad stats"
+    },
+    {
+      "code": 12100,
+      "name": "mathOverflow",
+      "msg": "Math overflow"
+    },
+    {
+      "code": 12101,
+      "name": "mathUnderflow",
+      "msg": "Math underflow"
+    },
+    {
+      "code": 12102,
+      "name": "divisionByZero",
+      "msg": "Division by zero"
+    },
+    {
+      "code": 12103,
+      "name": "invalidCalculation",
+      "msg": "Invalid calculation"
+    },
+    {
+      "code": 12104,
+      "name": "unauthorized",
+      "msg": "unauthorized"
+    },
+    {
+      "code": 12105,
+      "name": "invalidLookupTable",
+      "msg": "Invalid address lookup table"
+    },
+    {
+      "code": 12200,
+      "name": "invalidAuctionTimeRange",
+      "msg": "Invalid auction time range"
+    },
+    {
+      "code": 12201,
+      "name": "invalidAuctionBinsLength",
+      "msg": "Must have 1-10 auction bins"
+    },
+    {
+      "code": 12202,
+      "name": "invalidAuctionBinsPriceOrCap",
+      "msg": "Auction bin price and cap must be greater than zero"
+    },
+    {
+      "code": 12203,
+      "name": "claimFeeNotSupportedInIouMode",
+      "msg": "Claim fees are not supported in IOU mode"
+    },
+    {
+      "code": 12204,
+      "name": "invalidDonationBin",
+      "msg": "Donation bin price and cap must be zero"
+    },
+    {
+      "code": 12205,
+      "name": "invalidFreeClaimBin",
+      "msg": "Free-claim bin must have zero price and a per-user amount within its cap"
+    },
+    {
+      "code": 12206,
+      "name": "freeClaimRequiresWhitelist",
+      "msg": "Free-claim bins require the whitelist to be enabled"
+    },
+    {
+      "code": 12207,
+      "name": "invalidNftPaymentConfig",
+      "msg": "NFT payment value must be greater than zero"
+    },
+    {
+      "code": 12208,
+      "name": "invalidCapScalingConfig",
+      "msg": "Commit cap scaling requires a commit cap and whitelist authority, and a share of at most 100%"
+    },
+    {
+      "code": 12209,
+      "name": "invalidHardCapBin",
+      "msg": "Only sale bins can have a hard cap"
+    },
+    {
+      "code": 12210,
+      "name": "invalidBinRounds",
+      "msg": "Bins must be ordered by round starting from round 0"
+    },
+    {
+      "code": 12211,
+      "name": "invalidLoyaltyConfig",
+      "msg": "Loyalty cap multipliers require a commit cap and must not decrease by tier"
+    },
+    {
+      "code": 12212,
+      "name": "invalidClaimFeeSchedule",
+      "msg": "Claim fee schedule requires claim fees, a positive window and a discounted early rate"
+    },
+    {
+      "code": 12213,
+      "name": "invalidKeeperBounty",
+      "msg": "Keeper bounty must be greater than zero"
+    },
+    {
+      "code": 12214,
+      "name": "paymentMintNotApproved",
+      "msg": "Payment token mint is not approved by the launchpad config"
+    },
+    {
+      "code": 12215,
+      "name": "tooManyApprovedPaymentMints",
+      "msg": "Too many approved payment mints"
+    },
+    {
+      "code": 12216,
+      "name": "binTargetOverflow",
+      "msg": "Bin cap times price overflows"
+    },
+    {
+      "code": 12217,
+      "name": "tooManyClaimFeeExemptUsers",
+      "msg": "Too many users exempt from claim fees"
+    },
+    {
+      "code": 12218,
+      "name": "invalidReservedRefundWindow",
+      "msg": "Reserved refund window must be greater than zero"
+    },
+    {
+      "code": 12219,
+      "name": "invalidMaxCommitPerTx",
+      "msg": "Per-transaction commit limit must be greater than zero"
+    },
+    {
+      "code": 12220,
+      "name": "invalidClaimGracePeriod",
+      "msg": "Claim grace period must not be negative"
+    },
+    {
+      "code": 12221,
+      "name": "claimGracePeriodTooShort",
+      "msg": "Claim start is too close to commit end"
+    },
+    {
+      "code": 12222,
+      "name": "invalidSecurityDeposit",
+      "msg": "Security deposit must be greater than zero"
+    },
+    {
+      "code": 12223,
+      "name": "invalidCustodyAllocationLimit",
+      "msg": "Custody allocation limit must be between 1 and 10000 basis points"
+    },
+    {
+      "code": 12224,
+      "name": "tooManyBlockedSaleMints",
+      "msg": "Too many blocked sale mints"
+    },
+    {
+      "code": 12225,
+      "name": "invalidBurnProceeds",
+      "msg": "Burned proceeds can't have a proceeds recipient or NFT payments"
+    },
+    {
+      "code": 12226,
+      "name": "invalidCommitBoost",
+      "msg": "Commit boost requires a positive lock period, a weight above 10000 basis points and no NFT payments"
+    },
+    {
+      "code": 12227,
+      "name": "tooManyWhitelistAuthorities",
+      "msg": "Too many whitelist authorities"
+    },
+    {
+      "code": 12228,
+      "name": "invalidClaimTimingFee",
+      "msg": "Claim timing fee requires a non-negative free period, a positive step and claim fees"
+    },
+    {
+      "code": 12229,
+      "name": "invalidLateRefundPool",
+      "msg": "Late refund pool requires a refund reservation, a positive ramp period and no NFT payments"
+    },
+    {
+      "code": 12230,
+      "name": "invalidMaxPriceAge",
+      "msg": "Maximum price age must be positive"
+    },
+    {
+      "code": 12231,
+      "name": "invalidAdminTimelock",
+      "msg": "Admin timelock must be positive"
+    },
+    {
+      "code": 12232,
+      "name": "invalidRevenueShare",
+      "msg": "Revenue share must be between 1 and 10000 basis points"
+    },
+    {
+      "code": 12233,
+      "name": "invalidStakingRewardsVault",
+      "msg": "Missing or mismatched staking rewards vault"
+    },
+    {
+      "code": 12234,
+      "name": "invalidTimeUpdate",
+      "msg": "Auction times can only be pushed later, before their phase has ended"
+    },
+    {
+      "code": 12235,
+      "name": "binLayoutLocked",
+      "msg": "Bins can only be changed before the commit period starts"
+    },
+    {
+      "code": 12236,
+      "name": "invalidTopUp",
+      "msg": "Top-ups must add sale tokens to a sale bin"
+    },
+    {
+      "code": 12237,
+      "name": "invalidProceedsSplit",
+      "msg": "Invalid proceeds split"
+    },
+    {
+      "code": 12238,
+      "name": "invalidBatchWindow",
+      "msg": "Invalid batch window"
+    },
+    {
+      "code": 12239,
+      "name": "invalidProtocolFee",
+      "msg": "Invalid protocol fee"
+    },
+    {
+      "code": 12240,
+      "name": "invalidReservationMode",
+      "msg": "Invalid commit-time reservation configuration"
+    },
+    {
+      "code": 12241,
+      "name": "reservedBinPriceLocked",
+      "msg": "Bin price is locked by sale tokens reserved at it"
+    },
+    {
+      "code": 12242,
+      "name": "invalidClaimDeadline",
+      "msg": "Invalid claim deadline"
+    },
+    {
+      "code": 12300,
+      "name": "outOfCommitmentPeriod",
+      "msg": "Out of commitment period"
+    },
+    {
+      "code": 12301,
+      "name": "invalidCommitmentAmount",
+      "msg": "Invalid commitment amount"
+    },
+    {
+      "code": 12302,
+      "name": "invalidBinId",
+      "msg": "Invalid bin ID"
+    },
+    {
+      "code": 12303,
+      "name": "commitmentBinCapExceeded",
+      "msg": "Commitment bin cap exceeded"
+    },
+    {
+      "code": 12304,
+      "name": "outOfClaimPeriod",
+      "msg": "Out of claim period"
+    },
+    {
+      "code": 12305,
+      "name": "invalidClaimAmount",
+      "msg": "Invalid claim amount"
+    },
+    {
+      "code": 12306,
+      "name": "commitCapExceeded",
+      "msg": "Commit cap exceeded"
+    },
+    {
+      "code": 12307,
+      "name": "unsupportedBinKind",
+      "msg": "Operation not supported for this bin kind"
+    },
+    {
+      "code": 12308,
+      "name": "freeClaimAlreadyRegistered",
+      "msg": "Already registered for this free-claim bin"
+    },
+    {
+      "code": 12309,
+      "name": "refundDeadlinePassed",
+      "msg": "Refund deadline has passed"
+    },
+    {
+      "code": 12310,
+      "name": "commitPerTxExceeded",
+      "msg": "Commitment exceeds the per-transaction limit"
+    },
+    {
+      "code": 12311,
+      "name": "claimOrderViolated",
+      "msg": "Claim order policy requires settling the other leg first"
+    },
+    {
+      "code": 12312,
+      "name": "invalidCustodyCommitBatch",
+      "msg": "Invalid custody commit batch accounts"
+    },
+    {
+      "code": 12313,
+      "name": "identityGroupCapExceeded",
+      "msg": "Identity group commit cap exceeded"
+    },
+    {
+      "code": 12314,
+      "name": "custodyAllocationLimitExceeded",
+      "msg": "Custody allocation limit of the bin exceeded"
+    },
+    {
+      "code": 12315,
+      "name": "invalidCustodyClaimBatch",
+      "msg": "Invalid custody claim batch"
+    },
+    {
+      "code": 12316,
+      "name": "commitBoostDisabled",
+      "msg": "Commitment boosting is not enabled"
+    },
+    {
+      "code": 12317,
+      "name": "boostedCommitmentLocked",
+      "msg": "Boosted commitments can't be decreased"
+    },
+    {
+      "code": 12318,
+      "name": "saleTokensLocked",
+      "msg": "Sale tokens of boosted commitments are still locked"
+    },
+    {
+      "code": 12319,
+      "name": "termsHashMismatch",
+      "msg": "Terms hash doesn't match the auction's sale terms"
+    },
+    {
+      "code": 12320,
+      "name": "termsHashLocked",
+      "msg": "Sale terms can no longer be updated"
+    },
+    {
+      "code": 12321,
+      "name": "termsNotAccepted",
+      "msg": "Updated sale terms must be accepted before claiming sale tokens"
+    },
+    {
+      "code": 12322,
+      "name": "priceStale",
+      "msg": "Bin price is stale"
+    },
+    {
+      "code": 12323,
+      "name": "pdaParticipantForbidden",
+      "msg": "Auction only accepts commitments signed at the top level of the transaction"
+    },
+    {
+      "code": 12324,
+      "name": "contractParticipantForbidden",
+      "msg": "Auction only accepts commitments from wallets"
+    },
+    {
+      "code": 12325,
+      "name": "vouchersDisabled",
+      "msg": "No voucher partner configured for this auction"
+    },
+    {
+      "code": 12326,
+      "name": "partialSaleTokenReservation",
+      "msg": "Commitment must pay for whole sale tokens in reservation mode"
+    },
+    {
+      "code": 12327,
+      "name": "claimPeriodEnded",
+      "msg": "Claim period has ended"
+    },
+    {
+      "code": 12328,
+      "name": "claimPeriodNotEnded",
+      "msg": "Claim period has not ended yet"
+    },
+    {
+      "code": 12329,
+      "name": "invalidCrossAuctionClaim",
+      "msg": "Invalid cross-auction claim accounts"
+    },
+    {
+      "code": 12400,
+      "name": "inCommitmentPeriod",
+      "msg": "In commitment period"
+    },
+    {
+      "code": 12401,
+      "name": "doubleFundsWithdrawal",
+      "msg": "Double funds withdrawal"
+    },
+    {
+      "code": 12402,
+      "name": "noClaimFeesConfigured",
+      "msg": "No claim fees configured for this auction"
+    },
+    {
+      "code": 12403,
+      "name": "invalidWithdrawRecipient",
+      "msg": "Withdraw recipient does not match the configured recipient"
+    },
+    {
+      "code": 12404,
+      "name": "lateRefundPoolDisabled",
+      "msg": "No late refund pool configured for this auction"
+    },
+    {
+      "code": 12405,
+      "name": "auctionNotSettled",
+      "msg": "Auction is not settled: not finalized, funds not withdrawn or vaults not empty"
+    },
+    {
+      "code": 12406,
+      "name": "coSignerRequired",
+      "msg": "Withdrawal above the co-approval threshold requires the co-signer"
+    },
+    {
+      "code": 12407,
+      "name": "invalidFeeWithdrawAmount",
+      "msg": "Fee withdrawal amount exceeds the withdrawable fees"
+    },
+    {
+      "code": 12408,
+      "name": "dustRecipientRequired",
+      "msg": "Vault dust requires a recipient account"
+    },
+    {
+      "code": 12501,
+      "name": "missingSysvarInstructions",
+      "msg": "Missing sysvar instructions account"
+    },
+    {
+      "code": 12502,
+      "name": "wrongProgram",
+      "msg": "Wrong program ID for Ed25519 instruction"
+    },
+    {
+      "code": 12503,
+      "name": "malformedEd25519Ix",
+      "msg": "Malformed Ed25519 instruction"
+    },
+    {
+      "code": 12504,
+      "name": "wrongWhitelistAuthority",
+      "msg": "Wrong whitelist authority"
+    },
+    {
+      "code": 12505,
+      "name": "payloadMismatch",
+      "msg": "Payload mismatch in signature verification"
+    },
+    {
+      "code": 12506,
+      "name": "signatureExpired",
+      "msg": "Signature expired"
+    },
+    {
+      "code": 12507,
+      "name": "nonceOverflow",
+      "msg": "Nonce overflow"
+    },
+    {
+      "code": 12508,
+      "name": "serializationError",
+      "msg": "Serialization error"
+    },
+    {
+      "code": 12509,
+      "name": "missingExpiry",
+      "msg": "Missing expiry timestamp for whitelist verification"
+    },
+    {
+      "code": 12510,
+      "name": "invalidCustodyAuthority",
+      "msg": "Invalid custody authority"
+    },
+    {
+      "code": 12511,
+      "name": "invalidCapAttestation",
+      "msg": "Cap attestation share must be at most 100%"
+    },
+    {
+      "code": 12512,
+      "name": "capScalingDisabled",
+      "msg": "Commit cap scaling is not enabled"
+    },
+    {
+      "code": 12513,
+      "name": "identityGroupRequiresWhitelist",
+      "msg": "Identity groups require the whitelist"
+    },
+    {
+      "code": 12514,
+      "name": "invalidNonceFloor",
+      "msg": "Nonce floor must be above the user's current nonce"
+    },
+    {
+      "code": 12600,
+      "name": "iouModeDisabled",
+      "msg": "Auction is not in IOU mode"
+    },
+    {
+      "code": 12601,
+      "name": "redemptionAlreadyEnabled",
+      "msg": "Redemption already enabled"
+    },
+    {
+      "code": 12602,
+      "name": "redemptionNotEnabled",
+      "msg": "Redemption not enabled"
+    },
+    {
+      "code": 12603,
+      "name": "missingRedemptionAccount",
+      "msg": "Missing redemption account"
+    },
+    {
+      "code": 12604,
+      "name": "nothingToRedeem",
+      "msg": "Nothing to redeem"
+    },
+    {
+      "code": 12605,
+      "name": "iouModeEnabled",
+      "msg": "Auction is in IOU mode"
+    },
+    {
+      "code": 12606,
+      "name": "placeholderSwapExceeded",
+      "msg": "Swap exceeds the placeholder sale tokens claimed by the user"
+    },
+    {
+      "code": 12700,
+      "name": "auctionNotRefundOnly",
+      "msg": "Auction is not in refund-only state"
+    },
+    {
+      "code": 12701,
+      "name": "invalidRefundBatch",
+      "msg": "Invalid refund batch accounts"
+    },
+    {
+      "code": 12703,
+      "name": "refundModeAlreadyEnabled",
+      "msg": "Auction already refunds all commitments"
+    },
+    {
+      "code": 12704,
+      "name": "saleClaimsFrozen",
+      "msg": "Sale token claims are frozen by forced refund mode"
+    },
+    {
+      "code": 12705,
+      "name": "invalidSecurityDepositTreasury",
+      "msg": "Missing or mismatched security deposit treasury"
+    },
+    {
+      "code": 12706,
+      "name": "fundsAlreadyWithdrawn",
+      "msg": "Refunds can't be forced after the effective payment was withdrawn"
+    },
+    {
+      "code": 12800,
+      "name": "auctionNotFinalized",
+      "msg": "Auction not finalized"
+    },
+    {
+      "code": 12801,
+      "name": "auctionAlreadyFinalized",
+      "msg": "Auction already finalized"
+    },
+    {
+      "code": 12900,
+      "name": "nftPaymentDisabled",
+      "msg": "Auction is not in NFT payment mode"
+    },
+    {
+      "code": 12901,
+      "name": "nftPaymentEnabled",
+      "msg": "Payment tokens are not accepted in NFT payment mode"
+    },
+    {
+      "code": 12902,
+      "name": "invalidNftMetadata",
+      "msg": "Invalid NFT metadata account"
+    },
+    {
+      "code": 12903,
+      "name": "invalidNftCollection",
+      "msg": "NFT is not a verified member of the auction collection"
+    },
+    {
+      "code": 12904,
+      "name": "invalidNftMint",
+      "msg": "Mint is not an NFT"
+    },
+    {
+      "code": 12905,
+      "name": "nftRefundRequiresReturn",
+      "msg": "NFT commitments are refunded by returning the escrowed NFT"
+    },
+    {
+      "code": 12906,
+      "name": "invalidNftEscrowState",
+      "msg": "NFT cannot be returned or collected in the current auction state"
+    },
+    {
+      "code": 13000,
+      "name": "binNotHardCapped",
+      "msg": "Bin has no hard cap"
+    },
+    {
+      "code": 13001,
+      "name": "binNotFull",
+      "msg": "Bin has room for the commitment"
+    },
+    {
+      "code": 13002,
+      "name": "waitlistNotEmpty",
+      "msg": "Bin has users waiting, join the waitlist"
+    },
+    {
+      "code": 13003,
+      "name": "invalidWaitlistSequence",
+      "msg": "Invalid waitlist sequence"
+    },
+    {
+      "code": 13004,
+      "name": "invalidWaitlistBatch",
+      "msg": "Invalid waitlist batch accounts"
+    },
+    {
+      "code": 13100,
+      "name": "invalidRoundConfig",
+      "msg": "Invalid round configuration"
+    },
+    {
+      "code": 13101,
+      "name": "invalidRoundAccount",
+      "msg": "Missing or mismatched round account"
+    },
+    {
+      "code": 13102,
+      "name": "roundNotOpen",
+      "msg": "Round of the bin is not open"
+    },
+    {
+      "code": 13103,
+      "name": "roundNotEnded",
+      "msg": "Round has not ended"
+    },
+    {
+      "code": 13200,
+      "name": "frontendNotRegistered",
+      "msg": "Front-end is not registered"
+    },
+    {
+      "code": 13201,
+      "name": "tooManyRegisteredFrontends",
+      "msg": "Too many registered front-ends"
+    },
+    {
+      "code": 13202,
+      "name": "invalidFrontendFeeShare",
+      "msg": "Front-end fee share must be at most 10000 bps"
+    },
+    {
+      "code": 13203,
+      "name": "invalidFrontendFeesAccount",
+      "msg": "Missing or mismatched front-end fees account"
+    }
+  ],
+  "types": [
+    {
+      "name": "accountingHeaderEvent",
+      "docs": [
+        "First event of `export_accounting`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "type": "pubkey"
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "saleTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "paymentTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "binCount",
+            "docs": [
+              "Number of `BinAccountingEvent`s following"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "finalized",
+            "type": "bool"
+          },
+          {
+            "name": "forceRefund",
+            "type": "bool"
+          },
+          {
+            "name": "refundOnly",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "accountingTotalsEvent",
+      "docs": [
+        "Auction-wide refunds, withdrawals and fees, last event of `export_accounting`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalPaymentRefunded",
+            "type": "u64"
+          },
+          {
+            "name": "totalRefundDiverted",
+            "type": "u64"
+          },
+          {
+            "name": "totalPaymentReleased",
+            "type": "u64"
+          },
+          {
+            "name": "totalPaymentWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "totalPoolWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "totalSaleSwept",
+            "type": "u64"
+          },
+          {
+            "name": "totalPaymentSwept",
+            "type": "u64"
+          },
+          {
+            "name": "unsoldSaleTokensWithdrawn",
+            "type": "bool"
+          },
+          {
+            "name": "totalFeesCollected",
+            "type": "u64"
+          },
+          {
+            "name": "totalFeesWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "totalRentFunded",
+            "type": "u64"
+          },
+          {
+            "name": "securityDepositHeld",
+            "type": "u64"
+          },
+          {
+            "name": "securityDepositSlashed",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminAction",
+      "docs": [
+        "Sensitive admin action waiting for the auction's timelock",
+        "PDA: [\"admin_action\", auction, action_id]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "docs": [
+              "Auction the action applies to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "actionId",
+            "docs": [
+              "Id chosen by the queuer, so several actions can be pending"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "adminActionKind"
+              }
+            }
+          },
+          {
+            "name": "eta",
+            "docs": [
+              "Earliest execution time"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminActionKind",
+      "docs": [
+        "Admin operation queued in an `AdminAction`"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "setPrice",
+            "fields": [
+              {
+                "name": "binId",
+                "type": "u8"
+              },
+              {
+                "name": "newPrice",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "withdrawFunds"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auction",
+      "docs": [
+        "Core auction data account",
+        "PDA: [\"auction\", sale_token_mint]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "docs": [
+              "Launchpad admin"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "custody",
+            "docs": [
+              "Custody account for special permissions"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "saleTokenMint",
+            "docs": [
+              "Sale token mint"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "paymentTokenMint",
+            "docs": [
+              "Payment token mint"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "commitStartTime",
+            "docs": [
+              "Auction timing"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "commitEndTime",
+            "type": "i64"
+          },
+          {
+            "name": "claimStartTime",
+            "type": "i64"
+          },
+          {
+            "name": "bins",
+            "docs": [
+              "Auction bins (up to 10 bins)"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "auctionBin"
+                }
+              }
+            }
+          },
+          {
+            "name": "extensions",
+            "docs": [
+              "Extension configuration (directly embedded)"
+            ],
+            "type": {
+              "defined": {
+                "name": "auctionExtensions"
+              }
+            }
+          },
+          {
+            "name": "emergencyState",
+            "docs": [
+              "Emergency control state (newly added)"
+            ],
+            "type": {
+              "defined": {
+                "name": "emergencyState"
+              }
+            }
+          },
+          {
+            "name": "totalParticipants",
+            "docs": [
+              "Total number of unique participants in this auction"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unsoldSaleTokensAndEffectivePaymentTokensWithdrawn",
+            "docs": [
+              "Whether the unsold sale tokens and effective payment tokens have been",
+              "withdrawn, which is used to prevent double withdrawal by `withdraw_funds`"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "finalized",
+            "docs": [
+              "Whether `finalize_auction` has cached the allocation ratio of every bin"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "forceRefund",
+            "docs": [
+              "Whether `force_refund_mode` froze sale token claims, refunding the",
+              "effective payment of unclaimed sale tokens instead"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "cancelled",
+            "docs": [
+              "Whether `cancel_auction` pulled the launch before its commit period, blocking",
+              "every further operation"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "aborted",
+            "docs": [
+              "Whether `abort_auction` turned the auction refund-only, refunding every",
+              "commitment in full and blocking further commits"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "roundCount",
+            "docs": [
+              "Number of consecutive rounds the bins are split into (1 without rounds)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "roundsClosed",
+            "docs": [
+              "Number of rounds closed by `close_round`, i.e. the index of the open round"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "totalSaleEntitled",
+            "docs": [
+              "Sale tokens owed to all users, recorded by `finalize_auction`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalRefundEntitled",
+            "docs": [
+              "Payment tokens refunded to all users, recorded by `finalize_auction`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalPaymentRefunded",
+            "docs": [
+              "Payment tokens refunded to users by `claim` and `refund_batch`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalPaymentReleased",
+            "docs": [
+              "Payment tokens of fully claimed commitments not refunded, i.e. settled proceeds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalPaymentWithdrawn",
+            "docs": [
+              "Payment tokens withdrawn by `withdraw_funds`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalRefundDiverted",
+            "docs": [
+              "Payment tokens of late refunds diverted to the late refund pool by `claim`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalPoolWithdrawn",
+            "docs": [
+              "Payment tokens withdrawn by `withdraw_late_refund_pool`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalSaleSwept",
+            "docs": [
+              "Sale tokens left unclaimed at the claim deadline, swept by `sweep_unclaimed`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalPaymentSwept",
+            "docs": [
+              "Refunds left unclaimed at the claim deadline, swept by `sweep_unclaimed`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "waitlistEscrowed",
+            "docs": [
+              "Payment tokens escrowed by waitlist entries not promoted or left yet"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalRentFunded",
+            "docs": [
+              "Lamports paid by the lamport treasury for token accounts created on behalf of users"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalFeesCollected",
+            "docs": [
+              "Total fees collected from claimed sale tokens"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalFeesWithdrawn",
+            "docs": [
+              "Fees withdrawn already"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalFrontendFeesAccrued",
+            "docs": [
+              "Share of the claim fees accrued to front-ends users committed through"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalFrontendFeesWithdrawn",
+            "docs": [
+              "Front-end fees withdrawn by `withdraw_frontend_fees`, also in `total_fees_withdrawn`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "securityDepositHeld",
+            "docs": [
+              "Security deposit lamports still escrowed in the auction account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "securityDepositSlashed",
+            "docs": [
+              "Security deposit lamports slashed to participants by `force_refund_mode`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "redemptionMint",
+            "docs": [
+              "Real sale token mint delivered by `redeem` (IOU mode) or `swap_placeholder`,",
+              "set by `enable_redemption` / `set_redemption_mint`"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "placeholderSwapped",
+            "docs": [
+              "Placeholder sale tokens swapped by `swap_placeholder`, bounded per user by the",
+              "placeholders recorded in their redemption ledger"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "termsHash",
+            "docs": [
+              "Hash of the sale terms users acknowledge by passing it to `commit`, set by",
+              "`set_terms_hash` (all zeros until then). Users who acknowledged earlier terms",
+              "accept the updated ones on `claim` before claiming sale tokens"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "roles",
+            "docs": [
+              "Keys delegated individual operations of the authority, set by `set_auction_roles`"
+            ],
+            "type": {
+              "defined": {
+                "name": "auctionRoles"
+              }
+            }
+          },
+          {
+            "name": "vaultSaleBump",
+            "docs": [
+              "Vault PDA bump seeds for derivation"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "vaultPaymentBump",
+            "type": "u8"
+          },
+          {
+            "name": "vaultRedemptionBump",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionAbortedEvent",
+      "docs": [
+        "Auction abort event"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "totalPaymentRaised",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionArchivedEvent",
+      "docs": [
+        "Archival record of a closed auction, emitted through the event authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "type": "pubkey"
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "stateHash",
+            "docs": [
+              "SHA-256 of the auction account data when it was closed"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "saleTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "paymentTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalParticipants",
+            "type": "u64"
+          },
+          {
+            "name": "totalPaymentRaised",
+            "type": "u64"
+          },
+          {
+            "name": "totalSaleEntitled",
+            "type": "u64"
+          },
+          {
+            "name": "totalRefundEntitled",
+            "type": "u64"
+          },
+          {
+            "name": "totalPaymentWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "totalFeesCollected",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionBin",
+      "docs": [
+        "Individual auction bin data"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "saleTokenPrice",
+            "docs": [
+              "Price per sale token (in payment tokens)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "saleTokenCap",
+            "docs": [
+              "Maximum sale tokens this bin can sell"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "paymentTokenRaised",
+            "docs": [
+              "Payment tokens actually raised in this bin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "saleTokenClaimed",
+            "docs": [
+              "Sale tokens already claimed from this bin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "allocationRatio",
+            "docs": [
+              "Allocation ratio scaled by `PRECISION_FACTOR`, cached by `finalize_auction`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "freeClaimAmount",
+            "docs": [
+              "Fixed sale tokens each registered user claims (free-claim bins only)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "freeClaimRegistered",
+            "docs": [
+              "Number of users registered for the free claim (free-claim bins only)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "kind",
+            "docs": [
+              "Kind of the bin"
+            ],
+            "type": {
+              "defined": {
+                "name": "binKind"
+              }
+            }
+          },
+          {
+            "name": "hardCap",
+            "docs": [
+              "Rejects commitments beyond the cap instead of allocating pro-rata (sale bins only)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "waitlistHead",
+            "docs": [
+              "Sequence number of the next waitlist entry to promote"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "waitlistTail",
+            "docs": [
+              "Sequence number assigned to the next waitlist entry"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "round",
+            "docs": [
+              "Round the bin sells in"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "claimFeeRate",
+            "docs": [
+              "Claim fee rate of this bin, overriding the auction `claim_fee_rate` (if set)"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "feesCollected",
+            "docs": [
+              "Claim fees collected from this bin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "participants",
+            "docs": [
+              "Number of distinct users who committed to this bin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "custodyCommitted",
+            "docs": [
+              "Payment tokens committed through the custody, directly or by its authorization"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "boostedCommitted",
+            "docs": [
+              "Payment tokens of commitments boosted by `boost_commitment`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "boostWeightBps",
+            "docs": [
+              "Allocation weight of boosted commitments in basis points (0 if boosting is disabled)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "priceUpdatedAt",
+            "docs": [
+              "Time the price was last set, at creation or by `set_price`"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "windowCommitted",
+            "docs": [
+              "Payment tokens committed in the batch window, allowed past the hard cap and",
+              "sharing what earlier commitments left of the target pro-rata"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "saleTokenDistributed",
+            "docs": [
+              "Sale tokens claimed net of the claim fees, i.e. what claims took out of the vault"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "saleTokenReserved",
+            "docs": [
+              "Sale tokens reserved by commitments in reservation mode"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionBinParams",
+      "docs": [
+        "Parameters for creating auction bins"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "saleTokenPrice",
+            "type": "u64"
+          },
+          {
+            "name": "saleTokenCap",
+            "type": "u64"
+          },
+          {
+            "name": "freeClaimAmount",
+            "docs": [
+              "Fixed sale tokens per registered user, only for free-claim bins"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": {
+                "name": "binKind"
+              }
+            }
+          },
+          {
+            "name": "hardCap",
+            "docs": [
+              "Reject commitments beyond the cap, only for sale bins"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "round",
+            "docs": [
+              "Round the bin sells in, bins must be ordered by round starting from 0"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "claimFeeRate",
+            "docs": [
+              "Claim fee rate overriding the auction `claim_fee_rate` for this bin"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionCancelledEvent",
+      "docs": [
+        "Auction cancellation event"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "saleTokenRecipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "saleTokensReturned",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionExtensions",
+      "docs": [
+        "Extension configuration data (embedded in Auction)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "whitelistAuthorities",
+            "docs": [
+              "Whitelist authorities for access control (disabled if empty), any of them may",
+              "sign, e.g. blue/green backend keys during rotation, at most `MAX_WHITELIST_AUTHORITIES`"
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "commitCapPerUser",
+            "docs": [
+              "Per-user commitment cap (if enabled)"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "claimFeeRate",
+            "docs": [
+              "Claim fee rate (if enabled), bins may override it with their own rate"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "iouMode",
+            "docs": [
+              "Pre-TGE IOU mode: claims are recorded in a `Redemption` PDA instead of",
+              "transferring sale tokens, which are delivered by `redeem` once minted"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "softCap",
+            "docs": [
+              "Minimum payment tokens raised across all bins (if enabled), below which",
+              "the auction fails and every commitment is refunded"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "nftPayment",
+            "docs": [
+              "NFT payment mode (if enabled): users commit by escrowing NFTs from a",
+              "collection instead of payment tokens"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "nftPaymentConfig"
+                }
+              }
+            }
+          },
+          {
+            "name": "unattestedCapBps",
+            "docs": [
+              "Commit cap scaling (if enabled): share of `commit_cap_per_user` in basis",
+              "points granted to wallets without a `CommitCapAttestation`"
+            ],
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "loyaltyCapBps",
+            "docs": [
+              "Loyalty cap multipliers (if enabled): `commit_cap_per_user` multiplier in",
+              "basis points for `UserProfile` tiers 1 to `LOYALTY_TIER_COUNT`"
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u16",
+                  3
+                ]
+              }
+            }
+          },
+          {
+            "name": "claimFeeSchedule",
+            "docs": [
+              "Early-committer claim fee discount (if enabled), replacing `claim_fee_rate`",
+              "for commitments made early in the commit period"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "claimFeeSchedule"
+                }
+              }
+            }
+          },
+          {
+            "name": "keeperBounty",
+            "docs": [
+              "Lamports paid to callers of permissionless cranks (if enabled), out of the",
+              "auction account's balance above rent exemption, topped up by the authority"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "claimFeeExemptUsers",
+            "docs": [
+              "Users exempt from claim fees, e.g. the project treasury or market makers,",
+              "at most `MAX_CLAIM_FEE_EXEMPT_USERS`"
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "unsoldSaleTokenRecipient",
+            "docs": [
+              "Owner of the account receiving unsold sale tokens in `withdraw_funds`,",
+              "e.g. a burn or community wallet (the authority if not set)"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "proceedsRecipient",
+            "docs": [
+              "Owner of the account receiving payment token proceeds in `withdraw_funds`,",
+              "e.g. the project treasury (the authority if not set)"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "reservedRefundWindow",
+            "docs": [
+              "Refund reservation (if enabled): seconds after `claim_start_time` during which",
+              "`withdraw_funds` only releases the payment of settled commitments, keeping",
+              "outstanding refunds in the payment vault"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "maxCommitPerTx",
+            "docs": [
+              "Maximum payment tokens committed by a single `commit` or `join_waitlist`",
+              "(if enabled), forcing large commitments to be split"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "securityDeposit",
+            "docs": [
+              "Project security deposit (if enabled), escrowed in the auction account at",
+              "creation, slashed by `force_refund_mode` and returned by `withdraw_funds` otherwise"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "securityDeposit"
+                }
+              }
+            }
+          },
+          {
+            "name": "claimOrder",
+            "docs": [
+              "Which leg of `claim` must be settled first while both are outstanding"
+            ],
+            "type": {
+              "defined": {
+                "name": "claimOrder"
+              }
+            }
+          },
+          {
+            "name": "custodyAllocationLimit",
+            "docs": [
+              "Share of each bin's payment target in basis points that custody-routed",
+              "commitments may take (if enabled), preserving room for direct participants"
+            ],
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "burnProceeds",
+            "docs": [
+              "Buy-and-burn launch: `withdraw_funds` burns the effective payment tokens",
+              "instead of transferring them to the proceeds recipient"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "commitBoost",
+            "docs": [
+              "Commitment boosting (if enabled): users may lock their sale tokens for longer",
+              "in exchange for a larger share of oversubscribed bins"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "commitBoost"
+                }
+              }
+            }
+          },
+          {
+            "name": "claimTimingFee",
+            "docs": [
+              "Claim timing fee (if enabled): claims are fee-free for a while after",
+              "`claim_start_time`, then pay a growing share of their committed claim fee"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "claimTimingFee"
+                }
+              }
+            }
+          },
+          {
+            "name": "lateRefundPool",
+            "docs": [
+              "Late refund pool (if enabled): refunds stay claimable after the refund deadline,",
+              "with a growing share going to a community pool instead of the proceeds"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "lateRefundPool"
+                }
+              }
+            }
+          },
+          {
+            "name": "maxPriceAge",
+            "docs": [
+              "Maximum age in seconds of sale bin prices (if enabled): commitments are refused",
+              "until the price setter refreshes a stale price"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "adminTimelock",
+            "docs": [
+              "Delay in seconds of `set_price` and `withdraw_funds` (if enabled), queued by",
+              "`queue_admin_action` so committed users can react before they take effect"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "forbidPdaParticipants",
+            "docs": [
+              "Refuse program-controlled participants: commitments must be top-level instructions,",
+              "since PDAs can only sign through CPIs (wallets calling through other programs too)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "eoaOnly",
+            "docs": [
+              "EOA-only participation, e.g. when legal terms require direct human participants:",
+              "commitments are refused from program-owned accounts and PDAs"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "feeRecipient",
+            "docs": [
+              "Owner of the account receiving claim fees in `withdraw_fees`, e.g. the",
+              "platform treasury (the authority if not set), also allowed to withdraw them"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "withdrawCoApproval",
+            "docs": [
+              "Four-eyes rule on `withdraw_funds` (if enabled): withdrawals above a threshold",
+              "must also be signed by a co-signer, e.g. the custody or a guardian"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "withdrawCoApproval"
+                }
+              }
+            }
+          },
+          {
+            "name": "voucherPartner",
+            "docs": [
+              "Redemption partner (if enabled), e.g. an exchange distributing entitlements",
+              "off-chain: owner of the account receiving the sale tokens of claim vouchers"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "proceedsSplit",
+            "docs": [
+              "Platform revenue split (if enabled): share of the payment tokens withdrawn by",
+              "`withdraw_funds` sent to the platform treasury, the rest to the proceeds recipient"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "proceedsSplit"
+                }
+              }
+            }
+          },
+          {
+            "name": "batchWindow",
+            "docs": [
+              "Batch window in seconds before `commit_end_time` (if enabled): commitments to",
+              "hard-cap bins in the window may exceed the cap and are settled pro-rata among",
+              "themselves, so landing first in the final seconds doesn't pay off"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "reserveAtCommit",
+            "docs": [
+              "Commitments reserve whole sale tokens at the bin price when made, so bins can't",
+              "be oversubscribed and settle without a pro-rata step"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "claimDeadline",
+            "docs": [
+              "Claim deadline (if enabled): claims close `claim_window` seconds after",
+              "`claim_start_time`, and `sweep_unclaimed` moves what was left to the recovery"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "claimDeadline"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionResult",
+      "docs": [
+        "Compact settlement of an auction, written once by `finalize_auction` and only",
+        "modified by `abort_auction` turning it refund-only, for other programs to read",
+        "instead of the mutable `Auction` account",
+        "PDA: [\"auction_result\", auction]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "docs": [
+              "Settled auction"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "saleTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "paymentTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "finalizedAt",
+            "docs": [
+              "Time of `finalize_auction`"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "refundOnly",
+            "docs": [
+              "Whether the auction failed its soft cap or was aborted, and refunds every commitment"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "totalPaymentRaised",
+            "docs": [
+              "Payment tokens raised across all bins"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalSaleEntitled",
+            "docs": [
+              "Sale tokens owed to all users"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalRefundEntitled",
+            "docs": [
+              "Payment tokens refunded to all users"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bins",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "binResult"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionRoles",
+      "docs": [
+        "Role delegates of an auction (embedded in Auction), the authority where unset"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pauser",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "priceSetter",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "feeManager",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "fundWithdrawer",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionStateEvent",
+      "docs": [
+        "Full auction state, emitted by `register_indexer` through the event authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionKey",
+            "type": "pubkey"
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "auction",
+            "type": {
+              "defined": {
+                "name": "auction"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "binAccountingEvent",
+      "docs": [
+        "Ledger of one bin, emitted by `export_accounting` in bin ID order"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "type": "pubkey"
+          },
+          {
+            "name": "binId",
+            "type": "u8"
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": {
+                "name": "binKind"
+              }
+            }
+          },
+          {
+            "name": "saleTokenDeposited",
+            "docs": [
+              "Sale tokens deposited into the bin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "paymentTokenDeposited",
+            "docs": [
+              "Payment tokens committed to the bin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "saleTokensSold",
+            "type": "u64"
+          },
+          {
+            "name": "effectivePaymentTokens",
+            "docs": [
+              "Payment tokens paying for the sold sale tokens, or donated"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "refundEntitled",
+            "docs": [
+              "Payment tokens owed back to the bin's participants"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "saleTokenClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "feesCollected",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "binKind",
+      "docs": [
+        "Kind of an auction bin"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "sale"
+          },
+          {
+            "name": "donation"
+          },
+          {
+            "name": "freeClaim"
+          }
+        ]
+      }
+    },
+    {
+      "name": "binResult",
+      "docs": [
+        "Final price and allocation of a bin"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "saleTokenPrice",
+            "docs": [
+              "Final price per sale token (in payment tokens)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "saleTokenCap",
+            "docs": [
+              "Maximum sale tokens the bin sold"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "paymentTokenRaised",
+            "docs": [
+              "Payment tokens raised in the bin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "allocationRatio",
+            "docs": [
+              "Allocation ratio scaled by `PRECISION_FACTOR`"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "binSummary",
+      "docs": [
+        "Progress of one auction bin in a `ProjectSummary`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paymentTokenRaised",
+            "docs": [
+              "Payment tokens raised in the bin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "participants",
+            "docs": [
+              "Number of distinct users who committed to the bin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "saleTokenClaimed",
+            "docs": [
+              "Sale tokens claimed from the bin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "feesCollected",
+            "docs": [
+              "Claim fees collected from the bin"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "claimDeadline",
+      "docs": [
+        "Deadline of claims, after which unclaimed tokens are swept to a recovery account"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "claimWindow",
+            "docs": [
+              "Seconds after `claim_start_time` during which sale tokens and refunds can be claimed"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "recovery",
+            "docs": [
+              "Owner of the token accounts receiving the swept sale and payment tokens"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "claimFeeSchedule",
+      "docs": [
+        "Claim fee schedule rewarding early commitments"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "earlyWindow",
+            "docs": [
+              "Seconds from the commit start time during which commitments get the early rate"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "earlyFeeRate",
+            "docs": [
+              "Claim fee rate for early commitments, capped at each bin's regular rate"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "claimOrder",
+      "docs": [
+        "Order in which `claim` settles the sale token and refund legs of a commitment"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "any"
+          },
+          {
+            "name": "refundFirst"
+          },
+          {
+            "name": "saleFirst"
+          }
+        ]
+      }
+    },
+    {
+      "name": "claimTimingFee",
+      "docs": [
+        "Claim fee schedule rewarding prompt claims"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "freePeriod",
+            "docs": [
+              "Seconds from the claim start time during which claims are fee-free"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "stepPeriod",
+            "docs": [
+              "Seconds after which the fee rate grows by another `step_fee_rate`"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "stepFeeRate",
+            "docs": [
+              "Fee rate added every `step_period`, capped at the committed claim fee rate"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "claimVoucherEvent",
+      "docs": [
+        "Claim voucher honored by the voucher partner, emitted through the event authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "partner",
+            "type": "pubkey"
+          },
+          {
+            "name": "binId",
+            "type": "u8"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Index of the voucher among the user's vouchers of this auction"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "saleTokenAmount",
+            "docs": [
+              "Entitlement consumed by the voucher"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "partnerSaleTokens",
+            "docs": [
+              "Sale tokens moved to the partner, net of the claim fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "claimFee",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "commitBoost",
+      "docs": [
+        "Commitment boost trading a longer lock of sale tokens for allocation weight"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lockPeriod",
+            "docs": [
+              "Seconds after `claim_start_time` during which sale tokens of boosted",
+              "commitments stay locked"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "weightBps",
+            "docs": [
+              "Allocation weight of boosted commitments in basis points, above 10000"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "commitCapAttestation",
+      "docs": [
+        "Wallet-quality attestation scaling a user's commit cap",
+        "PDA: [\"cap_attestation\", auction_key, user_key]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "docs": [
+              "Reference to the auction account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "docs": [
+              "Attested user"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "capBps",
+            "docs": [
+              "Share of the per-user commit cap granted, in basis points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "commitDenialReason",
+      "docs": [
+        "Why a commitment would be rejected, see `CommitEligibility::limit` for the",
+        "limit reported with each reason"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "none"
+          },
+          {
+            "name": "paused"
+          },
+          {
+            "name": "cancelled"
+          },
+          {
+            "name": "aborted"
+          },
+          {
+            "name": "notStarted"
+          },
+          {
+            "name": "ended"
+          },
+          {
+            "name": "invalidBin"
+          },
+          {
+            "name": "roundNotOpen"
+          },
+          {
+            "name": "invalidAmount"
+          },
+          {
+            "name": "perTxLimitExceeded"
+          },
+          {
+            "name": "nftPaymentOnly"
+          },
+          {
+            "name": "alreadyRegistered"
+          },
+          {
+            "name": "waitlistNotEmpty"
+          },
+          {
+            "name": "userCapExceeded"
+          },
+          {
+            "name": "binCapExceeded"
+          },
+          {
+            "name": "priceStale"
+          }
+        ]
+      }
+    },
+    {
+      "name": "commitEligibility",
+      "docs": [
+        "Result of `can_commit`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "reason",
+            "type": {
+              "defined": {
+                "name": "commitDenialReason"
+              }
+            }
+          },
+          {
+            "name": "limit",
+            "docs": [
+              "Limit relevant to `reason`, documented on each reason"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "commitEvent",
+      "docs": [
+        "Event emitted when a commitment is accepted"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "receipt",
+            "type": {
+              "defined": {
+                "name": "commitReceipt"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "commitReceipt",
+      "docs": [
+        "Accepted commitment, emitted in `CommitEvent` and returned by `commit`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "docs": [
+              "The auction committed to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "docs": [
+              "User who made the commitment"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "binId",
+            "docs": [
+              "Bin committed to"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "paymentTokenCommitted",
+            "docs": [
+              "Payment tokens accepted by this commitment"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "userBinPaymentTokenCommitted",
+            "docs": [
+              "User's total payment tokens committed to the bin afterwards"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "binPaymentTokenRaised",
+            "docs": [
+              "Bin's total payment tokens raised afterwards"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sequence",
+            "docs": [
+              "User's commit sequence, i.e. the `Committed` nonce consumed by this commitment"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "attribution",
+            "docs": [
+              "Attribution tag of a custody commitment (if any)"
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "termsHash",
+            "docs": [
+              "Hash of the sale terms in effect, acknowledged by the user for direct commitments"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "whitelistSigner",
+            "docs": [
+              "Whitelist authority whose signature authorized the commitment (if any)"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "batchWindowId",
+            "docs": [
+              "Batch window the commitment was settled in (if any), identified by its start time"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "committed",
+      "docs": [
+        "User commitment data for all auction bins, zero-copy with a slot for every bin an",
+        "auction can have so the account never grows",
+        "PDA: [\"committed\", auction_key, user_key]"
+      ],
+      "serialization": "bytemuck",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "docs": [
+              "Reference to the auction account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "docs": [
+              "User who made the commitment"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "User's nonce for whitelist signature verification (prevents replay attacks)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "termsHash",
+            "docs": [
+              "Hash of the sale terms the user last acknowledged, by any commitment or `claim`"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "refundDiverted",
+            "docs": [
+              "Payment tokens of the user's late refunds diverted to the late refund pool"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "vouchersIssued",
+            "docs": [
+              "Claim vouchers issued by `issue_claim_voucher`, the nonce of the next one"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "waitlistPending",
+            "docs": [
+              "Payment tokens escrowed in the user's waitlist entries, counted against the",
+              "commit cap until promoted or taken back"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "frontendId",
+            "docs": [
+              "Front-end the user first committed through, accruing a share of the claim fees",
+              "(all zeros for direct commitments)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bins",
+            "docs": [
+              "Commitments of the user, the slot of each bin at index `bin_id`"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "committedBin"
+                  }
+                },
+                10
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          }
+        ]
+      }
     },
-    {
-      "name": "committed",
-      "discriminator": [
-        110,
-        101,
-        81,
-        18,
-        224,
-        16,
-        150,
-        18
-      ]
-    }
-  ],
-  "events": [
     {
       "name": "committedAccountClosedEvent",
-      "discriminator": [
-        99,
-        216,
-        87,
-        242,
-        221,
-        79,
-        123,
-        30
-      ]
-    },
-    {
-      "name": "emergencyControlEvent",
-      "discriminator": [
-        3,
-        252,
-        56,
-        49,
-        253,
-        0,
-        21,
-        181
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 12000,
-      "name": "operationPaused",
-      "msg": "Operation is paused by emergency control"
-    },
-    {
-      "code": 12001,
-      "name": "onlyLaunchpadAdmin",
-      "msg": "Only LaunchpadAdmin can access this function"
-    },
-    {
-      "code": 12100,
-      "name": "mathOverflow",
-      "msg": "Math overflow"
+      "docs": [
+        "Event emitted when a user's Committed account is fully claimed and closed"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "userKey",
+            "docs": [
+              "User who owned the committed account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "auctionKey",
+            "docs": [
+              "The auction this commitment was for"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "committedAccountKey",
+            "docs": [
+              "The committed account that was closed"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "rentReturned",
+            "docs": [
+              "Amount of rent returned to the user (in lamports)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "committedData",
+            "docs": [
+              "Snapshot of the committed account data at time of closure"
+            ],
+            "type": {
+              "defined": {
+                "name": "committedAccountSnapshot"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12101,
-      "name": "mathUnderflow",
-      "msg": "Math underflow"
+      "name": "committedAccountSnapshot",
+      "docs": [
+        "Snapshot of Committed account data for the closure event"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "docs": [
+              "Reference to the auction account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "docs": [
+              "User who made the commitment"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bins",
+            "docs": [
+              "All bins this user committed to"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "committedBinSnapshot"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "totalPaymentCommitted",
+            "docs": [
+              "Total payment tokens committed across all bins"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalSaleTokensClaimed",
+            "docs": [
+              "Total sale tokens claimed across all bins"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12102,
-      "name": "divisionByZero",
-      "msg": "Division by zero"
+      "name": "committedBin",
+      "docs": [
+        "Individual bin commitment data within a user's commitment, kept in the slot of its",
+        "bin in `Committed::bins`"
+      ],
+      "serialization": "bytemuck",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paymentTokenCommitted",
+            "docs": [
+              "Amount of payment tokens committed to this bin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "saleTokenClaimed",
+            "docs": [
+              "Amount of sale tokens already claimed from this bin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "paymentTokenRefunded",
+            "docs": [
+              "Payment tokens already refunded from this bin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "claimFeeRate",
+            "docs": [
+              "Claim fee rate recorded at commit time, weighted by amount across commitments"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "boostedCommitted",
+            "docs": [
+              "Part of `payment_token_committed` boosted by `boost_commitment`, allocated at the",
+              "boosted weight and locked for the boost lock period after `claim_start_time`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "windowCommitted",
+            "docs": [
+              "Part of `payment_token_committed` committed in the batch window of a hard-cap bin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "attribution",
+            "docs": [
+              "Attribution tag of the latest custody commitment to this bin, e.g. the hash of an",
+              "exchange user id, for reconciling the exchange's distribution (see `attribution()`)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "binId",
+            "docs": [
+              "Bin ID"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "active",
+            "docs": [
+              "Whether the user committed to this bin, unused slots are all zeros"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "hasAttribution",
+            "docs": [
+              "Whether `attribution` is set"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                5
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12103,
-      "name": "invalidCalculation",
-      "msg": "Invalid calculation"
+      "name": "committedBinSnapshot",
+      "docs": [
+        "Snapshot of a bin the user committed to, for the closure event"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "binId",
+            "docs": [
+              "Bin ID"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "paymentTokenCommitted",
+            "docs": [
+              "Amount of payment tokens committed to this bin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "saleTokenClaimed",
+            "docs": [
+              "Amount of sale tokens claimed from this bin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "paymentTokenRefunded",
+            "docs": [
+              "Payment tokens refunded from this bin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "claimFeeRate",
+            "docs": [
+              "Claim fee rate recorded at commit time"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "attribution",
+            "docs": [
+              "Attribution tag of the latest custody commitment to this bin (if any)"
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "boostedCommitted",
+            "docs": [
+              "Part of `payment_token_committed` boosted by `boost_commitment`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "windowCommitted",
+            "docs": [
+              "Part of `payment_token_committed` committed in the batch window of a hard-cap bin"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12104,
-      "name": "unauthorized",
-      "msg": "unauthorized"
+      "name": "crankCursor",
+      "docs": [
+        "Progress of a paginated crank over an auction's users",
+        "PDA: [\"crank_cursor\", auction_key, crank_kind]",
+        "",
+        "Informational only, keepers may process users in any order. Each crank closes",
+        "or marks what it processed, so a user is never processed twice, and the cursor",
+        "lets keepers monitor and resume progress."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "docs": [
+              "Reference to the auction account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "kind",
+            "docs": [
+              "Crank this cursor belongs to"
+            ],
+            "type": {
+              "defined": {
+                "name": "crankKind"
+              }
+            }
+          },
+          {
+            "name": "processedCount",
+            "docs": [
+              "Number of users processed so far"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastProcessedUser",
+            "docs": [
+              "Last processed user key"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 12200,
-      "name": "invalidAuctionTimeRange",
-      "msg": "Invalid auction time range"
+      "name": "crankKind",
+      "docs": [
+        "Kinds of paginated crank instructions over an auction's users, each tracked",
+        "by its own cursor",
+        "",
+        "Only `refund_batch` walks users. Other cranks keep their progress on the",
+        "auction itself, like `process_waitlist` on the bin's waitlist head and",
+        "`close_round` on `rounds_closed`, and `sweep_unclaimed` settles in a single call."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "refund"
+          }
+        ]
+      }
     },
     {
-      "code": 12201,
-      "name": "invalidAuctionBinsLength",
-      "msg": "Must have 1-10 auction bins"
+      "name": "custodyClaimEvent",
+      "docs": [
+        "Event emitted for each user settled by `claim_to_custody`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "type": "pubkey"
+          },
+          {
+            "name": "custody",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "binId",
+            "type": "u8"
+          },
+          {
+            "name": "saleTokens",
+            "docs": [
+              "Sale tokens transferred to the custody, net of the claim fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "claimFee",
+            "type": "u64"
+          },
+          {
+            "name": "refundPaymentTokens",
+            "type": "u64"
+          },
+          {
+            "name": "attribution",
+            "docs": [
+              "Attribution tag of the user's custody commitments"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12202,
-      "name": "invalidAuctionBinsPriceOrCap",
-      "msg": "Auction bin price and cap must be greater than zero"
+      "name": "custodyCommitEntry",
+      "docs": [
+        "Commitment made by the custody on behalf of a user in `custody_commit_batch`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paymentTokenCommitted",
+            "docs": [
+              "Payment tokens committed for the user"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "attribution",
+            "docs": [
+              "Attribution tag recorded with the commitment, e.g. the hash of an exchange user id"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12300,
-      "name": "outOfCommitmentPeriod",
-      "msg": "Out of commitment period"
+      "name": "emergencyControlEvent",
+      "docs": [
+        "Emergency control event"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "pausedOperations",
+            "type": "u64"
+          },
+          {
+            "name": "reasonCode",
+            "type": "u16"
+          },
+          {
+            "name": "message",
+            "type": "string"
+          }
+        ]
+      }
     },
     {
-      "code": 12301,
-      "name": "invalidCommitmentAmount",
-      "msg": "Invalid commitment amount"
+      "name": "emergencyControlParams",
+      "docs": [
+        "Emergency control parameters for instruction"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pauseAuctionCommit",
+            "type": "bool"
+          },
+          {
+            "name": "pauseAuctionClaim",
+            "type": "bool"
+          },
+          {
+            "name": "pauseAuctionWithdrawFees",
+            "type": "bool"
+          },
+          {
+            "name": "pauseAuctionWithdrawFunds",
+            "type": "bool"
+          },
+          {
+            "name": "pauseAuctionUpdation",
+            "type": "bool"
+          },
+          {
+            "name": "reasonCode",
+            "type": "u16"
+          },
+          {
+            "name": "message",
+            "type": "string"
+          }
+        ]
+      }
     },
     {
-      "code": 12302,
-      "name": "invalidBinId",
-      "msg": "Invalid bin ID"
+      "name": "emergencyState",
+      "docs": [
+        "Emergency control state (embedded in Auction)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pausedOperations",
+            "docs": [
+              "Paused operations bitmask"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reasonCode",
+            "docs": [
+              "Integrator-defined code for why operations are paused (0 when unspecified)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "message",
+            "docs": [
+              "Short human-readable reason, at most `MAX_PAUSE_MESSAGE_LEN` bytes"
+            ],
+            "type": "string"
+          }
+        ]
+      }
     },
     {
-      "code": 12303,
-      "name": "commitmentBinCapExceeded",
-      "msg": "Commitment bin cap exceeded"
+      "name": "feesReconciledEvent",
+      "docs": [
+        "Fee reconciliation event"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousTotalFeesCollected",
+            "type": "u64"
+          },
+          {
+            "name": "totalFeesCollected",
+            "type": "u64"
+          },
+          {
+            "name": "binsRepaired",
+            "type": "u8"
+          },
+          {
+            "name": "saleVaultShortfall",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12304,
-      "name": "outOfClaimPeriod",
-      "msg": "Out of claim period"
+      "name": "forceRefundModeEvent",
+      "docs": [
+        "Forced refund mode event"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "securityDepositSlashed",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12305,
-      "name": "invalidClaimAmount",
-      "msg": "Invalid claim amount"
+      "name": "frontendFees",
+      "docs": [
+        "Claim fee share of a front-end in an auction, accrued by `claim`,",
+        "`claim_across_auctions` and `issue_claim_voucher` for commitments made through it",
+        "PDA: [\"frontend_fees\", auction_key, frontend_id]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "docs": [
+              "Reference to the auction account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "frontend",
+            "docs": [
+              "Front-end, withdraws the fees with `withdraw_frontend_fees`"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "feesAccrued",
+            "docs": [
+              "Sale tokens of claim fees accrued to the front-end"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "feesWithdrawn",
+            "docs": [
+              "Sale tokens withdrawn already"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 12306,
-      "name": "commitCapExceeded",
-      "msg": "Commit cap exceeded"
+      "name": "globalConfig",
+      "docs": [
+        "Program-wide admin, rotatable without redeploying",
+        "PDA: [\"global_config\"]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "docs": [
+              "LaunchpadAdmin, authority of new auctions and of the launchpad config"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "protocolFee",
+            "docs": [
+              "Platform fee on the proceeds of every auction (if set)"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "protocolFee"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 12400,
-      "name": "inCommitmentPeriod",
-      "msg": "In commitment period"
+      "name": "identityGroup",
+      "docs": [
+        "Cumulative commitments of wallets linked to one identity, e.g. a KYC'd user",
+        "PDA: [\"identity_group\", auction_key, group_id]",
+        "",
+        "Wallets are linked by a whitelist signature naming the group, which then",
+        "shares a single `commit_cap_per_user` across all of them."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "docs": [
+              "Reference to the auction account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "groupId",
+            "docs": [
+              "Opaque group id assigned by the whitelist authority"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "paymentTokenCommitted",
+            "docs": [
+              "Payment tokens committed by all wallets of the group"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 12401,
-      "name": "doubleFundsWithdrawal",
-      "msg": "Double funds withdrawal"
+      "name": "lateRefundPool",
+      "docs": [
+        "Community pool receiving late refunds"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "docs": [
+              "Owner of the payment token account receiving the pool"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "rampPeriod",
+            "docs": [
+              "Seconds after the refund deadline over which the diverted share of late",
+              "refunds grows from nothing to all of it"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 12402,
-      "name": "noClaimFeesConfigured",
-      "msg": "No claim fees configured for this auction"
+      "name": "launchpadConfig",
+      "docs": [
+        "Global launchpad configuration maintained by the LaunchpadAdmin",
+        "PDA: [\"config\"]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "approvedPaymentMints",
+            "docs": [
+              "Payment mints new auctions may raise in"
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "allowUnlistedPaymentMints",
+            "docs": [
+              "Whether new auctions may raise in mints missing from `approved_payment_mints`"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "minClaimGracePeriod",
+            "docs": [
+              "Minimum seconds new auctions must leave between commit end and claim start"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "blockedSaleMints",
+            "docs": [
+              "Sale mints whose auctions can't `claim` or `withdraw_funds`, e.g. exploited tokens"
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "governanceAuthority",
+            "docs": [
+              "Governance or multisig expected to hold the program upgrade authority (if set)"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "revenueShare",
+            "docs": [
+              "Share of withdrawn fees routed to the launchpad token stakers (if set)"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "revenueShare"
+                }
+              }
+            }
+          },
+          {
+            "name": "pausedOperations",
+            "docs": [
+              "Operations paused on every auction, same flags as `EmergencyState`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "registeredFrontends",
+            "docs": [
+              "Integrating front-ends users may `commit` through"
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "frontendFeeShareBps",
+            "docs": [
+              "Share of the claim fees of commitments made through a front-end accrued to it,",
+              "in basis points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 12501,
-      "name": "missingSysvarInstructions",
-      "msg": "Missing sysvar instructions account"
+      "name": "launchpadStats",
+      "docs": [
+        "Launchpad-wide aggregate metrics, updated as auctions settle",
+        "PDA: [\"stats\"]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalAuctions",
+            "docs": [
+              "Number of finalized auctions"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "paymentMints",
+            "docs": [
+              "Totals per payment mint, the first `MAX_STATS_PAYMENT_MINTS` mints settled"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "paymentMintStats"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 12502,
-      "name": "wrongProgram",
-      "msg": "Wrong program ID for Ed25519 instruction"
+      "name": "nftEscrow",
+      "docs": [
+        "NFT escrowed as a commitment in NFT payment mode",
+        "PDA: [\"nft_escrow\", auction_key, nft_mint]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "docs": [
+              "Reference to the auction account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "docs": [
+              "User who escrowed the NFT"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "nftMint",
+            "docs": [
+              "Mint of the escrowed NFT"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "binId",
+            "docs": [
+              "Bin the NFT is committed to"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "vaultBump",
+            "docs": [
+              "NFT vault PDA bump seed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 12503,
-      "name": "malformedEd25519Ix",
-      "msg": "Malformed Ed25519 instruction"
+      "name": "nftPaymentConfig",
+      "docs": [
+        "NFT payment mode configuration"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collection",
+            "docs": [
+              "Verified Metaplex collection the escrowed NFTs must belong to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "paymentValue",
+            "docs": [
+              "Payment value each escrowed NFT counts as"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12504,
-      "name": "wrongWhitelistAuthority",
-      "msg": "Wrong whitelist authority"
+      "name": "paymentMintStats",
+      "docs": [
+        "Launchpad-wide totals of auctions raising in one payment mint"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "Payment token mint"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "auctions",
+            "docs": [
+              "Number of finalized auctions raising in the mint"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalRaised",
+            "docs": [
+              "Payment tokens raised and kept, i.e. excluding refunds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalFees",
+            "docs": [
+              "Claim fees withdrawn, valued in payment tokens at the price of their bins"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12505,
-      "name": "payloadMismatch",
-      "msg": "Payload mismatch in signature verification"
+      "name": "priceStaleEvent",
+      "docs": [
+        "Commitment refused at a stale bin price"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "type": "pubkey"
+          },
+          {
+            "name": "binId",
+            "type": "u8"
+          },
+          {
+            "name": "saleTokenPrice",
+            "type": "u64"
+          },
+          {
+            "name": "priceUpdatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 12506,
-      "name": "signatureExpired",
-      "msg": "Signature expired"
+      "name": "proceedsBurnedEvent",
+      "docs": [
+        "Buy-and-burn proceeds event"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "type": "pubkey"
+          },
+          {
+            "name": "paymentTokensBurned",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12507,
-      "name": "nonceOverflow",
-      "msg": "Nonce overflow"
+      "name": "proceedsSplit",
+      "docs": [
+        "Platform treasury share of the proceeds"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "treasury",
+            "docs": [
+              "Owner of the payment token account receiving the platform share"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "treasuryBps",
+            "docs": [
+              "Platform share of the withdrawn payment tokens in basis points"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 12508,
-      "name": "serializationError",
-      "msg": "Serialization error"
+      "name": "programAuthorityStatus",
+      "docs": [
+        "Upgrade-risk status of the program, returned by `verify_program_authority`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "upgradeAuthority",
+            "docs": [
+              "Current upgrade authority, none if the program is immutable"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "governanceAuthority",
+            "docs": [
+              "Governance or multisig configured in `LaunchpadConfig` (if set)"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "isGoverned",
+            "docs": [
+              "Whether the upgrade authority is the configured governance"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 12509,
-      "name": "missingExpiry",
-      "msg": "Missing expiry timestamp for whitelist verification"
+      "name": "projectSummary",
+      "docs": [
+        "Dashboard overview of an auction for its project team, returned by `get_project_summary`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bins",
+            "docs": [
+              "Per-bin progress, indexed by bin ID"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "binSummary"
+                }
+              }
+            }
+          },
+          {
+            "name": "totalPaymentRaised",
+            "docs": [
+              "Payment tokens raised across all bins"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalParticipants",
+            "docs": [
+              "Number of distinct users who committed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalFeesCollected",
+            "docs": [
+              "Claim fees collected so far"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalFeesWithdrawn",
+            "docs": [
+              "Claim fees withdrawn by `withdraw_fees`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "saleClaimProgressBps",
+            "docs": [
+              "Share of sold sale tokens claimed, in basis points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "refundProgressBps",
+            "docs": [
+              "Share of owed refunds paid out, in basis points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "finalized",
+            "docs": [
+              "Whether `finalize_auction` has run"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "fundsWithdrawn",
+            "docs": [
+              "Whether `withdraw_funds` has run"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "totalPaymentWithdrawn",
+            "docs": [
+              "Payment tokens withdrawn by `withdraw_funds`"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12510,
-      "name": "invalidCustodyAuthority",
-      "msg": "Invalid custody authority"
-    }
-  ],
-  "types": [
-    {
-      "name": "auction",
+      "name": "protocolFee",
       "docs": [
-        "Core auction data account",
-        "PDA: [\"auction\", sale_token_mint]"
+        "Platform fee taken from the payment tokens of every `withdraw_funds`"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authority",
+            "name": "treasury",
             "docs": [
-              "Launchpad admin"
+              "Owner of the platform treasury, one token account per payment mint"
             ],
             "type": "pubkey"
           },
           {
-            "name": "custody",
+            "name": "feeBps",
             "docs": [
-              "Custody account for special permissions"
+              "Share of the withdrawn payment tokens sent to the treasury, in basis points"
             ],
-            "type": "pubkey"
-          },
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "redemption",
+      "docs": [
+        "IOU balance of a user in a pre-TGE auction, or the placeholder sale tokens they",
+        "claimed from an auction selling a placeholder mint",
+        "PDA: [\"redemption\", auction_key, user_key]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "saleTokenMint",
+            "name": "auction",
             "docs": [
-              "Sale token mint"
+              "Reference to the auction account"
             ],
             "type": "pubkey"
           },
           {
-            "name": "paymentTokenMint",
+            "name": "user",
             "docs": [
-              "Payment token mint"
+              "User who owns the IOU"
             ],
             "type": "pubkey"
           },
           {
-            "name": "commitStartTime",
+            "name": "saleTokenOwed",
             "docs": [
-              "Auction timing"
+              "Sale tokens owed to the user, delivered by `redeem` once redemption is enabled"
             ],
-            "type": "i64"
+            "type": "u64"
           },
           {
-            "name": "commitEndTime",
-            "type": "i64"
+            "name": "placeholderSwappable",
+            "docs": [
+              "Placeholder sale tokens claimed by the user, net of claim fees, not yet swapped",
+              "for real ones by `swap_placeholder`"
+            ],
+            "type": "u64"
           },
           {
-            "name": "claimStartTime",
-            "type": "i64"
-          },
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "remainingEntitlement",
+      "docs": [
+        "Amounts a user can still claim from a bin, returned by `get_remaining_entitlement`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "bins",
+            "name": "saleTokens",
             "docs": [
-              "Auction bins (up to 10 bins)"
+              "Sale tokens not claimed yet"
             ],
-            "type": {
-              "vec": {
-                "defined": {
-                  "name": "auctionBin"
-                }
-              }
-            }
+            "type": "u64"
           },
           {
-            "name": "extensions",
+            "name": "refundPaymentTokens",
             "docs": [
-              "Extension configuration (directly embedded)"
+              "Payment tokens not refunded yet"
             ],
-            "type": {
-              "defined": {
-                "name": "auctionExtensions"
-              }
-            }
-          },
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "revenueShare",
+      "docs": [
+        "Fee routing to the staking pool of the launchpad token"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "emergencyState",
+            "name": "stakingRewardsAuthority",
             "docs": [
-              "Emergency control state (newly added)"
+              "Owner of the staking rewards vaults, one token account per fee mint"
             ],
-            "type": {
-              "defined": {
-                "name": "emergencyState"
-              }
-            }
+            "type": "pubkey"
           },
           {
-            "name": "totalParticipants",
+            "name": "shareBps",
             "docs": [
-              "Total number of unique participants in this auction"
+              "Share of every `withdraw_fees` sent to the staking rewards vault, in basis points"
             ],
-            "type": "u64"
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "round",
+      "docs": [
+        "One of consecutive rounds (seed, strategic, public...) of an auction",
+        "PDA: [\"round\", auction_key, index]",
+        "",
+        "Rounds sell their own bins against the auction's single sale-token vault.",
+        "Closing a round rolls its unsold supply forward into the next round."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "docs": [
+              "Reference to the auction account"
+            ],
+            "type": "pubkey"
           },
           {
-            "name": "unsoldSaleTokensAndEffectivePaymentTokensWithdrawn",
+            "name": "index",
             "docs": [
-              "Whether the unsold sale tokens and effective payment tokens have been",
-              "withdrawn, which is used to prevent double withdrawal by `withdraw_funds`"
+              "Round index, matching `AuctionBin::round`"
             ],
-            "type": "bool"
+            "type": "u8"
           },
           {
-            "name": "totalFeesCollected",
+            "name": "commitStartTime",
             "docs": [
-              "Total fees collected from claimed sale tokens"
+              "Round commit period, within the auction commit period"
             ],
-            "type": "u64"
+            "type": "i64"
           },
           {
-            "name": "totalFeesWithdrawn",
+            "name": "commitEndTime",
+            "type": "i64"
+          },
+          {
+            "name": "whitelistAuthority",
             "docs": [
-              "Fees withdrawn already"
+              "Whitelist authority overriding the auction's for this round (if set)"
             ],
-            "type": "u64"
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "vaultSaleBump",
+            "name": "bump",
             "docs": [
-              "Vault PDA bump seeds for derivation"
+              "PDA bump seed"
             ],
             "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "saleTokensToppedUpEvent",
+      "docs": [
+        "Sale token top-up event"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "type": "pubkey"
           },
           {
-            "name": "vaultPaymentBump",
+            "name": "binId",
             "type": "u8"
           },
           {
-            "name": "bump",
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "saleTokenCap",
+            "type": "u64"
+          },
+          {
+            "name": "allocationRatio",
             "docs": [
-              "PDA bump seed"
+              "Allocation ratio of the bin with its current commitments, scaled by `PRECISION_FACTOR`"
             ],
-            "type": "u8"
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "auctionBin",
+      "name": "securityDeposit",
       "docs": [
-        "Individual auction bin data"
+        "Project security deposit deterring cancelled launches"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lamports",
+            "docs": [
+              "Lamports escrowed from the authority"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "treasury",
+            "docs": [
+              "Account receiving the slashed deposit (if set), otherwise it is split among",
+              "participants pro-rata to their commitments as they settle"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "settlementSimulation",
+      "docs": [
+        "Settlement preview of an auction, returned by `simulate_settlement`"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "saleTokenPrice",
+            "name": "totalPaymentRaised",
+            "docs": [
+              "Payment tokens raised across all bins"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalSaleTokensSold",
+            "docs": [
+              "Sale tokens owed to users"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalUnsoldSaleTokens",
+            "docs": [
+              "Sale tokens left unsold"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalRefundsOutstanding",
+            "docs": [
+              "Payment tokens still to be refunded to users"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalFeesCollected",
+            "docs": [
+              "Claim fees collected so far"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalFeesProjected",
             "docs": [
-              "Price per sale token (in payment tokens)"
+              "Claim fees collected once every sold sale token is claimed"
             ],
             "type": "u64"
           },
           {
-            "name": "saleTokenCap",
+            "name": "withdrawableFees",
             "docs": [
-              "Maximum sale tokens this bin can sell"
+              "Claim fees `withdraw_fees` would transfer now"
             ],
             "type": "u64"
           },
           {
-            "name": "paymentTokenRaised",
+            "name": "withdrawablePaymentTokens",
             "docs": [
-              "Payment tokens actually raised in this bin"
+              "Payment tokens `withdraw_funds` would transfer now"
             ],
             "type": "u64"
           },
           {
-            "name": "saleTokenClaimed",
+            "name": "withdrawableUnsoldSaleTokens",
             "docs": [
-              "Sale tokens already claimed from this bin"
+              "Unsold sale tokens `withdraw_funds` would transfer now"
             ],
             "type": "u64"
           }
@@ -1571,104 +12671,86 @@ export type LaunchpadProgram = {
       }
     },
     {
-      "name": "auctionBinParams",
+      "name": "surplusRecoveredEvent",
       "docs": [
-        "Parameters for creating auction bins"
+        "Surplus recovery event"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "saleTokenPrice",
+            "name": "auction",
+            "type": "pubkey"
+          },
+          {
+            "name": "saleTokenLiabilities",
             "type": "u64"
           },
           {
-            "name": "saleTokenCap",
+            "name": "saleTokenSurplus",
             "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "auctionExtensions",
-      "docs": [
-        "Extension configuration data (embedded in Auction)"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "whitelistAuthority",
-            "docs": [
-              "Whitelist authority for access control"
-            ],
-            "type": {
-              "option": "pubkey"
-            }
+            "name": "paymentTokenLiabilities",
+            "type": "u64"
           },
           {
-            "name": "commitCapPerUser",
-            "docs": [
-              "Per-user commitment cap (if enabled)"
-            ],
-            "type": {
-              "option": "u64"
-            }
+            "name": "paymentTokenSurplus",
+            "type": "u64"
           },
           {
-            "name": "claimFeeRate",
-            "docs": [
-              "Claim fee rate (if enabled)"
-            ],
-            "type": {
-              "option": "u64"
-            }
+            "name": "redemptionTokenSurplus",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "committed",
+      "name": "template",
       "docs": [
-        "User commitment data for all auction bins",
-        "PDA: [\"committed\", auction_key, user_key]"
+        "Reusable auction configuration for `clone_auction`",
+        "PDA: [\"template\", template_id]"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "auction",
-            "docs": [
-              "Reference to the auction account"
-            ],
-            "type": "pubkey"
-          },
-          {
-            "name": "user",
+            "name": "templateId",
             "docs": [
-              "User who made the commitment"
+              "Template identifier"
             ],
-            "type": "pubkey"
+            "type": "u64"
           },
           {
             "name": "bins",
             "docs": [
-              "All bins this user has committed to"
+              "Bins of auctions cloned from this template"
             ],
             "type": {
               "vec": {
                 "defined": {
-                  "name": "committedBin"
+                  "name": "auctionBinParams"
                 }
               }
             }
           },
           {
-            "name": "nonce",
+            "name": "custody",
             "docs": [
-              "User's nonce for whitelist signature verification (prevents replay attacks)"
+              "Custody account of auctions cloned from this template"
             ],
-            "type": "u64"
+            "type": "pubkey"
+          },
+          {
+            "name": "extensions",
+            "docs": [
+              "Extension configuration, including fee settings"
+            ],
+            "type": {
+              "defined": {
+                "name": "auctionExtensions"
+              }
+            }
           },
           {
             "name": "bump",
@@ -1681,219 +12763,253 @@ export type LaunchpadProgram = {
       }
     },
     {
-      "name": "committedAccountClosedEvent",
+      "name": "timesUpdatedEvent",
       "docs": [
-        "Event emitted when a user's Committed account is fully claimed and closed"
+        "Auction times update event"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "userKey",
-            "docs": [
-              "User who owned the committed account"
-            ],
+            "name": "auction",
             "type": "pubkey"
           },
           {
-            "name": "auctionKey",
-            "docs": [
-              "The auction this commitment was for"
-            ],
+            "name": "authority",
             "type": "pubkey"
           },
           {
-            "name": "committedAccountKey",
-            "docs": [
-              "The committed account that was closed"
-            ],
-            "type": "pubkey"
+            "name": "previousCommitStartTime",
+            "type": "i64"
           },
           {
-            "name": "rentReturned",
-            "docs": [
-              "Amount of rent returned to the user (in lamports)"
-            ],
-            "type": "u64"
+            "name": "previousCommitEndTime",
+            "type": "i64"
           },
           {
-            "name": "committedData",
-            "docs": [
-              "Snapshot of the committed account data at time of closure"
-            ],
-            "type": {
-              "defined": {
-                "name": "committedAccountSnapshot"
-              }
-            }
+            "name": "previousClaimStartTime",
+            "type": "i64"
+          },
+          {
+            "name": "commitStartTime",
+            "type": "i64"
+          },
+          {
+            "name": "commitEndTime",
+            "type": "i64"
+          },
+          {
+            "name": "claimStartTime",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "committedAccountSnapshot",
+      "name": "unclaimedSweptEvent",
       "docs": [
-        "Snapshot of Committed account data for the closure event"
+        "Unclaimed tokens swept after the claim deadline"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "auction",
-            "docs": [
-              "Reference to the auction account"
-            ],
             "type": "pubkey"
           },
+          {
+            "name": "saleTokensSwept",
+            "type": "u64"
+          },
+          {
+            "name": "paymentTokensSwept",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userProfile",
+      "docs": [
+        "Cross-auction loyalty profile of a user, updated when a commitment is fully claimed",
+        "PDA: [\"user_profile\", user_key]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
             "name": "user",
             "docs": [
-              "User who made the commitment"
+              "Profile owner"
             ],
             "type": "pubkey"
           },
           {
-            "name": "bins",
+            "name": "auctionsParticipated",
             "docs": [
-              "All bins this user committed to"
+              "Number of auctions fully claimed"
             ],
-            "type": {
-              "vec": {
-                "defined": {
-                  "name": "committedBin"
-                }
-              }
-            }
+            "type": "u64"
           },
           {
-            "name": "bump",
+            "name": "totalPaymentCommitted",
             "docs": [
-              "PDA bump seed"
+              "Cumulative payment tokens committed across those auctions"
             ],
-            "type": "u8"
+            "type": "u64"
           },
           {
-            "name": "totalPaymentCommitted",
+            "name": "totalSaleTokensClaimed",
             "docs": [
-              "Total payment tokens committed across all bins"
+              "Cumulative sale tokens claimed across those auctions"
             ],
             "type": "u64"
           },
           {
-            "name": "totalSaleTokensClaimed",
+            "name": "bump",
             "docs": [
-              "Total sale tokens claimed across all bins"
+              "PDA bump seed"
             ],
-            "type": "u64"
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "committedBin",
+      "name": "vestingSchedule",
       "docs": [
-        "Individual bin commitment data within a user's commitment"
+        "Unlock timeline of a user's sale tokens in a bin, returned by `get_vesting_schedule`"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "binId",
+            "name": "totalSaleTokens",
             "docs": [
-              "Bin ID"
+              "Sale tokens the user is entitled to"
             ],
-            "type": "u8"
+            "type": "u64"
           },
           {
-            "name": "paymentTokenCommitted",
+            "name": "unlockedSaleTokens",
             "docs": [
-              "Amount of payment tokens committed to this bin"
+              "Sale tokens unlocked so far, claimed or not"
             ],
             "type": "u64"
           },
           {
-            "name": "saleTokenClaimed",
+            "name": "claimedSaleTokens",
             "docs": [
-              "Amount of sale tokens already claimed from this bin"
+              "Sale tokens claimed so far"
             ],
             "type": "u64"
           },
           {
-            "name": "paymentTokenRefunded",
+            "name": "cliffTime",
             "docs": [
-              "Payment tokens already refunded from this bin"
+              "Time before which nothing unlocks"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "nextUnlockTime",
+            "docs": [
+              "Time of the next unlock (if any sale tokens are still locked)"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "nextUnlockAmount",
+            "docs": [
+              "Sale tokens unlocking at `next_unlock_time`"
             ],
             "type": "u64"
+          },
+          {
+            "name": "fullyVestedTime",
+            "docs": [
+              "Time at which every sale token is unlocked"
+            ],
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "emergencyControlEvent",
+      "name": "waitlistEntry",
       "docs": [
-        "Emergency control event"
+        "Payment tokens escrowed while waiting for room in a full hard-cap bin",
+        "PDA: [\"waitlist\", auction_key, bin_id, sequence]"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "auction",
+            "docs": [
+              "Reference to the auction account"
+            ],
             "type": "pubkey"
           },
           {
-            "name": "authority",
+            "name": "user",
+            "docs": [
+              "Waiting user"
+            ],
             "type": "pubkey"
           },
           {
-            "name": "pausedOperations",
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "emergencyControlParams",
-      "docs": [
-        "Emergency control parameters for instruction"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "pauseAuctionCommit",
-            "type": "bool"
-          },
-          {
-            "name": "pauseAuctionClaim",
-            "type": "bool"
+            "name": "binId",
+            "docs": [
+              "Bin the user waits for"
+            ],
+            "type": "u8"
           },
           {
-            "name": "pauseAuctionWithdrawFees",
-            "type": "bool"
+            "name": "sequence",
+            "docs": [
+              "Position in the bin's waitlist"
+            ],
+            "type": "u64"
           },
           {
-            "name": "pauseAuctionWithdrawFunds",
-            "type": "bool"
+            "name": "paymentTokenAmount",
+            "docs": [
+              "Payment tokens escrowed, committed in full on promotion"
+            ],
+            "type": "u64"
           },
           {
-            "name": "pauseAuctionUpdation",
-            "type": "bool"
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "emergencyState",
+      "name": "withdrawCoApproval",
       "docs": [
-        "Emergency control state (embedded in Auction)"
+        "Co-signer required by large `withdraw_funds`"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "pausedOperations",
+            "name": "coSigner",
             "docs": [
-              "Paused operations bitmask"
+              "Key co-signing withdrawals above the threshold"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "Payment tokens a withdrawal may take without the co-signer"
             ],
             "type": "u64"
           }